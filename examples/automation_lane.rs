@@ -0,0 +1,117 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Length, Result, Size, application,
+    widget::{column, text},
+};
+use iced_audio::{
+    AutomationLane, Normal,
+    automation_lane::{self, Breakpoint, LaneGesture},
+    text_marks,
+};
+
+fn main() -> Result {
+    application(
+        AutomationLaneExample::default,
+        AutomationLaneExample::update,
+        AutomationLaneExample::view,
+    )
+    .window_size(Size::new(700.0, 500.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Cutoff(LaneGesture),
+    Volume(LaneGesture),
+}
+
+pub struct AutomationLaneExample {
+    cutoff: Vec<Breakpoint>,
+    volume: Vec<Breakpoint>,
+
+    cutoff_text_marks: text_marks::Group,
+    volume_text_marks: text_marks::Group,
+
+    output_text: String,
+}
+
+impl Default for AutomationLaneExample {
+    fn default() -> Self {
+        Self {
+            // The lanes show 8 beats of automation.
+            cutoff: vec![
+                Breakpoint::new(0.0, Normal::new(0.2)).tension(Normal::new(0.8)),
+                Breakpoint::new(2.0, Normal::new(0.9)),
+                Breakpoint::new(4.0, Normal::new(0.5)).tension(Normal::new(0.2)),
+                Breakpoint::new(7.0, Normal::new(0.1)),
+            ],
+            volume: vec![
+                Breakpoint::new(0.0, Normal::new(0.0)).tension(Normal::new(0.7)),
+                Breakpoint::new(1.0, Normal::new(0.8)),
+                Breakpoint::new(6.0, Normal::new(0.8)),
+                Breakpoint::new(8.0, Normal::new(0.0)),
+            ],
+
+            cutoff_text_marks: text_marks::Group::min_max_and_center("20 Hz", "20 kHz", "640 Hz"),
+            volume_text_marks: text_marks::Group::evenly_spaced(&[
+                "-inf", "-24 dB", "-12 dB", "-6 dB", "0 dB",
+            ]),
+
+            output_text: String::new(),
+        }
+    }
+}
+
+impl AutomationLaneExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Cutoff(LaneGesture::Gesturing(breakpoints)) => {
+                self.output_text = format!(
+                    "Cutoff: {} breakpoints, value at beat 3: {:.3}",
+                    breakpoints.len(),
+                    automation_lane::value_at(&breakpoints, 3.0)
+                        .map(|normal| normal.as_f32())
+                        .unwrap_or_default(),
+                );
+                self.cutoff = breakpoints;
+            }
+            Message::Volume(LaneGesture::Gesturing(breakpoints)) => {
+                self.output_text = format!("Volume: {} breakpoints", breakpoints.len());
+                self.volume = breakpoints;
+            }
+            _ => {}
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let cutoff_lane = AutomationLane::new(&self.cutoff)
+            .visible_range(0.0, 8.0)
+            .text_marks(&self.cutoff_text_marks)
+            .on_gesture(Message::Cutoff);
+
+        let volume_lane = AutomationLane::new(&self.volume)
+            .visible_range(0.0, 8.0)
+            .height(Length::Fixed(100.0))
+            .text_marks(&self.volume_text_marks)
+            .on_gesture(Message::Volume)
+            .style(style::automation_lane::CustomStyle);
+
+        column![
+            text(
+                "Double-click to add, Delete to remove, drag to select, \
+                 Alt + drag a segment to bend it"
+            )
+            .size(14),
+            text("Filter Cutoff"),
+            cutoff_lane,
+            text("Volume (Custom Style)"),
+            volume_lane,
+            text(&self.output_text).size(16),
+        ]
+        .spacing(16)
+        .padding(20)
+        .into()
+    }
+}
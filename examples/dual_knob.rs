@@ -0,0 +1,129 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Length, Result, Size, application,
+    widget::{column, row, text},
+};
+use iced_audio::{DualKnob, FloatRange, Gesture, IntRange, NormalParam};
+
+use crate::util::info_text::{info_text_f32, info_text_i32};
+
+fn main() -> Result {
+    application(
+        DualKnobExample::default,
+        DualKnobExample::update,
+        DualKnobExample::view,
+    )
+    .window_size(Size::new(600.0, 400.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Coarse(Gesture),
+    Fine(Gesture),
+    Pan(Gesture),
+    Width(Gesture),
+}
+
+pub struct DualKnobExample {
+    coarse_range: IntRange,
+    fine_range: FloatRange,
+    pan_range: FloatRange,
+    width_range: FloatRange,
+
+    coarse_param: NormalParam,
+    fine_param: NormalParam,
+    pan_param: NormalParam,
+    width_param: NormalParam,
+
+    output_text: String,
+}
+
+impl Default for DualKnobExample {
+    fn default() -> Self {
+        // initalize the parameter ranges
+        let coarse_range = IntRange::new(-24, 24);
+        let fine_range = FloatRange::new(-100.0, 100.0);
+        let pan_range = FloatRange::NORMAL_BIPOLAR;
+        let width_range = FloatRange::new(0.0, 200.0);
+
+        Self {
+            coarse_range,
+            fine_range,
+            pan_range,
+            width_range,
+
+            // initialize the state of the dual knob widgets
+            coarse_param: coarse_range.param(0, 0),
+            fine_param: fine_range.param(0.0, 0.0),
+            pan_param: pan_range.default_param(),
+            width_param: width_range.param(100.0, 100.0),
+
+            output_text: String::new(),
+        }
+    }
+}
+
+impl DualKnobExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Coarse(Gesture::Gesturing(normal)) => {
+                // Snap the coarse tune to whole semitones.
+                self.coarse_param.set(self.coarse_range.snap(normal));
+                self.output_text = info_text_i32("Coarse", normal, &self.coarse_range);
+            }
+            Message::Fine(Gesture::Gesturing(normal)) => {
+                self.fine_param.set(normal);
+                self.output_text = info_text_f32("Fine", normal, &self.fine_range);
+            }
+            Message::Pan(Gesture::Gesturing(normal)) => {
+                self.pan_param.set(normal);
+                self.output_text = info_text_f32("Pan", normal, &self.pan_range);
+            }
+            Message::Width(Gesture::Gesturing(normal)) => {
+                self.width_param.set(normal);
+                self.output_text = info_text_f32("Width", normal, &self.width_range);
+            }
+            _ => {}
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        // create each of the DualKnob widgets, passing in the values of
+        // the parameters of both rings
+
+        let tune = DualKnob::new(self.coarse_param, self.fine_param)
+            .on_gesture_outer(Message::Coarse)
+            .on_gesture_inner(Message::Fine);
+
+        let pan_width = DualKnob::new(self.pan_param, self.width_param)
+            .size(Length::Fixed(56.0))
+            .inner_size(0.55)
+            .on_gesture_outer(Message::Pan)
+            .on_gesture_inner(Message::Width)
+            .style(style::dual_knob::CustomStyle);
+
+        // push the widgets into rows
+        let knob_row = row![
+            column![text("Coarse / Fine Tune"), tune]
+                .width(Length::Fill)
+                .spacing(10),
+            column![text("Pan / Width (Custom Style)"), pan_width]
+                .width(Length::Fill)
+                .spacing(10),
+        ]
+        .spacing(20);
+
+        column![
+            knob_row,
+            text("Drag the outer ring or the center. Hold Alt to always control the inner ring.")
+                .size(14),
+            text(&self.output_text).size(16),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,148 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Length, Result, Size, Subscription, application,
+    widget::{column, row, text},
+    window,
+};
+use iced_audio::{
+    DBRange, GainReductionMeter, Gesture, Normal, NormalParam, VSlider, gain_reduction_meter,
+    text_marks, tick_marks,
+};
+use std::time::{Duration, Instant};
+
+const GR_RANGE: DBRange = DBRange::new(-24.0, 0.0, Normal::MAX, 1.0);
+const OUTPUT_RANGE: DBRange = DBRange::new(-60.0, 12.0, Normal::new(0.83), 2.0);
+
+fn main() -> Result {
+    application(
+        GainReductionMeterExample::default,
+        GainReductionMeterExample::update,
+        GainReductionMeterExample::view,
+    )
+    .subscription(GainReductionMeterExample::subscription)
+    .window_size(Size::new(600.0, 480.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Output(Gesture),
+    Frame(Instant),
+}
+
+pub struct GainReductionMeterExample {
+    output: NormalParam,
+
+    start: Instant,
+    gain_reduction: f32,
+
+    tick_marks: tick_marks::Group,
+    text_marks: text_marks::Group,
+}
+
+impl Default for GainReductionMeterExample {
+    fn default() -> Self {
+        let tick_marks: Vec<_> = [0.0, 3.0, 6.0, 9.0, 12.0, 18.0, 24.0]
+            .into_iter()
+            .map(|db: f32| {
+                let tier = if db % 6.0 == 0.0 {
+                    tick_marks::Tier::One
+                } else {
+                    tick_marks::Tier::Two
+                };
+                (GR_RANGE.map_db(-db), tier)
+            })
+            .collect();
+
+        let text_marks: Vec<_> = [0.0, 6.0, 12.0, 24.0]
+            .into_iter()
+            .map(|db: f32| (GR_RANGE.map_db(-db), format!("{db}")))
+            .collect();
+
+        Self {
+            output: OUTPUT_RANGE.default_param(),
+
+            start: Instant::now(),
+            gain_reduction: 0.0,
+
+            tick_marks: tick_marks::Group::from_normalized(&tick_marks),
+            text_marks: text_marks.into(),
+        }
+    }
+}
+
+impl GainReductionMeterExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Output(Gesture::Gesturing(normal)) => self.output.set(normal),
+            Message::Frame(now) => {
+                // Fake a compressor reacting to a drum loop: sharp hits that
+                // get louder and quieter over time.
+                let time = (now - self.start).as_secs_f32();
+                let beat = (time * 2.0).fract();
+                let swell = 0.5 + 0.5 * (time * 0.4).sin();
+
+                self.gain_reduction = if beat < 0.08 { 4.0 + 16.0 * swell } else { 0.0 };
+            }
+            _ => {}
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Frame)
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let fader = VSlider::new(self.output)
+            .height(Length::Fixed(260.0))
+            .on_gesture(Message::Output);
+
+        let meter = GainReductionMeter::new(self.gain_reduction)
+            .range(GR_RANGE)
+            .height(Length::Fixed(260.0))
+            .tick_marks(&self.tick_marks)
+            .text_marks(&self.text_marks);
+
+        let instant_meter = GainReductionMeter::new(self.gain_reduction)
+            .range(GR_RANGE)
+            .ballistics(gain_reduction_meter::Ballistics::INSTANT)
+            .height(Length::Fixed(260.0))
+            .tick_marks(&self.tick_marks);
+
+        let slow_meter = GainReductionMeter::new(self.gain_reduction)
+            .range(GR_RANGE)
+            .ballistics(gain_reduction_meter::Ballistics::new(
+                Duration::from_millis(20),
+                Duration::from_millis(1200),
+            ))
+            .width(Length::Fixed(20.0))
+            .height(Length::Fixed(260.0))
+            .tick_marks(&self.tick_marks)
+            .text_marks(&self.text_marks)
+            .style(style::gain_reduction_meter::CustomStyle);
+
+        let horizontal_meter = GainReductionMeter::new(self.gain_reduction)
+            .range(GR_RANGE)
+            .orientation(gain_reduction_meter::Orientation::Horizontal)
+            .width(Length::Fixed(300.0))
+            .tick_marks(&self.tick_marks)
+            .text_marks(&self.text_marks);
+
+        column![
+            text("Default ballistics, instant, and slow release").size(14),
+            row![fader, meter, instant_meter, slow_meter].spacing(40),
+            horizontal_meter,
+            text(format!(
+                "Gain reduction: {:.1} dB  Output: {:.1} dB",
+                self.gain_reduction,
+                OUTPUT_RANGE.unmap_to_db(self.output.normal),
+            ))
+            .size(16),
+        ]
+        .spacing(30)
+        .padding(40)
+        .into()
+    }
+}
@@ -0,0 +1,147 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Length, Result, Size, Subscription, application,
+    widget::{button, column, row, text},
+    window,
+};
+use iced_audio::{LoudnessMeter, loudness, loudness_meter::Scale, text_marks, tick_marks};
+use std::time::Instant;
+
+const SAMPLE_RATE: f32 = 48_000.0;
+
+fn main() -> Result {
+    application(
+        LoudnessMeterExample::default,
+        LoudnessMeterExample::update,
+        LoudnessMeterExample::view,
+    )
+    .subscription(LoudnessMeterExample::subscription)
+    .window_size(Size::new(500.0, 440.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Frame(Instant),
+    Reset,
+}
+
+pub struct LoudnessMeterExample {
+    analyzer: loudness::Analyzer,
+    measurement: loudness::Measurement,
+
+    tick_marks: tick_marks::Group,
+    text_marks: text_marks::Group,
+    wide_tick_marks: tick_marks::Group,
+    wide_text_marks: text_marks::Group,
+
+    last_frame: Option<Instant>,
+    time: f32,
+    noise: u32,
+    left: Vec<f32>,
+    right: Vec<f32>,
+}
+
+impl Default for LoudnessMeterExample {
+    fn default() -> Self {
+        Self {
+            analyzer: loudness::Analyzer::new(SAMPLE_RATE, 2),
+            measurement: loudness::Measurement::EMPTY,
+
+            tick_marks: Scale::EBU_PLUS_9.tick_marks(3.0),
+            text_marks: Scale::EBU_PLUS_9.text_marks(9.0),
+            wide_tick_marks: Scale::EBU_PLUS_18.tick_marks(6.0),
+            wide_text_marks: Scale::EBU_PLUS_18.text_marks(18.0),
+
+            last_frame: None,
+            time: 0.0,
+            noise: 0x1234_5678,
+            left: Vec::new(),
+            right: Vec::new(),
+        }
+    }
+}
+
+impl LoudnessMeterExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Frame(now) => {
+                let elapsed = self
+                    .last_frame
+                    .map_or(0.0, |last| (now - last).as_secs_f32().min(0.1));
+                self.last_frame = Some(now);
+
+                self.synthesize((elapsed * SAMPLE_RATE) as usize);
+                self.analyzer.process(&[&self.left, &self.right]);
+                self.measurement = self.analyzer.measurement();
+            }
+            Message::Reset => {
+                self.analyzer.reset();
+                self.measurement = self.analyzer.measurement();
+            }
+        }
+    }
+
+    /// Fakes program material: a chord over noise whose level swells up and
+    /// down every few seconds.
+    fn synthesize(&mut self, len: usize) {
+        self.left.clear();
+        self.right.clear();
+
+        for _ in 0..len {
+            self.time += 1.0 / SAMPLE_RATE;
+
+            self.noise ^= self.noise << 13;
+            self.noise ^= self.noise >> 17;
+            self.noise ^= self.noise << 5;
+            let white = (self.noise as f32 / u32::MAX as f32) * 2.0 - 1.0;
+
+            let t = self.time * std::f32::consts::TAU;
+            let chord = (t * 220.0).sin() + (t * 277.2).sin() + (t * 329.6).sin();
+            let level = 0.06 + 0.05 * (self.time * 0.7).sin() + 0.04 * (self.time * 2.3).sin();
+
+            self.left.push((chord * 0.3 + white * 0.2) * level);
+            self.right.push((chord * 0.3 - white * 0.2) * level);
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Frame)
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let meter = LoudnessMeter::new(self.measurement)
+            .tick_marks(&self.tick_marks)
+            .text_marks(&self.text_marks);
+
+        let custom_meter = LoudnessMeter::new(self.measurement)
+            .target(-16.0)
+            .scale(Scale::EBU_PLUS_18)
+            .height(Length::Fixed(300.0))
+            .tick_marks(&self.wide_tick_marks)
+            .text_marks(&self.wide_text_marks)
+            .style(style::loudness_meter::CustomStyle);
+
+        let m = self.measurement;
+        let readout = column![
+            text(format!("Momentary: {:.1} LUFS", m.momentary)),
+            text(format!("Short-term: {:.1} LUFS", m.short_term)),
+            text(format!("Integrated: {:.1} LUFS", m.integrated)),
+            text(format!("Loudness range: {:.1} LU", m.loudness_range())),
+            text(format!("True peak: {:.1} dBTP", m.true_peak)),
+            button("Reset").on_press(Message::Reset),
+        ]
+        .spacing(8);
+
+        row![
+            column![text("EBU +9, -23 LUFS"), meter].spacing(10),
+            column![text("EBU +18, -16 LUFS"), custom_meter].spacing(10),
+            readout,
+        ]
+        .spacing(50)
+        .padding(30)
+        .into()
+    }
+}
@@ -0,0 +1,93 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Length, Result, Size, application,
+    widget::{column, text},
+};
+use iced_audio::{MultiSlider, Normal, multi_slider::BarGesture};
+
+fn main() -> Result {
+    application(
+        MultiSliderExample::default,
+        MultiSliderExample::update,
+        MultiSliderExample::view,
+    )
+    .window_size(Size::new(600.0, 400.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Harmonics(BarGesture),
+    Steps(BarGesture),
+}
+
+pub struct MultiSliderExample {
+    harmonics: Vec<Normal>,
+    steps: Vec<Normal>,
+
+    output_text: String,
+}
+
+impl Default for MultiSliderExample {
+    fn default() -> Self {
+        Self {
+            // start with a sawtooth spectrum
+            harmonics: (1..=32)
+                .map(|harmonic| Normal::new(1.0 / harmonic as f32))
+                .collect(),
+            steps: vec![Normal::CENTER; 16],
+
+            output_text: String::new(),
+        }
+    }
+}
+
+impl MultiSliderExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Harmonics(BarGesture::Gesturing { index, normal }) => {
+                self.harmonics[index] = normal;
+                self.output_text = format!(
+                    "id: Harmonic {}  |  value: {:.3}",
+                    index + 1,
+                    normal.as_f32()
+                );
+            }
+            Message::Steps(BarGesture::Gesturing { index, normal }) => {
+                self.steps[index] = normal;
+                self.output_text = format!(
+                    "id: Step {}  |  value: {:.3}",
+                    index + 1,
+                    normal.as_f32() * 2.0 - 1.0
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        // create each of the MultiSlider widgets, passing in the values of
+        // the bars
+
+        let harmonics = MultiSlider::new(&self.harmonics).on_gesture(Message::Harmonics);
+
+        let steps = MultiSlider::new(&self.steps)
+            .on_gesture(Message::Steps)
+            .bipolar_center(Normal::CENTER)
+            .height(Length::Fixed(120.0))
+            .style(style::multi_slider::CustomStyle);
+
+        column![
+            text("Harmonics (hold Alt to draw a line)"),
+            harmonics,
+            text("Bipolar Steps"),
+            steps,
+            text(&self.output_text).size(16),
+        ]
+        .spacing(14)
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,117 @@
+mod style;
+mod util;
+
+use std::time::Instant;
+
+use iced::{
+    Element, Length, Result, Size, application,
+    widget::{button, column, row, text},
+};
+use iced_audio::{
+    Normal, PadGrid,
+    pad_grid::{PadEvent, VelocityMode},
+};
+
+fn main() -> Result {
+    application(
+        PadGridExample::default,
+        PadGridExample::update,
+        PadGridExample::view,
+    )
+    .window_size(Size::new(600.0, 500.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Default(PadEvent),
+    Custom(PadEvent),
+    SimulateMidi,
+}
+
+pub struct PadGridExample {
+    default_flashes: Vec<Option<Instant>>,
+    custom_flashes: Vec<Option<Instant>>,
+    next_midi_pad: usize,
+
+    output_text: String,
+}
+
+impl Default for PadGridExample {
+    fn default() -> Self {
+        Self {
+            default_flashes: vec![None; 16],
+            custom_flashes: vec![None; 8],
+            next_midi_pad: 0,
+
+            output_text: String::new(),
+        }
+    }
+}
+
+impl PadGridExample {
+    fn update(&mut self, message: Message) {
+        dbg!(&message);
+
+        match message {
+            Message::Default(event) => {
+                self.output_text = pad_text("Default", event);
+            }
+            Message::Custom(event) => {
+                self.output_text = pad_text("Custom", event);
+            }
+            Message::SimulateMidi => {
+                // Pretend an incoming MIDI note triggered the next pad.
+                self.default_flashes[self.next_midi_pad] = Some(Instant::now());
+                self.custom_flashes[self.next_midi_pad % 8] = Some(Instant::now());
+                self.next_midi_pad = (self.next_midi_pad + 1) % 16;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let pad_grid_default = PadGrid::new(4, 4)
+            .velocity_mode(VelocityMode::Vertical {
+                min: Normal::new(0.1),
+            })
+            .flashes(&self.default_flashes)
+            .on_pad(Message::Default);
+
+        let pad_grid_custom = PadGrid::new(2, 4)
+            .velocity_mode(VelocityMode::Fixed(Normal::new(0.8)))
+            .flashes(&self.custom_flashes)
+            .spacing(6.0)
+            .width(Length::Fixed(240.0))
+            .height(Length::Fixed(120.0))
+            .on_pad(Message::Custom)
+            .style(style::pad_grid::CustomStyle);
+
+        let pad_grid_row = row![
+            column![text("Default Style"), pad_grid_default]
+                .width(Length::Fill)
+                .spacing(10),
+            column![text("Custom Style"), pad_grid_custom]
+                .width(Length::Fill)
+                .spacing(10),
+        ]
+        .spacing(20);
+
+        column![
+            pad_grid_row,
+            button("Simulate incoming MIDI note").on_press(Message::SimulateMidi),
+            text(&self.output_text).size(16),
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
+
+fn pad_text(id: &str, event: PadEvent) -> String {
+    match event {
+        PadEvent::NoteOn { pad, velocity } => {
+            format!("id: {id:?}  |  note on: pad {pad}, velocity {velocity:.3}")
+        }
+        PadEvent::NoteOff { pad } => format!("id: {id:?}  |  note off: pad {pad}"),
+    }
+}
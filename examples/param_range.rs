@@ -0,0 +1,157 @@
+mod style;
+mod util;
+
+use iced::{
+    Alignment, Element, Result, Size, application,
+    widget::{column, row, text},
+};
+use iced_audio::{
+    DBRange, EnumRange, FreqRange, Gesture, IntRange, Knob, NormalParam, NoteRange, ParamRange,
+    TimeRange, ValueBox, text_marks, tick_marks,
+};
+
+fn main() -> Result {
+    application(
+        ParamRangeExample::default,
+        ParamRangeExample::update,
+        ParamRangeExample::view,
+    )
+    .window_size(Size::new(760.0, 320.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Gain(Gesture),
+    Cutoff(Gesture),
+    Delay(Gesture),
+    Pitch(Gesture),
+    Voices(Gesture),
+    Filter(Gesture),
+}
+
+/// One generic parameter control that works with any range: a knob with
+/// tick and text marks, the formatted value, and a value box.
+struct Control<R: ParamRange> {
+    label: &'static str,
+    range: R,
+    param: NormalParam,
+    tick_marks: tick_marks::Group,
+    text_marks: text_marks::Group,
+}
+
+impl<R> Control<R>
+where
+    R: ParamRange + Copy + 'static,
+{
+    fn new(label: &'static str, range: R, ticks: &[R::Value], labels: &[R::Value]) -> Self {
+        let ticks: Vec<(R::Value, tick_marks::Tier)> = ticks
+            .iter()
+            .map(|value| (*value, tick_marks::Tier::Two))
+            .collect();
+
+        Self {
+            label,
+            range,
+            param: range.default_param(),
+            tick_marks: tick_marks::Group::from_values(&range, &ticks),
+            text_marks: text_marks::Group::from_values(&range, labels),
+        }
+    }
+
+    fn update(&mut self, gesture: Gesture) {
+        if let Gesture::Gesturing(normal) = gesture {
+            // Discrete ranges snap so the widgets "step" when moved.
+            self.param.set(self.range.snap(normal));
+        }
+    }
+
+    fn view<'a>(&'a self, on_gesture: fn(Gesture) -> Message) -> Element<'a, Message> {
+        column![
+            text(self.label).size(14),
+            Knob::new(self.param)
+                .on_gesture(on_gesture)
+                .tick_marks(&self.tick_marks)
+                .text_marks(&self.text_marks),
+            text(self.range.format_normal(self.param.normal)).size(14),
+            ValueBox::new(self.param, self.range).on_gesture(on_gesture),
+        ]
+        .spacing(14)
+        .align_x(Alignment::Center)
+        .into()
+    }
+}
+
+struct ParamRangeExample {
+    gain: Control<DBRange>,
+    cutoff: Control<FreqRange>,
+    delay: Control<TimeRange>,
+    pitch: Control<NoteRange>,
+    voices: Control<IntRange>,
+    filter: Control<EnumRange>,
+}
+
+impl Default for ParamRangeExample {
+    fn default() -> Self {
+        Self {
+            gain: Control::new(
+                "Gain",
+                DBRange::NEG_12_TO_12,
+                &[-12.0, -6.0, 0.0, 6.0, 12.0],
+                &[-12.0, 12.0],
+            ),
+            cutoff: Control::new(
+                "Cutoff",
+                FreqRange::HZ_20_TO_20K,
+                &[20.0, 100.0, 1_000.0, 10_000.0, 20_000.0],
+                &[100.0, 10_000.0],
+            ),
+            delay: Control::new(
+                "Delay",
+                TimeRange::MS_1_TO_1S,
+                &[0.001, 0.01, 0.1, 1.0],
+                &[0.001, 1.0],
+            ),
+            pitch: Control::new(
+                "Pitch",
+                NoteRange::new(36.0, 84.0),
+                &[36.0, 48.0, 60.0, 72.0, 84.0],
+                &[36.0, 84.0],
+            ),
+            voices: Control::new("Voices", IntRange::new(1, 8), &[1, 2, 4, 8], &[1, 8]),
+            filter: Control::new(
+                "Filter",
+                EnumRange::new(&["LP", "HP", "BP", "Notch"]),
+                &[0, 1, 2, 3],
+                &[0, 1, 2, 3],
+            ),
+        }
+    }
+}
+
+impl ParamRangeExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Gain(gesture) => self.gain.update(gesture),
+            Message::Cutoff(gesture) => self.cutoff.update(gesture),
+            Message::Delay(gesture) => self.delay.update(gesture),
+            Message::Pitch(gesture) => self.pitch.update(gesture),
+            Message::Voices(gesture) => self.voices.update(gesture),
+            Message::Filter(gesture) => self.filter.update(gesture),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        row![
+            self.gain.view(Message::Gain),
+            self.cutoff.view(Message::Cutoff),
+            self.delay.view(Message::Delay),
+            self.pitch.view(Message::Pitch),
+            self.voices.view(Message::Voices),
+            self.filter.view(Message::Filter),
+        ]
+        .spacing(30)
+        .padding(40)
+        .into()
+    }
+}
@@ -2,10 +2,13 @@ mod style;
 mod util;
 
 use iced::{
-    Element, Length, Result, Size, application,
+    Element, Length, Result, Size, Theme, application,
     widget::{column, row, text},
 };
-use iced_audio::{FloatRange, Gesture, NormalParam, Ramp, ramp::RampDirection};
+use iced_audio::{
+    FloatRange, Gesture, NormalParam, Ramp,
+    ramp::{self, RampDirection},
+};
 
 use crate::util::info_text::info_text_f32;
 
@@ -21,6 +24,8 @@ enum Message {
     DefaultDown(Gesture),
     CustomUp(Gesture),
     CustomDown(Gesture),
+    ClosureUp(Gesture),
+    ClosureDown(Gesture),
 }
 
 pub struct RampExample {
@@ -28,6 +33,8 @@ pub struct RampExample {
     ramp_default_down_param: NormalParam,
     ramp_custom_up_param: NormalParam,
     ramp_custom_down_param: NormalParam,
+    ramp_closure_up_param: NormalParam,
+    ramp_closure_down_param: NormalParam,
 
     output_text: String,
 }
@@ -40,6 +47,8 @@ impl Default for RampExample {
             ramp_default_down_param: FloatRange::NORMAL_BIPOLAR.default_param(),
             ramp_custom_up_param: FloatRange::NORMAL_BIPOLAR.default_param(),
             ramp_custom_down_param: FloatRange::NORMAL_BIPOLAR.default_param(),
+            ramp_closure_up_param: FloatRange::NORMAL_BIPOLAR.default_param(),
+            ramp_closure_down_param: FloatRange::NORMAL_BIPOLAR.default_param(),
 
             output_text: String::new(),
         }
@@ -68,6 +77,15 @@ impl RampExample {
                 self.ramp_custom_down_param.set(normal);
                 self.output_text = info_text_f32("CustomDown", normal, &FloatRange::NORMAL_BIPOLAR);
             }
+            Message::ClosureUp(Gesture::Gesturing(normal)) => {
+                self.ramp_closure_up_param.set(normal);
+                self.output_text = info_text_f32("ClosureUp", normal, &FloatRange::NORMAL_BIPOLAR);
+            }
+            Message::ClosureDown(Gesture::Gesturing(normal)) => {
+                self.ramp_closure_down_param.set(normal);
+                self.output_text =
+                    info_text_f32("ClosureDown", normal, &FloatRange::NORMAL_BIPOLAR);
+            }
             _ => {}
         }
     }
@@ -90,6 +108,14 @@ impl RampExample {
             .on_gesture(Message::CustomDown)
             .style(style::ramp::CustomStyle);
 
+        let ramp_closure_up = Ramp::new(self.ramp_closure_up_param, RampDirection::Up)
+            .on_gesture(Message::ClosureUp)
+            .style_fn(palette_style);
+
+        let ramp_closure_down = Ramp::new(self.ramp_closure_down_param, RampDirection::Down)
+            .on_gesture(Message::ClosureDown)
+            .style_fn(palette_style);
+
         // push the widgets into rows
         let ramp_row = row![
             column![
@@ -108,6 +134,14 @@ impl RampExample {
             ]
             .width(Length::Fill)
             .spacing(10),
+            column![
+                text("Closure Style Up"),
+                ramp_closure_up,
+                text("Closure Style Down"),
+                ramp_closure_down,
+            ]
+            .width(Length::Fill)
+            .spacing(10),
         ]
         .spacing(20);
 
@@ -117,3 +151,23 @@ impl RampExample {
             .into()
     }
 }
+
+/// Styles a ramp with the colors of the current theme.
+fn palette_style(theme: &Theme, status: ramp::Status) -> ramp::Appearance {
+    let palette = theme.extended_palette();
+
+    let back_color = match status {
+        ramp::Status::Hovered | ramp::Status::Gesturing => palette.background.weak.color,
+        _ => palette.background.base.color,
+    };
+
+    ramp::Appearance {
+        back_color,
+        back_border_width: 1.0,
+        back_border_color: palette.background.strong.color,
+        line_width: 2.0,
+        line_center_color: palette.background.strong.color,
+        line_up_color: palette.primary.base.color,
+        line_down_color: palette.danger.base.color,
+    }
+}
@@ -0,0 +1,165 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Length, Result, Size, application,
+    widget::{column, row, text},
+};
+use iced_audio::{
+    FloatRange, Gesture, IntRange, NormalParam, RangeSlider,
+    range_slider::{Crossing, Orientation},
+    text_marks, tick_marks,
+};
+
+use crate::util::info_text::{info_text_f32, info_text_i32};
+
+const KEY_RANGE: IntRange = IntRange::new(0, 127);
+
+fn main() -> Result {
+    application(
+        RangeSliderExample::default,
+        RangeSliderExample::update,
+        RangeSliderExample::view,
+    )
+    .window_size(Size::new(600.0, 400.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    KeyLow(Gesture),
+    KeyHigh(Gesture),
+    LoopStart(Gesture),
+    LoopEnd(Gesture),
+    BandLow(Gesture),
+    BandHigh(Gesture),
+}
+
+pub struct RangeSliderExample {
+    key_low_param: NormalParam,
+    key_high_param: NormalParam,
+    loop_start_param: NormalParam,
+    loop_end_param: NormalParam,
+    band_low_param: NormalParam,
+    band_high_param: NormalParam,
+
+    key_tick_marks: tick_marks::Group,
+    key_text_marks: text_marks::Group,
+    float_tick_marks: tick_marks::Group,
+
+    output_text: String,
+}
+
+impl Default for RangeSliderExample {
+    fn default() -> Self {
+        Self {
+            // initialize the parameters of the RangeSlider widgets
+            key_low_param: KEY_RANGE.param(36, 36),
+            key_high_param: KEY_RANGE.param(84, 84),
+            loop_start_param: FloatRange::NORMAL.param(0.25, 0.25),
+            loop_end_param: FloatRange::NORMAL.param(0.75, 0.75),
+            band_low_param: FloatRange::NORMAL.param(0.2, 0.2),
+            band_high_param: FloatRange::NORMAL.param(0.6, 0.6),
+
+            key_tick_marks: (0..=10)
+                .map(|octave| {
+                    let tier = if octave % 2 == 0 {
+                        tick_marks::Tier::One
+                    } else {
+                        tick_marks::Tier::Two
+                    };
+                    (KEY_RANGE.map(octave * 12), tier)
+                })
+                .collect::<Vec<_>>()
+                .into(),
+            key_text_marks: vec![
+                (KEY_RANGE.map(24), "C1"),
+                (KEY_RANGE.map(60), "C4"),
+                (KEY_RANGE.map(96), "C7"),
+            ]
+            .into(),
+            float_tick_marks: tick_marks::Group::subdivided(1, 3, 0, Some(tick_marks::Tier::One)),
+
+            output_text: String::new(),
+        }
+    }
+}
+
+impl RangeSliderExample {
+    fn update(&mut self, message: Message) {
+        dbg!(&message);
+
+        match message {
+            Message::KeyLow(Gesture::Gesturing(normal)) => {
+                // Integer parameters must be snapped to make the widget "step" when moved.
+                self.key_low_param.set(KEY_RANGE.snap(normal));
+                self.output_text = info_text_i32("KeyLow", normal, &KEY_RANGE);
+            }
+            Message::KeyHigh(Gesture::Gesturing(normal)) => {
+                self.key_high_param.set(KEY_RANGE.snap(normal));
+                self.output_text = info_text_i32("KeyHigh", normal, &KEY_RANGE);
+            }
+            Message::LoopStart(Gesture::Gesturing(normal)) => {
+                self.loop_start_param.set(normal);
+                self.output_text = info_text_f32("LoopStart", normal, &FloatRange::NORMAL);
+            }
+            Message::LoopEnd(Gesture::Gesturing(normal)) => {
+                self.loop_end_param.set(normal);
+                self.output_text = info_text_f32("LoopEnd", normal, &FloatRange::NORMAL);
+            }
+            Message::BandLow(Gesture::Gesturing(normal)) => {
+                self.band_low_param.set(normal);
+                self.output_text = info_text_f32("BandLow", normal, &FloatRange::NORMAL);
+            }
+            Message::BandHigh(Gesture::Gesturing(normal)) => {
+                self.band_high_param.set(normal);
+                self.output_text = info_text_f32("BandHigh", normal, &FloatRange::NORMAL);
+            }
+            _ => {}
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        // create each of the RangeSlider widgets, passing in the values of
+        // the corresponding parameters
+
+        let key_zone = RangeSlider::new(self.key_low_param, self.key_high_param)
+            .on_gesture_lower(Message::KeyLow)
+            .on_gesture_upper(Message::KeyHigh)
+            .tick_marks(&self.key_tick_marks)
+            .text_marks(&self.key_text_marks);
+
+        let loop_window = RangeSlider::new(self.loop_start_param, self.loop_end_param)
+            .on_gesture_lower(Message::LoopStart)
+            .on_gesture_upper(Message::LoopEnd)
+            .crossing(Crossing::Swap)
+            .height(Length::Fixed(24.0))
+            .style(style::range_slider::CustomStyle);
+
+        let band = RangeSlider::new(self.band_low_param, self.band_high_param)
+            .on_gesture_lower(Message::BandLow)
+            .on_gesture_upper(Message::BandHigh)
+            .orientation(Orientation::Vertical)
+            .height(Length::Fixed(200.0))
+            .tick_marks(&self.float_tick_marks);
+
+        // push the widgets into rows
+        let range_slider_row = row![
+            column![
+                column![text("Key Zone"), key_zone].spacing(14),
+                column![text("Loop Window (swaps handles)"), loop_window].spacing(14),
+            ]
+            .width(Length::Fill)
+            .spacing(31),
+            column![text("Vertical"), band]
+                .width(Length::Fill)
+                .spacing(14),
+        ]
+        .spacing(20);
+
+        column![range_slider_row, text(&self.output_text).size(16),]
+            .spacing(20)
+            .padding(20)
+            .into()
+    }
+}
@@ -0,0 +1,169 @@
+mod style;
+mod util;
+
+use std::time::Instant;
+
+use iced::{
+    Element, Length, Result, Size, Subscription, application,
+    widget::{button, checkbox, column, row, text},
+    window,
+};
+use iced_audio::{
+    Normal, ShapeEditor,
+    shape_editor::{self, Grid, ShapeGesture, ShapeTool},
+};
+
+const TABLE_SIZE: usize = 256;
+const EXPORT_SIZE: usize = 2048;
+const LFO_RATE_HZ: f32 = 0.5;
+
+fn main() -> Result {
+    application(
+        ShapeEditorExample::default,
+        ShapeEditorExample::update,
+        ShapeEditorExample::view,
+    )
+    .subscription(ShapeEditorExample::subscription)
+    .window_size(Size::new(700.0, 500.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Shape(ShapeGesture),
+    CustomShape(ShapeGesture),
+    SelectTool(ShapeTool),
+    ToggleGrid(bool),
+    Undo,
+    Export,
+    Frame(Instant),
+}
+
+pub struct ShapeEditorExample {
+    table: Vec<Normal>,
+    custom_table: Vec<Normal>,
+    tool: ShapeTool,
+    snap_to_grid: bool,
+
+    // The state of the table before each stroke, so a whole stroke can be
+    // undone at once.
+    undo_stack: Vec<Vec<Normal>>,
+
+    phase: f32,
+    last_frame: Option<Instant>,
+
+    output_text: String,
+}
+
+impl Default for ShapeEditorExample {
+    fn default() -> Self {
+        let sine: Vec<Normal> = (0..TABLE_SIZE)
+            .map(|i| {
+                let phase = i as f32 / TABLE_SIZE as f32;
+                Normal::new(0.5 + 0.5 * (phase * std::f32::consts::TAU).sin())
+            })
+            .collect();
+
+        let saw: Vec<Normal> = (0..TABLE_SIZE)
+            .map(|i| Normal::new(1.0 - i as f32 / TABLE_SIZE as f32))
+            .collect();
+
+        Self {
+            table: sine,
+            custom_table: saw,
+            tool: ShapeTool::Pencil,
+            snap_to_grid: false,
+            undo_stack: Vec::new(),
+            phase: 0.0,
+            last_frame: None,
+            output_text: String::new(),
+        }
+    }
+}
+
+impl ShapeEditorExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Shape(gesture) => {
+                if let ShapeGesture::GestureStart = gesture {
+                    self.undo_stack.push(self.table.clone());
+                }
+                if let ShapeGesture::GestureEnd = gesture {
+                    self.output_text =
+                        format!("Stroke finished ({} undo steps)", self.undo_stack.len());
+                }
+                gesture.apply(&mut self.table);
+            }
+            Message::CustomShape(gesture) => gesture.apply(&mut self.custom_table),
+            Message::SelectTool(tool) => self.tool = tool,
+            Message::ToggleGrid(snap_to_grid) => self.snap_to_grid = snap_to_grid,
+            Message::Undo => {
+                if let Some(table) = self.undo_stack.pop() {
+                    self.table = table;
+                }
+            }
+            Message::Export => {
+                let exported = shape_editor::resample(&self.table, EXPORT_SIZE);
+                self.output_text = format!(
+                    "Exported {} samples, first: {:.3}, middle: {:.3}",
+                    exported.len(),
+                    exported[0].as_f32(),
+                    exported[EXPORT_SIZE / 2].as_f32(),
+                );
+            }
+            Message::Frame(now) => {
+                if let Some(last_frame) = self.last_frame {
+                    let elapsed = (now - last_frame).as_secs_f32();
+                    self.phase = (self.phase + elapsed * LFO_RATE_HZ).fract();
+                }
+                self.last_frame = Some(now);
+            }
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        // Drive the phase indicator from the application.
+        window::frames().map(Message::Frame)
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let grid = self.snap_to_grid.then_some(Grid::new(16, 8));
+
+        let shape_editor = ShapeEditor::new(&self.table)
+            .tool(self.tool)
+            .grid(grid)
+            .phase(Some(Normal::new(self.phase)))
+            .height(Length::Fixed(160.0))
+            .on_gesture(Message::Shape);
+
+        let custom_shape_editor = ShapeEditor::new(&self.custom_table)
+            .grid(Some(Grid::new(8, 4)))
+            .phase(Some(Normal::new(self.phase)))
+            .height(Length::Fixed(100.0))
+            .on_gesture(Message::CustomShape)
+            .style(style::shape_editor::CustomStyle);
+
+        let tool_row = row![
+            button("Pencil").on_press(Message::SelectTool(ShapeTool::Pencil)),
+            button("Line").on_press(Message::SelectTool(ShapeTool::Line)),
+            checkbox(self.snap_to_grid)
+                .label("Snap to grid (16 x 8)")
+                .on_toggle(Message::ToggleGrid),
+            button("Undo stroke").on_press(Message::Undo),
+            button("Export").on_press(Message::Export),
+        ]
+        .spacing(10);
+
+        column![
+            text("LFO Shape (hold Alt to draw a line)"),
+            tool_row,
+            shape_editor,
+            text("Custom Style (8 steps x 4 levels)"),
+            custom_shape_editor,
+            text(&self.output_text).size(16),
+        ]
+        .spacing(16)
+        .padding(20)
+        .into()
+    }
+}
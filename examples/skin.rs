@@ -0,0 +1,147 @@
+use iced::{
+    Color, Element, Length, Result, Size, Subscription, Theme, application,
+    widget::{column, row, text},
+    window,
+};
+use iced_audio::{
+    DBRange, FloatRange, FreqRange, Gesture, HSlider, Knob, NormalParam, VSlider, ValueBox,
+    style::skin::{Skin, SkinWatcher},
+    tick_marks,
+};
+use std::time::{Duration, Instant};
+
+const GAIN_RANGE: DBRange = DBRange::NEG_12_TO_12;
+const MIX_RANGE: FloatRange = FloatRange::NORMAL;
+const CUTOFF_RANGE: FreqRange = FreqRange::HZ_20_TO_20K;
+
+/// How often the skin file is checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+fn main() -> Result {
+    application(SkinExample::default, SkinExample::update, SkinExample::view)
+        .subscription(SkinExample::subscription)
+        .theme(|_: &SkinExample| Theme::Dark)
+        .window_size(Size::new(560.0, 360.0))
+        .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Gain(Gesture),
+    Mix(Gesture),
+    Cutoff(Gesture),
+    Frame(Instant),
+}
+
+pub struct SkinExample {
+    gain: NormalParam,
+    mix: NormalParam,
+    cutoff: NormalParam,
+
+    tick_marks: tick_marks::Group,
+
+    skin: Skin,
+    watcher: SkinWatcher,
+    last_poll: Option<Instant>,
+    error: Option<String>,
+}
+
+impl Default for SkinExample {
+    fn default() -> Self {
+        let mut example = Self {
+            gain: GAIN_RANGE.default_param(),
+            mix: MIX_RANGE.param(0.5, 0.5),
+            cutoff: CUTOFF_RANGE.param(1000.0, 1000.0),
+
+            tick_marks: tick_marks::Group::subdivided(1, 1, 1, Some(tick_marks::Tier::Two)),
+
+            skin: Skin::default(),
+            watcher: SkinWatcher::new(format!(
+                "{}/examples/skins/dark.toml",
+                env!("CARGO_MANIFEST_DIR")
+            )),
+            last_poll: None,
+            error: None,
+        };
+        example.reload();
+        example
+    }
+}
+
+impl SkinExample {
+    fn reload(&mut self) {
+        match self.watcher.poll() {
+            Some(Ok(skin)) => {
+                self.skin = skin;
+                self.error = None;
+            }
+            // Keep the last good skin so a typo doesn't reset everything.
+            Some(Err(error)) => self.error = Some(error.to_string()),
+            None => {}
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Gain(Gesture::Gesturing(normal)) => self.gain.set(normal),
+            Message::Mix(Gesture::Gesturing(normal)) => self.mix.set(normal),
+            Message::Cutoff(Gesture::Gesturing(normal)) => self.cutoff.set(normal),
+            Message::Frame(now)
+                if self
+                    .last_poll
+                    .is_none_or(|last| now.duration_since(last) >= POLL_INTERVAL) =>
+            {
+                self.last_poll = Some(now);
+                self.reload();
+            }
+            _ => {}
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Frame)
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let knob = Knob::new(self.gain)
+            .size(Length::Fixed(48.0))
+            .tick_marks(&self.tick_marks)
+            .on_gesture(Message::Gain)
+            .style(self.skin.knob());
+
+        let h_slider = HSlider::new(self.mix)
+            .width(Length::Fixed(200.0))
+            .tick_marks(&self.tick_marks)
+            .on_gesture(Message::Mix)
+            .style(self.skin.h_slider());
+
+        let v_slider = VSlider::new(self.cutoff)
+            .height(Length::Fixed(140.0))
+            .on_gesture(Message::Cutoff)
+            .style(self.skin.v_slider());
+
+        let value_box = ValueBox::new(self.cutoff, CUTOFF_RANGE)
+            .decimals(0)
+            .unit("Hz")
+            .width(Length::Fixed(100.0))
+            .on_gesture(Message::Cutoff)
+            .style(self.skin.value_box());
+
+        let status = match &self.error {
+            Some(error) => text(error).size(14).color(Color::from_rgb(1.0, 0.4, 0.4)),
+            None => text(format!(
+                "Edit {} and save it to reload the skin.",
+                self.watcher.path().display()
+            ))
+            .size(14),
+        };
+
+        column![
+            row![knob, h_slider, v_slider, value_box].spacing(30),
+            status,
+        ]
+        .spacing(30)
+        .padding(40)
+        .into()
+    }
+}
@@ -0,0 +1,109 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Length, Result, Size, Subscription, application,
+    widget::{column, text},
+    window,
+};
+use iced_audio::{FreqRange, Spectrogram, spectrogram::History};
+use std::time::Instant;
+
+const SAMPLE_RATE: f32 = 48_000.0;
+const FFT_SIZE: usize = 2048;
+
+fn main() -> Result {
+    application(
+        SpectrogramExample::default,
+        SpectrogramExample::update,
+        SpectrogramExample::view,
+    )
+    .subscription(SpectrogramExample::subscription)
+    .window_size(Size::new(800.0, 560.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Frame(Instant),
+}
+
+pub struct SpectrogramExample {
+    history: History,
+    narrow_history: History,
+
+    start: Instant,
+    noise: u32,
+    magnitudes: Vec<f32>,
+}
+
+impl Default for SpectrogramExample {
+    fn default() -> Self {
+        Self {
+            history: History::new(400, 256, SAMPLE_RATE),
+            narrow_history: History::new(200, 128, SAMPLE_RATE)
+                .freq_range(FreqRange::new(50.0, 5_000.0)),
+
+            start: Instant::now(),
+            noise: 0x1234_5678,
+            magnitudes: vec![0.0; FFT_SIZE / 2 + 1],
+        }
+    }
+}
+
+impl SpectrogramExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Frame(now) => {
+                self.synthesize_column((now - self.start).as_secs_f32());
+
+                self.history.push_column(&self.magnitudes);
+                self.narrow_history.push_column(&self.magnitudes);
+            }
+        }
+    }
+
+    /// Fakes the output of an FFT: a sweeping tone with harmonics over a
+    /// noise floor.
+    fn synthesize_column(&mut self, time: f32) {
+        let hz_per_bin = SAMPLE_RATE / FFT_SIZE as f32;
+        let fundamental = 110.0 * 2.0f32.powf(3.0 + 3.0 * (time * 0.5).sin());
+
+        for (bin, magnitude) in self.magnitudes.iter_mut().enumerate() {
+            self.noise ^= self.noise << 13;
+            self.noise ^= self.noise >> 17;
+            self.noise ^= self.noise << 5;
+            let noise = self.noise as f32 / u32::MAX as f32;
+
+            let freq = bin as f32 * hz_per_bin;
+            let mut db = -80.0 + noise * 12.0;
+
+            for harmonic in 1..=6 {
+                let distance = (freq - fundamental * harmonic as f32).abs() / hz_per_bin;
+                let peak = -6.0 * harmonic as f32 - distance * distance * 4.0;
+                db = db.max(peak);
+            }
+
+            *magnitude = db;
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Frame)
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        column![
+            text("20 Hz to 20 kHz"),
+            Spectrogram::new(&self.history),
+            text("50 Hz to 5 kHz (Custom Style)"),
+            Spectrogram::new(&self.narrow_history)
+                .width(Length::Fixed(400.0))
+                .height(Length::Fixed(160.0))
+                .style(style::spectrogram::CustomStyle),
+        ]
+        .spacing(16)
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,69 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced_audio::{Offset, automation_lane, text_marks};
+
+use super::colors;
+
+// Custom style for the AutomationLane widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const IDLE_STYLE: automation_lane::Appearance = automation_lane::Appearance {
+        back_color: colors::EMPTY,
+        back_border_width: 2.0,
+        back_border_radius: 3.0,
+        back_border_color: colors::BORDER,
+        value_line_color: Some(colors::KNOB),
+        line_color: colors::HANDLE,
+        line_width: 2.0,
+        fill_color: Some(Color {
+            r: colors::FILLED.r,
+            g: colors::FILLED.g,
+            b: colors::FILLED.b,
+            a: 0.4,
+        }),
+        point: automation_lane::PointAppearance {
+            diameter: 9.0,
+            color: colors::KNOB,
+            border_width: 2.0,
+            border_color: colors::HANDLE,
+        },
+        selected_point: automation_lane::PointAppearance {
+            diameter: 9.0,
+            color: colors::HANDLE,
+            border_width: 2.0,
+            border_color: Color::WHITE,
+        },
+        selection_color: Color {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+            a: 0.1,
+        },
+        selection_border_color: Color::WHITE,
+    };
+}
+impl automation_lane::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn idle(&self, _style: &Self::Style) -> automation_lane::Appearance {
+        Self::IDLE_STYLE
+    }
+
+    fn text_marks_appearance(
+        &self,
+        _style: &Self::Style,
+    ) -> Option<automation_lane::TextMarksAppearance> {
+        Some(automation_lane::TextMarksAppearance {
+            style: text_marks::Appearance {
+                color: colors::HANDLE,
+                ..Default::default()
+            },
+            placement: text_marks::Placement::LeftOrTop {
+                inside: true,
+                offset: Offset::new(6.0, 0.0),
+            },
+        })
+    }
+}
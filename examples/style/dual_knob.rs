@@ -0,0 +1,67 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced::widget::canvas::LineCap;
+use iced_audio::{
+    KnobAngleRange, dual_knob,
+    knob::{LineNotch, NotchShape, StyleLength, ValueArcAppearance},
+};
+
+use super::colors;
+
+// Custom style for the DualKnob widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    fn idle_style() -> dual_knob::Appearance {
+        dual_knob::Appearance {
+            outer_arc: ValueArcAppearance {
+                width: 4.0,
+                offset: -4.0,
+                empty_color: Some(colors::KNOB_ARC_EMPTY),
+                left_filled_color: colors::KNOB_ARC,
+                right_filled_color: Some(colors::KNOB_ARC_RIGHT),
+                cap: LineCap::Round,
+            },
+            inner_arc: ValueArcAppearance {
+                width: 3.0,
+                offset: -4.0,
+                empty_color: Some(colors::KNOB_ARC_EMPTY),
+                left_filled_color: colors::HANDLE,
+                right_filled_color: None,
+                cap: LineCap::Round,
+            },
+            cap: dual_knob::CapAppearance {
+                diameter: StyleLength::Scaled(0.34),
+                color: colors::KNOB,
+                border_width: 1.0,
+                border_color: colors::KNOB_BORDER,
+                notch: NotchShape::Line(LineNotch {
+                    color: colors::HANDLE,
+                    width: StyleLength::Fixed(2.0),
+                    length: StyleLength::Scaled(0.3),
+                    cap: LineCap::Round,
+                    offset: StyleLength::Scaled(0.1),
+                }),
+            },
+        }
+    }
+}
+impl dual_knob::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn idle(&self, _style: &Self::Style) -> dual_knob::Appearance {
+        Self::idle_style()
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> dual_knob::Appearance {
+        let mut appearance = Self::idle_style();
+        appearance.outer_arc.left_filled_color = colors::FILLED_HOVER;
+        appearance.inner_arc.left_filled_color = colors::HANDLE_HOVER;
+        appearance
+    }
+
+    fn inner_angle_range(&self, _style: &Self::Style) -> KnobAngleRange {
+        KnobAngleRange::from_deg(40.0, 320.0)
+    }
+}
@@ -0,0 +1,77 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced_audio::{Offset, gain_reduction_meter, text_marks, tick_marks};
+
+use super::colors;
+
+// Custom style for the GainReductionMeter widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const APPEARANCE: gain_reduction_meter::Appearance = gain_reduction_meter::Appearance {
+        back_color: colors::EMPTY,
+        back_border_width: 1.0,
+        back_border_radius: 2.0,
+        back_border_color: colors::BORDER,
+        color: colors::KNOB_ARC,
+        heavy_color: Some(colors::KNOB_ARC_RIGHT),
+        heavy_threshold: 9.0,
+    };
+}
+impl gain_reduction_meter::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> gain_reduction_meter::Appearance {
+        Self::APPEARANCE
+    }
+
+    fn tick_marks_appearance(
+        &self,
+        _style: &Self::Style,
+    ) -> Option<gain_reduction_meter::TickMarksAppearance> {
+        Some(gain_reduction_meter::TickMarksAppearance {
+            style: tick_marks::Appearance {
+                tier_1: tick_marks::Shape::Line {
+                    length: 6.0,
+                    width: 2.0,
+                    color: colors::HANDLE,
+                },
+                tier_2: tick_marks::Shape::Line {
+                    length: 4.0,
+                    width: 1.0,
+                    color: colors::KNOB,
+                },
+                tier_3: tick_marks::Shape::None,
+            },
+            vertical_placement: tick_marks::Placement::RightOrBottom {
+                offset: Offset::new(2.0, 0.0),
+                inside: false,
+            },
+            horizontal_placement: tick_marks::Placement::RightOrBottom {
+                offset: Offset::new(0.0, 2.0),
+                inside: false,
+            },
+        })
+    }
+
+    fn text_marks_appearance(
+        &self,
+        _style: &Self::Style,
+    ) -> Option<gain_reduction_meter::TextMarksAppearance> {
+        Some(gain_reduction_meter::TextMarksAppearance {
+            style: text_marks::Appearance {
+                color: colors::HANDLE,
+                ..Default::default()
+            },
+            vertical_placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset::new(11.0, 0.0),
+            },
+            horizontal_placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset::new(0.0, 11.0),
+            },
+        })
+    }
+}
@@ -0,0 +1,82 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced_audio::{Offset, loudness_meter, text_marks, tick_marks};
+
+use super::colors;
+
+// Custom style for the LoudnessMeter widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const APPEARANCE: loudness_meter::Appearance = loudness_meter::Appearance {
+        back_color: colors::EMPTY,
+        back_border_width: 1.0,
+        back_border_radius: 2.0,
+        back_border_color: colors::BORDER,
+        bar_gap: 3.0,
+        momentary_color: colors::HANDLE,
+        short_term_color: colors::FILLED,
+        integrated_color: colors::KNOB_ARC,
+        above_target_color: Some(Color::from_rgb(1.0, 0.78, 0.2)),
+        range_color: Some(Color {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+            a: 0.25,
+        }),
+        target_line_color: colors::KNOB_ARC_RIGHT,
+        target_line_width: 2.0,
+        peak_height: 8.0,
+        peak_color: colors::EMPTY,
+        peak_over_color: Color::from_rgb(1.0, 0.2, 0.2),
+    };
+}
+impl loudness_meter::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> loudness_meter::Appearance {
+        Self::APPEARANCE
+    }
+
+    fn tick_marks_appearance(
+        &self,
+        _style: &Self::Style,
+    ) -> Option<loudness_meter::TickMarksAppearance> {
+        Some(loudness_meter::TickMarksAppearance {
+            style: tick_marks::Appearance {
+                tier_1: tick_marks::Shape::Line {
+                    length: 6.0,
+                    width: 2.0,
+                    color: colors::HANDLE,
+                },
+                tier_2: tick_marks::Shape::Line {
+                    length: 4.0,
+                    width: 1.0,
+                    color: colors::KNOB,
+                },
+                tier_3: tick_marks::Shape::None,
+            },
+            placement: tick_marks::Placement::RightOrBottom {
+                offset: Offset::new(2.0, 0.0),
+                inside: false,
+            },
+        })
+    }
+
+    fn text_marks_appearance(
+        &self,
+        _style: &Self::Style,
+    ) -> Option<loudness_meter::TextMarksAppearance> {
+        Some(loudness_meter::TextMarksAppearance {
+            style: text_marks::Appearance {
+                color: colors::HANDLE,
+                ..Default::default()
+            },
+            placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset::new(11.0, 0.0),
+            },
+        })
+    }
+}
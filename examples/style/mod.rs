@@ -1,8 +1,23 @@
 mod colors;
 
+pub mod automation_lane;
+pub mod dual_knob;
+pub mod gain_reduction_meter;
 pub mod h_slider;
 pub mod knob;
+pub mod loudness_meter;
 pub mod mod_range_input;
+pub mod multi_slider;
+pub mod pad_grid;
 pub mod ramp;
+pub mod range_slider;
+pub mod shape_editor;
+pub mod spectrogram;
+pub mod transfer_curve;
+pub mod tuner;
 pub mod v_slider;
+pub mod value_box;
+pub mod vu_meter;
+pub mod waveform_editor;
+pub mod wheel;
 pub mod xy_pad;
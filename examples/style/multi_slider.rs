@@ -0,0 +1,43 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced_audio::multi_slider;
+
+use super::colors;
+
+// Custom style for the MultiSlider widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const IDLE_STYLE: multi_slider::Appearance = multi_slider::Appearance {
+        back_color: colors::EMPTY,
+        back_border_width: 1.0,
+        back_border_radius: 2.0,
+        back_border_color: colors::BORDER,
+        bar_spacing: 3.0,
+        filled_color: colors::FILLED,
+        lower_filled_color: colors::FILLED,
+        upper_filled_color: Color::from_rgb(0.0, 0.605, 0.0),
+        handle_color: colors::HANDLE,
+        handle_lower_color: colors::HANDLE,
+        handle_upper_color: Color::from_rgb(0.0, 0.9, 0.0),
+        handle_center_color: Color::from_rgb(0.7, 0.7, 0.7),
+        handle_height: 3,
+        center_line_color: Color::from_rgb(0.7, 0.7, 0.7),
+    };
+}
+impl multi_slider::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn idle(&self, _style: &Self::Style) -> multi_slider::Appearance {
+        Self::IDLE_STYLE
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> multi_slider::Appearance {
+        multi_slider::Appearance {
+            lower_filled_color: colors::FILLED_HOVER,
+            handle_lower_color: colors::HANDLE_HOVER,
+            ..Self::IDLE_STYLE
+        }
+    }
+}
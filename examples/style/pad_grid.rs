@@ -0,0 +1,42 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced_audio::pad_grid;
+
+use super::colors;
+
+// Custom style for the PadGrid widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const PAD: pad_grid::PadAppearance = pad_grid::PadAppearance {
+        color: colors::KNOB,
+        border_width: 1.0,
+        border_radius: 4.0,
+        border_color: colors::KNOB_BORDER,
+    };
+
+    const IDLE_STYLE: pad_grid::Appearance = pad_grid::Appearance {
+        back_color: colors::EMPTY,
+        back_border_width: 2.0,
+        back_border_radius: 4.0,
+        back_border_color: colors::BORDER,
+        pad: Self::PAD,
+        hovered_pad: pad_grid::PadAppearance {
+            color: colors::FILLED,
+            ..Self::PAD
+        },
+        pressed_pad: pad_grid::PadAppearance {
+            color: colors::HANDLE,
+            ..Self::PAD
+        },
+        flash_color: Color::from_rgb(0.0, 0.9, 0.0),
+    };
+}
+impl pad_grid::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn idle(&self, _style: &Self::Style) -> pad_grid::Appearance {
+        Self::IDLE_STYLE
+    }
+}
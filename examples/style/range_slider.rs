@@ -0,0 +1,58 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced_audio::range_slider;
+
+use super::colors;
+
+// Custom style for the RangeSlider widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const HANDLE: range_slider::HandleAppearance = range_slider::HandleAppearance {
+        color: colors::HANDLE,
+        border_width: 0.0,
+        border_radius: 2.0,
+        border_color: Color::TRANSPARENT,
+        notch_width: 0.0,
+        notch_color: Color::TRANSPARENT,
+    };
+    const IDLE_STYLE: range_slider::Appearance = range_slider::Appearance {
+        back_color: colors::EMPTY,
+        back_border_width: 1.0,
+        back_border_radius: 2.0,
+        back_border_color: colors::BORDER,
+        fill: range_slider::FillAppearance {
+            placement: range_slider::FillPlacement::Center {
+                thickness: 10.0,
+                offset: 0.0,
+            },
+            color: colors::FILLED,
+            border_width: 0.0,
+            border_radius: 2.0,
+            border_color: Color::TRANSPARENT,
+        },
+        handle: Self::HANDLE,
+    };
+}
+impl range_slider::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn idle(&self, _style: &Self::Style) -> range_slider::Appearance {
+        Self::IDLE_STYLE
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> range_slider::Appearance {
+        range_slider::Appearance {
+            fill: range_slider::FillAppearance {
+                color: colors::FILLED_HOVER,
+                ..Self::IDLE_STYLE.fill
+            },
+            handle: range_slider::HandleAppearance {
+                color: colors::HANDLE_HOVER,
+                ..Self::HANDLE
+            },
+            ..Self::IDLE_STYLE
+        }
+    }
+}
@@ -0,0 +1,44 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced_audio::shape_editor;
+
+use super::colors;
+
+// Custom style for the ShapeEditor widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const IDLE_STYLE: shape_editor::Appearance = shape_editor::Appearance {
+        back_color: colors::EMPTY,
+        back_border_width: 2.0,
+        back_border_radius: 3.0,
+        back_border_color: colors::BORDER,
+        grid_color: colors::KNOB,
+        center_line_color: None,
+        line_color: colors::HANDLE,
+        line_width: 2.0,
+        fill_color: Some(Color {
+            r: colors::FILLED.r,
+            g: colors::FILLED.g,
+            b: colors::FILLED.b,
+            a: 0.5,
+        }),
+        phase_dot_color: Color::WHITE,
+        phase_dot_diameter: 10.0,
+    };
+}
+impl shape_editor::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn idle(&self, _style: &Self::Style) -> shape_editor::Appearance {
+        Self::IDLE_STYLE
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> shape_editor::Appearance {
+        shape_editor::Appearance {
+            line_color: colors::HANDLE_HOVER,
+            ..Self::IDLE_STYLE
+        }
+    }
+}
@@ -0,0 +1,23 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced_audio::spectrogram;
+
+use super::colors;
+
+// Custom style for the Spectrogram widget
+
+pub struct CustomStyle;
+impl spectrogram::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> spectrogram::Appearance {
+        spectrogram::Appearance {
+            back_color: colors::BORDER,
+            back_border_width: 2.0,
+            back_border_radius: 3.0,
+            back_border_color: colors::BORDER,
+            color_map: vec![colors::BORDER, colors::FILLED, colors::HANDLE, Color::WHITE],
+        }
+    }
+}
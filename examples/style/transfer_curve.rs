@@ -0,0 +1,46 @@
+#![allow(unused)]
+
+use iced::{Color, Font};
+use iced_audio::transfer_curve;
+
+use super::colors;
+
+// Custom style for the TransferCurve widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const IDLE_STYLE: transfer_curve::Appearance = transfer_curve::Appearance {
+        back_color: colors::EMPTY,
+        back_border_width: 2.0,
+        back_border_radius: 3.0,
+        back_border_color: colors::BORDER,
+        grid_color: Some(colors::KNOB),
+        unity_line_color: Some(colors::KNOB_BORDER),
+        line_color: colors::HANDLE,
+        line_width: 2.0,
+        handle: transfer_curve::HandleAppearance {
+            diameter: 10.0,
+            color: colors::KNOB,
+            border_width: 2.0,
+            border_color: colors::HANDLE,
+        },
+        active_handle: transfer_curve::HandleAppearance {
+            diameter: 12.0,
+            color: colors::HANDLE,
+            border_width: 2.0,
+            border_color: Color::WHITE,
+        },
+        input_color: colors::KNOB_ARC_RIGHT,
+        input_diameter: 10.0,
+        readout_color: colors::HANDLE,
+        readout_text_size: 14,
+        readout_font: Font::MONOSPACE,
+    };
+}
+impl transfer_curve::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn idle(&self, _style: &Self::Style) -> transfer_curve::Appearance {
+        Self::IDLE_STYLE
+    }
+}
@@ -0,0 +1,74 @@
+#![allow(unused)]
+
+use iced::{Color, Font};
+use iced_audio::{Offset, text_marks, tick_marks, tuner};
+
+use super::colors;
+
+// Custom style for the Tuner widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const APPEARANCE: tuner::Appearance = tuner::Appearance {
+        back_color: colors::EMPTY,
+        back_border_width: 1.0,
+        back_border_radius: 6.0,
+        back_border_color: colors::BORDER,
+        note_color: colors::HANDLE,
+        in_tune_note_color: colors::KNOB_ARC,
+        note_text_size: 36,
+        cents_color: colors::HANDLE,
+        cents_text_size: 12,
+        font: Font::MONOSPACE,
+        inactive_color: colors::KNOB,
+        scale_color: colors::KNOB,
+        scale_height: 4.0,
+        in_tune_zone_color: colors::KNOB_ARC,
+        needle_color: colors::KNOB_ARC_RIGHT,
+        in_tune_needle_color: colors::KNOB_ARC,
+        needle_width: 2.0,
+        needle_overhang: 8.0,
+    };
+}
+impl tuner::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> tuner::Appearance {
+        Self::APPEARANCE
+    }
+
+    fn tick_marks_appearance(&self, _style: &Self::Style) -> Option<tuner::TickMarksAppearance> {
+        Some(tuner::TickMarksAppearance {
+            style: tick_marks::Appearance {
+                tier_1: tick_marks::Shape::Line {
+                    length: 10.0,
+                    width: 2.0,
+                    color: colors::HANDLE,
+                },
+                tier_2: tick_marks::Shape::Line {
+                    length: 6.0,
+                    width: 1.0,
+                    color: colors::KNOB,
+                },
+                tier_3: tick_marks::Shape::None,
+            },
+            placement: tick_marks::Placement::LeftOrTop {
+                offset: Offset::new(0.0, -3.0),
+                inside: false,
+            },
+        })
+    }
+
+    fn text_marks_appearance(&self, _style: &Self::Style) -> Option<tuner::TextMarksAppearance> {
+        Some(tuner::TextMarksAppearance {
+            style: text_marks::Appearance {
+                color: colors::HANDLE,
+                ..Default::default()
+            },
+            placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset::new(0.0, 8.0),
+            },
+        })
+    }
+}
@@ -0,0 +1,49 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced_audio::value_box;
+
+use super::colors;
+
+// Custom style for the ValueBox widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const ACTIVE_APPEARANCE: value_box::Appearance = value_box::Appearance {
+        back_color: colors::EMPTY,
+        back_border_width: 1.0,
+        back_border_radius: 3.0,
+        back_border_color: colors::BORDER,
+        text_color: colors::HANDLE,
+        digit_highlight_color: Some(colors::KNOB_ARC),
+        caret_color: colors::HANDLE,
+    };
+}
+impl value_box::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn idle(&self, _style: &Self::Style) -> value_box::Appearance {
+        Self::ACTIVE_APPEARANCE
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> value_box::Appearance {
+        value_box::Appearance {
+            back_border_color: colors::KNOB,
+            ..Self::ACTIVE_APPEARANCE
+        }
+    }
+
+    fn gesturing(&self, _style: &Self::Style) -> value_box::Appearance {
+        value_box::Appearance {
+            back_border_color: colors::KNOB_ARC,
+            ..Self::ACTIVE_APPEARANCE
+        }
+    }
+
+    fn editing(&self, _style: &Self::Style) -> value_box::Appearance {
+        value_box::Appearance {
+            back_border_color: colors::KNOB_ARC_RIGHT,
+            ..Self::ACTIVE_APPEARANCE
+        }
+    }
+}
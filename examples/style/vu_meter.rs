@@ -0,0 +1,64 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced_audio::{text_marks, tick_marks, vu_meter};
+
+use super::colors;
+
+// Custom style for the VuMeter widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const APPEARANCE: vu_meter::Appearance = vu_meter::Appearance {
+        back_color: colors::EMPTY,
+        back_border_width: 1.0,
+        back_border_radius: 6.0,
+        back_border_color: colors::BORDER,
+        scale_color: colors::HANDLE,
+        scale_width: 1.0,
+        red_zone_color: Some(colors::KNOB_ARC_RIGHT),
+        red_zone_width: 3.0,
+        needle_color: colors::KNOB_ARC,
+        needle_width: 2.0,
+        pivot_color: colors::KNOB,
+        pivot_radius: 7.0,
+    };
+}
+impl vu_meter::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> vu_meter::Appearance {
+        Self::APPEARANCE
+    }
+
+    fn tick_marks_appearance(&self, _style: &Self::Style) -> Option<vu_meter::TickMarksAppearance> {
+        Some(vu_meter::TickMarksAppearance {
+            style: tick_marks::Appearance {
+                tier_1: tick_marks::Shape::Line {
+                    length: 8.0,
+                    width: 2.0,
+                    color: colors::HANDLE,
+                },
+                tier_2: tick_marks::Shape::Line {
+                    length: 5.0,
+                    width: 1.0,
+                    color: colors::KNOB,
+                },
+                tier_3: tick_marks::Shape::None,
+            },
+            offset: 1.0,
+        })
+    }
+
+    fn text_marks_appearance(&self, _style: &Self::Style) -> Option<vu_meter::TextMarksAppearance> {
+        Some(vu_meter::TextMarksAppearance {
+            style: text_marks::Appearance {
+                color: colors::HANDLE,
+                ..Default::default()
+            },
+            offset: 17.0,
+            h_char_offset: 2.0,
+            v_offset: -0.75,
+        })
+    }
+}
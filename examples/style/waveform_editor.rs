@@ -0,0 +1,65 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced_audio::waveform_editor;
+
+use super::colors;
+
+// Custom style for the WaveformEditor widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const IDLE_STYLE: waveform_editor::Appearance = waveform_editor::Appearance {
+        back_color: colors::EMPTY,
+        back_border_width: 2.0,
+        back_border_radius: 3.0,
+        back_border_color: colors::BORDER,
+        waveform_color: colors::HANDLE,
+        center_line_color: Some(colors::KNOB),
+        outside_color: Color {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 0.45,
+        },
+        loop_color: Color {
+            r: colors::FILLED.r,
+            g: colors::FILLED.g,
+            b: colors::FILLED.b,
+            a: 0.4,
+        },
+        crossfade_color: Color {
+            r: colors::KNOB_ARC_RIGHT.r,
+            g: colors::KNOB_ARC_RIGHT.g,
+            b: colors::KNOB_ARC_RIGHT.b,
+            a: 0.25,
+        },
+        start_marker: waveform_editor::MarkerAppearance {
+            color: Color::WHITE,
+            width: 2.0,
+            flag_size: 10.0,
+        },
+        end_marker: waveform_editor::MarkerAppearance {
+            color: Color::WHITE,
+            width: 2.0,
+            flag_size: 10.0,
+        },
+        loop_marker: waveform_editor::MarkerAppearance {
+            color: colors::KNOB_ARC,
+            width: 2.0,
+            flag_size: 10.0,
+        },
+        crossfade_marker: waveform_editor::MarkerAppearance {
+            color: colors::KNOB_ARC_RIGHT,
+            width: 2.0,
+            flag_size: 10.0,
+        },
+    };
+}
+impl waveform_editor::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn idle(&self, _style: &Self::Style) -> waveform_editor::Appearance {
+        Self::IDLE_STYLE
+    }
+}
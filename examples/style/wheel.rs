@@ -0,0 +1,56 @@
+#![allow(unused)]
+
+use iced::Color;
+use iced_audio::wheel;
+
+use super::colors;
+
+// Custom style for the Wheel widget
+
+pub struct CustomStyle;
+impl CustomStyle {
+    const IDLE_STYLE: wheel::Appearance = wheel::Appearance {
+        back_color: colors::BORDER,
+        back_border_width: 2.0,
+        back_border_radius: 4.0,
+        back_border_color: colors::KNOB_BORDER,
+        wheel_padding: 3.0,
+        wheel_color: colors::KNOB,
+        wheel_edge_color: colors::EMPTY,
+        wheel_border_radius: 3.0,
+        ridge_color: Color {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 0.45,
+        },
+        ridge_width: 3.0,
+        ridge_count: 8,
+        notch: Some(wheel::WheelNotch {
+            color: colors::HANDLE,
+            width: 4.0,
+        }),
+        center_marker: Some(wheel::CenterMarker {
+            color: colors::HANDLE,
+            width: 2.0,
+            length: 3.0,
+        }),
+    };
+}
+impl wheel::StyleSheet for CustomStyle {
+    type Style = iced::Theme;
+
+    fn idle(&self, _style: &Self::Style) -> wheel::Appearance {
+        Self::IDLE_STYLE
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> wheel::Appearance {
+        wheel::Appearance {
+            notch: Some(wheel::WheelNotch {
+                color: colors::HANDLE_HOVER,
+                width: 4.0,
+            }),
+            ..Self::IDLE_STYLE
+        }
+    }
+}
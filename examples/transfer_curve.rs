@@ -0,0 +1,163 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Length, Result, Size, Subscription, application,
+    widget::{column, row, text},
+    window,
+};
+use iced_audio::{
+    DBRange, FloatRange, Gesture, Normal, NormalParam, TransferCurve, text_marks, tick_marks,
+    transfer_curve,
+};
+use std::time::Instant;
+
+const THRESHOLD_RANGE: DBRange = DBRange::new(-60.0, 0.0, Normal::MAX, 1.0);
+const RATIO_RANGE: FloatRange = FloatRange::new(1.0, 20.0);
+const KNEE_RANGE: FloatRange = FloatRange::new(0.0, 24.0);
+const MAKEUP_RANGE: DBRange = DBRange::new(0.0, 24.0, Normal::MIN, 1.0);
+
+fn main() -> Result {
+    application(
+        TransferCurveExample::default,
+        TransferCurveExample::update,
+        TransferCurveExample::view,
+    )
+    .subscription(TransferCurveExample::subscription)
+    .window_size(Size::new(700.0, 480.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Threshold(Gesture),
+    Ratio(Gesture),
+    Knee(Gesture),
+    Makeup(Gesture),
+    Frame(Instant),
+}
+
+pub struct TransferCurveExample {
+    threshold: NormalParam,
+    ratio: NormalParam,
+    knee: NormalParam,
+    makeup: NormalParam,
+
+    start: Instant,
+    input_level: f32,
+
+    tick_marks: tick_marks::Group,
+    text_marks: text_marks::Group,
+}
+
+impl Default for TransferCurveExample {
+    fn default() -> Self {
+        // Grid lines every 6 dB and labels every 12 dB from -60 dB to 0 dB.
+        let normal = |db: f32| Normal::new((db + 60.0) / 60.0);
+
+        let tick_marks: Vec<_> = (0..=10)
+            .map(|i| {
+                let tier = if i % 2 == 0 {
+                    tick_marks::Tier::One
+                } else {
+                    tick_marks::Tier::Two
+                };
+                (normal(-60.0 + 6.0 * i as f32), tier)
+            })
+            .collect();
+
+        let text_marks: Vec<_> = (0..=5)
+            .map(|i| {
+                let db = -60.0 + 12.0 * i as f32;
+                (normal(db), format!("{db}"))
+            })
+            .collect();
+
+        Self {
+            threshold: THRESHOLD_RANGE.param(-24.0, -24.0),
+            ratio: RATIO_RANGE.param(4.0, 4.0),
+            knee: KNEE_RANGE.param(6.0, 6.0),
+            makeup: MAKEUP_RANGE.param(0.0, 0.0),
+
+            start: Instant::now(),
+            input_level: -60.0,
+
+            tick_marks: tick_marks::Group::from_normalized(&tick_marks),
+            text_marks: text_marks.into(),
+        }
+    }
+}
+
+impl TransferCurveExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Threshold(Gesture::Gesturing(normal)) => self.threshold.set(normal),
+            Message::Ratio(Gesture::Gesturing(normal)) => self.ratio.set(normal),
+            Message::Knee(Gesture::Gesturing(normal)) => self.knee.set(normal),
+            Message::Makeup(Gesture::Gesturing(normal)) => self.makeup.set(normal),
+            Message::Frame(now) => {
+                // Fake an input signal that swells up and down.
+                let time = (now - self.start).as_secs_f32();
+                self.input_level = -30.0 + 25.0 * (time * 0.8).sin() * (time * 0.13).cos();
+            }
+            _ => {}
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Frame)
+    }
+
+    fn gain_reduction(&self) -> f32 {
+        let threshold = THRESHOLD_RANGE.unmap_to_db(self.threshold.normal);
+        let ratio = RATIO_RANGE.unmap_to_value(self.ratio.normal);
+        let knee = KNEE_RANGE.unmap_to_value(self.knee.normal);
+
+        transfer_curve::output_db(self.input_level, threshold, ratio, knee) - self.input_level
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let curve = TransferCurve::new(self.threshold, self.ratio, self.knee, self.makeup)
+            .input_level(Some(self.input_level))
+            .gain_reduction(Some(self.gain_reduction()))
+            .tick_marks(&self.tick_marks)
+            .text_marks(&self.text_marks)
+            .on_gesture_threshold(Message::Threshold)
+            .on_gesture_ratio(Message::Ratio)
+            .on_gesture_knee(Message::Knee)
+            .on_gesture_makeup(Message::Makeup);
+
+        let custom_curve = TransferCurve::new(self.threshold, self.ratio, self.knee, self.makeup)
+            .width(Length::Fixed(260.0))
+            .height(Length::Fixed(260.0))
+            .input_level(Some(self.input_level))
+            .gain_reduction(Some(self.gain_reduction()))
+            .tick_marks(&self.tick_marks)
+            .text_marks(&self.text_marks)
+            .on_gesture_threshold(Message::Threshold)
+            .on_gesture_ratio(Message::Ratio)
+            .on_gesture_knee(Message::Knee)
+            .on_gesture_makeup(Message::Makeup)
+            .style(style::transfer_curve::CustomStyle);
+
+        column![
+            text(
+                "Drag the handles to change threshold, ratio (right edge), knee (Alt), \
+                 and makeup (left edge). Double-click to reset."
+            )
+            .size(14),
+            row![curve, custom_curve].spacing(60),
+            text(format!(
+                "Threshold: {:.1} dB  Ratio: {:.1}:1  Knee: {:.1} dB  Makeup: {:.1} dB",
+                THRESHOLD_RANGE.unmap_to_db(self.threshold.normal),
+                RATIO_RANGE.unmap_to_value(self.ratio.normal),
+                KNEE_RANGE.unmap_to_value(self.knee.normal),
+                MAKEUP_RANGE.unmap_to_db(self.makeup.normal),
+            ))
+            .size(16),
+        ]
+        .spacing(30)
+        .padding(40)
+        .into()
+    }
+}
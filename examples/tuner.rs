@@ -0,0 +1,109 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Length, Result, Size, Subscription, application,
+    widget::{column, row, text},
+    window,
+};
+use iced_audio::{FloatRange, Gesture, HSlider, NormalParam, Tuner, text_marks, tick_marks, tuner};
+use std::time::Instant;
+
+const REFERENCE_RANGE: FloatRange = FloatRange::new(415.0, 466.0);
+
+fn main() -> Result {
+    application(
+        TunerExample::default,
+        TunerExample::update,
+        TunerExample::view,
+    )
+    .subscription(TunerExample::subscription)
+    .window_size(Size::new(640.0, 360.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Reference(Gesture),
+    Frame(Instant),
+}
+
+pub struct TunerExample {
+    reference: NormalParam,
+
+    start: Instant,
+    frequency: Option<f32>,
+
+    tick_marks: tick_marks::Group,
+    text_marks: text_marks::Group,
+}
+
+impl Default for TunerExample {
+    fn default() -> Self {
+        Self {
+            reference: REFERENCE_RANGE.param(440.0, 440.0),
+
+            start: Instant::now(),
+            frequency: None,
+
+            tick_marks: tuner::standard_tick_marks(),
+            text_marks: tuner::standard_text_marks(),
+        }
+    }
+}
+
+impl TunerExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Reference(Gesture::Gesturing(normal)) => self.reference.set(normal),
+            Message::Frame(now) => {
+                // Fake a pitch detector listening to someone tuning the low E
+                // string of a guitar, with a short silence between plucks.
+                let time = (now - self.start).as_secs_f32();
+                let pluck = time % 4.0;
+
+                self.frequency = if pluck < 3.5 {
+                    let cents = 40.0 * (time * 0.3).sin() * (1.0 - pluck / 4.0);
+                    Some(82.41 * 2.0f32.powf(cents / 1200.0))
+                } else {
+                    None
+                };
+            }
+            _ => {}
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Frame)
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let reference = REFERENCE_RANGE.unmap_to_value(self.reference.normal);
+
+        let tuner = Tuner::new(self.frequency)
+            .reference(reference)
+            .tick_marks(&self.tick_marks)
+            .text_marks(&self.text_marks);
+
+        let custom_tuner = Tuner::new(self.frequency)
+            .reference(reference)
+            .accidentals(tuner::Accidentals::Flats)
+            .in_tune_cents(2.0)
+            .tick_marks(&self.tick_marks)
+            .text_marks(&self.text_marks)
+            .style(style::tuner::CustomStyle);
+
+        let reference_slider = HSlider::new(self.reference)
+            .width(Length::Fixed(240.0))
+            .on_gesture(Message::Reference);
+
+        column![
+            row![tuner, custom_tuner].spacing(40),
+            reference_slider,
+            text(format!("A4 = {reference:.1} Hz")).size(16),
+        ]
+        .spacing(30)
+        .padding(40)
+        .into()
+    }
+}
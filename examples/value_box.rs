@@ -0,0 +1,103 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Length, Result, Size, application,
+    widget::{column, row, text},
+};
+use iced_audio::{DBRange, FloatRange, FreqRange, Gesture, IntRange, NormalParam, ValueBox};
+
+const TEMPO_RANGE: FloatRange = FloatRange::new(20.0, 300.0);
+const TRANSPOSE_RANGE: IntRange = IntRange::new(-24, 24);
+const GAIN_RANGE: DBRange = DBRange::NEG_24_TO_24;
+const CUTOFF_RANGE: FreqRange = FreqRange::HZ_20_TO_20K;
+
+fn main() -> Result {
+    application(
+        ValueBoxExample::default,
+        ValueBoxExample::update,
+        ValueBoxExample::view,
+    )
+    .window_size(Size::new(560.0, 320.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Tempo(Gesture),
+    Transpose(Gesture),
+    Gain(Gesture),
+    Cutoff(Gesture),
+}
+
+pub struct ValueBoxExample {
+    tempo: NormalParam,
+    transpose: NormalParam,
+    gain: NormalParam,
+    cutoff: NormalParam,
+}
+
+impl Default for ValueBoxExample {
+    fn default() -> Self {
+        Self {
+            tempo: TEMPO_RANGE.param(120.0, 120.0),
+            transpose: TRANSPOSE_RANGE.param(0, 0),
+            gain: GAIN_RANGE.default_param(),
+            cutoff: CUTOFF_RANGE.param(1000.0, 1000.0),
+        }
+    }
+}
+
+impl ValueBoxExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Tempo(Gesture::Gesturing(normal)) => self.tempo.set(normal),
+            Message::Transpose(Gesture::Gesturing(normal)) => self.transpose.set(normal),
+            Message::Gain(Gesture::Gesturing(normal)) => self.gain.set(normal),
+            Message::Cutoff(Gesture::Gesturing(normal)) => self.cutoff.set(normal),
+            _ => {}
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let tempo = ValueBox::new(self.tempo, TEMPO_RANGE)
+            .unit("BPM")
+            .width(Length::Fixed(100.0))
+            .on_gesture(Message::Tempo);
+
+        let transpose = ValueBox::new(self.transpose, TRANSPOSE_RANGE)
+            .unit("st")
+            .on_gesture(Message::Transpose);
+
+        // Without a unit or decimals, the range formats the value.
+        let gain = ValueBox::new(self.gain, GAIN_RANGE)
+            .on_gesture(Message::Gain)
+            .style(style::value_box::CustomStyle);
+
+        let cutoff = ValueBox::new(self.cutoff, CUTOFF_RANGE)
+            .width(Length::Fixed(100.0))
+            .on_gesture(Message::Cutoff)
+            .style(style::value_box::CustomStyle);
+
+        column![
+            text(
+                "Drag a digit up or down to change it, or scroll over it. \
+                 Click a box and type a value, then press Enter. \
+                 Double-click to reset."
+            )
+            .size(14),
+            row![tempo, transpose, gain, cutoff].spacing(20),
+            text(format!(
+                "Tempo: {:.2}  Transpose: {}  Gain: {:.2} dB  Cutoff: {:.1} Hz",
+                TEMPO_RANGE.unmap_to_value(self.tempo.normal),
+                TRANSPOSE_RANGE.unmap_to_value(self.transpose.normal),
+                GAIN_RANGE.unmap_to_db(self.gain.normal),
+                CUTOFF_RANGE.unmap_to_freq(self.cutoff.normal),
+            ))
+            .size(16),
+        ]
+        .spacing(30)
+        .padding(40)
+        .into()
+    }
+}
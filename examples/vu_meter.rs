@@ -0,0 +1,91 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Result, Size, Subscription, application,
+    widget::{column, row, text},
+    window,
+};
+use iced_audio::{VuMeter, text_marks, tick_marks, vu_meter};
+use std::time::Instant;
+
+fn main() -> Result {
+    application(
+        VuMeterExample::default,
+        VuMeterExample::update,
+        VuMeterExample::view,
+    )
+    .subscription(VuMeterExample::subscription)
+    .window_size(Size::new(640.0, 360.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Frame(Instant),
+}
+
+pub struct VuMeterExample {
+    start: Instant,
+    level: f32,
+
+    tick_marks: tick_marks::Group,
+    text_marks: text_marks::Group,
+}
+
+impl Default for VuMeterExample {
+    fn default() -> Self {
+        Self {
+            start: Instant::now(),
+            level: f32::NEG_INFINITY,
+
+            tick_marks: vu_meter::standard_tick_marks(),
+            text_marks: vu_meter::standard_text_marks(),
+        }
+    }
+}
+
+impl VuMeterExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Frame(now) => {
+                // Fake the RMS level of a mix: phrases that swell around the
+                // reference level with the odd loud hit.
+                let time = (now - self.start).as_secs_f32();
+                let phrase = 6.0 * (time * 0.7).sin() + 3.0 * (time * 2.3).sin();
+                let hit = if (time * 0.5).fract() < 0.05 {
+                    5.0
+                } else {
+                    0.0
+                };
+
+                self.level = -20.0 + phrase + hit;
+            }
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Frame)
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let meter = VuMeter::new(self.level)
+            .tick_marks(&self.tick_marks)
+            .text_marks(&self.text_marks);
+
+        let custom_meter = VuMeter::new(self.level)
+            .reference(-14.0)
+            .tick_marks(&self.tick_marks)
+            .text_marks(&self.text_marks)
+            .style(style::vu_meter::CustomStyle);
+
+        column![
+            text("0 VU = -18 dBFS (left) and 0 VU = -14 dBFS (right)").size(14),
+            row![meter, custom_meter].spacing(40),
+            text(format!("Level: {:.1} dBFS", self.level)).size(16),
+        ]
+        .spacing(30)
+        .padding(40)
+        .into()
+    }
+}
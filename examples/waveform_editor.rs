@@ -0,0 +1,141 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Result, Size, application,
+    widget::{checkbox, column, text},
+};
+use iced_audio::{FloatRange, Gesture, NormalParam, WaveformEditor};
+
+fn main() -> Result {
+    application(
+        WaveformEditorExample::default,
+        WaveformEditorExample::update,
+        WaveformEditorExample::view,
+    )
+    .window_size(Size::new(800.0, 500.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Start(Gesture),
+    End(Gesture),
+    LoopStart(Gesture),
+    LoopEnd(Gesture),
+    Crossfade(Gesture),
+    SnapToZeroCrossings(bool),
+}
+
+pub struct WaveformEditorExample {
+    samples: Vec<f32>,
+
+    start: NormalParam,
+    end: NormalParam,
+    loop_start: NormalParam,
+    loop_end: NormalParam,
+    crossfade: NormalParam,
+
+    snap_to_zero_crossings: bool,
+
+    output_text: String,
+}
+
+impl Default for WaveformEditorExample {
+    fn default() -> Self {
+        // A decaying, slightly detuned tone with a bit of noise.
+        const LEN: usize = 48_000;
+        let mut noise: u32 = 0x1234_5678;
+        let samples = (0..LEN)
+            .map(|i| {
+                let t = i as f32 / LEN as f32;
+                noise ^= noise << 13;
+                noise ^= noise >> 17;
+                noise ^= noise << 5;
+                let white = (noise as f32 / u32::MAX as f32) * 2.0 - 1.0;
+
+                let tone = (t * 220.0 * std::f32::consts::TAU).sin() * 0.7
+                    + (t * 331.0 * std::f32::consts::TAU).sin() * 0.3;
+                (tone * 0.9 + white * 0.1) * (-t * 3.0).exp()
+            })
+            .collect();
+
+        let range = FloatRange::NORMAL;
+
+        Self {
+            samples,
+
+            start: range.param(0.0, 0.0),
+            end: range.param(1.0, 1.0),
+            loop_start: range.param(0.3, 0.3),
+            loop_end: range.param(0.7, 0.7),
+            crossfade: range.param(0.6, 0.6),
+
+            snap_to_zero_crossings: true,
+
+            output_text: String::new(),
+        }
+    }
+}
+
+impl WaveformEditorExample {
+    fn update(&mut self, message: Message) {
+        let (name, param, gesture) = match message {
+            Message::Start(gesture) => ("Start", &mut self.start, gesture),
+            Message::End(gesture) => ("End", &mut self.end, gesture),
+            Message::LoopStart(gesture) => ("Loop Start", &mut self.loop_start, gesture),
+            Message::LoopEnd(gesture) => ("Loop End", &mut self.loop_end, gesture),
+            Message::Crossfade(gesture) => ("Crossfade", &mut self.crossfade, gesture),
+            Message::SnapToZeroCrossings(snap) => {
+                self.snap_to_zero_crossings = snap;
+                return;
+            }
+        };
+
+        if let Gesture::Gesturing(normal) = gesture {
+            param.set(normal);
+            self.output_text = format!(
+                "{}: sample {}",
+                name,
+                (normal.as_f32() * (self.samples.len() - 1) as f32).round()
+            );
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let waveform_editor = WaveformEditor::new(&self.samples, self.start, self.end)
+            .loop_points(self.loop_start, self.loop_end)
+            .crossfade(self.crossfade)
+            .snap_to_zero_crossings(self.snap_to_zero_crossings)
+            .on_gesture_start(Message::Start)
+            .on_gesture_end(Message::End)
+            .on_gesture_loop_start(Message::LoopStart)
+            .on_gesture_loop_end(Message::LoopEnd)
+            .on_gesture_crossfade(Message::Crossfade);
+
+        let custom_waveform_editor = WaveformEditor::new(&self.samples, self.start, self.end)
+            .snap_to_zero_crossings(self.snap_to_zero_crossings)
+            .on_gesture_start(Message::Start)
+            .on_gesture_end(Message::End)
+            .style(style::waveform_editor::CustomStyle);
+
+        column![
+            text(
+                "Drag markers to move them (Shift to skip snapping), double-click to reset. \
+                 Scroll to move, Ctrl + scroll to zoom."
+            )
+            .size(14),
+            checkbox(self.snap_to_zero_crossings)
+                .label("Snap to zero crossings")
+                .on_toggle(Message::SnapToZeroCrossings),
+            text("With Loop and Crossfade"),
+            waveform_editor,
+            text("Start and End Only (Custom Style)"),
+            custom_waveform_editor,
+            text(&self.output_text).size(16),
+        ]
+        .spacing(16)
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,117 @@
+mod style;
+mod util;
+
+use iced::{
+    Element, Length, Result, Size, application,
+    widget::{column, row, text},
+};
+use iced_audio::{FloatRange, Gesture, Normal, NormalParam, Wheel, wheel::WheelMode};
+
+use crate::util::info_text::info_text_f32;
+
+fn main() -> Result {
+    application(
+        WheelExample::default,
+        WheelExample::update,
+        WheelExample::view,
+    )
+    .window_size(Size::new(600.0, 400.0))
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    PitchBend(Gesture),
+    ModWheel(Gesture),
+    CustomPitchBend(Gesture),
+}
+
+pub struct WheelExample {
+    pitch_bend_range: FloatRange,
+    mod_wheel_range: FloatRange,
+
+    pitch_bend_param: NormalParam,
+    mod_wheel_param: NormalParam,
+    custom_pitch_bend_param: NormalParam,
+
+    output_text: String,
+}
+
+impl Default for WheelExample {
+    fn default() -> Self {
+        // initalize the parameter ranges
+        let pitch_bend_range = FloatRange::new(-2.0, 2.0);
+        let mod_wheel_range = FloatRange::NORMAL;
+
+        Self {
+            pitch_bend_range,
+            mod_wheel_range,
+
+            // initialize the state of the wheel widgets
+            pitch_bend_param: pitch_bend_range.default_param(),
+            mod_wheel_param: mod_wheel_range.default_param(),
+            custom_pitch_bend_param: pitch_bend_range.default_param(),
+
+            output_text: String::new(),
+        }
+    }
+}
+
+impl WheelExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::PitchBend(Gesture::Gesturing(normal)) => {
+                self.pitch_bend_param.set(normal);
+                self.output_text = info_text_f32("PitchBend", normal, &self.pitch_bend_range);
+            }
+            Message::ModWheel(Gesture::Gesturing(normal)) => {
+                self.mod_wheel_param.set(normal);
+                self.output_text = info_text_f32("ModWheel", normal, &self.mod_wheel_range);
+            }
+            Message::CustomPitchBend(Gesture::Gesturing(normal)) => {
+                self.custom_pitch_bend_param.set(normal);
+                self.output_text = info_text_f32("CustomPitchBend", normal, &self.pitch_bend_range);
+            }
+            _ => {}
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        // create each of the Wheel widgets, passing in the value of
+        // the corresponding parameter
+
+        let pitch_bend = Wheel::new(self.pitch_bend_param)
+            .mode(WheelMode::Spring)
+            .bipolar_center(Normal::CENTER)
+            .on_gesture(Message::PitchBend);
+
+        let mod_wheel = Wheel::new(self.mod_wheel_param).on_gesture(Message::ModWheel);
+
+        let custom_pitch_bend = Wheel::new(self.custom_pitch_bend_param)
+            .mode(WheelMode::Spring)
+            .bipolar_center(Normal::CENTER)
+            .width(Length::Fixed(30.0))
+            .height(Length::Fixed(130.0))
+            .on_gesture(Message::CustomPitchBend)
+            .style(style::wheel::CustomStyle);
+
+        // push the widgets into rows
+        let wheel_row = row![
+            column![text("Pitch Bend"), pitch_bend]
+                .width(Length::Fill)
+                .spacing(10),
+            column![text("Mod Wheel"), mod_wheel]
+                .width(Length::Fill)
+                .spacing(10),
+            column![text("Custom Style"), custom_pitch_bend]
+                .width(Length::Fill)
+                .spacing(10),
+        ]
+        .spacing(20);
+
+        column![wheel_row, text(&self.output_text).size(16),]
+            .spacing(20)
+            .padding(20)
+            .into()
+    }
+}
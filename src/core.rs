@@ -4,6 +4,7 @@
 //! different runtime implementations.
 
 pub mod knob_angle_range;
+pub mod loudness;
 pub mod math;
 pub mod modulation_range;
 pub mod normal;
@@ -12,6 +13,9 @@ pub mod param;
 pub mod range;
 pub mod virtual_slider;
 
+#[cfg(feature = "serde")]
+pub(crate) mod serde_support;
+
 pub mod text_marks;
 pub mod tick_marks;
 
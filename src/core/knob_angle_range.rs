@@ -20,11 +20,42 @@ pub const DEFAULT_ANGLE_MAX: f32 = (360.0 - 30.0) * PI_OVER_180;
 /// maximum is `330` degrees, giving a span of `300` degrees, and a halfway
 /// point pointing strait up.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedKnobAngleRange")
+)]
 pub struct KnobAngleRange {
     min: f32,
     max: f32,
 }
 
+/// Deserializes a [`KnobAngleRange`] so that bad data is rejected by the
+/// same rules as [`from_rad`].
+///
+/// [`KnobAngleRange`]: struct.KnobAngleRange.html
+/// [`from_rad`]: struct.KnobAngleRange.html#method.from_rad
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedKnobAngleRange {
+    min: f32,
+    max: f32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedKnobAngleRange> for KnobAngleRange {
+    type Error = &'static str;
+
+    fn try_from(unchecked: UncheckedKnobAngleRange) -> Result<Self, Self::Error> {
+        let range = KnobAngleRange {
+            min: unchecked.min,
+            max: unchecked.max,
+        };
+        range.check()?;
+        Ok(range)
+    }
+}
+
 impl std::default::Default for KnobAngleRange {
     fn default() -> Self {
         Self {
@@ -88,6 +119,18 @@ impl KnobAngleRange {
         Self { min, max }
     }
 
+    #[cfg(feature = "serde")]
+    fn check(&self) -> Result<(), &'static str> {
+        if !(0.0..TWO_PI).contains(&self.min) || !(0.0..TWO_PI).contains(&self.max) {
+            return Err("min and max must be in the range [0.0, TWO_PI)");
+        }
+        if self.min > self.max {
+            return Err("max must be >= min");
+        }
+
+        Ok(())
+    }
+
     /// returns the minimum angle (between `0.0` and `TWO_PI` in radians)
     pub fn min(&self) -> f32 {
         self.min
@@ -97,3 +140,19 @@ impl KnobAngleRange {
         self.max
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_checked() {
+        let unchecked = |min, max| UncheckedKnobAngleRange { min, max };
+
+        assert!(KnobAngleRange::try_from(unchecked(0.5, 5.0)).is_ok());
+        assert!(KnobAngleRange::try_from(unchecked(5.0, 0.5)).is_err());
+        assert!(KnobAngleRange::try_from(unchecked(-0.5, 5.0)).is_err());
+        assert!(KnobAngleRange::try_from(unchecked(0.5, TWO_PI)).is_err());
+        assert!(KnobAngleRange::try_from(unchecked(f32::NAN, 5.0)).is_err());
+    }
+}
@@ -0,0 +1,609 @@
+//! Loudness measurement as specified in EBU R128 / ITU-R BS.1770.
+//!
+//! Feed blocks of samples to an [`Analyzer`] and read back the momentary,
+//! short-term and integrated loudness, the loudness range and the true peak.
+//!
+//! [`Analyzer`]: struct.Analyzer.html
+
+use std::collections::VecDeque;
+use std::f64::consts::PI;
+
+/// The length of one gating step in seconds. Momentary and short-term
+/// loudness are updated once every step.
+const STEP_SECONDS: f64 = 0.1;
+/// The number of steps in a momentary (400 ms) block.
+const MOMENTARY_STEPS: usize = 4;
+/// The number of steps in a short-term (3 s) block.
+const SHORT_TERM_STEPS: usize = 30;
+/// The absolute gate in LUFS.
+const ABSOLUTE_GATE: f64 = -70.0;
+/// The relative gate of the integrated loudness in LU.
+const INTEGRATED_RELATIVE_GATE: f64 = -10.0;
+/// The relative gate of the loudness range in LU.
+const RANGE_RELATIVE_GATE: f64 = -20.0;
+/// The lower and upper percentiles of the loudness range.
+const RANGE_PERCENTILES: (f64, f64) = (0.10, 0.95);
+/// The number of bins of a gating histogram, covering -70 to +30 LUFS.
+const HISTOGRAM_BINS: usize = 1000;
+/// The width of one bin of a gating histogram in LU.
+const HISTOGRAM_BIN_WIDTH: f64 = 0.1;
+/// The oversampling factor used to find the true peak.
+const TRUE_PEAK_OVERSAMPLING: usize = 4;
+/// The number of taps of each phase of the true peak interpolation filter.
+const TRUE_PEAK_TAPS_PER_PHASE: usize = 12;
+
+/// A snapshot of the values measured by an [`Analyzer`].
+///
+/// Values that could not be measured yet (for example the short-term
+/// loudness in the first three seconds) are `f32::NEG_INFINITY`.
+///
+/// [`Analyzer`]: struct.Analyzer.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Measurement {
+    /// The momentary loudness (400 ms window) in LUFS.
+    pub momentary: f32,
+    /// The short-term loudness (3 s window) in LUFS.
+    pub short_term: f32,
+    /// The gated integrated loudness since the last reset in LUFS.
+    pub integrated: f32,
+    /// The lower bound (10th percentile) of the loudness range in LUFS.
+    pub range_low: f32,
+    /// The upper bound (95th percentile) of the loudness range in LUFS.
+    pub range_high: f32,
+    /// The highest true peak since the last reset in dBTP.
+    pub true_peak: f32,
+}
+
+impl Measurement {
+    /// A `Measurement` where nothing has been measured.
+    pub const EMPTY: Self = Self {
+        momentary: f32::NEG_INFINITY,
+        short_term: f32::NEG_INFINITY,
+        integrated: f32::NEG_INFINITY,
+        range_low: f32::NEG_INFINITY,
+        range_high: f32::NEG_INFINITY,
+        true_peak: f32::NEG_INFINITY,
+    };
+
+    /// Returns the loudness range (LRA) in LU, or `0.0` if it could not be
+    /// measured yet.
+    pub fn loudness_range(&self) -> f32 {
+        if self.range_low.is_finite() && self.range_high.is_finite() {
+            self.range_high - self.range_low
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for Measurement {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// A biquad filter in transposed direct form II.
+#[derive(Debug, Copy, Clone)]
+struct Biquad {
+    b0: f64,
+    b1: f64,
+    b2: f64,
+    a1: f64,
+    a2: f64,
+    z1: f64,
+    z2: f64,
+}
+
+impl Biquad {
+    /// The first stage of the K-weighting filter, a high shelf that models
+    /// the acoustic effect of the head.
+    fn k_weighting_shelf(sample_rate: f64) -> Self {
+        let f0 = 1681.974450955533;
+        let gain = 3.999843853973347;
+        let q = 0.7071752369554196;
+
+        let k = (PI * f0 / sample_rate).tan();
+        let vh = 10.0f64.powf(gain / 20.0);
+        let vb = vh.powf(0.4996667741545416);
+        let a0 = 1.0 + k / q + k * k;
+
+        Self::new(
+            (vh + vb * k / q + k * k) / a0,
+            2.0 * (k * k - vh) / a0,
+            (vh - vb * k / q + k * k) / a0,
+            2.0 * (k * k - 1.0) / a0,
+            (1.0 - k / q + k * k) / a0,
+        )
+    }
+
+    /// The second stage of the K-weighting filter, a high pass filter
+    /// (the "RLB" curve).
+    fn k_weighting_high_pass(sample_rate: f64) -> Self {
+        let f0 = 38.13547087602444;
+        let q = 0.5003270373238773;
+
+        let k = (PI * f0 / sample_rate).tan();
+        let a0 = 1.0 + k / q + k * k;
+
+        Self::new(
+            1.0,
+            -2.0,
+            1.0,
+            2.0 * (k * k - 1.0) / a0,
+            (1.0 - k / q + k * k) / a0,
+        )
+    }
+
+    fn new(b0: f64, b1: f64, b2: f64, a1: f64, a2: f64) -> Self {
+        Self {
+            b0,
+            b1,
+            b2,
+            a1,
+            a2,
+            z1: 0.0,
+            z2: 0.0,
+        }
+    }
+
+    fn process(&mut self, x: f64) -> f64 {
+        let y = self.b0 * x + self.z1;
+        self.z1 = self.b1 * x - self.a1 * y + self.z2;
+        self.z2 = self.b2 * x - self.a2 * y;
+        y
+    }
+
+    fn reset(&mut self) {
+        self.z1 = 0.0;
+        self.z2 = 0.0;
+    }
+}
+
+/// The blocks that passed the absolute gate, binned by loudness as in
+/// libebur128. Gating and percentiles only need to walk the bins, so the
+/// cost stays the same no matter how long the analyzer has been running.
+#[derive(Debug, Clone)]
+struct Histogram {
+    /// The number of blocks in each bin.
+    counts: Vec<u64>,
+    /// The sum of the mean squares of the blocks in each bin.
+    powers: Vec<f64>,
+}
+
+impl Histogram {
+    fn new() -> Self {
+        Self {
+            counts: vec![0; HISTOGRAM_BINS],
+            powers: vec![0.0; HISTOGRAM_BINS],
+        }
+    }
+
+    /// Adds the mean square of a block, unless it falls below the absolute
+    /// gate.
+    fn add(&mut self, power: f64) {
+        let loudness = loudness(power);
+        if loudness <= ABSOLUTE_GATE {
+            return;
+        }
+
+        let bin = Self::bin(loudness);
+        self.counts[bin] += 1;
+        self.powers[bin] += power;
+    }
+
+    /// Returns the index of the bin that holds `loudness`.
+    fn bin(loudness: f64) -> usize {
+        let bin = ((loudness - ABSOLUTE_GATE) / HISTOGRAM_BIN_WIDTH).max(0.0) as usize;
+        bin.min(HISTOGRAM_BINS - 1)
+    }
+
+    /// Returns the bins that pass the relative gate (relative to the
+    /// loudness of all blocks in the histogram) as `(count, power)` pairs,
+    /// or `None` if the histogram is empty.
+    fn gated(&self, relative_gate: f64) -> Option<impl Iterator<Item = (u64, f64)> + '_> {
+        let count: u64 = self.counts.iter().sum();
+        if count == 0 {
+            return None;
+        }
+
+        let power: f64 = self.powers.iter().sum();
+        let first = Self::bin(loudness(power / count as f64) + relative_gate);
+
+        Some(
+            self.counts[first..]
+                .iter()
+                .copied()
+                .zip(self.powers[first..].iter().copied()),
+        )
+    }
+
+    /// Returns the loudness of the blocks that pass the relative gate in
+    /// LUFS.
+    fn gated_loudness(&self, relative_gate: f64) -> Option<f64> {
+        let (count, power) = self
+            .gated(relative_gate)?
+            .fold((0, 0.0), |(count, power), bin| {
+                (count + bin.0, power + bin.1)
+            });
+
+        (count > 0).then(|| loudness(power / count as f64))
+    }
+
+    /// Returns the loudness of the blocks at the given percentiles of the
+    /// blocks that pass the relative gate in LUFS.
+    fn gated_percentiles(&self, relative_gate: f64, percentiles: (f64, f64)) -> Option<(f64, f64)> {
+        let bins: Vec<(u64, f64)> = self
+            .gated(relative_gate)?
+            .filter(|(count, _)| *count > 0)
+            .collect();
+        let total: u64 = bins.iter().map(|(count, _)| count).sum();
+        if total == 0 {
+            return None;
+        }
+
+        let percentile = |p: f64| {
+            let index = ((total - 1) as f64 * p).round() as u64;
+            let mut seen = 0;
+            for (count, power) in &bins {
+                seen += count;
+                if seen > index {
+                    return loudness(power / *count as f64);
+                }
+            }
+            f64::NEG_INFINITY
+        };
+
+        Some((percentile(percentiles.0), percentile(percentiles.1)))
+    }
+
+    fn clear(&mut self) {
+        self.counts.fill(0);
+        self.powers.fill(0.0);
+    }
+}
+
+/// The state of one input channel.
+#[derive(Debug, Clone)]
+struct Channel {
+    weight: f64,
+    shelf: Biquad,
+    high_pass: Biquad,
+    /// The most recent samples, newest first, for true peak interpolation.
+    history: [f32; TRUE_PEAK_TAPS_PER_PHASE],
+}
+
+/// Measures loudness as specified in EBU R128 / ITU-R BS.1770.
+///
+/// Samples are K-weighted, and the mean square of each channel is summed
+/// with the channel's weight. Momentary loudness uses a 400 ms window,
+/// short-term loudness a 3 s window, both updated every 100 ms. The
+/// integrated loudness and the loudness range are gated as specified, and
+/// the true peak is found by oversampling 4 times.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::loudness::Analyzer;
+///
+/// let mut analyzer = Analyzer::new(48_000.0, 2);
+///
+/// let left = vec![0.0; 4800];
+/// let right = vec![0.0; 4800];
+/// analyzer.process(&[&left, &right]);
+///
+/// let measurement = analyzer.measurement();
+/// assert_eq!(measurement.integrated, f32::NEG_INFINITY);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Analyzer {
+    channels: Vec<Channel>,
+    /// The true peak interpolation filter, one row of taps per phase.
+    true_peak_filter: Vec<[f32; TRUE_PEAK_TAPS_PER_PHASE]>,
+    step_len: usize,
+    step_pos: usize,
+    step_sum: f64,
+    /// The mean square of the most recent steps, oldest first.
+    recent_steps: VecDeque<f64>,
+    /// The momentary blocks since the last reset.
+    momentary_blocks: Histogram,
+    /// The short-term blocks since the last reset.
+    short_term_blocks: Histogram,
+    true_peak: f32,
+}
+
+impl Analyzer {
+    /// Creates a new `Analyzer`.
+    ///
+    /// * `sample_rate` - The sample rate of the audio in Hz.
+    /// * `channels` - The number of channels of the audio. Every channel
+    ///   starts with a weight of `1.0`.
+    pub fn new(sample_rate: f32, channels: usize) -> Self {
+        let sample_rate = f64::from(sample_rate.max(1.0));
+
+        let channel = Channel {
+            weight: 1.0,
+            shelf: Biquad::k_weighting_shelf(sample_rate),
+            high_pass: Biquad::k_weighting_high_pass(sample_rate),
+            history: [0.0; TRUE_PEAK_TAPS_PER_PHASE],
+        };
+
+        Self {
+            channels: vec![channel; channels],
+            true_peak_filter: true_peak_filter(),
+            step_len: ((sample_rate * STEP_SECONDS).round() as usize).max(1),
+            step_pos: 0,
+            step_sum: 0.0,
+            recent_steps: VecDeque::with_capacity(SHORT_TERM_STEPS),
+            momentary_blocks: Histogram::new(),
+            short_term_blocks: Histogram::new(),
+            true_peak: 0.0,
+        }
+    }
+
+    /// Sets the weight of a channel.
+    ///
+    /// BS.1770 uses `1.0` for the left, right and center channels, `1.41`
+    /// for the surround channels, and `0.0` to leave out the LFE channel.
+    pub fn set_channel_weight(&mut self, channel: usize, weight: f32) {
+        if let Some(channel) = self.channels.get_mut(channel) {
+            channel.weight = f64::from(weight);
+        }
+    }
+
+    /// Processes a block of samples, one slice per channel. Extra channels
+    /// are ignored, and only as many samples as the shortest slice holds are
+    /// processed.
+    pub fn process(&mut self, block: &[&[f32]]) {
+        let channels = self.channels.len().min(block.len());
+        let len = block[..channels]
+            .iter()
+            .map(|samples| samples.len())
+            .min()
+            .unwrap_or(0);
+
+        for i in 0..len {
+            let mut sum = 0.0;
+
+            for (channel, samples) in self.channels.iter_mut().zip(block) {
+                let sample = samples[i];
+
+                let weighted = channel
+                    .high_pass
+                    .process(channel.shelf.process(f64::from(sample)));
+                sum += weighted * weighted * channel.weight;
+
+                channel.history.rotate_right(1);
+                channel.history[0] = sample;
+
+                self.true_peak = self.true_peak.max(sample.abs());
+                for taps in &self.true_peak_filter {
+                    let interpolated: f32 = taps
+                        .iter()
+                        .zip(&channel.history)
+                        .map(|(tap, sample)| tap * sample)
+                        .sum();
+                    self.true_peak = self.true_peak.max(interpolated.abs());
+                }
+            }
+
+            self.step_sum += sum;
+            self.step_pos += 1;
+
+            if self.step_pos == self.step_len {
+                self.finish_step();
+            }
+        }
+    }
+
+    fn finish_step(&mut self) {
+        if self.recent_steps.len() == SHORT_TERM_STEPS {
+            self.recent_steps.pop_front();
+        }
+        self.recent_steps
+            .push_back(self.step_sum / self.step_len as f64);
+
+        self.step_sum = 0.0;
+        self.step_pos = 0;
+
+        if let Some(power) = self.mean_of_recent_steps(MOMENTARY_STEPS) {
+            self.momentary_blocks.add(power);
+        }
+        if let Some(power) = self.mean_of_recent_steps(SHORT_TERM_STEPS) {
+            self.short_term_blocks.add(power);
+        }
+    }
+
+    /// Returns the mean square of the last `steps` steps, or `None` if not
+    /// enough steps have been processed yet.
+    fn mean_of_recent_steps(&self, steps: usize) -> Option<f64> {
+        if self.recent_steps.len() < steps {
+            return None;
+        }
+
+        let sum: f64 = self.recent_steps.iter().rev().take(steps).sum();
+        Some(sum / steps as f64)
+    }
+
+    /// Returns the momentary loudness (400 ms window) in LUFS.
+    pub fn momentary(&self) -> f32 {
+        self.mean_of_recent_steps(MOMENTARY_STEPS)
+            .map_or(f32::NEG_INFINITY, |power| loudness(power) as f32)
+    }
+
+    /// Returns the short-term loudness (3 s window) in LUFS.
+    pub fn short_term(&self) -> f32 {
+        self.mean_of_recent_steps(SHORT_TERM_STEPS)
+            .map_or(f32::NEG_INFINITY, |power| loudness(power) as f32)
+    }
+
+    /// Returns the gated integrated loudness since the last reset in LUFS.
+    pub fn integrated(&self) -> f32 {
+        self.momentary_blocks
+            .gated_loudness(INTEGRATED_RELATIVE_GATE)
+            .map_or(f32::NEG_INFINITY, |loudness| loudness as f32)
+    }
+
+    /// Returns the lower and upper bound of the loudness range in LUFS, or
+    /// `None` if it could not be measured yet. The loudness range (LRA) is
+    /// the difference between the two.
+    pub fn loudness_range(&self) -> Option<(f32, f32)> {
+        self.short_term_blocks
+            .gated_percentiles(RANGE_RELATIVE_GATE, RANGE_PERCENTILES)
+            .map(|(low, high)| (low as f32, high as f32))
+    }
+
+    /// Returns the highest true peak since the last reset in dBTP.
+    pub fn true_peak(&self) -> f32 {
+        20.0 * self.true_peak.log10()
+    }
+
+    /// Returns a snapshot of all measured values.
+    pub fn measurement(&self) -> Measurement {
+        let (range_low, range_high) = self
+            .loudness_range()
+            .unwrap_or((f32::NEG_INFINITY, f32::NEG_INFINITY));
+
+        Measurement {
+            momentary: self.momentary(),
+            short_term: self.short_term(),
+            integrated: self.integrated(),
+            range_low,
+            range_high,
+            true_peak: self.true_peak(),
+        }
+    }
+
+    /// Clears all measurements and filter states.
+    pub fn reset(&mut self) {
+        for channel in &mut self.channels {
+            channel.shelf.reset();
+            channel.high_pass.reset();
+            channel.history = [0.0; TRUE_PEAK_TAPS_PER_PHASE];
+        }
+
+        self.step_pos = 0;
+        self.step_sum = 0.0;
+        self.recent_steps.clear();
+        self.momentary_blocks.clear();
+        self.short_term_blocks.clear();
+        self.true_peak = 0.0;
+    }
+}
+
+/// Converts a mean square to loudness in LUFS.
+fn loudness(power: f64) -> f64 {
+    -0.691 + 10.0 * power.log10()
+}
+
+/// Designs the polyphase interpolation filter used to find the true peak,
+/// a Blackman windowed sinc with its cutoff at the original Nyquist
+/// frequency.
+fn true_peak_filter() -> Vec<[f32; TRUE_PEAK_TAPS_PER_PHASE]> {
+    let len = TRUE_PEAK_OVERSAMPLING * TRUE_PEAK_TAPS_PER_PHASE;
+    let center = (len - 1) as f64 / 2.0;
+
+    let taps: Vec<f64> = (0..len)
+        .map(|i| {
+            let x = (i as f64 - center) / TRUE_PEAK_OVERSAMPLING as f64;
+            let sinc = if x == 0.0 {
+                1.0
+            } else {
+                (PI * x).sin() / (PI * x)
+            };
+
+            let w = 2.0 * PI * i as f64 / (len - 1) as f64;
+            let window = 0.42 - 0.5 * w.cos() + 0.08 * (2.0 * w).cos();
+
+            sinc * window
+        })
+        .collect();
+
+    (0..TRUE_PEAK_OVERSAMPLING)
+        .map(|phase| {
+            let mut phase_taps = [0.0; TRUE_PEAK_TAPS_PER_PHASE];
+            for (k, tap) in phase_taps.iter_mut().enumerate() {
+                *tap = taps[phase + k * TRUE_PEAK_OVERSAMPLING] as f32;
+            }
+
+            // Normalize each phase to unity gain at DC.
+            let sum: f32 = phase_taps.iter().sum();
+            for tap in &mut phase_taps {
+                *tap /= sum;
+            }
+
+            phase_taps
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Analyzer;
+
+    fn sine(sample_rate: f32, freq: f32, db: f32, seconds: f32) -> Vec<f32> {
+        let amplitude = 10.0f32.powf(db / 20.0);
+        (0..(sample_rate * seconds) as usize)
+            .map(|i| (i as f32 / sample_rate * freq * std::f32::consts::TAU).sin() * amplitude)
+            .collect()
+    }
+
+    #[test]
+    fn stereo_sine_at_minus_23_dbfs() {
+        for sample_rate in [44_100.0, 48_000.0] {
+            let tone = sine(sample_rate, 1000.0, -23.0, 5.0);
+
+            let mut analyzer = Analyzer::new(sample_rate, 2);
+            analyzer.process(&[&tone, &tone]);
+            let measurement = analyzer.measurement();
+
+            assert!((measurement.momentary + 23.0).abs() < 0.1);
+            assert!((measurement.short_term + 23.0).abs() < 0.1);
+            assert!((measurement.integrated + 23.0).abs() < 0.1);
+            assert!(measurement.loudness_range() < 0.1);
+            assert!((measurement.true_peak + 23.0).abs() < 0.2);
+        }
+    }
+
+    #[test]
+    fn silence_is_gated() {
+        let tone = sine(48_000.0, 1000.0, -23.0, 10.0);
+        let silence = vec![0.0; 240_000];
+
+        let mut analyzer = Analyzer::new(48_000.0, 2);
+        analyzer.process(&[&tone, &tone]);
+        analyzer.process(&[&silence, &silence]);
+
+        assert!((analyzer.integrated() + 23.0).abs() < 0.1);
+        assert!(analyzer.momentary() < -70.0);
+    }
+
+    #[test]
+    fn loudness_range_of_two_levels() {
+        let loud = sine(48_000.0, 1000.0, -20.0, 10.0);
+        let quiet = sine(48_000.0, 1000.0, -30.0, 10.0);
+
+        let mut analyzer = Analyzer::new(48_000.0, 2);
+        analyzer.process(&[&loud, &loud]);
+        analyzer.process(&[&quiet, &quiet]);
+
+        let (low, high) = analyzer.loudness_range().unwrap();
+        assert!((low + 30.0).abs() < 0.2);
+        assert!((high + 20.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn true_peak_between_samples() {
+        // A sine at a quarter of the sample rate with a 45 degree phase
+        // offset never hits its peak on a sample.
+        let samples: Vec<f32> = (0..4800)
+            .map(|i| (i as f32 * std::f32::consts::FRAC_PI_2 + std::f32::consts::FRAC_PI_4).sin())
+            .collect();
+
+        let mut analyzer = Analyzer::new(48_000.0, 1);
+        analyzer.process(&[&samples]);
+
+        let sample_peak = 20.0 * std::f32::consts::FRAC_1_SQRT_2.log10();
+        assert!(analyzer.true_peak() > sample_peak + 2.5);
+        assert!(analyzer.true_peak().abs() < 0.5);
+    }
+}
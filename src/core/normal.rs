@@ -155,6 +155,22 @@ impl fmt::Display for Normal {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Normal {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f32(self.0)
+    }
+}
+
+/// Values outside of `[0.0..=1.0]` are rejected instead of clamped.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Normal {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <f32 as serde::Deserialize>::deserialize(deserializer)?;
+        Normal::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{Normal, NormalOutOfRange};
@@ -225,4 +241,17 @@ mod tests {
         assert_eq!(err, NormalOutOfRange(1.1));
         assert_eq!(normal.as_f32(), 1.0);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_checked() {
+        use serde::Deserialize;
+        use serde::de::value::{Error, F32Deserializer};
+
+        let normal = Normal::deserialize(F32Deserializer::<Error>::new(0.25));
+        assert_eq!(normal, Ok(Normal::new(0.25)));
+
+        let out_of_range = Normal::deserialize(F32Deserializer::<Error>::new(1.5));
+        assert!(out_of_range.is_err());
+    }
 }
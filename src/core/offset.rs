@@ -4,6 +4,7 @@ use iced_core::{Point, Rectangle};
 
 /// A 2D offset vector with a horizontal and vertical offset in pixels.
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Offset {
     /// The horizontal offset in pixels.
     pub x: f32,
@@ -15,6 +15,7 @@ use std::fmt::Debug;
 ///
 /// [`Normal`]: ../struct.Normal.html
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NormalParam {
     /// The value of the parameter represented as a [`Normal`]
     ///
@@ -2,20 +2,211 @@
 ///
 /// [`Normal`]: ../struct.Normal.html
 use crate::core::Normal;
+use crate::core::math::{amplitude_to_db_f32, db_to_amplitdue_f32};
 use crate::core::param::NormalParam;
+use crate::core::{text_marks, tick_marks};
 
 use std::fmt::Debug;
 
+/// Deserializes a range through an unchecked copy of its fields, so that
+/// bad data is rejected by the same `check` as the range's constructor.
+#[cfg(feature = "serde")]
+macro_rules! checked_deserialize {
+    ($range:ident, $unchecked:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+        #[derive(serde::Deserialize)]
+        struct $unchecked {
+            $($field: $ty),*
+        }
+
+        impl TryFrom<$unchecked> for $range {
+            type Error = &'static str;
+
+            fn try_from(unchecked: $unchecked) -> Result<Self, Self::Error> {
+                let range = $range {
+                    $($field: unchecked.$field),*
+                };
+                range.check()?;
+                Ok(range)
+            }
+        }
+    };
+}
+
+/// A value that a [`ParamRange`] maps to and from a [`Normal`].
+///
+/// This lets code that only deals in `f32` (such as a text box) work with
+/// any range.
+///
+/// [`ParamRange`]: trait.ParamRange.html
+/// [`Normal`]: ../struct.Normal.html
+pub trait RangeValue: Copy + Debug + PartialEq {
+    /// Converts an `f32` into this value, rounding if needed.
+    fn from_f32(value: f32) -> Self;
+
+    /// Converts this value into an `f32`.
+    fn to_f32(self) -> f32;
+}
+
+impl RangeValue for f32 {
+    fn from_f32(value: f32) -> Self {
+        value
+    }
+
+    fn to_f32(self) -> f32 {
+        self
+    }
+}
+
+impl RangeValue for i32 {
+    fn from_f32(value: f32) -> Self {
+        value.round() as i32
+    }
+
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+}
+
+impl RangeValue for usize {
+    fn from_f32(value: f32) -> Self {
+        value.round().max(0.0) as usize
+    }
+
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+}
+
+impl RangeValue for bool {
+    fn from_f32(value: f32) -> Self {
+        value >= 0.5
+    }
+
+    fn to_f32(self) -> f32 {
+        if self { 1.0 } else { 0.0 }
+    }
+}
+
+/// A range of parameter values that maps to and from a [`Normal`].
+///
+/// All of the ranges in this module implement this trait, so code can be
+/// generic over them. Each range also keeps its own methods (such as
+/// [`DBRange::map_db`]), which are the same as the ones of this trait.
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`DBRange::map_db`]: struct.DBRange.html#method.map_db
+pub trait ParamRange: Debug {
+    /// The type of the values in this range
+    type Value: RangeValue;
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn map(&self, value: Self::Value) -> Normal;
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn unmap(&self, normal: Normal) -> Self::Value;
+
+    /// Returns `value` clamped to this range.
+    fn clamp(&self, value: Self::Value) -> Self::Value;
+
+    /// Returns the value used by [`default_param`].
+    ///
+    /// [`default_param`]: #method.default_param
+    fn default_value(&self) -> Self::Value;
+
+    /// Formats `value` for display, including its unit (such as
+    /// `"-6.0 dB"` or `"1.50 kHz"`).
+    fn format(&self, value: Self::Value) -> String;
+
+    /// Returns `true` if this range only has discrete steps, such as an
+    /// [`IntRange`].
+    ///
+    /// [`IntRange`]: struct.IntRange.html
+    fn is_discrete(&self) -> bool {
+        false
+    }
+
+    /// Returns a [`Normal`] that is snapped to the closest step in this
+    /// range. Continuous ranges return `normal` as is.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn snap(&self, normal: Normal) -> Normal {
+        normal
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    fn param(&self, value: Self::Value, default: Self::Value) -> NormalParam {
+        NormalParam {
+            normal: self.map(value),
+            default: self.map(default),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is
+    /// [`default_value`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    /// [`default_value`]: #tymethod.default_value
+    fn default_param(&self) -> NormalParam {
+        let default = self.map(self.default_value());
+
+        NormalParam {
+            normal: default,
+            default,
+            ..Default::default()
+        }
+    }
+
+    /// Formats the value at `normal` for display.
+    fn format_normal(&self, normal: Normal) -> String {
+        self.format(self.unmap(normal))
+    }
+
+    /// Returns the corresponding [`Normal`] from a value given as an `f32`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn map_f32(&self, value: f32) -> Normal {
+        self.map(Self::Value::from_f32(value))
+    }
+
+    /// Returns the corresponding value from the supplied [`Normal`] as an
+    /// `f32`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn unmap_to_f32(&self, normal: Normal) -> f32 {
+        self.unmap(normal).to_f32()
+    }
+}
+
 /// A range that maps a continuous linear range of `f32` values
 /// to a [`Normal`]
 ///
 /// [`Normal`]: ../struct.Normal.html
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedFloatRange")
+)]
 pub struct FloatRange {
     min: f32,
     max: f32,
 }
 
+#[cfg(feature = "serde")]
+checked_deserialize!(FloatRange, UncheckedFloatRange { min: f32, max: f32 });
+
 impl FloatRange {
     /// A float range of `[0.0..=1.0]`
     pub const NORMAL: Self = Self { min: 0.0, max: 1.0 };
@@ -36,9 +227,23 @@ impl FloatRange {
     ///
     /// This will panic if `max` < `min`
     pub const fn new(min: f32, max: f32) -> Self {
-        assert!(max >= min);
+        let range = Self { min, max };
+        if let Err(error) = range.check() {
+            panic!("{}", error);
+        }
+        range
+    }
+
+    const fn check(&self) -> Result<(), &'static str> {
+        if !self.min.is_finite() || !self.max.is_finite() {
+            return Err("min and max must be finite");
+        }
+        let ordered = self.max >= self.min;
+        if !ordered {
+            return Err("max must be >= min");
+        }
 
-        Self { min, max }
+        Ok(())
     }
 
     pub const fn min(&self) -> f32 {
@@ -117,47 +322,170 @@ impl Default for FloatRange {
     }
 }
 
-/// A range that defines a discrete linear range of i32 values
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub struct IntRange {
-    min: i32,
-    max: i32,
+impl ParamRange for FloatRange {
+    type Value = f32;
+
+    fn map(&self, value: f32) -> Normal {
+        FloatRange::map(self, value)
+    }
+
+    fn unmap(&self, normal: Normal) -> f32 {
+        self.unmap_to_value(normal)
+    }
+
+    fn clamp(&self, value: f32) -> f32 {
+        FloatRange::clamp(self, value)
+    }
+
+    fn default_value(&self) -> f32 {
+        0.0
+    }
+
+    fn format(&self, value: f32) -> String {
+        format!("{value:.2}")
+    }
 }
 
-impl IntRange {
-    /// Creates a new `IntRange`
+/// A range that maps a continuous range of `f32` values to a [`Normal`]
+/// along a power curve, so that part of the range gets more of the travel
+/// of the widget.
+///
+/// The [`Normal`] is the linear position in the range raised to the power
+/// of the skew factor, so a skew factor below `1.0` gives more travel to
+/// the values near `min`, and a skew factor above `1.0` gives more travel
+/// to the values near `max`. A symmetric range skews both halves away from
+/// (or towards) the center of the range instead.
+///
+/// [`Normal`]: ../struct.Normal.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedSkewedRange")
+)]
+pub struct SkewedRange {
+    min: f32,
+    max: f32,
+    skew: f32,
+    symmetric: bool,
+}
+
+#[cfg(feature = "serde")]
+checked_deserialize!(
+    SkewedRange,
+    UncheckedSkewedRange {
+        min: f32,
+        max: f32,
+        skew: f32,
+        symmetric: bool,
+    }
+);
+
+impl SkewedRange {
+    /// Creates a new `SkewedRange`
     ///
     /// # Arguments
     ///
     /// * `min` - the minimum of the range (inclusive)
     /// * `max` - the maximum of the range (inclusive)
+    /// * `skew` - the skew factor of the curve. `1.0` is linear.
     ///
     /// # Panics
     ///
-    /// This will panic if `max` < `min`
-    pub const fn new(min: i32, max: i32) -> Self {
-        assert!(max >= min);
+    /// This will panic if
+    /// * `max < min`
+    /// * `skew <= 0.0`
+    pub const fn new(min: f32, max: f32, skew: f32) -> Self {
+        let range = Self {
+            min,
+            max,
+            skew,
+            symmetric: false,
+        };
+        if let Err(error) = range.check() {
+            panic!("{}", error);
+        }
+        range
+    }
+
+    const fn check(&self) -> Result<(), &'static str> {
+        if !self.min.is_finite() || !self.max.is_finite() {
+            return Err("min and max must be finite");
+        }
+        let ordered = self.max >= self.min;
+        if !ordered {
+            return Err("max must be >= min");
+        }
+        let positive = self.skew > 0.0;
+        if !positive {
+            return Err("skew must be > 0.0");
+        }
+        if !self.skew.is_finite() {
+            return Err("skew must be finite");
+        }
 
-        Self { min, max }
+        Ok(())
     }
 
-    pub const fn min(&self) -> i32 {
+    /// Creates a new `SkewedRange` that is skewed symmetrically around the
+    /// center of the range, for bipolar parameters such as pan or detune.
+    ///
+    /// A skew factor below `1.0` gives more travel to the values near the
+    /// center.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max < min`
+    /// * `skew <= 0.0`
+    pub const fn symmetric(min: f32, max: f32, skew: f32) -> Self {
+        let mut range = Self::new(min, max, skew);
+        range.symmetric = true;
+        range
+    }
+
+    /// Creates a new `SkewedRange` where `midpoint` sits at the center of
+    /// travel (`Normal::CENTER`).
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `midpoint` is not between `min` and `max`
+    /// (exclusive).
+    pub fn from_midpoint(min: f32, max: f32, midpoint: f32) -> Self {
+        assert!(
+            midpoint > min && midpoint < max,
+            "midpoint must be between min and max"
+        );
+
+        let proportion = (midpoint - min) / (max - min);
+
+        Self::new(min, max, 0.5_f32.ln() / proportion.ln())
+    }
+
+    pub const fn min(&self) -> f32 {
         self.min
     }
 
-    pub const fn max(&self) -> i32 {
+    pub const fn max(&self) -> f32 {
         self.max
     }
 
-    pub const fn span(&self) -> i32 {
-        self.max - self.min
+    /// Returns the skew factor of the curve.
+    pub const fn skew(&self) -> f32 {
+        self.skew
     }
 
-    pub const fn span_f32(&self) -> f32 {
-        (self.max - self.min) as f32
+    /// Returns `true` if the range is skewed symmetrically around its
+    /// center.
+    pub const fn is_symmetric(&self) -> bool {
+        self.symmetric
     }
 
-    pub fn clamp(&self, value: i32) -> i32 {
+    pub fn span(&self) -> f32 {
+        self.max - self.min
+    }
+
+    pub fn clamp(&self, value: f32) -> f32 {
         if value <= self.min {
             self.min
         } else if value >= self.max {
@@ -174,7 +502,7 @@ impl IntRange {
     ///
     /// * `value` - The inital value of the parameter.
     /// * `default_value` - The default value of the parameter.
-    pub fn param(&self, value: i32, default: i32) -> NormalParam {
+    pub fn param(&self, value: f32, default: f32) -> NormalParam {
         NormalParam {
             normal: self.map(value),
             default: self.map(default),
@@ -183,110 +511,189 @@ impl IntRange {
     }
 
     /// Creates a new [`NormalParam`] with values mapped
-    /// from this range where `value` and `default_value` is `0`.
+    /// from this range where `value` and `default_value` is `0.0`.
     ///
     /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
     pub fn default_param(&self) -> NormalParam {
         NormalParam {
-            normal: self.map(0),
-            default: self.map(0),
+            normal: self.map(0.0),
+            default: self.map(0.0),
             ..Default::default()
         }
     }
 
-    /// Returns a [`Normal`] that is snapped to the closest integer
-    /// value in this range.
-    ///
-    /// [`Normal`]: ../struct.Normal.html
-    pub fn snap(&self, normal: Normal) -> Normal {
-        let value_int = self.unmap_to_value(normal);
-        self.map(value_int)
-    }
-
     /// Returns the corresponding [`Normal`] from the supplied value
     ///
     /// [`Normal`]: ../struct.Normal.html
-    pub fn map(&self, value: i32) -> Normal {
-        let span = self.span_f32();
+    pub fn map(&self, value: f32) -> Normal {
+        let span = self.span();
         if span == 0.0 {
-            Normal::MIN
+            return Normal::MIN;
+        }
+
+        let proportion = (self.clamp(value) - self.min) / span;
+
+        if self.symmetric {
+            let distance = proportion * 2.0 - 1.0;
+            let curved = distance.abs().powf(self.skew).copysign(distance);
+
+            Normal::new((curved + 1.0) * 0.5)
         } else {
-            let value = self.clamp(value);
-            Normal::new((value - self.min) as f32 / self.span_f32())
+            Normal::new(proportion.powf(self.skew))
         }
     }
 
     /// Returns the corresponding value from the supplied [`Normal`]
     ///
     /// [`Normal`]: ../struct.Normal.html
-    pub fn unmap_to_value(&self, normal: Normal) -> i32 {
-        (normal.as_f32() * self.span_f32()).round() as i32 + self.min
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        let proportion = if self.symmetric {
+            let distance = normal.as_f32() * 2.0 - 1.0;
+            let linear = distance.abs().powf(self.skew.recip()).copysign(distance);
+
+            (linear + 1.0) * 0.5
+        } else {
+            normal.as_f32().powf(self.skew.recip())
+        };
+
+        proportion * self.span() + self.min
     }
 }
 
-impl Default for IntRange {
+impl Default for SkewedRange {
     fn default() -> Self {
-        IntRange::new(0, 100)
+        SkewedRange::new(0.0, 1.0, 1.0)
     }
 }
 
-/// A range that defines a continuous logarithmic range of `dB` values,
-/// with an inflection/stationary point at 0 dB
+impl ParamRange for SkewedRange {
+    type Value = f32;
+
+    fn map(&self, value: f32) -> Normal {
+        SkewedRange::map(self, value)
+    }
+
+    fn unmap(&self, normal: Normal) -> f32 {
+        self.unmap_to_value(normal)
+    }
+
+    fn clamp(&self, value: f32) -> f32 {
+        SkewedRange::clamp(self, value)
+    }
+
+    fn default_value(&self) -> f32 {
+        0.0
+    }
+
+    fn format(&self, value: f32) -> String {
+        format!("{value:.2}")
+    }
+}
+
+/// A range that maps a continuous range of `f32` values to a [`Normal`]
+/// along an exponential curve.
 ///
-/// Values around 0 dB (positive and negative) will increment slower per
-/// slider movement than values farther away from 0 dB.
+/// Unlike [`FreqRange`] and [`TimeRange`], the range may include zero and
+/// negative values. A positive curve factor gives more travel to the
+/// values near `min`, a negative one gives more travel to the values near
+/// `max`, and `0.0` is linear.
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`FreqRange`]: struct.FreqRange.html
+/// [`TimeRange`]: struct.TimeRange.html
 #[derive(Debug, Copy, Clone, PartialEq)]
-pub struct DBRange {
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedExpRange")
+)]
+pub struct ExpRange {
     min: f32,
     max: f32,
-    skew_factor: f32,
-    zero_position: Normal,
+    factor: f32,
 }
 
-impl DBRange {
-    pub const DEFAULT_SKEW_FACTOR: f32 = 1.38;
-
-    pub const NEG_3_TO_3: Self = Self::new(-6.0, 6.0, Normal::CENTER, Self::DEFAULT_SKEW_FACTOR);
-    pub const NEG_6_TO_6: Self = Self::new(-6.0, 6.0, Normal::CENTER, Self::DEFAULT_SKEW_FACTOR);
-    pub const NEG_12_TO_12: Self =
-        Self::new(-12.0, 12.0, Normal::CENTER, Self::DEFAULT_SKEW_FACTOR);
-    pub const NEG_24_TO_24: Self =
-        Self::new(-24.0, 24.0, Normal::CENTER, Self::DEFAULT_SKEW_FACTOR);
+#[cfg(feature = "serde")]
+checked_deserialize!(
+    ExpRange,
+    UncheckedExpRange {
+        min: f32,
+        max: f32,
+        factor: f32,
+    }
+);
 
-    /// Creates a new `LogDBRange`
+impl ExpRange {
+    /// Creates a new `ExpRange`
     ///
     /// # Arguments
     ///
-    /// * `min` - the minimum of the range in dB (inclusive), must be <= 0.0
-    /// * `max` - the maximum of the range in dB (inclusive), must be >= 0.0
-    /// * `zero_position` - a normal that defines where on the slider 0 decibels
-    ///   should be. For example, `Normal::CENTER` will have 0 dB at the center
-    ///   of the slider. Normals of `1.0` and `0.0` can be used for only negative
-    ///   or only positive decibels respectively
-    /// * `skew_factor` - The exponent of the parameter curve. Larger values are
-    ///   more skewed towards the zero position. Must be greater than or equal to `1.0`
-    ///   A good default value is [`DBRange::DEFAULT_SKEW_FACTOR`] (1.38).
+    /// * `min` - the minimum of the range (inclusive)
+    /// * `max` - the maximum of the range (inclusive)
+    /// * `factor` - the steepness of the curve. `0.0` is linear.
     ///
     /// # Panics
     ///
     /// This will panic if
     /// * `max < min`
-    /// * `min > 0.0`
-    /// * `max < 0.0`
-    /// * `skew_factor < 1.0`
-    ///
-    pub const fn new(min: f32, max: f32, zero_position: Normal, skew_factor: f32) -> Self {
-        assert!(max >= min, "max must be >= min");
-        assert!(max >= 0.0, "max must be >= 0.0");
-        assert!(min <= 0.0, "min must be <= 0.0");
-        assert!(skew_factor >= 1.0, "skew_factor must be >= 1.0");
-
-        Self {
-            min,
-            max,
-            skew_factor,
-            zero_position,
+    /// * `factor` is not finite
+    pub const fn new(min: f32, max: f32, factor: f32) -> Self {
+        let range = Self { min, max, factor };
+        if let Err(error) = range.check() {
+            panic!("{}", error);
         }
+        range
+    }
+
+    const fn check(&self) -> Result<(), &'static str> {
+        if !self.min.is_finite() || !self.max.is_finite() {
+            return Err("min and max must be finite");
+        }
+        let ordered = self.max >= self.min;
+        if !ordered {
+            return Err("max must be >= min");
+        }
+        if !self.factor.is_finite() {
+            return Err("factor must be finite");
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new `ExpRange` where `midpoint` sits at the center of
+    /// travel (`Normal::CENTER`).
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `midpoint` is not between `min` and `max`
+    /// (exclusive).
+    pub fn from_midpoint(min: f32, max: f32, midpoint: f32) -> Self {
+        assert!(
+            midpoint > min && midpoint < max,
+            "midpoint must be between min and max"
+        );
+
+        let proportion = (midpoint - min) / (max - min);
+
+        // Solves `(e^(k/2) - 1) / (e^k - 1) = proportion` for `k`.
+        Self::new(min, max, 2.0 * (proportion.recip() - 1.0).ln())
+    }
+
+    pub const fn min(&self) -> f32 {
+        self.min
+    }
+
+    pub const fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns the steepness of the curve.
+    pub const fn factor(&self) -> f32 {
+        self.factor
+    }
+
+    pub fn span(&self) -> f32 {
+        self.max - self.min
     }
 
     pub fn clamp(&self, value: f32) -> f32 {
@@ -308,8 +715,8 @@ impl DBRange {
     /// * `default_value` - The default value of the parameter.
     pub fn param(&self, value: f32, default: f32) -> NormalParam {
         NormalParam {
-            normal: self.map_db(value),
-            default: self.map_db(default),
+            normal: self.map(value),
+            default: self.map(default),
             ..Default::default()
         }
     }
@@ -320,142 +727,133 @@ impl DBRange {
     /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
     pub fn default_param(&self) -> NormalParam {
         NormalParam {
-            normal: self.map_db(0.0),
-            default: self.map_db(0.0),
+            normal: self.map(0.0),
+            default: self.map(0.0),
             ..Default::default()
         }
     }
 
-    /// Returns the corresponding [`Normal`] from the supplied `value`
+    /// Returns the corresponding [`Normal`] from the supplied value
     ///
     /// [`Normal`]: ../struct.Normal.html
-    pub fn map_db(&self, value: f32) -> Normal {
-        let value = self.clamp(value);
-        if value == 0.0 || self.min == self.max {
-            self.zero_position
-        } else if value < 0.0 {
-            if self.min >= 0.0 {
-                return Normal::MIN;
-            }
-
-            let min_recip = if self.min == 0.0 {
-                0.0
-            } else {
-                self.min.recip()
-            };
-
-            let neg_normal = value * min_recip;
+    pub fn map(&self, value: f32) -> Normal {
+        let span = self.span();
+        if span == 0.0 {
+            return Normal::MIN;
+        }
 
-            let log_normal = 1.0 - neg_normal.abs().powf(self.skew_factor.recip());
+        let proportion = (self.clamp(value) - self.min) / span;
 
-            Normal::new(log_normal * self.zero_position.as_f32())
+        if self.factor == 0.0 {
+            Normal::new(proportion)
         } else {
-            if self.max <= 0.0 {
-                return Normal::MAX;
-            }
-
-            let max_recip = if self.max == 0.0 {
-                0.0
-            } else {
-                self.max.recip()
-            };
-
-            let pos_normal = value * max_recip;
-
-            let log_normal = pos_normal.abs().powf(self.skew_factor.recip());
-
-            Normal::new(
-                (log_normal * (1.0 - self.zero_position.as_f32())) + self.zero_position.as_f32(),
-            )
+            Normal::new((proportion * self.factor.exp_m1()).ln_1p() / self.factor)
         }
     }
 
-    /// Returns the corresponding dB value from the supplied [`Normal`]
+    /// Returns the corresponding value from the supplied [`Normal`]
     ///
     /// [`Normal`]: ../struct.Normal.html
-    pub fn unmap_to_db(&self, normal: Normal) -> f32 {
-        if normal == self.zero_position || self.min == self.max {
-            0.0
-        } else if normal < self.zero_position {
-            if self.min >= 0.0 {
-                return self.min;
-            }
-
-            let zero_pos_recip = if self.zero_position.as_f32() == 0.0 {
-                0.0
-            } else {
-                1.0 / self.zero_position.as_f32()
-            };
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        let proportion = if self.factor == 0.0 {
+            normal.as_f32()
+        } else {
+            (normal.as_f32() * self.factor).exp_m1() / self.factor.exp_m1()
+        };
 
-            let neg_normal = 1.0 - (normal.as_f32() * zero_pos_recip);
+        proportion * self.span() + self.min
+    }
+}
 
-            let log_normal = 1.0 - neg_normal.abs().powf(self.skew_factor);
+impl Default for ExpRange {
+    fn default() -> Self {
+        ExpRange::new(0.0, 1.0, 0.0)
+    }
+}
 
-            (1.0 - log_normal) * self.min
-        } else {
-            if self.zero_position.as_f32() == 1.0 || self.max <= 0.0 {
-                return self.max;
-            }
+impl ParamRange for ExpRange {
+    type Value = f32;
 
-            let one_min_zero_pos_recip = if self.zero_position.as_f32() == 0.0 {
-                0.0
-            } else {
-                1.0 / (1.0 - self.zero_position.as_f32())
-            };
+    fn map(&self, value: f32) -> Normal {
+        ExpRange::map(self, value)
+    }
 
-            let pos_normal =
-                (normal.as_f32() - self.zero_position.as_f32()) * one_min_zero_pos_recip;
+    fn unmap(&self, normal: Normal) -> f32 {
+        self.unmap_to_value(normal)
+    }
 
-            let log_normal = pos_normal.abs().powf(self.skew_factor);
+    fn clamp(&self, value: f32) -> f32 {
+        ExpRange::clamp(self, value)
+    }
 
-            log_normal * self.max
-        }
+    fn default_value(&self) -> f32 {
+        0.0
     }
-}
 
-impl Default for DBRange {
-    fn default() -> Self {
-        DBRange::NEG_12_TO_12
+    fn format(&self, value: f32) -> String {
+        format!("{value:.2}")
     }
 }
 
-/// A [`NormalParam`] that defines a continuous logarithmic range of `f32` frequency
-/// values, with each octave in the 10 octave spectrum spaced evenly.
-///
-/// Smaller frequencies will increment slower per slider movement than larger
-/// ones.
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub struct FreqRange {
-    min: f32,
-    max: f32,
+/// A range that defines a discrete linear range of i32 values
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedIntRange")
+)]
+pub struct IntRange {
+    min: i32,
+    max: i32,
 }
 
-impl FreqRange {
-    pub const HZ_20_TO_20K: Self = Self::new(20.0, 20_000.0);
+#[cfg(feature = "serde")]
+checked_deserialize!(IntRange, UncheckedIntRange { min: i32, max: i32 });
 
-    /// Creates a new `OctaveNormalParam`
+impl IntRange {
+    /// Creates a new `IntRange`
     ///
     /// # Arguments
     ///
-    /// * `min` - the minimum of the range in Hz (inclusive). Must be greater
-    ///   than 0.0.
-    /// * `max` - the maximum of the range in Hz (inclusive). Must be greater
-    ///   than 0.0.
+    /// * `min` - the minimum of the range (inclusive)
+    /// * `max` - the maximum of the range (inclusive)
+    ///
     /// # Panics
     ///
-    /// This will panic if
-    /// * `max < min`
-    /// * `min <= 0.0`
-    /// * `max <= 0.0`
-    pub const fn new(min: f32, max: f32) -> Self {
-        assert!(max >= min);
-        assert!(min > 0.0);
-        assert!(max > 0.0);
+    /// This will panic if `max` < `min`
+    pub const fn new(min: i32, max: i32) -> Self {
+        let range = Self { min, max };
+        if let Err(error) = range.check() {
+            panic!("{}", error);
+        }
+        range
+    }
+
+    const fn check(&self) -> Result<(), &'static str> {
+        if self.max < self.min {
+            return Err("max must be >= min");
+        }
 
-        Self { min, max }
+        Ok(())
     }
 
-    pub fn clamp(&self, value: f32) -> f32 {
+    pub const fn min(&self) -> i32 {
+        self.min
+    }
+
+    pub const fn max(&self) -> i32 {
+        self.max
+    }
+
+    pub const fn span(&self) -> i32 {
+        self.max - self.min
+    }
+
+    pub const fn span_f32(&self) -> f32 {
+        (self.max - self.min) as f32
+    }
+
+    pub fn clamp(&self, value: i32) -> i32 {
         if value <= self.min {
             self.min
         } else if value >= self.max {
@@ -472,85 +870,2711 @@ impl FreqRange {
     ///
     /// * `value` - The inital value of the parameter.
     /// * `default_value` - The default value of the parameter.
-    pub fn param(&self, value: f32, default: f32) -> NormalParam {
+    pub fn param(&self, value: i32, default: i32) -> NormalParam {
         NormalParam {
-            normal: self.map_freq(value),
-            default: self.map_freq(default),
+            normal: self.map(value),
+            default: self.map(default),
             ..Default::default()
         }
     }
 
     /// Creates a new [`NormalParam`] with values mapped
-    /// from this range where `value` and `default_value` is `20480.0`.
+    /// from this range where `value` and `default_value` is `0`.
     ///
     /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
     pub fn default_param(&self) -> NormalParam {
         NormalParam {
-            normal: self.map_freq(20_480.0),
-            default: self.map_freq(20_480.0),
+            normal: self.map(0),
+            default: self.map(0),
             ..Default::default()
         }
     }
 
-    /// Returns the corresponding [`Normal`] from the supplied frequency value
+    /// Returns a [`Normal`] that is snapped to the closest integer
+    /// value in this range.
     ///
     /// [`Normal`]: ../struct.Normal.html
-    pub fn map_freq(&self, value: f32) -> Normal {
-        if self.min == self.max {
+    pub fn snap(&self, normal: Normal) -> Normal {
+        let value_int = self.unmap_to_value(normal);
+        self.map(value_int)
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map(&self, value: i32) -> Normal {
+        let span = self.span_f32();
+        if span == 0.0 {
             Normal::MIN
         } else {
             let value = self.clamp(value);
-
-            let spectrum_normal = octave_spectrum_map_to_normal(value);
-            let min_spectrum_normal = octave_spectrum_map_to_normal(self.min);
-            let max_spectrum_normal = octave_spectrum_map_to_normal(self.max);
-
-            let spectrum_normal_span = max_spectrum_normal.as_f32() - min_spectrum_normal.as_f32();
-
-            Normal::new(
-                (spectrum_normal.as_f32() - min_spectrum_normal.as_f32()) / spectrum_normal_span,
-            )
+            Normal::new((value - self.min) as f32 / self.span_f32())
         }
     }
 
-    /// Returns the corresponding frequency value from the supplied [`Normal`]
+    /// Returns the corresponding value from the supplied [`Normal`]
     ///
     /// [`Normal`]: ../struct.Normal.html
-    pub fn unmap_to_freq(&self, normal: Normal) -> f32 {
-        if self.min == self.max {
-            self.min
-        } else {
-            let min_spectrum_normal = octave_spectrum_map_to_normal(self.min);
-            let max_spectrum_normal = octave_spectrum_map_to_normal(self.max);
-
-            let spectrum_normal_span = max_spectrum_normal.as_f32() - min_spectrum_normal.as_f32();
-
-            let spectrum_normal =
-                Normal::new(normal.as_f32() * spectrum_normal_span + min_spectrum_normal.as_f32());
-
-            octave_normal_to_spectrum(spectrum_normal)
-        }
+    pub fn unmap_to_value(&self, normal: Normal) -> i32 {
+        (normal.as_f32() * self.span_f32()).round() as i32 + self.min
     }
 }
 
-impl Default for FreqRange {
+impl Default for IntRange {
     fn default() -> Self {
-        FreqRange::HZ_20_TO_20K
+        IntRange::new(0, 100)
     }
 }
 
-/// Returns the corresponding frequency for the whole 10 octave spectrum
-/// (between 20 Hz and 20480 Hz)
-#[inline]
-fn octave_normal_to_spectrum(value: Normal) -> f32 {
-    40.0 * 2.0_f32.powf((10.0 * value.as_f32()) - 1.0)
+impl ParamRange for IntRange {
+    type Value = i32;
+
+    fn map(&self, value: i32) -> Normal {
+        IntRange::map(self, value)
+    }
+
+    fn unmap(&self, normal: Normal) -> i32 {
+        self.unmap_to_value(normal)
+    }
+
+    fn clamp(&self, value: i32) -> i32 {
+        IntRange::clamp(self, value)
+    }
+
+    fn default_value(&self) -> i32 {
+        0
+    }
+
+    fn format(&self, value: i32) -> String {
+        value.to_string()
+    }
+
+    fn is_discrete(&self) -> bool {
+        true
+    }
+
+    fn snap(&self, normal: Normal) -> Normal {
+        IntRange::snap(self, normal)
+    }
 }
 
-/// Returns the corresponding [`Normal`] for a frequency in the whole
-/// 10 octave spectrum (between 20 Hz and 20480 Hz)
+/// A range that defines a discrete list of labeled variants, such as the
+/// types of a filter.
+///
+/// Each variant is mapped to an evenly spaced [`Normal`] by its index, so
+/// the first variant is at `Normal::MIN` and the last one is at
+/// `Normal::MAX`.
 ///
 /// [`Normal`]: ../struct.Normal.html
-#[inline]
-fn octave_spectrum_map_to_normal(freq: f32) -> Normal {
-    Normal::new(((freq / 40.0).log2() + 1.0) * 0.1)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Vec<String>", into = "Vec<String>")
+)]
+pub struct EnumRange {
+    labels: &'static [&'static str],
+}
+
+impl EnumRange {
+    /// Creates a new `EnumRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `labels` - the label of each variant, in order
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `labels` is empty
+    pub const fn new(labels: &'static [&'static str]) -> Self {
+        assert!(!labels.is_empty(), "there must be at least one label");
+
+        Self { labels }
+    }
+
+    /// Returns the labels of the variants.
+    pub const fn labels(&self) -> &'static [&'static str] {
+        self.labels
+    }
+
+    /// Returns the number of variants.
+    pub const fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Always returns `false`, since an `EnumRange` has at least one
+    /// variant.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the label of the variant at `index`, or `None` if there is
+    /// no such variant.
+    pub fn label(&self, index: usize) -> Option<&'static str> {
+        self.labels.get(index).copied()
+    }
+
+    /// Returns the index of the variant with the given label.
+    pub fn index_of(&self, label: &str) -> Option<usize> {
+        self.labels.iter().position(|l| *l == label)
+    }
+
+    pub fn clamp(&self, index: usize) -> usize {
+        index.min(self.len() - 1)
+    }
+
+    /// Creates a new [`NormalParam`] with indexes mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital index of the parameter.
+    /// * `default_value` - The default index of the parameter.
+    pub fn param(&self, value: usize, default: usize) -> NormalParam {
+        NormalParam {
+            normal: self.map(value),
+            default: self.map(default),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with indexes mapped
+    /// from this range where `value` and `default_value` is the first
+    /// variant.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_param(&self) -> NormalParam {
+        NormalParam {
+            normal: Normal::MIN,
+            default: Normal::MIN,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a [`Normal`] that is snapped to the closest variant.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snap(&self, normal: Normal) -> Normal {
+        self.map(self.unmap_to_index(normal))
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied index
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map(&self, index: usize) -> Normal {
+        if self.len() == 1 {
+            Normal::MIN
+        } else {
+            Normal::new(self.clamp(index) as f32 / (self.len() - 1) as f32)
+        }
+    }
+
+    /// Returns the corresponding index from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_index(&self, normal: Normal) -> usize {
+        (normal.as_f32() * (self.len() - 1) as f32).round() as usize
+    }
+
+    /// Returns the label of the variant at the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_label(&self, normal: Normal) -> &'static str {
+        self.labels[self.unmap_to_index(normal)]
+    }
+
+    /// Returns a group of tier 1 tick marks, one on each variant.
+    pub fn tick_marks(&self) -> tick_marks::Group {
+        tick_marks::Group::evenly_spaced(self.len(), tick_marks::Tier::One)
+    }
+
+    /// Returns a group of text marks with the label of each variant.
+    pub fn text_marks(&self) -> text_marks::Group {
+        let marks: Vec<(Normal, &str)> = self
+            .labels
+            .iter()
+            .enumerate()
+            .map(|(index, label)| (self.map(index), *label))
+            .collect();
+
+        text_marks::Group::from(marks)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Vec<String>> for EnumRange {
+    type Error = &'static str;
+
+    fn try_from(labels: Vec<String>) -> Result<Self, Self::Error> {
+        if labels.is_empty() {
+            return Err("there must be at least one label");
+        }
+        Ok(Self::new(crate::core::serde_support::intern_slice(&labels)))
+    }
+}
+
+impl From<EnumRange> for Vec<String> {
+    fn from(range: EnumRange) -> Self {
+        range.labels.iter().map(|label| label.to_string()).collect()
+    }
+}
+
+impl ParamRange for EnumRange {
+    type Value = usize;
+
+    fn map(&self, value: usize) -> Normal {
+        EnumRange::map(self, value)
+    }
+
+    fn unmap(&self, normal: Normal) -> usize {
+        self.unmap_to_index(normal)
+    }
+
+    fn clamp(&self, value: usize) -> usize {
+        EnumRange::clamp(self, value)
+    }
+
+    fn default_value(&self) -> usize {
+        0
+    }
+
+    fn format(&self, value: usize) -> String {
+        String::from(self.labels[self.clamp(value)])
+    }
+
+    fn is_discrete(&self) -> bool {
+        true
+    }
+
+    fn snap(&self, normal: Normal) -> Normal {
+        EnumRange::snap(self, normal)
+    }
+}
+
+/// A range that defines an on/off switch, where off is `Normal::MIN` and
+/// on is `Normal::MAX`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoolRange {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::core::serde_support::static_str")
+    )]
+    off: &'static str,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::core::serde_support::static_str")
+    )]
+    on: &'static str,
+}
+
+impl BoolRange {
+    /// A switch labeled "Off" and "On"
+    pub const OFF_ON: Self = Self::new("Off", "On");
+
+    /// Creates a new `BoolRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `off` - the label of the off state
+    /// * `on` - the label of the on state
+    pub const fn new(off: &'static str, on: &'static str) -> Self {
+        Self { off, on }
+    }
+
+    /// Returns the label of the off state.
+    pub const fn off_label(&self) -> &'static str {
+        self.off
+    }
+
+    /// Returns the label of the on state.
+    pub const fn on_label(&self) -> &'static str {
+        self.on
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn param(&self, value: bool, default: bool) -> NormalParam {
+        NormalParam {
+            normal: self.map(value),
+            default: self.map(default),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is `false`.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_param(&self) -> NormalParam {
+        NormalParam {
+            normal: Normal::MIN,
+            default: Normal::MIN,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a [`Normal`] that is snapped to either `Normal::MIN` or
+    /// `Normal::MAX`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snap(&self, normal: Normal) -> Normal {
+        self.map(self.unmap_to_bool(normal))
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map(&self, value: bool) -> Normal {
+        if value { Normal::MAX } else { Normal::MIN }
+    }
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_bool(&self, normal: Normal) -> bool {
+        normal >= Normal::CENTER
+    }
+
+    /// Returns a group of tier 1 tick marks on the off and on positions.
+    pub fn tick_marks(&self) -> tick_marks::Group {
+        tick_marks::Group::min_max(tick_marks::Tier::One)
+    }
+
+    /// Returns a group of text marks with the off and on labels.
+    pub fn text_marks(&self) -> text_marks::Group {
+        text_marks::Group::min_max(self.off, self.on)
+    }
+}
+
+impl Default for BoolRange {
+    fn default() -> Self {
+        BoolRange::OFF_ON
+    }
+}
+
+impl ParamRange for BoolRange {
+    type Value = bool;
+
+    fn map(&self, value: bool) -> Normal {
+        BoolRange::map(self, value)
+    }
+
+    fn unmap(&self, normal: Normal) -> bool {
+        self.unmap_to_bool(normal)
+    }
+
+    fn clamp(&self, value: bool) -> bool {
+        value
+    }
+
+    fn default_value(&self) -> bool {
+        false
+    }
+
+    fn format(&self, value: bool) -> String {
+        String::from(if value { self.on } else { self.off })
+    }
+
+    fn is_discrete(&self) -> bool {
+        true
+    }
+
+    fn snap(&self, normal: Normal) -> Normal {
+        BoolRange::snap(self, normal)
+    }
+}
+
+/// A range that defines a continuous logarithmic range of `dB` values,
+/// with an inflection/stationary point at 0 dB
+///
+/// Values around 0 dB (positive and negative) will increment slower per
+/// slider movement than values farther away from 0 dB.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedDBRange")
+)]
+pub struct DBRange {
+    min: f32,
+    max: f32,
+    skew_factor: f32,
+    zero_position: Normal,
+}
+
+#[cfg(feature = "serde")]
+checked_deserialize!(
+    DBRange,
+    UncheckedDBRange {
+        min: f32,
+        max: f32,
+        skew_factor: f32,
+        zero_position: Normal,
+    }
+);
+
+impl DBRange {
+    pub const DEFAULT_SKEW_FACTOR: f32 = 1.38;
+
+    pub const NEG_3_TO_3: Self = Self::new(-6.0, 6.0, Normal::CENTER, Self::DEFAULT_SKEW_FACTOR);
+    pub const NEG_6_TO_6: Self = Self::new(-6.0, 6.0, Normal::CENTER, Self::DEFAULT_SKEW_FACTOR);
+    pub const NEG_12_TO_12: Self =
+        Self::new(-12.0, 12.0, Normal::CENTER, Self::DEFAULT_SKEW_FACTOR);
+    pub const NEG_24_TO_24: Self =
+        Self::new(-24.0, 24.0, Normal::CENTER, Self::DEFAULT_SKEW_FACTOR);
+
+    /// Creates a new `LogDBRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range in dB (inclusive), must be <= 0.0
+    /// * `max` - the maximum of the range in dB (inclusive), must be >= 0.0
+    /// * `zero_position` - a normal that defines where on the slider 0 decibels
+    ///   should be. For example, `Normal::CENTER` will have 0 dB at the center
+    ///   of the slider. Normals of `1.0` and `0.0` can be used for only negative
+    ///   or only positive decibels respectively
+    /// * `skew_factor` - The exponent of the parameter curve. Larger values are
+    ///   more skewed towards the zero position. Must be greater than or equal to `1.0`
+    ///   A good default value is [`DBRange::DEFAULT_SKEW_FACTOR`] (1.38).
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max < min`
+    /// * `min > 0.0`
+    /// * `max < 0.0`
+    /// * `skew_factor < 1.0`
+    ///
+    pub const fn new(min: f32, max: f32, zero_position: Normal, skew_factor: f32) -> Self {
+        let range = Self {
+            min,
+            max,
+            skew_factor,
+            zero_position,
+        };
+        if let Err(error) = range.check() {
+            panic!("{}", error);
+        }
+        range
+    }
+
+    const fn check(&self) -> Result<(), &'static str> {
+        if !self.min.is_finite() || !self.max.is_finite() {
+            return Err("min and max must be finite");
+        }
+        let ordered = self.max >= self.min;
+        if !ordered {
+            return Err("max must be >= min");
+        }
+        let max_above_zero = self.max >= 0.0;
+        if !max_above_zero {
+            return Err("max must be >= 0.0");
+        }
+        let min_below_zero = self.min <= 0.0;
+        if !min_below_zero {
+            return Err("min must be <= 0.0");
+        }
+        let skewed = self.skew_factor >= 1.0;
+        if !skewed {
+            return Err("skew_factor must be >= 1.0");
+        }
+        if !self.skew_factor.is_finite() {
+            return Err("skew_factor must be finite");
+        }
+
+        Ok(())
+    }
+
+    pub fn clamp(&self, value: f32) -> f32 {
+        if value <= self.min {
+            self.min
+        } else if value >= self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam {
+            normal: self.map_db(value),
+            default: self.map_db(default),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is `0.0`.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_param(&self) -> NormalParam {
+        NormalParam {
+            normal: self.map_db(0.0),
+            default: self.map_db(0.0),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied `value`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_db(&self, value: f32) -> Normal {
+        let value = self.clamp(value);
+        if value == 0.0 || self.min == self.max {
+            self.zero_position
+        } else if value < 0.0 {
+            if self.min >= 0.0 {
+                return Normal::MIN;
+            }
+
+            let min_recip = if self.min == 0.0 {
+                0.0
+            } else {
+                self.min.recip()
+            };
+
+            let neg_normal = value * min_recip;
+
+            let log_normal = 1.0 - neg_normal.abs().powf(self.skew_factor.recip());
+
+            Normal::new(log_normal * self.zero_position.as_f32())
+        } else {
+            if self.max <= 0.0 {
+                return Normal::MAX;
+            }
+
+            let max_recip = if self.max == 0.0 {
+                0.0
+            } else {
+                self.max.recip()
+            };
+
+            let pos_normal = value * max_recip;
+
+            let log_normal = pos_normal.abs().powf(self.skew_factor.recip());
+
+            Normal::new(
+                (log_normal * (1.0 - self.zero_position.as_f32())) + self.zero_position.as_f32(),
+            )
+        }
+    }
+
+    /// Returns the corresponding dB value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_db(&self, normal: Normal) -> f32 {
+        if normal == self.zero_position || self.min == self.max {
+            0.0
+        } else if normal < self.zero_position {
+            if self.min >= 0.0 {
+                return self.min;
+            }
+
+            let zero_pos_recip = if self.zero_position.as_f32() == 0.0 {
+                0.0
+            } else {
+                1.0 / self.zero_position.as_f32()
+            };
+
+            let neg_normal = 1.0 - (normal.as_f32() * zero_pos_recip);
+
+            let log_normal = 1.0 - neg_normal.abs().powf(self.skew_factor);
+
+            (1.0 - log_normal) * self.min
+        } else {
+            if self.zero_position.as_f32() == 1.0 || self.max <= 0.0 {
+                return self.max;
+            }
+
+            let one_min_zero_pos_recip = if self.zero_position.as_f32() == 0.0 {
+                0.0
+            } else {
+                1.0 / (1.0 - self.zero_position.as_f32())
+            };
+
+            let pos_normal =
+                (normal.as_f32() - self.zero_position.as_f32()) * one_min_zero_pos_recip;
+
+            let log_normal = pos_normal.abs().powf(self.skew_factor);
+
+            log_normal * self.max
+        }
+    }
+}
+
+impl Default for DBRange {
+    fn default() -> Self {
+        DBRange::NEG_12_TO_12
+    }
+}
+
+impl ParamRange for DBRange {
+    type Value = f32;
+
+    fn map(&self, value: f32) -> Normal {
+        self.map_db(value)
+    }
+
+    fn unmap(&self, normal: Normal) -> f32 {
+        self.unmap_to_db(normal)
+    }
+
+    fn clamp(&self, value: f32) -> f32 {
+        DBRange::clamp(self, value)
+    }
+
+    fn default_value(&self) -> f32 {
+        0.0
+    }
+
+    fn format(&self, value: f32) -> String {
+        format!("{value:.1} dB")
+    }
+}
+
+/// A range that maps decibels to a [`Normal`] with the piecewise law of a
+/// mixing console fader.
+///
+/// The law is a list of breakpoints, each a position on the fader and the
+/// level in dB at that position. Between two breakpoints the level changes
+/// linearly in dB. Below the lowest breakpoint the gain fades out linearly
+/// in amplitude, down to a true off position (−∞ dB) at `Normal::MIN`.
+///
+/// [`Normal`]: ../struct.Normal.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Vec<(f32, f32)>", into = "Vec<(f32, f32)>")
+)]
+pub struct FaderRange {
+    breakpoints: [(f32, f32); FaderRange::MAX_BREAKPOINTS],
+    len: usize,
+}
+
+impl FaderRange {
+    /// The maximum number of breakpoints in a `FaderRange`
+    pub const MAX_BREAKPOINTS: usize = 8;
+
+    /// A fader law from −∞ dB to +12 dB, with 0 dB at 75% of travel
+    pub const NEG_INF_TO_12: Self = Self::new(&[
+        (0.1, -60.0),
+        (0.3, -30.0),
+        (0.55, -10.0),
+        (0.75, 0.0),
+        (1.0, 12.0),
+    ]);
+    /// A fader law from −∞ dB to +6 dB, with 0 dB at 80% of travel
+    pub const NEG_INF_TO_6: Self = Self::new(&[
+        (0.1, -60.0),
+        (0.3, -30.0),
+        (0.55, -10.0),
+        (0.8, 0.0),
+        (1.0, 6.0),
+    ]);
+
+    /// Creates a new `FaderRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `breakpoints` - the `(position, dB)` breakpoints of the law, from
+    ///   the bottom of the fader to the top. The positions must be between
+    ///   `0.0` (exclusive) and `1.0` (inclusive), and the last one must be
+    ///   `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * there are no breakpoints, or more than [`MAX_BREAKPOINTS`]
+    /// * the first position is not greater than `0.0`
+    /// * the last position is not `1.0`
+    /// * the positions or levels are not strictly increasing
+    ///
+    /// [`MAX_BREAKPOINTS`]: #associatedconstant.MAX_BREAKPOINTS
+    pub const fn new(breakpoints: &[(f32, f32)]) -> Self {
+        if let Err(error) = Self::check(breakpoints) {
+            panic!("{}", error);
+        }
+
+        let mut points = [(1.0, 0.0); Self::MAX_BREAKPOINTS];
+        let mut i = 0;
+        while i < breakpoints.len() {
+            points[i] = breakpoints[i];
+            i += 1;
+        }
+
+        Self {
+            breakpoints: points,
+            len: breakpoints.len(),
+        }
+    }
+
+    /// Checks the breakpoints given to [`new`].
+    ///
+    /// [`new`]: #method.new
+    const fn check(breakpoints: &[(f32, f32)]) -> Result<(), &'static str> {
+        if breakpoints.is_empty() {
+            return Err("there must be at least one breakpoint");
+        }
+        if breakpoints.len() > Self::MAX_BREAKPOINTS {
+            return Err("there are too many breakpoints");
+        }
+
+        // The off position below the first breakpoint is the only level
+        // that may be infinite, and it is implied rather than listed.
+        let mut i = 0;
+        while i < breakpoints.len() {
+            if !breakpoints[i].0.is_finite() || !breakpoints[i].1.is_finite() {
+                return Err("positions and levels must be finite");
+            }
+            i += 1;
+        }
+
+        if breakpoints[0].0 <= 0.0 {
+            return Err("the first position must be > 0.0");
+        }
+        if breakpoints[breakpoints.len() - 1].0 != 1.0 {
+            return Err("the last position must be 1.0");
+        }
+
+        let mut i = 1;
+        while i < breakpoints.len() {
+            if breakpoints[i].0 <= breakpoints[i - 1].0 {
+                return Err("positions must be strictly increasing");
+            }
+            if breakpoints[i].1 <= breakpoints[i - 1].1 {
+                return Err("levels must be strictly increasing");
+            }
+            i += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the `(position, dB)` breakpoints of the law.
+    pub fn breakpoints(&self) -> &[(f32, f32)] {
+        &self.breakpoints[..self.len]
+    }
+
+    /// Returns the level of the lowest breakpoint in dB. Levels below this
+    /// fade out to −∞ dB.
+    pub fn min_db(&self) -> f32 {
+        self.breakpoints[0].1
+    }
+
+    /// Returns the level at the top of the fader in dB.
+    pub fn max_db(&self) -> f32 {
+        self.breakpoints[self.len - 1].1
+    }
+
+    /// Returns `value` clamped to this range. `f32::NEG_INFINITY` (off) is
+    /// kept as is.
+    pub fn clamp(&self, value: f32) -> f32 {
+        if value.is_nan() {
+            f32::NEG_INFINITY
+        } else {
+            value.min(self.max_db())
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values in dB mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam {
+            normal: self.map_db(value),
+            default: self.map_db(default),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is `0.0` dB.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_param(&self) -> NormalParam {
+        NormalParam {
+            normal: self.map_db(0.0),
+            default: self.map_db(0.0),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied dB value.
+    /// `f32::NEG_INFINITY` maps to `Normal::MIN`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_db(&self, value: f32) -> Normal {
+        let value = self.clamp(value);
+        let (first_position, first_db) = self.breakpoints[0];
+
+        if value < first_db {
+            let amplitude = db_to_amplitdue_f32(value) / db_to_amplitdue_f32(first_db);
+            return Normal::new(amplitude * first_position);
+        }
+
+        for pair in self.breakpoints().windows(2) {
+            let ((low_position, low_db), (high_position, high_db)) = (pair[0], pair[1]);
+
+            if value <= high_db {
+                let t = (value - low_db) / (high_db - low_db);
+                return Normal::new(low_position + t * (high_position - low_position));
+            }
+        }
+
+        Normal::MAX
+    }
+
+    /// Returns the corresponding dB value from the supplied [`Normal`].
+    /// `Normal::MIN` maps to `f32::NEG_INFINITY`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_db(&self, normal: Normal) -> f32 {
+        let position = normal.as_f32();
+        let (first_position, first_db) = self.breakpoints[0];
+
+        if position < first_position {
+            // `amplitude_to_db_f32(0.0)` is `f32::NEG_INFINITY`.
+            return first_db + amplitude_to_db_f32(position / first_position);
+        }
+
+        for pair in self.breakpoints().windows(2) {
+            let ((low_position, low_db), (high_position, high_db)) = (pair[0], pair[1]);
+
+            if position <= high_position {
+                let t = (position - low_position) / (high_position - low_position);
+                return low_db + t * (high_db - low_db);
+            }
+        }
+
+        self.max_db()
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied amplitude
+    /// (linear gain, where `1.0` is 0 dB).
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_amplitude(&self, amplitude: f32) -> Normal {
+        if amplitude <= 0.0 {
+            Normal::MIN
+        } else {
+            self.map_db(amplitude_to_db_f32(amplitude))
+        }
+    }
+
+    /// Returns the corresponding amplitude (linear gain, where `1.0` is
+    /// 0 dB) from the supplied [`Normal`]. `Normal::MIN` maps to `0.0`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_amplitude(&self, normal: Normal) -> f32 {
+        let (first_position, first_db) = self.breakpoints[0];
+
+        if normal.as_f32() < first_position {
+            // Stay linear in amplitude so the off position is exact.
+            db_to_amplitdue_f32(first_db) * normal.as_f32() / first_position
+        } else {
+            db_to_amplitdue_f32(self.unmap_to_db(normal))
+        }
+    }
+}
+
+impl TryFrom<Vec<(f32, f32)>> for FaderRange {
+    type Error = &'static str;
+
+    fn try_from(breakpoints: Vec<(f32, f32)>) -> Result<Self, Self::Error> {
+        Self::check(&breakpoints)?;
+        Ok(Self::new(&breakpoints))
+    }
+}
+
+impl From<FaderRange> for Vec<(f32, f32)> {
+    fn from(range: FaderRange) -> Self {
+        range.breakpoints().to_vec()
+    }
+}
+
+impl Default for FaderRange {
+    fn default() -> Self {
+        FaderRange::NEG_INF_TO_12
+    }
+}
+
+impl ParamRange for FaderRange {
+    type Value = f32;
+
+    fn map(&self, value: f32) -> Normal {
+        self.map_db(value)
+    }
+
+    fn unmap(&self, normal: Normal) -> f32 {
+        self.unmap_to_db(normal)
+    }
+
+    fn clamp(&self, value: f32) -> f32 {
+        FaderRange::clamp(self, value)
+    }
+
+    fn default_value(&self) -> f32 {
+        0.0
+    }
+
+    fn format(&self, value: f32) -> String {
+        if value == f32::NEG_INFINITY {
+            String::from("-inf dB")
+        } else {
+            format!("{value:.1} dB")
+        }
+    }
+}
+
+/// A range that defines a continuous logarithmic range of `f32` frequency
+/// values, with each octave spaced evenly.
+///
+/// Smaller frequencies will increment slower per slider movement than larger
+/// ones. Any positive span works, from sub-hertz LFO rates to ultrasonic
+/// frequencies.
+///
+/// A range can also have a linear region near zero, which lets the range
+/// start at 0 Hz. Below the start of the linear region the frequency
+/// changes linearly, and the two regions meet with the same slope.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedFreqRange")
+)]
+pub struct FreqRange {
+    min: f32,
+    max: f32,
+    linear_below: f32,
+}
+
+#[cfg(feature = "serde")]
+checked_deserialize!(
+    FreqRange,
+    UncheckedFreqRange {
+        min: f32,
+        max: f32,
+        linear_below: f32,
+    }
+);
+
+impl FreqRange {
+    /// The audible spectrum, from 20 Hz to 20 kHz
+    pub const HZ_20_TO_20K: Self = Self::new(20.0, 20_000.0);
+    /// The spectrum up to the Nyquist frequency at a 96 kHz sample rate,
+    /// from 20 Hz to 48 kHz
+    pub const HZ_20_TO_48K: Self = Self::new(20.0, 48_000.0);
+    /// An extended spectrum for high sample rates, from 10 Hz to 40 kHz
+    pub const HZ_10_TO_40K: Self = Self::new(10.0, 40_000.0);
+    /// A range of LFO rates, from 0.01 Hz to 50 Hz
+    pub const LFO: Self = Self::new(0.01, 50.0);
+    /// A range of LFO rates that starts at 0 Hz, with a linear region
+    /// below 0.1 Hz
+    pub const LFO_FROM_ZERO: Self = Self::with_linear_region(0.0, 50.0, 0.1);
+
+    /// Creates a new `FreqRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range in Hz (inclusive). Must be greater
+    ///   than 0.0.
+    /// * `max` - the maximum of the range in Hz (inclusive). Must be greater
+    ///   than 0.0.
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max < min`
+    /// * `min <= 0.0`
+    /// * `max <= 0.0`
+    pub const fn new(min: f32, max: f32) -> Self {
+        let range = Self {
+            min,
+            max,
+            linear_below: 0.0,
+        };
+        if let Err(error) = range.check() {
+            panic!("{}", error);
+        }
+        range
+    }
+
+    /// Creates a new `FreqRange` that is linear below `linear_below` and
+    /// logarithmic above it.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range in Hz (inclusive). May be 0.0.
+    /// * `max` - the maximum of the range in Hz (inclusive).
+    /// * `linear_below` - the frequency in Hz where the linear region ends
+    ///   and the logarithmic region starts.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `min < 0.0`
+    /// * `linear_below <= min`
+    /// * `max <= linear_below`
+    pub const fn with_linear_region(min: f32, max: f32, linear_below: f32) -> Self {
+        let linear = linear_below > min;
+        assert!(linear, "linear_below must be > min");
+
+        let range = Self {
+            min,
+            max,
+            linear_below,
+        };
+        if let Err(error) = range.check() {
+            panic!("{}", error);
+        }
+        range
+    }
+
+    const fn check(&self) -> Result<(), &'static str> {
+        if !self.min.is_finite() || !self.max.is_finite() {
+            return Err("min and max must be finite");
+        }
+        let ordered = self.max >= self.min;
+        if !ordered {
+            return Err("max must be >= min");
+        }
+        if !self.linear_below.is_finite() {
+            return Err("linear_below must be finite");
+        }
+
+        if self.linear_below > self.min {
+            let min_positive = self.min >= 0.0;
+            if !min_positive {
+                return Err("min must be >= 0.0");
+            }
+            let max_above_linear = self.max > self.linear_below;
+            if !max_above_linear {
+                return Err("max must be > linear_below");
+            }
+        } else {
+            // The whole range is logarithmic.
+            let min_positive = self.min > 0.0;
+            if !min_positive {
+                return Err("min must be > 0.0");
+            }
+        }
+
+        Ok(())
+    }
+
+    pub const fn min(&self) -> f32 {
+        self.min
+    }
+
+    pub const fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns the frequency in Hz where the linear region ends, or `None`
+    /// if the whole range is logarithmic.
+    pub fn linear_below(&self) -> Option<f32> {
+        if self.linear_below > self.min {
+            Some(self.linear_below)
+        } else {
+            None
+        }
+    }
+
+    pub fn clamp(&self, value: f32) -> f32 {
+        if value <= self.min {
+            self.min
+        } else if value >= self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam {
+            normal: self.map_freq(value),
+            default: self.map_freq(default),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` are at the center
+    /// of the range's travel.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_param(&self) -> NormalParam {
+        NormalParam {
+            normal: Normal::CENTER,
+            default: Normal::CENTER,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied frequency value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_freq(&self, value: f32) -> Normal {
+        if self.min == self.max {
+            return Normal::MIN;
+        }
+
+        let value = self.clamp(value);
+
+        match self.linear_below() {
+            Some(linear_below) => {
+                let linear_share = self.linear_share(linear_below);
+
+                if value < linear_below {
+                    Normal::new((value - self.min) / (linear_below - self.min) * linear_share)
+                } else {
+                    let log = (value / linear_below).ln() / (self.max / linear_below).ln();
+                    Normal::new(linear_share + log * (1.0 - linear_share))
+                }
+            }
+            None => Normal::new((value / self.min).ln() / (self.max / self.min).ln()),
+        }
+    }
+
+    /// Returns the corresponding frequency value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_freq(&self, normal: Normal) -> f32 {
+        if self.min == self.max {
+            return self.min;
+        }
+
+        let normal = normal.as_f32();
+
+        match self.linear_below() {
+            Some(linear_below) => {
+                let linear_share = self.linear_share(linear_below);
+
+                if normal < linear_share {
+                    self.min + normal / linear_share * (linear_below - self.min)
+                } else {
+                    let log = (normal - linear_share) / (1.0 - linear_share);
+                    linear_below * (self.max / linear_below).powf(log)
+                }
+            }
+            None => self.min * (self.max / self.min).powf(normal),
+        }
+    }
+
+    /// Returns the share of the travel taken by the linear region, chosen so
+    /// that both regions have the same slope where they meet.
+    fn linear_share(&self, linear_below: f32) -> f32 {
+        let linear_span = linear_below - self.min;
+
+        linear_span / (linear_span + linear_below * (self.max / linear_below).ln())
+    }
+}
+
+impl Default for FreqRange {
+    fn default() -> Self {
+        FreqRange::HZ_20_TO_20K
+    }
+}
+
+impl ParamRange for FreqRange {
+    type Value = f32;
+
+    fn map(&self, value: f32) -> Normal {
+        self.map_freq(value)
+    }
+
+    fn unmap(&self, normal: Normal) -> f32 {
+        self.unmap_to_freq(normal)
+    }
+
+    fn clamp(&self, value: f32) -> f32 {
+        FreqRange::clamp(self, value)
+    }
+
+    /// The frequency at the center of the travel, which is the geometric
+    /// midpoint of `min` and `max` when the whole range is logarithmic.
+    fn default_value(&self) -> f32 {
+        self.unmap_to_freq(Normal::CENTER)
+    }
+
+    fn format(&self, value: f32) -> String {
+        if value < 1.0 {
+            format!("{value:.3} Hz")
+        } else if value < 10.0 {
+            format!("{value:.2} Hz")
+        } else if value < 100.0 {
+            format!("{value:.1} Hz")
+        } else if value < 1_000.0 {
+            format!("{value:.0} Hz")
+        } else if value < 10_000.0 {
+            format!("{:.2} kHz", value / 1_000.0)
+        } else {
+            format!("{:.1} kHz", value / 1_000.0)
+        }
+    }
+}
+
+/// The length of a [`NoteDivision`] before it is dotted or made a triplet.
+///
+/// [`NoteDivision`]: struct.NoteDivision.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NoteValue {
+    /// A 1/64 note
+    SixtyFourth,
+    /// A 1/32 note
+    ThirtySecond,
+    /// A 1/16 note
+    Sixteenth,
+    /// A 1/8 note
+    Eighth,
+    /// A 1/4 note (one beat)
+    Quarter,
+    /// A 1/2 note
+    Half,
+    /// A whole note (one bar)
+    Whole,
+    /// Two bars
+    TwoBars,
+    /// Four bars
+    FourBars,
+}
+
+impl NoteValue {
+    /// Returns the length of this note value in beats (quarter notes).
+    pub const fn beats(&self) -> f32 {
+        match self {
+            NoteValue::SixtyFourth => 1.0 / 16.0,
+            NoteValue::ThirtySecond => 1.0 / 8.0,
+            NoteValue::Sixteenth => 1.0 / 4.0,
+            NoteValue::Eighth => 1.0 / 2.0,
+            NoteValue::Quarter => 1.0,
+            NoteValue::Half => 2.0,
+            NoteValue::Whole => 4.0,
+            NoteValue::TwoBars => 8.0,
+            NoteValue::FourBars => 16.0,
+        }
+    }
+
+    const fn label(&self) -> &'static str {
+        match self {
+            NoteValue::SixtyFourth => "1/64",
+            NoteValue::ThirtySecond => "1/32",
+            NoteValue::Sixteenth => "1/16",
+            NoteValue::Eighth => "1/8",
+            NoteValue::Quarter => "1/4",
+            NoteValue::Half => "1/2",
+            NoteValue::Whole => "1/1",
+            NoteValue::TwoBars => "2/1",
+            NoteValue::FourBars => "4/1",
+        }
+    }
+}
+
+/// Whether a [`NoteDivision`] is straight, dotted, or a triplet.
+///
+/// [`NoteDivision`]: struct.NoteDivision.html
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NoteModifier {
+    /// The plain note value
+    #[default]
+    Straight,
+    /// One and a half times the note value
+    Dotted,
+    /// Two thirds of the note value
+    Triplet,
+}
+
+impl NoteModifier {
+    /// Returns the factor this modifier applies to the length of a note.
+    pub const fn factor(&self) -> f32 {
+        match self {
+            NoteModifier::Straight => 1.0,
+            NoteModifier::Dotted => 1.5,
+            NoteModifier::Triplet => 2.0 / 3.0,
+        }
+    }
+
+    const fn suffix(&self) -> &'static str {
+        match self {
+            NoteModifier::Straight => "",
+            NoteModifier::Dotted => "D",
+            NoteModifier::Triplet => "T",
+        }
+    }
+}
+
+/// A musical note division used by the tempo-synced mode of a [`TimeRange`].
+///
+/// Bars are assumed to be in 4/4, so a [`NoteValue::Whole`] is one bar.
+///
+/// [`TimeRange`]: struct.TimeRange.html
+/// [`NoteValue::Whole`]: enum.NoteValue.html#variant.Whole
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NoteDivision {
+    /// The note value
+    pub value: NoteValue,
+    /// Whether the note value is straight, dotted, or a triplet
+    pub modifier: NoteModifier,
+}
+
+impl NoteDivision {
+    /// All divisions from a 1/64 note to four bars, sorted from shortest
+    /// to longest.
+    pub const ALL: [NoteDivision; 25] = [
+        Self::new(NoteValue::SixtyFourth, NoteModifier::Straight),
+        Self::new(NoteValue::ThirtySecond, NoteModifier::Triplet),
+        Self::new(NoteValue::SixtyFourth, NoteModifier::Dotted),
+        Self::new(NoteValue::ThirtySecond, NoteModifier::Straight),
+        Self::new(NoteValue::Sixteenth, NoteModifier::Triplet),
+        Self::new(NoteValue::ThirtySecond, NoteModifier::Dotted),
+        Self::new(NoteValue::Sixteenth, NoteModifier::Straight),
+        Self::new(NoteValue::Eighth, NoteModifier::Triplet),
+        Self::new(NoteValue::Sixteenth, NoteModifier::Dotted),
+        Self::new(NoteValue::Eighth, NoteModifier::Straight),
+        Self::new(NoteValue::Quarter, NoteModifier::Triplet),
+        Self::new(NoteValue::Eighth, NoteModifier::Dotted),
+        Self::new(NoteValue::Quarter, NoteModifier::Straight),
+        Self::new(NoteValue::Half, NoteModifier::Triplet),
+        Self::new(NoteValue::Quarter, NoteModifier::Dotted),
+        Self::new(NoteValue::Half, NoteModifier::Straight),
+        Self::new(NoteValue::Whole, NoteModifier::Triplet),
+        Self::new(NoteValue::Half, NoteModifier::Dotted),
+        Self::new(NoteValue::Whole, NoteModifier::Straight),
+        Self::new(NoteValue::TwoBars, NoteModifier::Triplet),
+        Self::new(NoteValue::Whole, NoteModifier::Dotted),
+        Self::new(NoteValue::TwoBars, NoteModifier::Straight),
+        Self::new(NoteValue::FourBars, NoteModifier::Triplet),
+        Self::new(NoteValue::TwoBars, NoteModifier::Dotted),
+        Self::new(NoteValue::FourBars, NoteModifier::Straight),
+    ];
+
+    /// Creates a new `NoteDivision`
+    pub const fn new(value: NoteValue, modifier: NoteModifier) -> Self {
+        Self { value, modifier }
+    }
+
+    /// Creates a new straight `NoteDivision`
+    pub const fn straight(value: NoteValue) -> Self {
+        Self::new(value, NoteModifier::Straight)
+    }
+
+    /// Creates a new dotted `NoteDivision`
+    pub const fn dotted(value: NoteValue) -> Self {
+        Self::new(value, NoteModifier::Dotted)
+    }
+
+    /// Creates a new triplet `NoteDivision`
+    pub const fn triplet(value: NoteValue) -> Self {
+        Self::new(value, NoteModifier::Triplet)
+    }
+
+    /// Returns the length of this division in beats (quarter notes).
+    pub fn beats(&self) -> f32 {
+        self.value.beats() * self.modifier.factor()
+    }
+
+    /// Returns the length of this division in seconds at the given tempo.
+    pub fn seconds(&self, bpm: f32) -> f32 {
+        self.beats() * 60.0 / bpm
+    }
+
+    /// Returns the position of this division in [`NoteDivision::ALL`].
+    /// Returns `None` for divisions outside of that list (a dotted four
+    /// bars or a 1/64 triplet).
+    ///
+    /// [`NoteDivision::ALL`]: #associatedconstant.ALL
+    pub fn index(&self) -> Option<usize> {
+        Self::ALL.iter().position(|division| division == self)
+    }
+
+    /// Returns a short label for this division, such as `"1/8"`, `"1/8D"`,
+    /// or `"1/8T"`.
+    pub fn label(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl std::fmt::Display for NoteDivision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.value.label(), self.modifier.suffix())
+    }
+}
+
+/// Every decade from 1 ms to 100 s with its label.
+const TIME_DECADES: [(f32, &str); 6] = [
+    (0.001, "1ms"),
+    (0.01, "10ms"),
+    (0.1, "100ms"),
+    (1.0, "1s"),
+    (10.0, "10s"),
+    (100.0, "100s"),
+];
+
+/// A range that defines a continuous logarithmic range of time values
+/// in seconds, with a tempo-synced mode that maps the same [`Normal`] onto
+/// musical [`NoteDivision`]s.
+///
+/// Every decade of time (for example 1 ms to 10 ms, and 100 ms to 1 s) is
+/// spaced evenly, so short times increment slower per slider movement than
+/// long ones.
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`NoteDivision`]: struct.NoteDivision.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedTimeRange")
+)]
+pub struct TimeRange {
+    min: f32,
+    max: f32,
+    min_division: usize,
+    max_division: usize,
+}
+
+#[cfg(feature = "serde")]
+checked_deserialize!(
+    TimeRange,
+    UncheckedTimeRange {
+        min: f32,
+        max: f32,
+        min_division: usize,
+        max_division: usize,
+    }
+);
+
+impl TimeRange {
+    /// A time range of 1 ms to 1 second
+    pub const MS_1_TO_1S: Self = Self::new(0.001, 1.0);
+    /// A time range of 1 ms to 10 seconds
+    pub const MS_1_TO_10S: Self = Self::new(0.001, 10.0);
+
+    /// Creates a new `TimeRange` that syncs to all divisions between
+    /// a 1/64 note and four bars.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range in seconds (inclusive). Must be
+    ///   greater than 0.0.
+    /// * `max` - the maximum of the range in seconds (inclusive).
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max < min`
+    /// * `min <= 0.0`
+    pub const fn new(min: f32, max: f32) -> Self {
+        let range = Self {
+            min,
+            max,
+            min_division: 0,
+            max_division: NoteDivision::ALL.len() - 1,
+        };
+        if let Err(error) = range.check() {
+            panic!("{}", error);
+        }
+        range
+    }
+
+    const fn check(&self) -> Result<(), &'static str> {
+        if !self.min.is_finite() || !self.max.is_finite() {
+            return Err("min and max must be finite");
+        }
+        let ordered = self.max >= self.min;
+        if !ordered {
+            return Err("max must be >= min");
+        }
+        let min_positive = self.min > 0.0;
+        if !min_positive {
+            return Err("min must be > 0.0");
+        }
+        if self.max_division >= NoteDivision::ALL.len() {
+            return Err("max_division is not an index into NoteDivision::ALL");
+        }
+        if self.max_division < self.min_division {
+            return Err("max_division must not be shorter than min_division");
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new `TimeRange` from a range in milliseconds.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max_ms < min_ms`
+    /// * `min_ms <= 0.0`
+    pub const fn from_ms(min_ms: f32, max_ms: f32) -> Self {
+        Self::new(min_ms / 1000.0, max_ms / 1000.0)
+    }
+
+    /// Limits the divisions of the tempo-synced mode to the ones
+    /// between `min` and `max` (inclusive).
+    ///
+    /// # Panics
+    ///
+    /// This will panic if either division is not in
+    /// [`NoteDivision::ALL`], or if `max` is shorter than `min`.
+    ///
+    /// [`NoteDivision::ALL`]: struct.NoteDivision.html#associatedconstant.ALL
+    pub fn with_divisions(mut self, min: NoteDivision, max: NoteDivision) -> Self {
+        let min = min.index().expect("min is not a supported division");
+        let max = max.index().expect("max is not a supported division");
+        assert!(max >= min, "max must not be shorter than min");
+
+        self.min_division = min;
+        self.max_division = max;
+        self
+    }
+
+    pub const fn min(&self) -> f32 {
+        self.min
+    }
+
+    pub const fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns the divisions of the tempo-synced mode, from shortest
+    /// to longest.
+    pub fn divisions(&self) -> &'static [NoteDivision] {
+        let (min, max) = (self.min_division, self.max_division);
+
+        &NoteDivision::ALL[min..=max]
+    }
+
+    pub fn clamp(&self, value: f32) -> f32 {
+        if value <= self.min {
+            self.min
+        } else if value >= self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values in seconds mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam {
+            normal: self.map_seconds(value),
+            default: self.map_seconds(default),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with divisions mapped
+    /// from the tempo-synced mode of this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital division of the parameter.
+    /// * `default_value` - The default division of the parameter.
+    pub fn division_param(&self, value: NoteDivision, default: NoteDivision) -> NormalParam {
+        NormalParam {
+            normal: self.map_division(value),
+            default: self.map_division(default),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is the minimum
+    /// of the range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_param(&self) -> NormalParam {
+        NormalParam {
+            normal: Normal::MIN,
+            default: Normal::MIN,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied time in seconds
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_seconds(&self, value: f32) -> Normal {
+        if self.min == self.max {
+            Normal::MIN
+        } else {
+            let value = self.clamp(value);
+
+            Normal::new((value / self.min).ln() / (self.max / self.min).ln())
+        }
+    }
+
+    /// Returns the corresponding time in seconds from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_seconds(&self, normal: Normal) -> f32 {
+        if self.min == self.max {
+            self.min
+        } else {
+            self.min * (self.max / self.min).powf(normal.as_f32())
+        }
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied time in
+    /// milliseconds
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_ms(&self, value: f32) -> Normal {
+        self.map_seconds(value / 1000.0)
+    }
+
+    /// Returns the corresponding time in milliseconds from the supplied
+    /// [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_ms(&self, normal: Normal) -> f32 {
+        self.unmap_to_seconds(normal) * 1000.0
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied division in
+    /// tempo-synced mode. Divisions outside of this range are clamped.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_division(&self, division: NoteDivision) -> Normal {
+        let (min, max) = (self.min_division, self.max_division);
+        let span = max - min;
+        if span == 0 {
+            return Normal::MIN;
+        }
+
+        // Divisions that are not in the list are placed by their length.
+        let index = division.index().unwrap_or_else(|| {
+            if division.beats() < NoteDivision::ALL[0].beats() {
+                0
+            } else {
+                NoteDivision::ALL.len() - 1
+            }
+        });
+        let index = index.clamp(min, max);
+
+        Normal::new((index - min) as f32 / span as f32)
+    }
+
+    /// Returns the corresponding division from the supplied [`Normal`] in
+    /// tempo-synced mode
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_division(&self, normal: Normal) -> NoteDivision {
+        let (min, max) = (self.min_division, self.max_division);
+        let index = (normal.as_f32() * (max - min) as f32).round() as usize;
+
+        NoteDivision::ALL[min + index]
+    }
+
+    /// Returns a [`Normal`] that is snapped to the closest division in
+    /// tempo-synced mode.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snap_division(&self, normal: Normal) -> Normal {
+        self.map_division(self.unmap_to_division(normal))
+    }
+
+    /// Returns the time in seconds of the division the supplied [`Normal`]
+    /// maps to in tempo-synced mode, at the given tempo.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_synced_seconds(&self, normal: Normal, bpm: f32) -> f32 {
+        self.unmap_to_division(normal).seconds(bpm)
+    }
+
+    /// Returns a group of tick marks for the free-running mode, with a
+    /// tier 1 tick mark on each decade (1 ms, 10 ms, 100 ms, ...) and a
+    /// tier 2 tick mark on each step in between.
+    pub fn tick_marks(&self) -> tick_marks::Group {
+        let mut marks = Vec::new();
+
+        for (decade, _) in TIME_DECADES {
+            for step in 1..10 {
+                let seconds = decade * step as f32;
+                if seconds < self.min * 0.999 || seconds > self.max * 1.001 {
+                    continue;
+                }
+
+                let tier = if step == 1 {
+                    tick_marks::Tier::One
+                } else {
+                    tick_marks::Tier::Two
+                };
+                marks.push((self.map_seconds(seconds), tier));
+            }
+        }
+
+        tick_marks::Group::from_normalized(&marks)
+    }
+
+    /// Returns a group of text marks for the free-running mode, with a text
+    /// mark on each decade (`"1ms"`, `"10ms"`, `"100ms"`, `"1s"`, ...).
+    pub fn text_marks(&self) -> text_marks::Group {
+        let marks: Vec<(Normal, &str)> = TIME_DECADES
+            .iter()
+            .filter(|(seconds, _)| *seconds >= self.min * 0.999 && *seconds <= self.max * 1.001)
+            .map(|(seconds, label)| (self.map_seconds(*seconds), *label))
+            .collect();
+
+        text_marks::Group::from(marks)
+    }
+
+    /// Returns a group of tick marks for the tempo-synced mode, with a
+    /// tier 1 tick mark on each straight division and a tier 3 tick mark on
+    /// each dotted and triplet division.
+    pub fn division_tick_marks(&self) -> tick_marks::Group {
+        let marks: Vec<(Normal, tick_marks::Tier)> = self
+            .divisions()
+            .iter()
+            .map(|division| {
+                let tier = match division.modifier {
+                    NoteModifier::Straight => tick_marks::Tier::One,
+                    _ => tick_marks::Tier::Three,
+                };
+                (self.map_division(*division), tier)
+            })
+            .collect();
+
+        tick_marks::Group::from_normalized(&marks)
+    }
+
+    /// Returns a group of text marks for the tempo-synced mode, with a
+    /// text mark on each straight division.
+    pub fn division_text_marks(&self) -> text_marks::Group {
+        let marks: Vec<(Normal, String)> = self
+            .divisions()
+            .iter()
+            .filter(|division| division.modifier == NoteModifier::Straight)
+            .map(|division| (self.map_division(*division), division.label()))
+            .collect();
+
+        text_marks::Group::from(marks)
+    }
+}
+
+impl Default for TimeRange {
+    fn default() -> Self {
+        TimeRange::MS_1_TO_1S
+    }
+}
+
+impl ParamRange for TimeRange {
+    type Value = f32;
+
+    fn map(&self, value: f32) -> Normal {
+        self.map_seconds(value)
+    }
+
+    fn unmap(&self, normal: Normal) -> f32 {
+        self.unmap_to_seconds(normal)
+    }
+
+    fn clamp(&self, value: f32) -> f32 {
+        TimeRange::clamp(self, value)
+    }
+
+    fn default_value(&self) -> f32 {
+        self.min
+    }
+
+    fn format(&self, value: f32) -> String {
+        if value < 0.1 {
+            format!("{:.1} ms", value * 1_000.0)
+        } else if value < 1.0 {
+            format!("{:.0} ms", value * 1_000.0)
+        } else if value < 10.0 {
+            format!("{value:.2} s")
+        } else {
+            format!("{value:.1} s")
+        }
+    }
+}
+
+/// The names of the twelve pitch classes, starting from C.
+pub(crate) const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// A musical scale that a [`NoteRange`] can snap to.
+///
+/// A scale is a set of pitch classes that repeats every octave.
+///
+/// [`NoteRange`]: struct.NoteRange.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scale {
+    /// One bit per pitch class, where bit `0` is C.
+    pitch_classes: u16,
+}
+
+impl Scale {
+    /// All twelve semitones
+    pub const CHROMATIC: Self = Self {
+        pitch_classes: 0b1111_1111_1111,
+    };
+
+    /// Creates a new `Scale` from a root pitch class (`0` is C, `9` is A)
+    /// and the intervals of the scale in semitones above the root.
+    ///
+    /// Intervals outside of one octave wrap around.
+    pub const fn new(root: i32, intervals: &[i32]) -> Self {
+        let mut pitch_classes = 0;
+        let mut i = 0;
+        while i < intervals.len() {
+            pitch_classes |= 1 << (root + intervals[i]).rem_euclid(12);
+            i += 1;
+        }
+
+        Self { pitch_classes }
+    }
+
+    /// Creates a major (ionian) scale with the given root pitch class.
+    pub const fn major(root: i32) -> Self {
+        Self::new(root, &[0, 2, 4, 5, 7, 9, 11])
+    }
+
+    /// Creates a natural minor (aeolian) scale with the given root pitch
+    /// class.
+    pub const fn minor(root: i32) -> Self {
+        Self::new(root, &[0, 2, 3, 5, 7, 8, 10])
+    }
+
+    /// Creates a major pentatonic scale with the given root pitch class.
+    pub const fn major_pentatonic(root: i32) -> Self {
+        Self::new(root, &[0, 2, 4, 7, 9])
+    }
+
+    /// Creates a minor pentatonic scale with the given root pitch class.
+    pub const fn minor_pentatonic(root: i32) -> Self {
+        Self::new(root, &[0, 3, 5, 7, 10])
+    }
+
+    /// Returns `true` if the MIDI note is in this scale.
+    pub const fn contains(&self, note: i32) -> bool {
+        self.pitch_classes & (1 << note.rem_euclid(12)) != 0
+    }
+
+    /// Returns `true` if this scale has no notes.
+    pub const fn is_empty(&self) -> bool {
+        self.pitch_classes == 0
+    }
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Scale::CHROMATIC
+    }
+}
+
+/// A range that defines a continuous linear range of MIDI note numbers,
+/// where `69.0` is A4 and `60.0` is middle C (C4).
+///
+/// Fractional notes are cents above the note, so `60.5` is C4 + 50 cents.
+/// Because notes are spaced evenly, every octave is spaced evenly in
+/// frequency too.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedNoteRange")
+)]
+pub struct NoteRange {
+    min: f32,
+    max: f32,
+    reference: f32,
+}
+
+#[cfg(feature = "serde")]
+checked_deserialize!(
+    NoteRange,
+    UncheckedNoteRange {
+        min: f32,
+        max: f32,
+        reference: f32,
+    }
+);
+
+impl NoteRange {
+    /// The default frequency of A4 in Hz
+    pub const DEFAULT_REFERENCE: f32 = 440.0;
+
+    /// The full range of MIDI notes, from C-1 (`0`) to G9 (`127`)
+    pub const MIDI: Self = Self::new(0.0, 127.0);
+    /// The range of an 88 key piano, from A0 (`21`) to C8 (`108`)
+    pub const PIANO: Self = Self::new(21.0, 108.0);
+
+    /// Creates a new `NoteRange` with A4 tuned to 440 Hz
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range as a MIDI note number (inclusive)
+    /// * `max` - the maximum of the range as a MIDI note number (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max` < `min`
+    pub const fn new(min: f32, max: f32) -> Self {
+        let range = Self {
+            min,
+            max,
+            reference: Self::DEFAULT_REFERENCE,
+        };
+        if let Err(error) = range.check() {
+            panic!("{}", error);
+        }
+        range
+    }
+
+    /// Sets the frequency of A4 in Hz used to convert notes to and from
+    /// frequencies.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `reference <= 0.0`
+    pub const fn with_reference(mut self, reference: f32) -> Self {
+        self.reference = reference;
+        if let Err(error) = self.check() {
+            panic!("{}", error);
+        }
+        self
+    }
+
+    const fn check(&self) -> Result<(), &'static str> {
+        if !self.min.is_finite() || !self.max.is_finite() {
+            return Err("min and max must be finite");
+        }
+        let ordered = self.max >= self.min;
+        if !ordered {
+            return Err("max must be >= min");
+        }
+        let positive = self.reference > 0.0;
+        if !positive {
+            return Err("reference must be > 0.0");
+        }
+        if !self.reference.is_finite() {
+            return Err("reference must be finite");
+        }
+
+        Ok(())
+    }
+
+    pub const fn min(&self) -> f32 {
+        self.min
+    }
+
+    pub const fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns the frequency of A4 in Hz.
+    pub const fn reference(&self) -> f32 {
+        self.reference
+    }
+
+    pub fn span(&self) -> f32 {
+        self.max - self.min
+    }
+
+    pub fn clamp(&self, value: f32) -> f32 {
+        if value <= self.min {
+            self.min
+        } else if value >= self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with notes mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital note of the parameter.
+    /// * `default_value` - The default note of the parameter.
+    pub fn param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam {
+            normal: self.map_note(value),
+            default: self.map_note(default),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with notes mapped
+    /// from this range where `value` and `default_value` is middle C
+    /// (`60.0`).
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_param(&self) -> NormalParam {
+        NormalParam {
+            normal: self.map_note(60.0),
+            default: self.map_note(60.0),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied MIDI note
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_note(&self, note: f32) -> Normal {
+        let span = self.span();
+        if span == 0.0 {
+            Normal::MIN
+        } else {
+            let note = self.clamp(note);
+            Normal::new((note - self.min) / span)
+        }
+    }
+
+    /// Returns the corresponding MIDI note from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_note(&self, normal: Normal) -> f32 {
+        (normal.as_f32() * self.span()) + self.min
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied frequency in Hz
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_freq(&self, freq: f32) -> Normal {
+        self.map_note(self.freq_to_note(freq))
+    }
+
+    /// Returns the corresponding frequency in Hz from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_freq(&self, normal: Normal) -> f32 {
+        self.note_to_freq(self.unmap_to_note(normal))
+    }
+
+    /// Returns the frequency in Hz of a MIDI note with this range's A4
+    /// reference.
+    pub fn note_to_freq(&self, note: f32) -> f32 {
+        self.reference * 2.0_f32.powf((note - 69.0) / 12.0)
+    }
+
+    /// Returns the MIDI note of a frequency in Hz with this range's A4
+    /// reference. Frequencies of `0.0` or below return the minimum of the
+    /// range.
+    pub fn freq_to_note(&self, freq: f32) -> f32 {
+        if freq <= 0.0 {
+            self.min
+        } else {
+            69.0 + 12.0 * (freq / self.reference).log2()
+        }
+    }
+
+    /// Returns a [`Normal`] that is snapped to the closest semitone in
+    /// this range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snap(&self, normal: Normal) -> Normal {
+        let note = self.unmap_to_note(normal).round();
+        self.map_note(self.clamp_to_semitone(note))
+    }
+
+    /// Returns a [`Normal`] that is snapped to the closest note of `scale`
+    /// in this range. If no note of the scale is in this range, this
+    /// snaps to the closest semitone instead.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snap_to_scale(&self, normal: Normal, scale: Scale) -> Normal {
+        let note = self.unmap_to_note(normal);
+        let min = self.min.ceil() as i32;
+        let max = self.max.floor() as i32;
+
+        let closest = (min..=max)
+            .filter(|candidate| scale.contains(*candidate))
+            .min_by(|a, b| {
+                let a = (*a as f32 - note).abs();
+                let b = (*b as f32 - note).abs();
+                a.total_cmp(&b)
+            });
+
+        match closest {
+            Some(closest) => self.map_note(closest as f32),
+            None => self.snap(normal),
+        }
+    }
+
+    /// Returns a group of tick marks with a tier 1 tick mark on each C and
+    /// a tier 3 tick mark on every other semitone.
+    pub fn tick_marks(&self) -> tick_marks::Group {
+        let min = self.min.ceil() as i32;
+        let max = self.max.floor() as i32;
+
+        let marks: Vec<(Normal, tick_marks::Tier)> = (min..=max)
+            .map(|note| {
+                let tier = if note.rem_euclid(12) == 0 {
+                    tick_marks::Tier::One
+                } else {
+                    tick_marks::Tier::Three
+                };
+                (self.map_note(note as f32), tier)
+            })
+            .collect();
+
+        tick_marks::Group::from_normalized(&marks)
+    }
+
+    /// Returns a group of text marks with a label on each C, such as
+    /// `"C2"` and `"C3"`.
+    pub fn text_marks(&self) -> text_marks::Group {
+        let min = self.min.ceil() as i32;
+        let max = self.max.floor() as i32;
+
+        let marks: Vec<(Normal, String)> = (min..=max)
+            .filter(|note| note.rem_euclid(12) == 0)
+            .map(|note| (self.map_note(note as f32), Self::note_name(note)))
+            .collect();
+
+        text_marks::Group::from(marks)
+    }
+
+    /// Returns the name and octave of a MIDI note, such as `"C4"` for
+    /// middle C or `"A#2"`.
+    pub fn note_name(note: i32) -> String {
+        format!(
+            "{}{}",
+            NOTE_NAMES[note.rem_euclid(12) as usize],
+            note.div_euclid(12) - 1
+        )
+    }
+
+    fn clamp_to_semitone(&self, note: f32) -> f32 {
+        if note < self.min {
+            self.min.ceil()
+        } else if note > self.max {
+            self.max.floor()
+        } else {
+            note
+        }
+    }
+}
+
+impl Default for NoteRange {
+    fn default() -> Self {
+        NoteRange::MIDI
+    }
+}
+
+impl ParamRange for NoteRange {
+    type Value = f32;
+
+    fn map(&self, value: f32) -> Normal {
+        self.map_note(value)
+    }
+
+    fn unmap(&self, normal: Normal) -> f32 {
+        self.unmap_to_note(normal)
+    }
+
+    fn clamp(&self, value: f32) -> f32 {
+        NoteRange::clamp(self, value)
+    }
+
+    fn default_value(&self) -> f32 {
+        60.0
+    }
+
+    fn format(&self, value: f32) -> String {
+        let note = value.round();
+        let cents = ((value - note) * 100.0).round() as i32;
+        let name = Self::note_name(note as i32);
+
+        if cents == 0 {
+            name
+        } else {
+            format!("{name} {cents:+}ct")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BoolRange, DBRange, EnumRange, ExpRange, FaderRange, FreqRange, IntRange, NoteDivision,
+        NoteModifier, NoteRange, NoteValue, ParamRange, Scale, SkewedRange, TimeRange,
+    };
+    use crate::core::Normal;
+
+    #[test]
+    fn note_divisions_are_sorted() {
+        for pair in NoteDivision::ALL.windows(2) {
+            assert!(pair[0].beats() < pair[1].beats());
+        }
+    }
+
+    #[test]
+    fn time_round_trip() {
+        let range = TimeRange::MS_1_TO_10S;
+
+        assert_eq!(range.map_seconds(0.001), Normal::MIN);
+        assert_eq!(range.map_seconds(10.0), Normal::MAX);
+        assert!((range.map_seconds(0.1).as_f32() - 0.5).abs() < 1.0e-6);
+
+        for ms in [1.0, 20.0, 250.0, 1_000.0, 7_500.0] {
+            let normal = range.map_ms(ms);
+            assert!((range.unmap_to_ms(normal) - ms).abs() / ms < 1.0e-4);
+        }
+    }
+
+    #[test]
+    fn division_round_trip() {
+        let range = TimeRange::default().with_divisions(
+            NoteDivision::straight(NoteValue::Sixteenth),
+            NoteDivision::straight(NoteValue::Whole),
+        );
+
+        assert_eq!(range.divisions().len(), 13);
+        for division in range.divisions() {
+            let normal = range.map_division(*division);
+            assert_eq!(range.unmap_to_division(normal), *division);
+        }
+
+        let below = NoteDivision::triplet(NoteValue::SixtyFourth);
+        assert_eq!(range.map_division(below), Normal::MIN);
+    }
+
+    #[test]
+    fn synced_seconds() {
+        let eighth = NoteDivision::straight(NoteValue::Eighth);
+        assert_eq!(eighth.seconds(120.0), 0.25);
+
+        let dotted = NoteDivision::dotted(NoteValue::Eighth);
+        assert_eq!(dotted.seconds(120.0), 0.375);
+        assert_eq!(dotted.modifier, NoteModifier::Dotted);
+        assert_eq!(dotted.label(), "1/8D");
+
+        let range = TimeRange::default();
+        let normal = range.map_division(eighth);
+        assert_eq!(range.unmap_to_synced_seconds(normal, 120.0), 0.25);
+    }
+
+    #[test]
+    fn note_freq_round_trip() {
+        let range = NoteRange::MIDI;
+        assert!((range.note_to_freq(69.0) - 440.0).abs() < 1.0e-3);
+        assert!((range.freq_to_note(261.6256) - 60.0).abs() < 1.0e-3);
+
+        let range = NoteRange::PIANO.with_reference(432.0);
+        let normal = range.map_freq(432.0);
+        assert!((range.unmap_to_note(normal) - 69.0).abs() < 1.0e-3);
+        assert!((range.unmap_to_freq(normal) - 432.0).abs() < 1.0e-2);
+    }
+
+    #[test]
+    fn note_snapping() {
+        let range = NoteRange::MIDI;
+
+        let normal = range.map_note(60.4);
+        assert_eq!(range.unmap_to_note(range.snap(normal)), 60.0);
+
+        // C# is not in C major, so it snaps down to C or up to D.
+        let c_major = Scale::major(0);
+        let snapped = range.snap_to_scale(range.map_note(61.2), c_major);
+        assert_eq!(range.unmap_to_note(snapped).round(), 62.0);
+        let snapped = range.snap_to_scale(range.map_note(60.9), c_major);
+        assert_eq!(range.unmap_to_note(snapped).round(), 60.0);
+    }
+
+    #[test]
+    fn note_text_marks() {
+        let range = NoteRange::new(36.0, 60.0);
+        let marks = range.text_marks();
+        let labels: Vec<&str> = marks
+            .group
+            .iter()
+            .map(|(_, label)| label.as_str())
+            .collect();
+
+        assert_eq!(labels, ["C2", "C3", "C4"]);
+        assert_eq!(NoteRange::note_name(70), "A#4");
+    }
+
+    fn generic_round_trip<R: ParamRange>(range: &R, value: R::Value) -> f32 {
+        range.unmap_to_f32(range.map(value))
+    }
+
+    #[test]
+    fn param_range_is_generic() {
+        assert_eq!(generic_round_trip(&IntRange::new(0, 10), 7), 7.0);
+        assert!((generic_round_trip(&DBRange::NEG_12_TO_12, -6.0) + 6.0).abs() < 1.0e-4);
+        assert!((generic_round_trip(&FreqRange::HZ_20_TO_20K, 440.0) - 440.0).abs() < 1.0e-2);
+
+        let range = FreqRange::HZ_20_TO_20K;
+        assert_eq!(ParamRange::default_param(&range), range.default_param());
+        assert_eq!(range.format(1_500.0), "1.50 kHz");
+        assert_eq!(TimeRange::default().format(0.25), "250 ms");
+        assert_eq!(NoteRange::default().format(60.12), "C4 +12ct");
+
+        let range = IntRange::new(0, 4);
+        assert!(range.is_discrete());
+        assert_eq!(ParamRange::snap(&range, range.map(2)), range.map(2));
+    }
+
+    #[test]
+    fn skewed_midpoint() {
+        let range = SkewedRange::from_midpoint(20.0, 20_000.0, 1_000.0);
+        assert!((range.map(1_000.0).as_f32() - 0.5).abs() < 1.0e-5);
+        assert_eq!(range.map(20.0), Normal::MIN);
+        assert_eq!(range.map(20_000.0), Normal::MAX);
+
+        for value in [20.0, 150.0, 1_000.0, 7_000.0, 20_000.0] {
+            let round_trip = range.unmap_to_value(range.map(value));
+            assert!((round_trip - value).abs() / value < 1.0e-4);
+        }
+    }
+
+    #[test]
+    fn skewed_symmetric() {
+        let range = SkewedRange::symmetric(-100.0, 100.0, 0.5);
+        assert_eq!(range.map(0.0), Normal::CENTER);
+
+        // Both halves are skewed the same way around the center.
+        let above = range.map(25.0).as_f32() - 0.5;
+        let below = 0.5 - range.map(-25.0).as_f32();
+        assert!((above - below).abs() < 1.0e-6);
+        assert!(above > 0.125);
+
+        for value in [-100.0, -40.0, -1.0, 0.0, 3.0, 60.0, 100.0] {
+            let round_trip = range.unmap_to_value(range.map(value));
+            assert!((round_trip - value).abs() < 1.0e-3);
+        }
+    }
+
+    #[test]
+    fn exponential_midpoint() {
+        let range = ExpRange::from_midpoint(0.0, 100.0, 30.0);
+        assert!((range.map(30.0).as_f32() - 0.5).abs() < 1.0e-5);
+        assert!((range.unmap_to_value(Normal::CENTER) - 30.0).abs() < 1.0e-3);
+
+        let range = ExpRange::from_midpoint(-1.0, 1.0, 0.5);
+        assert!(range.factor() < 0.0);
+        for value in [-1.0, -0.5, 0.0, 0.5, 0.9, 1.0] {
+            let round_trip = range.unmap_to_value(range.map(value));
+            assert!((round_trip - value).abs() < 1.0e-4);
+        }
+
+        let linear = ExpRange::new(0.0, 10.0, 0.0);
+        assert_eq!(linear.map(2.5).as_f32(), 0.25);
+    }
+
+    #[test]
+    fn fader_law() {
+        let range = FaderRange::NEG_INF_TO_12;
+        assert_eq!(range.map_db(f32::NEG_INFINITY), Normal::MIN);
+        assert_eq!(range.unmap_to_db(Normal::MIN), f32::NEG_INFINITY);
+        assert_eq!(range.unmap_to_amplitude(Normal::MIN), 0.0);
+        assert_eq!(range.map_db(12.0), Normal::MAX);
+        assert_eq!(range.map_db(24.0), Normal::MAX);
+        assert_eq!(range.map_db(0.0).as_f32(), 0.75);
+
+        for db in [-90.0, -60.0, -42.0, -10.0, -3.0, 0.0, 6.0, 12.0] {
+            let round_trip = range.unmap_to_db(range.map_db(db));
+            assert!((round_trip - db).abs() < 1.0e-3);
+        }
+
+        let unity = range.map_amplitude(1.0);
+        assert_eq!(unity, range.map_db(0.0));
+        assert!((range.unmap_to_amplitude(unity) - 1.0).abs() < 1.0e-5);
+        assert_eq!(range.map_amplitude(0.0), Normal::MIN);
+    }
+
+    #[test]
+    fn freq_any_span() {
+        // The audible spectrum spaces each octave evenly, as before.
+        let range = FreqRange::HZ_20_TO_20K;
+        let octave = range.map_freq(40.0).as_f32() - range.map_freq(20.0).as_f32();
+        let upper_octave = range.map_freq(8_000.0).as_f32() - range.map_freq(4_000.0).as_f32();
+        assert!((octave - upper_octave).abs() < 1.0e-5);
+
+        for range in [
+            FreqRange::LFO,
+            FreqRange::HZ_10_TO_40K,
+            FreqRange::HZ_20_TO_48K,
+        ] {
+            assert_eq!(range.map_freq(range.min()), Normal::MIN);
+            assert_eq!(range.map_freq(range.max()), Normal::MAX);
+
+            for normal in [0.0, 0.1, 0.37, 0.5, 0.9, 1.0] {
+                let freq = range.unmap_to_freq(Normal::new(normal));
+                assert!((range.map_freq(freq).as_f32() - normal).abs() < 1.0e-4);
+            }
+        }
+
+        let range = FreqRange::LFO;
+        assert!((range.unmap_to_freq(Normal::CENTER) - (0.01_f32 * 50.0).sqrt()).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn freq_linear_region() {
+        let range = FreqRange::LFO_FROM_ZERO;
+        assert_eq!(range.map_freq(0.0), Normal::MIN);
+        assert_eq!(range.unmap_to_freq(Normal::MIN), 0.0);
+
+        for freq in [0.0, 0.02, 0.05, 0.1, 0.5, 3.0, 50.0] {
+            let round_trip = range.unmap_to_freq(range.map_freq(freq));
+            assert!((round_trip - freq).abs() < 1.0e-4 * freq.max(1.0));
+        }
+
+        // The slope is continuous where the regions meet.
+        let below = range.map_freq(0.099).as_f32();
+        let at = range.map_freq(0.1).as_f32();
+        let above = range.map_freq(0.101).as_f32();
+        assert!(((at - below) - (above - at)).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn freq_default_inside_lfo_presets() {
+        let range = FreqRange::LFO;
+        let default = ParamRange::default_value(&range);
+        assert!((default - (0.01_f32 * 50.0).sqrt()).abs() < 1.0e-4);
+        assert_eq!(range.default_param().normal, Normal::CENTER);
+        assert_eq!(ParamRange::default_param(&range).default, Normal::CENTER);
+
+        let range = FreqRange::LFO_FROM_ZERO;
+        let default = ParamRange::default_value(&range);
+        assert!(default > 0.1 && default < 50.0);
+        assert_eq!(range.default_param().normal, Normal::CENTER);
+        assert!((range.map_freq(default).as_f32() - 0.5).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn enum_range() {
+        let range = EnumRange::new(&["LP", "HP", "BP", "Notch"]);
+        assert_eq!(range.map(0), Normal::MIN);
+        assert_eq!(range.map(3), Normal::MAX);
+        assert_eq!(range.map(9), Normal::MAX);
+        assert_eq!(range.unmap_to_label(Normal::new(0.4)), "HP");
+        assert_eq!(range.snap(Normal::new(0.6)), range.map(2));
+        assert_eq!(range.index_of("Notch"), Some(3));
+        assert_eq!(range.format_normal(range.map(2)), "BP");
+
+        let marks = range.text_marks();
+        assert_eq!(marks.group.len(), 4);
+        assert_eq!(marks.group[1], (range.map(1), String::from("HP")));
+        assert_eq!(range.tick_marks().len(), 4);
+    }
+
+    #[test]
+    fn bool_range() {
+        let range = BoolRange::new("Free", "Sync");
+        assert_eq!(range.map(true), Normal::MAX);
+        assert!(!range.unmap_to_bool(Normal::new(0.3)));
+        assert_eq!(range.snap(Normal::new(0.7)), Normal::MAX);
+        assert_eq!(range.format(true), "Sync");
+        assert!(range.is_discrete());
+        assert_eq!(range.unmap_to_f32(Normal::MAX), 1.0);
+    }
+
+    #[test]
+    fn fader_breakpoints_checked() {
+        let range = FaderRange::try_from(vec![(0.5, -20.0), (1.0, 0.0)]).unwrap();
+        assert_eq!(Vec::from(range), vec![(0.5, -20.0), (1.0, 0.0)]);
+
+        assert!(FaderRange::try_from(vec![]).is_err());
+        assert!(FaderRange::try_from(vec![(0.5, -20.0), (0.9, 0.0)]).is_err());
+        assert!(FaderRange::try_from(vec![(0.5, 0.0), (1.0, -20.0)]).is_err());
+        assert!(FaderRange::try_from(vec![(f32::NAN, -20.0), (1.0, 0.0)]).is_err());
+        assert!(FaderRange::try_from(vec![(0.5, f32::NAN), (1.0, 0.0)]).is_err());
+        assert!(FaderRange::try_from(vec![(0.5, f32::NEG_INFINITY), (1.0, 0.0)]).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn enum_labels_checked() {
+        let labels = vec!["LP".to_string(), "HP".to_string()];
+        let range = EnumRange::try_from(labels.clone()).unwrap();
+        assert_eq!(range.labels(), &["LP", "HP"]);
+        assert_eq!(Vec::from(range), labels);
+
+        // The same labels share one leaked slice.
+        let again = EnumRange::try_from(labels).unwrap();
+        assert!(std::ptr::eq(range.labels(), again.labels()));
+
+        assert!(EnumRange::try_from(Vec::new()).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn float_range_checked() {
+        use super::{FloatRange, UncheckedFloatRange};
+
+        assert!(FloatRange::try_from(UncheckedFloatRange { min: 0.0, max: 1.0 }).is_ok());
+        assert!(FloatRange::try_from(UncheckedFloatRange { min: 1.0, max: 0.0 }).is_err());
+        assert!(
+            FloatRange::try_from(UncheckedFloatRange {
+                min: f32::NAN,
+                max: 1.0
+            })
+            .is_err()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn skewed_range_checked() {
+        use super::UncheckedSkewedRange;
+
+        let unchecked = |max, skew| UncheckedSkewedRange {
+            min: 0.0,
+            max,
+            skew,
+            symmetric: false,
+        };
+        assert!(SkewedRange::try_from(unchecked(1.0, 0.5)).is_ok());
+        assert!(SkewedRange::try_from(unchecked(-1.0, 0.5)).is_err());
+        assert!(SkewedRange::try_from(unchecked(1.0, 0.0)).is_err());
+        assert!(SkewedRange::try_from(unchecked(f32::INFINITY, 0.5)).is_err());
+        assert!(SkewedRange::try_from(unchecked(1.0, f32::INFINITY)).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn exp_range_checked() {
+        use super::UncheckedExpRange;
+
+        let unchecked = |max, factor| UncheckedExpRange {
+            min: 0.0,
+            max,
+            factor,
+        };
+        assert!(ExpRange::try_from(unchecked(1.0, 4.0)).is_ok());
+        assert!(ExpRange::try_from(unchecked(-1.0, 4.0)).is_err());
+        assert!(ExpRange::try_from(unchecked(1.0, f32::INFINITY)).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn int_range_checked() {
+        use super::UncheckedIntRange;
+
+        assert!(IntRange::try_from(UncheckedIntRange { min: 0, max: 10 }).is_ok());
+        assert!(IntRange::try_from(UncheckedIntRange { min: 10, max: 0 }).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn db_range_checked() {
+        use super::UncheckedDBRange;
+
+        let unchecked = |min, max, skew_factor| UncheckedDBRange {
+            min,
+            max,
+            skew_factor,
+            zero_position: Normal::new(0.5),
+        };
+        assert!(DBRange::try_from(unchecked(-12.0, 12.0, 1.0)).is_ok());
+        assert!(DBRange::try_from(unchecked(12.0, -12.0, 1.0)).is_err());
+        assert!(DBRange::try_from(unchecked(1.0, 12.0, 1.0)).is_err());
+        assert!(DBRange::try_from(unchecked(-12.0, -1.0, 1.0)).is_err());
+        assert!(DBRange::try_from(unchecked(-12.0, 12.0, 0.5)).is_err());
+        assert!(DBRange::try_from(unchecked(f32::NEG_INFINITY, 12.0, 1.0)).is_err());
+        assert!(DBRange::try_from(unchecked(-12.0, 12.0, f32::NAN)).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn freq_range_checked() {
+        use super::UncheckedFreqRange;
+
+        let unchecked = |min, max, linear_below| UncheckedFreqRange {
+            min,
+            max,
+            linear_below,
+        };
+        assert!(FreqRange::try_from(unchecked(20.0, 20_000.0, 0.0)).is_ok());
+        assert!(FreqRange::try_from(unchecked(0.0, 20_000.0, 100.0)).is_ok());
+        assert!(FreqRange::try_from(unchecked(0.0, 20_000.0, 0.0)).is_err());
+        assert!(FreqRange::try_from(unchecked(20_000.0, 20.0, 0.0)).is_err());
+        assert!(FreqRange::try_from(unchecked(-10.0, 20_000.0, 100.0)).is_err());
+        assert!(FreqRange::try_from(unchecked(0.0, 50.0, 100.0)).is_err());
+        assert!(FreqRange::try_from(unchecked(20.0, 20_000.0, f32::NAN)).is_err());
+        assert!(FreqRange::try_from(unchecked(20.0, f32::INFINITY, 0.0)).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn time_range_checked() {
+        use super::UncheckedTimeRange;
+
+        let unchecked = |min, min_division, max_division| UncheckedTimeRange {
+            min,
+            max: 1.0,
+            min_division,
+            max_division,
+        };
+        let last = NoteDivision::ALL.len() - 1;
+        assert!(TimeRange::try_from(unchecked(0.001, 0, last)).is_ok());
+        assert!(TimeRange::try_from(unchecked(0.0, 0, last)).is_err());
+        assert!(TimeRange::try_from(unchecked(0.001, 0, last + 1)).is_err());
+        assert!(TimeRange::try_from(unchecked(0.001, 3, 2)).is_err());
+        assert!(TimeRange::try_from(unchecked(f32::NAN, 0, last)).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn note_range_checked() {
+        use super::UncheckedNoteRange;
+
+        let unchecked = |max, reference| UncheckedNoteRange {
+            min: 0.0,
+            max,
+            reference,
+        };
+        assert!(NoteRange::try_from(unchecked(127.0, 440.0)).is_ok());
+        assert!(NoteRange::try_from(unchecked(-1.0, 440.0)).is_err());
+        assert!(NoteRange::try_from(unchecked(127.0, 0.0)).is_err());
+        assert!(NoteRange::try_from(unchecked(127.0, f32::INFINITY)).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_rejects_nan() {
+        use serde::Deserialize;
+        use serde::de::value::{Error, MapDeserializer};
+
+        let fields = vec![("min", 0.0), ("max", 127.0), ("reference", f32::NAN)];
+        let deserializer = MapDeserializer::<_, Error>::new(fields.into_iter());
+        assert!(NoteRange::deserialize(deserializer).is_err());
+
+        let fields = vec![("min", 20.0), ("max", 20_000.0), ("linear_below", f32::NAN)];
+        let deserializer = MapDeserializer::<_, Error>::new(fields.into_iter());
+        assert!(FreqRange::deserialize(deserializer).is_err());
+    }
 }
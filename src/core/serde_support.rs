@@ -0,0 +1,155 @@
+//! Helpers for serializing types from `iced` that don't implement
+//! `Serialize` and `Deserialize` themselves.
+
+use iced_core::Font;
+use iced_core::font::{Family, Stretch, Style, Weight};
+use iced_graphics::geometry::LineCap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use std::sync::Mutex;
+
+/// Returns a `&'static str` with the contents of `value`.
+///
+/// `iced` font names and the labels of some ranges are `&'static str`, so
+/// deserialized strings have to be leaked. Each distinct string is only
+/// leaked once.
+pub(crate) fn intern(value: &str) -> &'static str {
+    static STRINGS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+    let mut strings = STRINGS.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(interned) = strings.iter().find(|s| **s == value) {
+        return interned;
+    }
+
+    let interned: &'static str = Box::leak(value.into());
+    strings.push(interned);
+    interned
+}
+
+/// Returns a `&'static [&'static str]` with the contents of `values`.
+///
+/// Like [`intern`], each distinct list is only leaked once.
+pub(crate) fn intern_slice(values: &[String]) -> &'static [&'static str] {
+    static SLICES: Mutex<Vec<&'static [&'static str]>> = Mutex::new(Vec::new());
+
+    let mut slices = SLICES.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(interned) = slices.iter().find(|s| s.iter().eq(values.iter())) {
+        return interned;
+    }
+
+    let interned: Vec<&'static str> = values.iter().map(|v| intern(v)).collect();
+    let interned: &'static [&'static str] = Box::leak(interned.into_boxed_slice());
+    slices.push(interned);
+    interned
+}
+
+/// (De)serializes a `&'static str`.
+pub(crate) mod static_str {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(value: &&'static str, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(value)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<&'static str, D::Error> {
+        let value = String::deserialize(d)?;
+        Ok(super::intern(&value))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "LineCap")]
+pub(crate) enum LineCapDef {
+    Butt,
+    Square,
+    Round,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Weight")]
+enum WeightDef {
+    Thin,
+    ExtraLight,
+    Light,
+    Normal,
+    Medium,
+    Semibold,
+    Bold,
+    ExtraBold,
+    Black,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Stretch")]
+enum StretchDef {
+    UltraCondensed,
+    ExtraCondensed,
+    Condensed,
+    SemiCondensed,
+    Normal,
+    SemiExpanded,
+    Expanded,
+    ExtraExpanded,
+    UltraExpanded,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Style")]
+enum StyleDef {
+    Normal,
+    Italic,
+    Oblique,
+}
+
+/// (De)serializes a font `Family`, with the name of a family as an owned
+/// string.
+mod family {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    enum FamilyRepr {
+        Name(String),
+        Serif,
+        SansSerif,
+        Cursive,
+        Fantasy,
+        Monospace,
+    }
+
+    pub(super) fn serialize<S: Serializer>(family: &Family, s: S) -> Result<S::Ok, S::Error> {
+        let repr = match family {
+            Family::Name(name) => FamilyRepr::Name(String::from(*name)),
+            Family::Serif => FamilyRepr::Serif,
+            Family::SansSerif => FamilyRepr::SansSerif,
+            Family::Cursive => FamilyRepr::Cursive,
+            Family::Fantasy => FamilyRepr::Fantasy,
+            Family::Monospace => FamilyRepr::Monospace,
+        };
+
+        repr.serialize(s)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Family, D::Error> {
+        Ok(match FamilyRepr::deserialize(d)? {
+            FamilyRepr::Name(name) => Family::Name(super::intern(&name)),
+            FamilyRepr::Serif => Family::Serif,
+            FamilyRepr::SansSerif => Family::SansSerif,
+            FamilyRepr::Cursive => Family::Cursive,
+            FamilyRepr::Fantasy => Family::Fantasy,
+            FamilyRepr::Monospace => Family::Monospace,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Font")]
+pub(crate) struct FontDef {
+    #[serde(with = "family")]
+    family: Family,
+    #[serde(with = "WeightDef")]
+    weight: Weight,
+    #[serde(with = "StretchDef")]
+    stretch: Stretch,
+    #[serde(with = "StyleDef")]
+    style: Style,
+}
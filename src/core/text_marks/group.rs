@@ -2,10 +2,11 @@
 
 use std::fmt::Debug;
 
-use crate::core::Normal;
+use crate::core::{Normal, ParamRange};
 
 /// A group of text marks.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Group {
     /// The group of text marks.
     pub group: Vec<(Normal, String)>,
@@ -26,6 +27,19 @@ impl Group {
         Self::from_string(group)
     }
 
+    /// Constructs a new `Group` with a text mark at each of `values` in
+    /// `range`, labeled with [`ParamRange::format`].
+    ///
+    /// [`ParamRange::format`]: ../../range/trait.ParamRange.html#tymethod.format
+    pub fn from_values<R: ParamRange>(range: &R, values: &[R::Value]) -> Self {
+        let group = values
+            .iter()
+            .map(|value| (range.map(*value), range.format(*value)))
+            .collect();
+
+        Self::from_string(group)
+    }
+
     /// Constructs a new `Group` from a vector of [`TextMark`]s.
     ///
     /// [`Group`]: struct.Group.html
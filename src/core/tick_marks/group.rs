@@ -3,12 +3,13 @@
 use std::fmt::Debug;
 
 use super::Tier;
-use crate::core::Normal;
+use crate::core::{Normal, ParamRange};
 
 /// A group of tick marks.
 ///
 /// tick mark: struct.TickMark.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Group {
     tier_1_positions: Vec<Normal>,
     tier_2_positions: Vec<Normal>,
@@ -65,6 +66,18 @@ impl Group {
         }
     }
 
+    /// Constructs a new `Group` with a tick mark at each value in `range`.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn from_values<R: ParamRange>(range: &R, tick_marks: &[(R::Value, Tier)]) -> Self {
+        let tick_marks: Vec<(Normal, Tier)> = tick_marks
+            .iter()
+            .map(|(value, tier)| (range.map(*value), *tier))
+            .collect();
+
+        Self::from_normalized(&tick_marks)
+    }
+
     /// Returns a new [`Group`] with a single tick mark in
     /// the center position.
     ///
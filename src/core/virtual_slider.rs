@@ -217,26 +217,26 @@ impl<'a, Message> VirtualSlider<'a, Message> {
 
         match event {
             Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
-                if state.is_dragging {
-                    let (pos, delta) = if drag_horizontally {
-                        (position.x, state.prev_drag_pos - position.x)
-                    } else {
-                        (position.y, position.y - state.prev_drag_pos)
-                    };
-
-                    state.prev_drag_pos = pos;
-
-                    let drag_scalar = if halve_speed {
-                        self.config.drag_scalar * 0.5
-                    } else {
-                        self.config.drag_scalar
-                    };
-
-                    self.move_virtual_slider(state, delta * drag_scalar, shell, &mut status);
-
-                    capture_event = true;
-                }
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.is_dragging =>
+            {
+                let (pos, delta) = if drag_horizontally {
+                    (position.x, state.prev_drag_pos - position.x)
+                } else {
+                    (position.y, position.y - state.prev_drag_pos)
+                };
+
+                state.prev_drag_pos = pos;
+
+                let drag_scalar = if halve_speed {
+                    self.config.drag_scalar * 0.5
+                } else {
+                    self.config.drag_scalar
+                };
+
+                self.move_virtual_slider(state, delta * drag_scalar, shell, &mut status);
+
+                capture_event = true;
             }
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
                 if self.config.wheel_scalar == 0.0 {
@@ -365,6 +365,35 @@ impl<'a, Message> VirtualSlider<'a, Message> {
         status
     }
 
+    /// A method that custom virtual slider widgets can call to set the value of
+    /// the parameter from outside of the user's input (i.e. from an animation).
+    ///
+    /// A [`Gesture::GestureStart`] will be sent first if no gesture is currently
+    /// in progress. Call [`VirtualSlider::finish_gesture`] once the value has
+    /// settled.
+    pub fn set_normal(
+        &mut self,
+        state: &mut State,
+        normal: Normal,
+        shell: &mut Shell<'_, Message>,
+    ) -> UpdateStatus {
+        let mut status = UpdateStatus::default();
+        self.set_param_value(normal.as_f32(), state, shell, &mut status);
+        status
+    }
+
+    /// A method that custom virtual slider widgets can call to end the current
+    /// gesture (if there is one).
+    pub fn finish_gesture(
+        &mut self,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+    ) -> UpdateStatus {
+        let mut status = UpdateStatus::default();
+        self.end_gesture(state, shell, &mut status);
+        status
+    }
+
     fn move_virtual_slider(
         &mut self,
         state: &mut State,
@@ -433,3 +462,47 @@ impl<'a, Message> VirtualSlider<'a, Message> {
         state.last_sent_gesture = Gesture::GestureEnd;
     }
 }
+
+/// The boxed callback a widget calls with each [`Gesture`] of one param.
+#[cfg(any(
+    feature = "range_slider",
+    feature = "waveform_editor",
+    feature = "transfer_curve"
+))]
+pub(crate) type OnGesture<'a, Message> = Option<Box<dyn 'a + FnMut(Gesture) -> Message>>;
+
+/// Sets the value of one param of a widget that gestures several params at
+/// once, sending a [`Gesture::GestureStart`] first if needed. Returns `true`
+/// if the value has changed.
+#[cfg(any(
+    feature = "range_slider",
+    feature = "waveform_editor",
+    feature = "transfer_curve"
+))]
+pub(crate) fn set_param_value<Message>(
+    param: &mut NormalParam,
+    new_value: Normal,
+    last_sent_gesture: &mut Gesture,
+    on_gesture: &mut OnGesture<'_, Message>,
+    shell: &mut Shell<'_, Message>,
+) -> bool {
+    if (new_value.as_f32() - param.normal.as_f32()).abs() <= f32::EPSILON {
+        return false;
+    }
+    param.normal = new_value;
+
+    if let Gesture::GestureEnd = last_sent_gesture {
+        // Send a GestureStart message first.
+        if let Some(on_gesture) = on_gesture {
+            shell.publish((on_gesture)(Gesture::GestureStart));
+        }
+        *last_sent_gesture = Gesture::GestureStart;
+    }
+
+    if let Some(on_gesture) = on_gesture {
+        shell.publish((on_gesture)(Gesture::Gesturing(new_value)));
+    }
+    *last_sent_gesture = Gesture::Gesturing(new_value);
+
+    true
+}
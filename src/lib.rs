@@ -37,3 +37,78 @@ pub use widget::xy_pad::XYPad;
 pub use widget::mod_range_input;
 #[cfg(feature = "mod_range_input")]
 pub use widget::mod_range_input::ModRangeInput;
+
+#[cfg(feature = "pad_grid")]
+pub use widget::pad_grid;
+#[cfg(feature = "pad_grid")]
+pub use widget::pad_grid::PadGrid;
+
+#[cfg(feature = "wheel")]
+pub use widget::wheel;
+#[cfg(feature = "wheel")]
+pub use widget::wheel::Wheel;
+
+#[cfg(feature = "range_slider")]
+pub use widget::range_slider;
+#[cfg(feature = "range_slider")]
+pub use widget::range_slider::RangeSlider;
+
+#[cfg(feature = "multi_slider")]
+pub use widget::multi_slider;
+#[cfg(feature = "multi_slider")]
+pub use widget::multi_slider::MultiSlider;
+
+#[cfg(feature = "dual_knob")]
+pub use widget::dual_knob;
+#[cfg(feature = "dual_knob")]
+pub use widget::dual_knob::DualKnob;
+
+#[cfg(feature = "shape_editor")]
+pub use widget::shape_editor;
+#[cfg(feature = "shape_editor")]
+pub use widget::shape_editor::ShapeEditor;
+
+#[cfg(feature = "automation_lane")]
+pub use widget::automation_lane;
+#[cfg(feature = "automation_lane")]
+pub use widget::automation_lane::AutomationLane;
+
+#[cfg(feature = "waveform_editor")]
+pub use widget::waveform_editor;
+#[cfg(feature = "waveform_editor")]
+pub use widget::waveform_editor::WaveformEditor;
+
+#[cfg(feature = "spectrogram")]
+pub use widget::spectrogram;
+#[cfg(feature = "spectrogram")]
+pub use widget::spectrogram::Spectrogram;
+
+#[cfg(feature = "loudness_meter")]
+pub use widget::loudness_meter;
+#[cfg(feature = "loudness_meter")]
+pub use widget::loudness_meter::LoudnessMeter;
+
+#[cfg(feature = "transfer_curve")]
+pub use widget::transfer_curve;
+#[cfg(feature = "transfer_curve")]
+pub use widget::transfer_curve::TransferCurve;
+
+#[cfg(feature = "gain_reduction_meter")]
+pub use widget::gain_reduction_meter;
+#[cfg(feature = "gain_reduction_meter")]
+pub use widget::gain_reduction_meter::GainReductionMeter;
+
+#[cfg(feature = "vu_meter")]
+pub use widget::vu_meter;
+#[cfg(feature = "vu_meter")]
+pub use widget::vu_meter::VuMeter;
+
+#[cfg(feature = "tuner")]
+pub use widget::tuner;
+#[cfg(feature = "tuner")]
+pub use widget::tuner::Tuner;
+
+#[cfg(feature = "value_box")]
+pub use widget::value_box;
+#[cfg(feature = "value_box")]
+pub use widget::value_box::ValueBox;
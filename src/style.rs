@@ -2,11 +2,65 @@
 
 mod default_colors;
 
+pub mod automation_lane;
+pub mod dual_knob;
+pub mod gain_reduction_meter;
 pub mod h_slider;
 pub mod knob;
+pub mod loudness_meter;
 pub mod mod_range_input;
+pub mod multi_slider;
+pub mod pad_grid;
 pub mod ramp;
+pub mod range_slider;
+pub mod shape_editor;
+#[cfg(feature = "skin")]
+pub mod skin;
+pub mod spectrogram;
 pub mod text_marks;
 pub mod tick_marks;
+pub mod transfer_curve;
+pub mod tuner;
 pub mod v_slider;
+pub mod value_box;
+pub mod vu_meter;
+pub mod waveform_editor;
+pub mod wheel;
 pub mod xy_pad;
+
+/// The interaction status of a widget, used to pick its appearance in a
+/// `Catalog`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The widget is enabled and idle.
+    Idle,
+    /// The cursor is over the widget.
+    Hovered,
+    /// The widget is being gestured (dragged).
+    Gesturing,
+    /// The widget is disabled.
+    Disabled,
+}
+
+impl Status {
+    /// Returns the status of a pointer-driven widget.
+    #[cfg(any(
+        feature = "knob",
+        feature = "h_slider",
+        feature = "v_slider",
+        feature = "ramp",
+        feature = "xy_pad",
+        feature = "mod_range_input"
+    ))]
+    pub(crate) fn new(enabled: bool, gesturing: bool, hovered: bool) -> Self {
+        if !enabled {
+            Status::Disabled
+        } else if gesturing {
+            Status::Gesturing
+        } else if hovered {
+            Status::Hovered
+        } else {
+            Status::Idle
+        }
+    }
+}
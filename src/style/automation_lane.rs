@@ -0,0 +1,227 @@
+//! Various styles for the [`AutomationLane`] widget
+//!
+//! [`AutomationLane`]: ../native/automation_lane/struct.AutomationLane.html
+
+use crate::{
+    Offset,
+    style::{default_colors, text_marks},
+};
+use iced_core::Color;
+
+/// The appearance of an [`AutomationLane`].
+///
+/// [`AutomationLane`]: ../../native/automation_lane/struct.AutomationLane.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// color of the background rectangle
+    pub back_color: Color,
+    /// width of the background rectangle border
+    pub back_border_width: f32,
+    /// radius of the background rectangle
+    pub back_border_radius: f32,
+    /// color of the background rectangle border
+    pub back_border_color: Color,
+    /// color of the horizontal lines drawn at each text mark of the value
+    /// axis. Set this to `None` for no lines.
+    pub value_line_color: Option<Color>,
+    /// color of the automation curve
+    pub line_color: Color,
+    /// width of the automation curve
+    pub line_width: f32,
+    /// color of the area below the automation curve. Set this to `None` for
+    /// no fill.
+    pub fill_color: Option<Color>,
+    /// appearance of a breakpoint
+    pub point: PointAppearance,
+    /// appearance of a selected breakpoint
+    pub selected_point: PointAppearance,
+    /// color of the rectangle drawn while selecting breakpoints
+    pub selection_color: Color,
+    /// color of the border of the rectangle drawn while selecting
+    /// breakpoints
+    pub selection_border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: default_colors::BORDER,
+            value_line_color: Some(default_colors::SHAPE_EDITOR_GRID),
+            line_color: default_colors::BORDER,
+            line_width: 2.0,
+            fill_color: Some(default_colors::SHAPE_EDITOR_FILL),
+            point: PointAppearance::default(),
+            selected_point: PointAppearance {
+                color: default_colors::AUTOMATION_LANE_SELECTED,
+                ..PointAppearance::default()
+            },
+            selection_color: default_colors::AUTOMATION_LANE_SELECTION,
+            selection_border_color: default_colors::AUTOMATION_LANE_SELECTED,
+        }
+    }
+}
+
+/// The appearance of a breakpoint of an [`AutomationLane`].
+///
+/// [`AutomationLane`]: ../../native/automation_lane/struct.AutomationLane.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointAppearance {
+    /// the diameter of the breakpoint
+    pub diameter: f32,
+    /// the color of the breakpoint
+    pub color: Color,
+    /// the width of the border of the breakpoint
+    pub border_width: f32,
+    /// the color of the border of the breakpoint
+    pub border_color: Color,
+}
+
+impl Default for PointAppearance {
+    fn default() -> Self {
+        PointAppearance {
+            diameter: 8.0,
+            color: default_colors::LIGHT_BACK,
+            border_width: 1.0,
+            border_color: default_colors::BORDER,
+        }
+    }
+}
+
+/// Style of the text marks of the value axis of an [`AutomationLane`].
+///
+/// [`AutomationLane`]: ../../native/automation_lane/struct.AutomationLane.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextMarksAppearance {
+    /// The style of the text marks
+    pub style: text_marks::Appearance,
+    /// The placement of the text marks
+    pub placement: text_marks::Placement,
+}
+
+/// A set of rules that dictate the style of an [`AutomationLane`].
+///
+/// [`AutomationLane`]: ../../native/automation_lane/struct.AutomationLane.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of an enabled, idle [`AutomationLane`].
+    ///
+    /// [`AutomationLane`]: ../../native/automation_lane/struct.AutomationLane.html
+    fn idle(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a hovered [`AutomationLane`].
+    ///
+    /// [`AutomationLane`]: ../../native/automation_lane/struct.AutomationLane.html
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// Produces the style of an [`AutomationLane`] that is being gestured
+    /// (edited).
+    ///
+    /// [`AutomationLane`]: ../../native/automation_lane/struct.AutomationLane.html
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the style of an [`AutomationLane`] that is currently
+    /// disabled.
+    ///
+    /// [`AutomationLane`]: ../../native/automation_lane/struct.AutomationLane.html
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// The style of the text marks of the value axis of an
+    /// [`AutomationLane`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`AutomationLane`]: ../../native/automation_lane/struct.AutomationLane.html
+    fn text_marks_appearance(&self, _style: &Self::Style) -> Option<TextMarksAppearance> {
+        None
+    }
+}
+
+/// The style of an AutomationLane.
+#[derive(Default)]
+pub enum AutomationLane {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for AutomationLane
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        AutomationLane::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = AutomationLane;
+
+    fn idle(&self, style: &Self::Style) -> Appearance {
+        match style {
+            AutomationLane::Default => Default::default(),
+            AutomationLane::Custom(custom) => custom.idle(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        match style {
+            AutomationLane::Default => Appearance {
+                back_color: default_colors::LIGHT_BACK_HOVER,
+                ..Default::default()
+            },
+            AutomationLane::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        match style {
+            AutomationLane::Default => self.hovered(style),
+            AutomationLane::Custom(custom) => custom.gesturing(self),
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        match style {
+            AutomationLane::Default => {
+                let mut appearance = self.idle(style);
+                appearance.line_color = default_colors::disabled(appearance.line_color);
+                appearance.fill_color = appearance.fill_color.map(default_colors::disabled);
+                appearance.point.color = default_colors::disabled(appearance.point.color);
+                appearance.point.border_color =
+                    default_colors::disabled(appearance.point.border_color);
+                appearance.selected_point = appearance.point;
+                appearance
+            }
+            AutomationLane::Custom(custom) => custom.disabled(self),
+        }
+    }
+
+    fn text_marks_appearance(&self, style: &Self::Style) -> Option<TextMarksAppearance> {
+        match style {
+            AutomationLane::Default => Some(TextMarksAppearance {
+                style: Default::default(),
+                placement: text_marks::Placement::LeftOrTop {
+                    inside: true,
+                    offset: Offset::new(4.0, 0.0),
+                },
+            }),
+            AutomationLane::Custom(custom) => custom.text_marks_appearance(self),
+        }
+    }
+}
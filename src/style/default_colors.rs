@@ -65,6 +65,127 @@ pub const XY_PAD_CENTER_LINE: Color = Color {
     a: 0.5,
 };
 
+pub const PAD_GRID_BACK: Color = Color::from_rgb(0.85, 0.85, 0.85);
+pub const PAD_PRESSED: Color = Color::from_rgb(0.8, 0.8, 0.8);
+pub const PAD_FLASH: Color = Color::from_rgb(1.0, 0.62, 0.2);
+
+pub const RANGE_SLIDER_FILL: Color = Color::from_rgb(0.8, 0.8, 0.8);
+
+pub const MULTI_SLIDER_FILLED: Color = Color::from_rgb(0.75, 0.75, 0.75);
+
+pub const DUAL_KNOB_EMPTY: Color = Color::from_rgb(0.85, 0.85, 0.85);
+pub const DUAL_KNOB_EMPTY_HOVER: Color = Color::from_rgb(0.8, 0.8, 0.8);
+pub const DUAL_KNOB_INNER_FILLED: Color = Color::from_rgb(0.55, 0.55, 0.55);
+
+pub const SHAPE_EDITOR_GRID: Color = Color::from_rgb(0.88, 0.88, 0.88);
+pub const SHAPE_EDITOR_FILL: Color = Color {
+    r: 0.315,
+    g: 0.315,
+    b: 0.315,
+    a: 0.15,
+};
+pub const SHAPE_EDITOR_PHASE: Color = Color::from_rgb(1.0, 0.62, 0.2);
+
+pub const AUTOMATION_LANE_SELECTED: Color = Color::from_rgb(1.0, 0.62, 0.2);
+pub const AUTOMATION_LANE_SELECTION: Color = Color {
+    r: 1.0,
+    g: 0.62,
+    b: 0.2,
+    a: 0.15,
+};
+
+pub const WAVEFORM: Color = Color::from_rgb(0.4, 0.4, 0.4);
+pub const WAVEFORM_OUTSIDE: Color = Color {
+    r: 0.0,
+    g: 0.0,
+    b: 0.0,
+    a: 0.12,
+};
+pub const WAVEFORM_LOOP: Color = Color {
+    r: 0.2,
+    g: 0.5,
+    b: 0.9,
+    a: 0.15,
+};
+pub const WAVEFORM_CROSSFADE: Color = Color {
+    r: 1.0,
+    g: 0.62,
+    b: 0.2,
+    a: 0.2,
+};
+pub const WAVEFORM_START_MARKER: Color = Color::from_rgb(0.2, 0.7, 0.3);
+pub const WAVEFORM_END_MARKER: Color = Color::from_rgb(0.85, 0.25, 0.25);
+pub const WAVEFORM_LOOP_MARKER: Color = Color::from_rgb(0.2, 0.5, 0.9);
+pub const WAVEFORM_CROSSFADE_MARKER: Color = Color::from_rgb(1.0, 0.62, 0.2);
+
+pub const SPECTROGRAM_COLOR_MAP: [Color; 5] = [
+    Color::from_rgb(0.02, 0.02, 0.06),
+    Color::from_rgb(0.2, 0.05, 0.4),
+    Color::from_rgb(0.7, 0.15, 0.35),
+    Color::from_rgb(1.0, 0.55, 0.1),
+    Color::from_rgb(1.0, 0.98, 0.75),
+];
+
+pub const LOUDNESS_MOMENTARY: Color = Color::from_rgb(0.55, 0.55, 0.55);
+pub const LOUDNESS_SHORT_TERM: Color = Color::from_rgb(0.4, 0.4, 0.4);
+pub const LOUDNESS_INTEGRATED: Color = Color::from_rgb(0.2, 0.5, 0.9);
+pub const LOUDNESS_ABOVE_TARGET: Color = Color::from_rgb(1.0, 0.62, 0.2);
+pub const LOUDNESS_RANGE: Color = Color {
+    r: 0.2,
+    g: 0.5,
+    b: 0.9,
+    a: 0.3,
+};
+pub const LOUDNESS_TARGET: Color = Color::from_rgb(0.2, 0.7, 0.3);
+pub const LOUDNESS_PEAK_OVER: Color = Color::from_rgb(0.85, 0.25, 0.25);
+
+pub const TRANSFER_CURVE_HANDLE_ACTIVE: Color = Color::from_rgb(1.0, 0.62, 0.2);
+pub const TRANSFER_CURVE_INPUT: Color = Color::from_rgb(0.2, 0.7, 0.3);
+
+pub const GAIN_REDUCTION: Color = Color::from_rgb(1.0, 0.62, 0.2);
+pub const GAIN_REDUCTION_HEAVY: Color = Color::from_rgb(0.85, 0.25, 0.25);
+
+pub const VU_FACE: Color = Color::from_rgb(0.96, 0.89, 0.7);
+pub const VU_SCALE: Color = Color::from_rgb(0.15, 0.13, 0.1);
+pub const VU_RED_ZONE: Color = Color::from_rgb(0.8, 0.15, 0.1);
+pub const VU_NEEDLE: Color = Color::from_rgb(0.1, 0.1, 0.1);
+
+pub const TUNER_NOTE: Color = Color::from_rgb(0.2, 0.2, 0.2);
+pub const TUNER_INACTIVE: Color = Color::from_rgb(0.7, 0.7, 0.7);
+pub const TUNER_IN_TUNE: Color = Color::from_rgb(0.2, 0.7, 0.3);
+pub const TUNER_IN_TUNE_ZONE: Color = Color {
+    r: 0.2,
+    g: 0.7,
+    b: 0.3,
+    a: 0.35,
+};
+pub const TUNER_NEEDLE: Color = Color::from_rgb(1.0, 0.62, 0.2);
+
+pub const VALUE_BOX_TEXT: Color = Color::from_rgb(0.2, 0.2, 0.2);
+pub const VALUE_BOX_DIGIT: Color = Color::from_rgb(1.0, 0.62, 0.2);
+pub const VALUE_BOX_EDITING: Color = Color::from_rgb(0.2, 0.5, 0.9);
+
+pub const WHEEL_BACK: Color = Color::from_rgb(0.3, 0.3, 0.3);
+pub const WHEEL_EDGE: Color = Color::from_rgb(0.55, 0.55, 0.55);
+pub const WHEEL_HOVER: Color = Color::from_rgb(1.0, 1.0, 1.0);
+pub const WHEEL_RIDGE: Color = Color {
+    r: 0.315,
+    g: 0.315,
+    b: 0.315,
+    a: 0.6,
+};
+
+/// The share of its alpha a color keeps in a default disabled style.
+pub const DISABLED_ALPHA: f32 = 0.4;
+
+/// Returns `color` faded for a default disabled style.
+pub const fn disabled(color: Color) -> Color {
+    Color {
+        a: color.a * DISABLED_ALPHA,
+        ..color
+    }
+}
+
 /*
 pub const DB_METER_BACK: Color = Color::from_rgb(0.45, 0.45, 0.45);
 pub const DB_METER_BORDER: Color = Color::from_rgb(0.2, 0.2, 0.2);
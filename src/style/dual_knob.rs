@@ -0,0 +1,229 @@
+//! Various styles for the [`DualKnob`] widget
+//!
+//! [`DualKnob`]: ../native/dual_knob/struct.DualKnob.html
+
+use crate::{
+    KnobAngleRange,
+    style::{
+        default_colors,
+        knob::{CircleNotch, NotchShape, StyleLength, ValueArcAppearance},
+    },
+};
+use iced_core::Color;
+use iced_graphics::geometry::LineCap;
+
+/// The appearance of a [`DualKnob`].
+///
+/// Each ring picks its own state, so the `outer_arc` of the hovered
+/// [`Appearance`] is only used while the outer ring is hovered, and the
+/// `inner_arc` and `cap` of the hovered [`Appearance`] are only used while
+/// the inner ring is hovered.
+///
+/// [`DualKnob`]: ../../native/dual_knob/struct.DualKnob.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// The value arc of the outer ring. The `offset` is from the outer edge
+    /// of the whole knob, so a negative offset draws the arc inside of it.
+    pub outer_arc: ValueArcAppearance,
+    /// The value arc of the inner ring. The `offset` is from the edge of the
+    /// inner ring, so a negative offset draws the arc inside of it.
+    pub inner_arc: ValueArcAppearance,
+    /// The cap in the center of the knob. Its notch points at the value of
+    /// the inner ring.
+    pub cap: CapAppearance,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            outer_arc: ValueArcAppearance {
+                width: 3.0,
+                offset: -3.0,
+                empty_color: Some(default_colors::DUAL_KNOB_EMPTY),
+                left_filled_color: default_colors::BORDER,
+                right_filled_color: None,
+                cap: LineCap::Butt,
+            },
+            inner_arc: ValueArcAppearance {
+                width: 3.0,
+                offset: -3.0,
+                empty_color: Some(default_colors::DUAL_KNOB_EMPTY),
+                left_filled_color: default_colors::DUAL_KNOB_INNER_FILLED,
+                right_filled_color: None,
+                cap: LineCap::Butt,
+            },
+            cap: CapAppearance::default(),
+        }
+    }
+}
+
+/// The appearance of the cap in the center of a [`DualKnob`].
+///
+/// [`DualKnob`]: ../../native/dual_knob/struct.DualKnob.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CapAppearance {
+    /// The diameter of the cap. A [`StyleLength::Scaled`] length is scaled
+    /// from the diameter of the whole knob.
+    ///
+    /// [`StyleLength::Scaled`]: ../knob/enum.StyleLength.html#variant.Scaled
+    pub diameter: StyleLength,
+    /// The color of the cap
+    pub color: Color,
+    /// The width of the border around the cap
+    pub border_width: f32,
+    /// The color of the border around the cap
+    pub border_color: Color,
+    /// The shape of the notch. Scaled lengths are scaled from the diameter
+    /// of the cap.
+    pub notch: NotchShape,
+}
+
+impl Default for CapAppearance {
+    fn default() -> Self {
+        CapAppearance {
+            diameter: StyleLength::Scaled(0.4),
+            color: default_colors::LIGHT_BACK,
+            border_width: 1.0,
+            border_color: default_colors::BORDER,
+            notch: NotchShape::Circle(CircleNotch {
+                color: default_colors::BORDER,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                diameter: StyleLength::Scaled(0.17),
+                offset: StyleLength::Scaled(0.15),
+            }),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a [`DualKnob`].
+///
+/// [`DualKnob`]: ../../native/dual_knob/struct.DualKnob.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of an enabled, idle [`DualKnob`].
+    ///
+    /// [`DualKnob`]: ../../native/dual_knob/struct.DualKnob.html
+    fn idle(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a hovered ring of a [`DualKnob`].
+    ///
+    /// [`DualKnob`]: ../../native/dual_knob/struct.DualKnob.html
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// Produces the style of a ring of a [`DualKnob`] that is being gestured
+    /// (dragged).
+    ///
+    /// [`DualKnob`]: ../../native/dual_knob/struct.DualKnob.html
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the style of a [`DualKnob`] that is currently disabled.
+    ///
+    /// [`DualKnob`]: ../../native/dual_knob/struct.DualKnob.html
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// a [`KnobAngleRange`] that defines the minimum and maximum angle that
+    /// the outer ring rotates
+    ///
+    /// [`KnobAngleRange`]: ../../core/knob_angle_range/struct.KnobAngleRange.html
+    fn outer_angle_range(&self, _style: &Self::Style) -> KnobAngleRange {
+        KnobAngleRange::default()
+    }
+
+    /// a [`KnobAngleRange`] that defines the minimum and maximum angle that
+    /// the inner ring rotates
+    ///
+    /// [`KnobAngleRange`]: ../../core/knob_angle_range/struct.KnobAngleRange.html
+    fn inner_angle_range(&self, _style: &Self::Style) -> KnobAngleRange {
+        KnobAngleRange::default()
+    }
+}
+
+/// The style of a DualKnob.
+#[derive(Default)]
+pub enum DualKnob {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for DualKnob
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        DualKnob::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = DualKnob;
+
+    fn idle(&self, style: &Self::Style) -> Appearance {
+        match style {
+            DualKnob::Default => Default::default(),
+            DualKnob::Custom(custom) => custom.idle(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        match style {
+            DualKnob::Default => {
+                let mut appearance = Appearance::default();
+                appearance.outer_arc.empty_color = Some(default_colors::DUAL_KNOB_EMPTY_HOVER);
+                appearance.inner_arc.empty_color = Some(default_colors::DUAL_KNOB_EMPTY_HOVER);
+                appearance.cap.color = default_colors::KNOB_BACK_HOVER;
+                appearance
+            }
+            DualKnob::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        match style {
+            DualKnob::Default => self.hovered(style),
+            DualKnob::Custom(custom) => custom.gesturing(self),
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        match style {
+            DualKnob::Default => {
+                let mut appearance = self.idle(style);
+                for arc in [&mut appearance.outer_arc, &mut appearance.inner_arc] {
+                    arc.left_filled_color = default_colors::disabled(arc.left_filled_color);
+                    arc.right_filled_color = arc.right_filled_color.map(default_colors::disabled);
+                }
+                appearance.cap.border_color = default_colors::disabled(appearance.cap.border_color);
+                appearance
+            }
+            DualKnob::Custom(custom) => custom.disabled(self),
+        }
+    }
+
+    fn outer_angle_range(&self, style: &Self::Style) -> KnobAngleRange {
+        match style {
+            DualKnob::Default => KnobAngleRange::default(),
+            DualKnob::Custom(custom) => custom.outer_angle_range(self),
+        }
+    }
+
+    fn inner_angle_range(&self, style: &Self::Style) -> KnobAngleRange {
+        match style {
+            DualKnob::Default => KnobAngleRange::default(),
+            DualKnob::Custom(custom) => custom.inner_angle_range(self),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Various styles for the [`GainReductionMeter`] widget
+//!
+//! [`GainReductionMeter`]: ../native/gain_reduction_meter/struct.GainReductionMeter.html
+
+use crate::{
+    Offset,
+    style::{default_colors, text_marks, tick_marks},
+};
+use iced_core::Color;
+
+/// The appearance of a [`GainReductionMeter`].
+///
+/// [`GainReductionMeter`]: ../../native/gain_reduction_meter/struct.GainReductionMeter.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// color of the background rectangle
+    pub back_color: Color,
+    /// width of the background rectangle border
+    pub back_border_width: f32,
+    /// radius of the background rectangle
+    pub back_border_radius: f32,
+    /// color of the background rectangle border
+    pub back_border_color: Color,
+    /// color of the gain reduction bar
+    pub color: Color,
+    /// color of the part of the bar past `heavy_threshold`. Set this to
+    /// `None` to draw the whole bar in `color`.
+    pub heavy_color: Option<Color>,
+    /// the amount of gain reduction in dB (as a positive number) past which
+    /// the bar is drawn in `heavy_color`
+    pub heavy_threshold: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: default_colors::BORDER,
+            color: default_colors::GAIN_REDUCTION,
+            heavy_color: Some(default_colors::GAIN_REDUCTION_HEAVY),
+            heavy_threshold: 12.0,
+        }
+    }
+}
+
+/// Style of tick marks for a [`GainReductionMeter`].
+///
+/// [`GainReductionMeter`]: ../../native/gain_reduction_meter/struct.GainReductionMeter.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickMarksAppearance {
+    /// The style of the tick marks
+    pub style: tick_marks::Appearance,
+    /// The placement of the tick marks on a vertical meter
+    pub vertical_placement: tick_marks::Placement,
+    /// The placement of the tick marks on a horizontal meter
+    pub horizontal_placement: tick_marks::Placement,
+}
+
+/// Style of text marks for a [`GainReductionMeter`].
+///
+/// [`GainReductionMeter`]: ../../native/gain_reduction_meter/struct.GainReductionMeter.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextMarksAppearance {
+    /// The style of the text marks
+    pub style: text_marks::Appearance,
+    /// The placement of the text marks on a vertical meter
+    pub vertical_placement: text_marks::Placement,
+    /// The placement of the text marks on a horizontal meter
+    pub horizontal_placement: text_marks::Placement,
+}
+
+/// A set of rules that dictate the style of a [`GainReductionMeter`].
+///
+/// [`GainReductionMeter`]: ../../native/gain_reduction_meter/struct.GainReductionMeter.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of a [`GainReductionMeter`].
+    ///
+    /// [`GainReductionMeter`]: ../../native/gain_reduction_meter/struct.GainReductionMeter.html
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// The style of tick marks for a [`GainReductionMeter`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`GainReductionMeter`]: ../../native/gain_reduction_meter/struct.GainReductionMeter.html
+    fn tick_marks_appearance(&self, _style: &Self::Style) -> Option<TickMarksAppearance> {
+        None
+    }
+
+    /// The style of text marks for a [`GainReductionMeter`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`GainReductionMeter`]: ../../native/gain_reduction_meter/struct.GainReductionMeter.html
+    fn text_marks_appearance(&self, _style: &Self::Style) -> Option<TextMarksAppearance> {
+        None
+    }
+}
+
+/// The style of a GainReductionMeter.
+#[derive(Default)]
+pub enum GainReductionMeter {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for GainReductionMeter
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        GainReductionMeter::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = GainReductionMeter;
+
+    fn appearance(&self, style: &Self::Style) -> Appearance {
+        match style {
+            GainReductionMeter::Default => Default::default(),
+            GainReductionMeter::Custom(custom) => custom.appearance(self),
+        }
+    }
+
+    fn tick_marks_appearance(&self, style: &Self::Style) -> Option<TickMarksAppearance> {
+        match style {
+            GainReductionMeter::Default => Some(TickMarksAppearance {
+                style: tick_marks::Appearance {
+                    tier_1: tick_marks::Shape::Line {
+                        length: 4.0,
+                        width: 2.0,
+                        color: default_colors::TICK_TIER_1,
+                    },
+                    tier_2: tick_marks::Shape::Line {
+                        length: 3.0,
+                        width: 1.0,
+                        color: default_colors::TICK_TIER_2,
+                    },
+                    tier_3: tick_marks::Shape::Line {
+                        length: 2.0,
+                        width: 1.0,
+                        color: default_colors::TICK_TIER_3,
+                    },
+                },
+                vertical_placement: tick_marks::Placement::LeftOrTop {
+                    offset: Offset::new(-2.0, 0.0),
+                    inside: false,
+                },
+                horizontal_placement: tick_marks::Placement::LeftOrTop {
+                    offset: Offset::new(0.0, -2.0),
+                    inside: false,
+                },
+            }),
+            GainReductionMeter::Custom(custom) => custom.tick_marks_appearance(self),
+        }
+    }
+
+    fn text_marks_appearance(&self, style: &Self::Style) -> Option<TextMarksAppearance> {
+        match style {
+            GainReductionMeter::Default => Some(TextMarksAppearance {
+                style: Default::default(),
+                vertical_placement: text_marks::Placement::LeftOrTop {
+                    inside: false,
+                    offset: Offset::new(-9.0, 0.0),
+                },
+                horizontal_placement: text_marks::Placement::LeftOrTop {
+                    inside: false,
+                    offset: Offset::new(0.0, -9.0),
+                },
+            }),
+            GainReductionMeter::Custom(custom) => custom.text_marks_appearance(self),
+        }
+    }
+}
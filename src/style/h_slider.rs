@@ -8,12 +8,16 @@ use crate::{
 };
 use iced_core::{Color, Rectangle, image::Handle};
 
+pub use crate::style::Status;
+
 /// The appearance of an [`HSlider`].
 ///
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Appearance {
     /// uses an image texture for the handle
+    #[cfg_attr(feature = "serde", serde(skip))]
     Texture(TextureAppearance),
     /// modeled after hardware sliders
     Classic(ClassicAppearance),
@@ -26,6 +30,7 @@ pub enum Appearance {
 
 /// A classic line rail style
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassicRail {
     /// Colors of the top and bottom of the rail
     pub rail_colors: (Color, Color),
@@ -61,6 +66,7 @@ pub struct TextureAppearance {
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
 /// [`ClassicHandle`]: struct.ClassicHandle.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassicAppearance {
     /// The rail style
     pub rail: ClassicRail,
@@ -86,6 +92,7 @@ impl Default for ClassicAppearance {
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
 /// [`ClassicStyle`]: struct.ClassicStyle.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassicHandle {
     /// background color
     pub color: Color,
@@ -123,6 +130,7 @@ impl Default for ClassicHandle {
 /// [`Appearance`]: enum.Appearance.html
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RectAppearance {
     /// color of the background rectangle
     pub back_color: Color,
@@ -150,6 +158,7 @@ pub struct RectAppearance {
 /// [`Appearance`]: enum.Appearance.html
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RectBipolarAppearance {
     /// color of the background rectangle
     pub back_color: Color,
@@ -185,6 +194,7 @@ pub struct RectBipolarAppearance {
 /// [`ModRangeStyle`]: struct.ModRangeStyle.html
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ModRangePlacement {
     /// In the center of the widget
     Center {
@@ -220,6 +230,7 @@ pub enum ModRangePlacement {
 /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModRangeAppearance {
     /// The placement of the line relative to the widget
     pub placement: ModRangePlacement,
@@ -243,6 +254,7 @@ pub struct ModRangeAppearance {
 ///
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TickMarksAppearance {
     /// The style of the tick marks
     pub style: tick_marks::Appearance,
@@ -254,6 +266,7 @@ pub struct TickMarksAppearance {
 ///
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextMarksAppearance {
     /// The style of the text marks
     pub style: text_marks::Appearance,
@@ -333,69 +346,191 @@ pub trait StyleSheet {
     }
 }
 
-/// The style of a HSlider.
+/// The theme catalog of a [`HSlider`].
+///
+/// Every [`StyleSheet`] is a [`Catalog`] whose classes are its styles, so
+/// existing themes keep working.
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+/// [`Catalog`]: trait.Catalog.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Produces the [`Appearance`] of a [`HSlider`] with the given [`Status`].
+    ///
+    /// [`Appearance`]: enum.Appearance.html
+    /// [`Status`]: ../enum.Status.html
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+
+    /// The style of tick marks for an [`HSlider`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn tick_marks_appearance(&self, _class: &Self::Class<'_>) -> Option<TickMarksAppearance> {
+        None
+    }
+
+    /// The style of an [`ModulationRange`] line for an [`HSlider`]
+    ///
+    /// For no modulation range line, don't override this or set this to return `None`.
+    ///
+    /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn mod_range_appearance(&self, _class: &Self::Class<'_>) -> Option<ModRangeAppearance> {
+        None
+    }
+
+    /// The style of a second [`ModulationRange`] line for an [`HSlider`]
+    ///
+    /// For no second modulation range line, don't override this or set this to return `None`.
+    ///
+    /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn mod_range_appearance_2(&self, _class: &Self::Class<'_>) -> Option<ModRangeAppearance> {
+        None
+    }
+
+    /// The style of text marks for an [`HSlider`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn text_marks_appearance(&self, _class: &Self::Class<'_>) -> Option<TextMarksAppearance> {
+        None
+    }
+}
+
+impl<T> Catalog for T
+where
+    T: StyleSheet,
+    T::Style: Default,
+{
+    type Class<'a> = T::Style;
+
+    fn default<'a>() -> Self::Class<'a> {
+        T::Style::default()
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        status_appearance(self, class, status)
+    }
+
+    fn tick_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TickMarksAppearance> {
+        StyleSheet::tick_marks_appearance(self, class)
+    }
+
+    fn mod_range_appearance(&self, class: &Self::Class<'_>) -> Option<ModRangeAppearance> {
+        StyleSheet::mod_range_appearance(self, class)
+    }
+
+    fn mod_range_appearance_2(&self, class: &Self::Class<'_>) -> Option<ModRangeAppearance> {
+        StyleSheet::mod_range_appearance_2(self, class)
+    }
+
+    fn text_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TextMarksAppearance> {
+        StyleSheet::text_marks_appearance(self, class)
+    }
+}
+
+/// Picks the [`StyleSheet`] method for `status`.
+///
+/// [`StyleSheet`]: trait.StyleSheet.html
+fn status_appearance<S>(sheet: &S, style: &S::Style, status: Status) -> Appearance
+where
+    S: StyleSheet + ?Sized,
+{
+    match status {
+        Status::Idle => sheet.idle(style),
+        Status::Hovered => sheet.hovered(style),
+        Status::Gesturing => sheet.gesturing(style),
+        Status::Disabled => sheet.disabled(style),
+    }
+}
+
+/// A styling function for a [`HSlider`].
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The class of a [`HSlider`] in the built-in theme.
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
 #[derive(Default)]
-pub enum HSlider {
+pub enum HSlider<'a> {
     /// The default style.
     #[default]
     Default,
     /// A custom style.
-    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme> + 'a>),
+    /// A closure that produces the [`Appearance`] of each [`Status`].
+    /// Everything else comes from the default style.
+    ///
+    /// [`Appearance`]: enum.Appearance.html
+    /// [`Status`]: ../enum.Status.html
+    Styled(StyleFn<'a, iced_core::Theme>),
 }
 
-impl<S> From<S> for HSlider
+impl<'a, S> From<S> for HSlider<'a>
 where
-    S: 'static + StyleSheet<Style = iced_core::Theme>,
+    S: 'a + StyleSheet<Style = iced_core::Theme>,
 {
     fn from(val: S) -> Self {
         HSlider::Custom(Box::new(val))
     }
 }
 
-impl StyleSheet for iced_core::Theme {
-    type Style = HSlider;
-
-    fn idle(&self, style: &Self::Style) -> Appearance {
-        match style {
-            HSlider::Default => Appearance::Classic(Default::default()),
-            HSlider::Custom(custom) => custom.idle(self),
-        }
+impl<'a> From<StyleFn<'a, iced_core::Theme>> for HSlider<'a> {
+    fn from(style: StyleFn<'a, iced_core::Theme>) -> Self {
+        HSlider::Styled(style)
     }
+}
 
-    fn hovered(&self, style: &Self::Style) -> Appearance {
-        match style {
-            HSlider::Default => Appearance::Classic(ClassicAppearance {
-                handle: ClassicHandle {
-                    color: default_colors::LIGHT_BACK_HOVER,
-                    ..Default::default()
-                },
-                ..Default::default()
-            }),
-            HSlider::Custom(custom) => custom.hovered(self),
-        }
+impl Catalog for iced_core::Theme {
+    type Class<'a> = HSlider<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        HSlider::Default
     }
 
-    fn gesturing(&self, style: &Self::Style) -> Appearance {
-        match style {
-            HSlider::Default => Appearance::Classic(ClassicAppearance {
-                handle: ClassicHandle {
-                    color: default_colors::LIGHT_BACK_DRAG,
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            HSlider::Default => match status {
+                Status::Idle | Status::Disabled => Appearance::Classic(Default::default()),
+                Status::Hovered => Appearance::Classic(ClassicAppearance {
+                    handle: ClassicHandle {
+                        color: default_colors::LIGHT_BACK_HOVER,
+                        ..Default::default()
+                    },
                     ..Default::default()
-                },
-                ..Default::default()
-            }),
-            HSlider::Custom(custom) => custom.gesturing(self),
+                }),
+                Status::Gesturing => Appearance::Classic(ClassicAppearance {
+                    handle: ClassicHandle {
+                        color: default_colors::LIGHT_BACK_DRAG,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+            },
+            HSlider::Custom(custom) => status_appearance(custom.as_ref(), self, status),
+            HSlider::Styled(style) => style(self, status),
         }
     }
 
-    fn disabled(&self, style: &Self::Style) -> Appearance {
-        // TODO
-        self.idle(style)
-    }
-
-    fn tick_marks_appearance(&self, style: &Self::Style) -> Option<TickMarksAppearance> {
-        match style {
-            HSlider::Default => Some(TickMarksAppearance {
+    fn tick_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TickMarksAppearance> {
+        match class {
+            HSlider::Custom(custom) => StyleSheet::tick_marks_appearance(custom.as_ref(), self),
+            _ => Some(TickMarksAppearance {
                 style: tick_marks::Appearance {
                     tier_1: tick_marks::Shape::Line {
                         length: 24.0,
@@ -418,34 +553,33 @@ impl StyleSheet for iced_core::Theme {
                     fill_length: false,
                 },
             }),
-            HSlider::Custom(custom) => custom.tick_marks_appearance(self),
         }
     }
 
-    fn mod_range_appearance(&self, style: &Self::Style) -> Option<ModRangeAppearance> {
-        match style {
-            HSlider::Default => None,
-            HSlider::Custom(custom) => custom.mod_range_appearance(self),
+    fn mod_range_appearance(&self, class: &Self::Class<'_>) -> Option<ModRangeAppearance> {
+        match class {
+            HSlider::Custom(custom) => StyleSheet::mod_range_appearance(custom.as_ref(), self),
+            _ => None,
         }
     }
 
-    fn mod_range_appearance_2(&self, style: &Self::Style) -> Option<ModRangeAppearance> {
-        match style {
-            HSlider::Default => None,
-            HSlider::Custom(custom) => custom.mod_range_appearance_2(self),
+    fn mod_range_appearance_2(&self, class: &Self::Class<'_>) -> Option<ModRangeAppearance> {
+        match class {
+            HSlider::Custom(custom) => StyleSheet::mod_range_appearance_2(custom.as_ref(), self),
+            _ => None,
         }
     }
 
-    fn text_marks_appearance(&self, style: &Self::Style) -> Option<TextMarksAppearance> {
-        match style {
-            HSlider::Default => Some(TextMarksAppearance {
+    fn text_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TextMarksAppearance> {
+        match class {
+            HSlider::Custom(custom) => StyleSheet::text_marks_appearance(custom.as_ref(), self),
+            _ => Some(TextMarksAppearance {
                 style: Default::default(),
                 placement: text_marks::Placement::RightOrBottom {
                     inside: false,
                     offset: Offset { x: 0.0, y: 7.0 },
                 },
             }),
-            HSlider::Custom(custom) => custom.text_marks_appearance(self),
         }
     }
 }
@@ -9,10 +9,13 @@ use crate::{
 use iced_core::Color;
 use iced_graphics::geometry::LineCap;
 
+pub use crate::style::Status;
+
 /// The appearance of a [`Knob`],
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Appearance {
     //Texture(TextureStyle),
     /// A classic circular style
@@ -49,6 +52,7 @@ pub struct TextureStyle {
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StyleLength {
     /// The diameter of the knob scaled to this value
     Scaled(f32),
@@ -69,6 +73,7 @@ impl StyleLength {
 
 /// Circle notch
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CircleNotch {
     /// The color of the circle
     pub color: Color,
@@ -84,6 +89,7 @@ pub struct CircleNotch {
 
 /// Line notch
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineNotch {
     /// The color of the line
     pub color: Color,
@@ -92,6 +98,10 @@ pub struct LineNotch {
     /// The length of the line
     pub length: StyleLength,
     /// The cap at the ends of the line
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::core::serde_support::LineCapDef")
+    )]
     pub cap: LineCap,
     /// The offset from the edge of the knob to the center of the notch.
     pub offset: StyleLength,
@@ -99,6 +109,7 @@ pub struct LineNotch {
 
 /// The shape of the notch
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NotchShape {
     /// No notch
     None,
@@ -113,6 +124,7 @@ pub enum NotchShape {
 /// [`Appearance`]: enum.Appearance.html
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CircleAppearance {
     /// The color of the knob
     pub color: Color,
@@ -146,6 +158,7 @@ impl Default for CircleAppearance {
 /// [`Appearance`]: enum.Appearance.html
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArcAppearance {
     /// The width (thickness) of the arc
     pub width: StyleLength,
@@ -156,6 +169,10 @@ pub struct ArcAppearance {
     /// The shape of the notch
     pub notch: NotchShape,
     /// The cap at the ends of the arc
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::core::serde_support::LineCapDef")
+    )]
     pub cap: LineCap,
 }
 
@@ -166,6 +183,7 @@ pub struct ArcAppearance {
 /// [`Appearance`]: enum.Appearance.html
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArcBipolarAppearance {
     /// The width (thickness) of the arc
     pub width: StyleLength,
@@ -181,6 +199,10 @@ pub struct ArcBipolarAppearance {
     /// center. Set this to `None` to only use `notch_center`.
     pub notch_left_right: Option<(NotchShape, NotchShape)>,
     /// The cap at the ends of the arc
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::core::serde_support::LineCapDef")
+    )]
     pub cap: LineCap,
 }
 
@@ -188,6 +210,7 @@ pub struct ArcBipolarAppearance {
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValueArcAppearance {
     /// The width (thickness) of the arc
     pub width: f32,
@@ -203,6 +226,10 @@ pub struct ValueArcAppearance {
     /// Set this to `None` for unipolar mode.
     pub right_filled_color: Option<Color>,
     /// The cap at the ends of the arc
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::core::serde_support::LineCapDef")
+    )]
     pub cap: LineCap,
 }
 
@@ -211,6 +238,7 @@ pub struct ValueArcAppearance {
 /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModRangeArcAppearance {
     /// The width (thickness) of the arc
     pub width: f32,
@@ -225,6 +253,10 @@ pub struct ModRangeArcAppearance {
     /// `start`
     pub filled_inverse_color: Color,
     /// The cap at the ends of the arc
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::core::serde_support::LineCapDef")
+    )]
     pub cap: LineCap,
 }
 
@@ -232,6 +264,7 @@ pub struct ModRangeArcAppearance {
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TickMarksAppearance {
     /// The style of the tick marks
     pub style: tick_marks::Appearance,
@@ -243,6 +276,7 @@ pub struct TickMarksAppearance {
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextMarksAppearance {
     /// The style of the text marks
     pub style: text_marks::Appearance,
@@ -362,67 +396,218 @@ pub trait StyleSheet {
     }
 }
 
-/// The style of a Knob.
+/// The theme catalog of a [`Knob`].
+///
+/// Every [`StyleSheet`] is a [`Catalog`] whose classes are its styles, so
+/// existing themes keep working.
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+/// [`Catalog`]: trait.Catalog.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Produces the [`Appearance`] of a [`Knob`] with the given [`Status`].
+    ///
+    /// [`Appearance`]: enum.Appearance.html
+    /// [`Status`]: ../enum.Status.html
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+
+    /// a [`KnobAngleRange`] that defines the minimum and maximum angle that the
+    /// knob rotates
+    ///
+    /// [`KnobAngleRange`]: struct.KnobAngleRange.html
+    fn angle_range(&self, _class: &Self::Class<'_>) -> KnobAngleRange {
+        KnobAngleRange::default()
+    }
+
+    /// The style of tick marks around a [`Knob`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`TickMarkGroup`]: ../../core/tick_marks/struct.TickMarkGroup.html
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn tick_marks_appearance(&self, _class: &Self::Class<'_>) -> Option<TickMarksAppearance> {
+        None
+    }
+
+    /// The style of a value arc around a [`Knob`]
+    ///
+    /// For no value arc, don't override this or set this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn value_arc_appearance(&self, _class: &Self::Class<'_>) -> Option<ValueArcAppearance> {
+        None
+    }
+
+    /// The style of a [`ModulationRange`] arc around a [`Knob`]
+    ///
+    /// For no modulation range arc, don't override this or set this to return `None`.
+    ///
+    /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn mod_range_arc_appearance(&self, _class: &Self::Class<'_>) -> Option<ModRangeArcAppearance> {
+        None
+    }
+
+    /// The style of a second [`ModulationRange`] arc around a [`Knob`]
+    ///
+    /// For no second modulation range arc, don't override this or set this to return `None`.
+    ///
+    /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn mod_range_arc_appearance_2(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<ModRangeArcAppearance> {
+        None
+    }
+
+    /// The style of text marks around a [`Knob`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`TextMarkGroup`]: ../../core/text_marks/struct.TextMarkGroup.html
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn text_marks_appearance(&self, _class: &Self::Class<'_>) -> Option<TextMarksAppearance> {
+        None
+    }
+}
+
+impl<T> Catalog for T
+where
+    T: StyleSheet,
+    T::Style: Default,
+{
+    type Class<'a> = T::Style;
+
+    fn default<'a>() -> Self::Class<'a> {
+        T::Style::default()
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        status_appearance(self, class, status)
+    }
+
+    fn angle_range(&self, class: &Self::Class<'_>) -> KnobAngleRange {
+        StyleSheet::angle_range(self, class)
+    }
+
+    fn tick_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TickMarksAppearance> {
+        StyleSheet::tick_marks_appearance(self, class)
+    }
+
+    fn value_arc_appearance(&self, class: &Self::Class<'_>) -> Option<ValueArcAppearance> {
+        StyleSheet::value_arc_appearance(self, class)
+    }
+
+    fn mod_range_arc_appearance(&self, class: &Self::Class<'_>) -> Option<ModRangeArcAppearance> {
+        StyleSheet::mod_range_arc_appearance(self, class)
+    }
+
+    fn mod_range_arc_appearance_2(&self, class: &Self::Class<'_>) -> Option<ModRangeArcAppearance> {
+        StyleSheet::mod_range_arc_appearance_2(self, class)
+    }
+
+    fn text_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TextMarksAppearance> {
+        StyleSheet::text_marks_appearance(self, class)
+    }
+}
+
+/// Picks the [`StyleSheet`] method for `status`.
+///
+/// [`StyleSheet`]: trait.StyleSheet.html
+fn status_appearance<S>(sheet: &S, style: &S::Style, status: Status) -> Appearance
+where
+    S: StyleSheet + ?Sized,
+{
+    match status {
+        Status::Idle => sheet.idle(style),
+        Status::Hovered => sheet.hovered(style),
+        Status::Gesturing => sheet.gesturing(style),
+        Status::Disabled => sheet.disabled(style),
+    }
+}
+
+/// A styling function for a [`Knob`].
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The class of a [`Knob`] in the built-in theme.
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Default)]
-pub enum Knob {
+pub enum Knob<'a> {
     /// The default style.
     #[default]
     Default,
     /// A custom style.
-    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme> + 'a>),
+    /// A closure that produces the [`Appearance`] of each [`Status`].
+    /// Everything else comes from the default style.
+    ///
+    /// [`Appearance`]: enum.Appearance.html
+    /// [`Status`]: ../enum.Status.html
+    Styled(StyleFn<'a, iced_core::Theme>),
 }
 
-impl<S> From<S> for Knob
+impl<'a, S> From<S> for Knob<'a>
 where
-    S: 'static + StyleSheet<Style = iced_core::Theme>,
+    S: 'a + StyleSheet<Style = iced_core::Theme>,
 {
     fn from(val: S) -> Self {
         Knob::Custom(Box::new(val))
     }
 }
 
-impl StyleSheet for iced_core::Theme {
-    type Style = Knob;
-
-    fn idle(&self, style: &Self::Style) -> Appearance {
-        match style {
-            Knob::Default => Appearance::Circle(Default::default()),
-            Knob::Custom(custom) => custom.idle(self),
-        }
+impl<'a> From<StyleFn<'a, iced_core::Theme>> for Knob<'a> {
+    fn from(style: StyleFn<'a, iced_core::Theme>) -> Self {
+        Knob::Styled(style)
     }
+}
 
-    fn hovered(&self, style: &Self::Style) -> Appearance {
-        match style {
-            Knob::Default => Appearance::Circle(CircleAppearance {
-                color: default_colors::KNOB_BACK_HOVER,
-                ..Default::default()
-            }),
-            Knob::Custom(custom) => custom.hovered(self),
-        }
-    }
+impl Catalog for iced_core::Theme {
+    type Class<'a> = Knob<'a>;
 
-    fn gesturing(&self, style: &Self::Style) -> Appearance {
-        match style {
-            Knob::Default => self.hovered(style),
-            Knob::Custom(custom) => custom.gesturing(self),
-        }
+    fn default<'a>() -> Self::Class<'a> {
+        Knob::Default
     }
 
-    fn disabled(&self, style: &Self::Style) -> Appearance {
-        // TODO
-        self.idle(style)
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            Knob::Default => match status {
+                Status::Idle | Status::Disabled => Appearance::Circle(Default::default()),
+                Status::Hovered | Status::Gesturing => Appearance::Circle(CircleAppearance {
+                    color: default_colors::KNOB_BACK_HOVER,
+                    ..Default::default()
+                }),
+            },
+            Knob::Custom(custom) => status_appearance(custom.as_ref(), self, status),
+            Knob::Styled(style) => style(self, status),
+        }
     }
 
-    fn angle_range(&self, style: &Self::Style) -> KnobAngleRange {
-        match style {
-            Knob::Default => KnobAngleRange::default(),
-            Knob::Custom(custom) => custom.angle_range(self),
+    fn angle_range(&self, class: &Self::Class<'_>) -> KnobAngleRange {
+        match class {
+            Knob::Custom(custom) => StyleSheet::angle_range(custom.as_ref(), self),
+            _ => KnobAngleRange::default(),
         }
     }
 
-    fn tick_marks_appearance(&self, style: &Self::Style) -> Option<TickMarksAppearance> {
-        match style {
-            Knob::Default => Some(TickMarksAppearance {
+    fn tick_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TickMarksAppearance> {
+        match class {
+            Knob::Custom(custom) => StyleSheet::tick_marks_appearance(custom.as_ref(), self),
+            _ => Some(TickMarksAppearance {
                 style: tick_marks::Appearance {
                     tier_1: tick_marks::Shape::Circle {
                         diameter: 4.0,
@@ -439,40 +624,39 @@ impl StyleSheet for iced_core::Theme {
                 },
                 offset: 3.5,
             }),
-            Knob::Custom(custom) => custom.tick_marks_appearance(self),
         }
     }
 
-    fn value_arc_appearance(&self, style: &Self::Style) -> Option<ValueArcAppearance> {
-        match style {
-            Knob::Default => None,
-            Knob::Custom(custom) => custom.value_arc_appearance(self),
+    fn value_arc_appearance(&self, class: &Self::Class<'_>) -> Option<ValueArcAppearance> {
+        match class {
+            Knob::Custom(custom) => StyleSheet::value_arc_appearance(custom.as_ref(), self),
+            _ => None,
         }
     }
 
-    fn mod_range_arc_appearance(&self, style: &Self::Style) -> Option<ModRangeArcAppearance> {
-        match style {
-            Knob::Default => None,
-            Knob::Custom(custom) => custom.mod_range_arc_appearance(self),
+    fn mod_range_arc_appearance(&self, class: &Self::Class<'_>) -> Option<ModRangeArcAppearance> {
+        match class {
+            Knob::Custom(custom) => StyleSheet::mod_range_arc_appearance(custom.as_ref(), self),
+            _ => None,
         }
     }
 
-    fn mod_range_arc_appearance_2(&self, style: &Self::Style) -> Option<ModRangeArcAppearance> {
-        match style {
-            Knob::Default => None,
-            Knob::Custom(custom) => custom.mod_range_arc_appearance_2(self),
+    fn mod_range_arc_appearance_2(&self, class: &Self::Class<'_>) -> Option<ModRangeArcAppearance> {
+        match class {
+            Knob::Custom(custom) => StyleSheet::mod_range_arc_appearance_2(custom.as_ref(), self),
+            _ => None,
         }
     }
 
-    fn text_marks_appearance(&self, style: &Self::Style) -> Option<TextMarksAppearance> {
-        match style {
-            Knob::Default => Some(TextMarksAppearance {
+    fn text_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TextMarksAppearance> {
+        match class {
+            Knob::Custom(custom) => StyleSheet::text_marks_appearance(custom.as_ref(), self),
+            _ => Some(TextMarksAppearance {
                 style: Default::default(),
                 offset: 14.0,
                 h_char_offset: 3.0,
                 v_offset: -0.75,
             }),
-            Knob::Custom(custom) => custom.text_marks_appearance(self),
         }
     }
 }
@@ -0,0 +1,200 @@
+//! Various styles for the [`LoudnessMeter`] widget
+//!
+//! [`LoudnessMeter`]: ../native/loudness_meter/struct.LoudnessMeter.html
+
+use crate::{
+    Offset,
+    style::{default_colors, text_marks, tick_marks},
+};
+use iced_core::Color;
+
+/// The appearance of a [`LoudnessMeter`].
+///
+/// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// color of the background rectangle behind each bar
+    pub back_color: Color,
+    /// width of the background rectangle border
+    pub back_border_width: f32,
+    /// radius of the background rectangle
+    pub back_border_radius: f32,
+    /// color of the background rectangle border
+    pub back_border_color: Color,
+    /// the gap between the momentary, short-term, and integrated bars
+    pub bar_gap: f32,
+    /// color of the momentary loudness bar
+    pub momentary_color: Color,
+    /// color of the short-term loudness bar
+    pub short_term_color: Color,
+    /// color of the integrated loudness bar
+    pub integrated_color: Color,
+    /// color of the parts of the bars above the target. Set this to `None`
+    /// to use the color of each bar.
+    pub above_target_color: Option<Color>,
+    /// color of the loudness range drawn over the integrated bar. Set this
+    /// to `None` to not show the loudness range.
+    pub range_color: Option<Color>,
+    /// color of the line at the target loudness
+    pub target_line_color: Color,
+    /// width of the line at the target loudness
+    pub target_line_width: f32,
+    /// height of the true peak indicator above the bars
+    pub peak_height: f32,
+    /// color of the true peak indicator while the true peak is below the
+    /// limit
+    pub peak_color: Color,
+    /// color of the true peak indicator once the true peak has gone over
+    /// the limit
+    pub peak_over_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: default_colors::BORDER,
+            bar_gap: 2.0,
+            momentary_color: default_colors::LOUDNESS_MOMENTARY,
+            short_term_color: default_colors::LOUDNESS_SHORT_TERM,
+            integrated_color: default_colors::LOUDNESS_INTEGRATED,
+            above_target_color: Some(default_colors::LOUDNESS_ABOVE_TARGET),
+            range_color: Some(default_colors::LOUDNESS_RANGE),
+            target_line_color: default_colors::LOUDNESS_TARGET,
+            target_line_width: 2.0,
+            peak_height: 6.0,
+            peak_color: default_colors::LIGHT_BACK,
+            peak_over_color: default_colors::LOUDNESS_PEAK_OVER,
+        }
+    }
+}
+
+/// Style of tick marks for a [`LoudnessMeter`].
+///
+/// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickMarksAppearance {
+    /// The style of the tick marks
+    pub style: tick_marks::Appearance,
+    /// The placement of the tick marks
+    pub placement: tick_marks::Placement,
+}
+
+/// Style of text marks for a [`LoudnessMeter`].
+///
+/// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextMarksAppearance {
+    /// The style of the text marks
+    pub style: text_marks::Appearance,
+    /// The placement of the text marks
+    pub placement: text_marks::Placement,
+}
+
+/// A set of rules that dictate the style of a [`LoudnessMeter`].
+///
+/// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of a [`LoudnessMeter`].
+    ///
+    /// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// The style of tick marks for a [`LoudnessMeter`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+    fn tick_marks_appearance(&self, _style: &Self::Style) -> Option<TickMarksAppearance> {
+        None
+    }
+
+    /// The style of text marks for a [`LoudnessMeter`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+    fn text_marks_appearance(&self, _style: &Self::Style) -> Option<TextMarksAppearance> {
+        None
+    }
+}
+
+/// The style of a LoudnessMeter.
+#[derive(Default)]
+pub enum LoudnessMeter {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for LoudnessMeter
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        LoudnessMeter::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = LoudnessMeter;
+
+    fn appearance(&self, style: &Self::Style) -> Appearance {
+        match style {
+            LoudnessMeter::Default => Default::default(),
+            LoudnessMeter::Custom(custom) => custom.appearance(self),
+        }
+    }
+
+    fn tick_marks_appearance(&self, style: &Self::Style) -> Option<TickMarksAppearance> {
+        match style {
+            LoudnessMeter::Default => Some(TickMarksAppearance {
+                style: tick_marks::Appearance {
+                    tier_1: tick_marks::Shape::Line {
+                        length: 4.0,
+                        width: 2.0,
+                        color: default_colors::TICK_TIER_1,
+                    },
+                    tier_2: tick_marks::Shape::Line {
+                        length: 3.0,
+                        width: 1.0,
+                        color: default_colors::TICK_TIER_2,
+                    },
+                    tier_3: tick_marks::Shape::Line {
+                        length: 2.0,
+                        width: 1.0,
+                        color: default_colors::TICK_TIER_3,
+                    },
+                },
+                placement: tick_marks::Placement::LeftOrTop {
+                    offset: Offset::new(-2.0, 0.0),
+                    inside: false,
+                },
+            }),
+            LoudnessMeter::Custom(custom) => custom.tick_marks_appearance(self),
+        }
+    }
+
+    fn text_marks_appearance(&self, style: &Self::Style) -> Option<TextMarksAppearance> {
+        match style {
+            LoudnessMeter::Default => Some(TextMarksAppearance {
+                style: Default::default(),
+                placement: text_marks::Placement::LeftOrTop {
+                    inside: false,
+                    offset: Offset::new(-9.0, 0.0),
+                },
+            }),
+            LoudnessMeter::Custom(custom) => custom.text_marks_appearance(self),
+        }
+    }
+}
@@ -5,10 +5,13 @@
 use crate::style::default_colors;
 use iced_core::{Color, Theme};
 
+pub use crate::style::Status;
+
 /// The appearance of an [`ModRangeInput`]
 ///
 /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Appearance {
     /// A circle style
     Circle(CircleAppearance),
@@ -27,6 +30,7 @@ pub enum Appearance {
 /// [`Appearance`]: enum.Appearance.html
 /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CircleAppearance {
     /// Color of the circle
     pub color: Color,
@@ -51,6 +55,7 @@ impl Default for CircleAppearance {
 /// [`Appearance`]: enum.Appearance.html
 /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SquareAppearance {
     /// Color of the square
     pub color: Color,
@@ -123,62 +128,123 @@ impl StyleSheet for InvisibleStyle {
     }
 }
 
-/// The style of a [`ModRangeInput`].
+/// The theme catalog of a [`ModRangeInput`].
+///
+/// Every [`StyleSheet`] is a [`Catalog`] whose classes are its styles, so
+/// existing themes keep working.
+///
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+/// [`Catalog`]: trait.Catalog.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Produces the [`Appearance`] of a [`ModRangeInput`] with the given [`Status`].
+    ///
+    /// [`Appearance`]: enum.Appearance.html
+    /// [`Status`]: ../enum.Status.html
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+}
+
+impl<T> Catalog for T
+where
+    T: StyleSheet,
+    T::Style: Default,
+{
+    type Class<'a> = T::Style;
+
+    fn default<'a>() -> Self::Class<'a> {
+        T::Style::default()
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        status_appearance(self, class, status)
+    }
+}
+
+/// Picks the [`StyleSheet`] method for `status`.
+///
+/// [`StyleSheet`]: trait.StyleSheet.html
+fn status_appearance<S>(sheet: &S, style: &S::Style, status: Status) -> Appearance
+where
+    S: StyleSheet + ?Sized,
+{
+    match status {
+        Status::Idle => sheet.idle(style),
+        Status::Hovered => sheet.hovered(style),
+        Status::Gesturing => sheet.gesturing(style),
+        Status::Disabled => sheet.disabled(style),
+    }
+}
+
+/// A styling function for a [`ModRangeInput`].
+///
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The class of a [`ModRangeInput`] in the built-in theme.
+///
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
 #[derive(Default)]
-pub enum ModRangeInput {
+pub enum ModRangeInput<'a> {
     /// The default style.
     #[default]
     Default,
     /// The invisible style.
     Invisible,
     /// A custom style.
-    Custom(Box<dyn StyleSheet<Style = Theme>>),
+    Custom(Box<dyn StyleSheet<Style = Theme> + 'a>),
+    /// A closure that produces the [`Appearance`] of each [`Status`].
+    /// Everything else comes from the default style.
+    ///
+    /// [`Appearance`]: enum.Appearance.html
+    /// [`Status`]: ../enum.Status.html
+    Styled(StyleFn<'a, Theme>),
 }
 
-impl<S> From<S> for ModRangeInput
+impl<'a, S> From<S> for ModRangeInput<'a>
 where
-    S: 'static + StyleSheet<Style = Theme>,
+    S: 'a + StyleSheet<Style = Theme>,
 {
     fn from(val: S) -> Self {
         ModRangeInput::Custom(Box::new(val))
     }
 }
 
-impl StyleSheet for Theme {
-    type Style = ModRangeInput;
-
-    fn idle(&self, style: &Self::Style) -> Appearance {
-        match style {
-            ModRangeInput::Default => Appearance::Circle(Default::default()),
-            ModRangeInput::Invisible => Appearance::Invisible,
-            ModRangeInput::Custom(custom) => custom.idle(self),
-        }
+impl<'a> From<StyleFn<'a, Theme>> for ModRangeInput<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        ModRangeInput::Styled(style)
     }
+}
 
-    fn hovered(&self, style: &Self::Style) -> Appearance {
-        match style {
-            ModRangeInput::Default => Appearance::Circle(CircleAppearance {
-                color: default_colors::KNOB_BACK_HOVER,
-                ..Default::default()
-            }),
-            ModRangeInput::Invisible => self.idle(style),
-            ModRangeInput::Custom(custom) => custom.idle(self),
-        }
-    }
+impl Catalog for Theme {
+    type Class<'a> = ModRangeInput<'a>;
 
-    fn gesturing(&self, style: &Self::Style) -> Appearance {
-        match style {
-            ModRangeInput::Default => self.hovered(style),
-            ModRangeInput::Invisible => self.idle(style),
-            ModRangeInput::Custom(custom) => custom.idle(self),
-        }
+    fn default<'a>() -> Self::Class<'a> {
+        ModRangeInput::Default
     }
 
-    fn disabled(&self, style: &Self::Style) -> Appearance {
-        match style {
-            ModRangeInput::Default => Appearance::Circle(Default::default()),
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            ModRangeInput::Default => match status {
+                Status::Idle | Status::Disabled => Appearance::Circle(Default::default()),
+                Status::Hovered | Status::Gesturing => Appearance::Circle(CircleAppearance {
+                    color: default_colors::KNOB_BACK_HOVER,
+                    ..Default::default()
+                }),
+            },
             ModRangeInput::Invisible => Appearance::Invisible,
-            ModRangeInput::Custom(custom) => custom.disabled(self),
+            ModRangeInput::Custom(custom) => status_appearance(custom.as_ref(), self, status),
+            ModRangeInput::Styled(style) => style(self, status),
         }
     }
 }
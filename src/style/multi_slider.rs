@@ -0,0 +1,176 @@
+//! Various styles for the [`MultiSlider`] widget
+//!
+//! [`MultiSlider`]: ../native/multi_slider/struct.MultiSlider.html
+
+use crate::style::default_colors;
+use iced_core::Color;
+
+/// The appearance of a [`MultiSlider`].
+///
+/// The colors follow the [`RectAppearance`] and [`RectBipolarAppearance`]
+/// styles of the sliders. When the [`MultiSlider`] is bipolar, bars below the
+/// center use the `lower_*` colors and bars above the center use the
+/// `upper_*` colors.
+///
+/// [`MultiSlider`]: ../../native/multi_slider/struct.MultiSlider.html
+/// [`RectAppearance`]: ../h_slider/struct.RectAppearance.html
+/// [`RectBipolarAppearance`]: ../h_slider/struct.RectBipolarAppearance.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// color of the background rectangle
+    pub back_color: Color,
+    /// width of the background rectangle border
+    pub back_border_width: f32,
+    /// radius of the background rectangle
+    pub back_border_radius: f32,
+    /// color of the background rectangle border
+    pub back_border_color: Color,
+    /// the gap between neighbouring bars
+    pub bar_spacing: f32,
+    /// color of the filled portion of a bar
+    pub filled_color: Color,
+    /// color of the filled portion of a bar below the center
+    pub lower_filled_color: Color,
+    /// color of the filled portion of a bar above the center
+    pub upper_filled_color: Color,
+    /// color of the handle at the top of a bar
+    pub handle_color: Color,
+    /// color of the handle of a bar below the center
+    pub handle_lower_color: Color,
+    /// color of the handle of a bar above the center
+    pub handle_upper_color: Color,
+    /// color of the handle of a bar that is in the center
+    pub handle_center_color: Color,
+    /// height of the handle at the top of each bar
+    pub handle_height: u16,
+    /// color of the line drawn through the center of a bipolar
+    /// [`MultiSlider`]
+    ///
+    /// [`MultiSlider`]: ../../native/multi_slider/struct.MultiSlider.html
+    pub center_line_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: default_colors::BORDER,
+            bar_spacing: 1.0,
+            filled_color: default_colors::MULTI_SLIDER_FILLED,
+            lower_filled_color: default_colors::MULTI_SLIDER_FILLED,
+            upper_filled_color: default_colors::MULTI_SLIDER_FILLED,
+            handle_color: default_colors::BORDER,
+            handle_lower_color: default_colors::BORDER,
+            handle_upper_color: default_colors::BORDER,
+            handle_center_color: default_colors::BORDER,
+            handle_height: 2,
+            center_line_color: default_colors::XY_PAD_CENTER_LINE,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a [`MultiSlider`].
+///
+/// [`MultiSlider`]: ../../native/multi_slider/struct.MultiSlider.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of an enabled, idle [`MultiSlider`].
+    ///
+    /// [`MultiSlider`]: ../../native/multi_slider/struct.MultiSlider.html
+    fn idle(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a hovered [`MultiSlider`].
+    ///
+    /// [`MultiSlider`]: ../../native/multi_slider/struct.MultiSlider.html
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// Produces the style of a [`MultiSlider`] that is being gestured
+    /// (painted).
+    ///
+    /// [`MultiSlider`]: ../../native/multi_slider/struct.MultiSlider.html
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the style of a [`MultiSlider`] that is currently disabled.
+    ///
+    /// [`MultiSlider`]: ../../native/multi_slider/struct.MultiSlider.html
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+}
+
+/// The style of a MultiSlider.
+#[derive(Default)]
+pub enum MultiSlider {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for MultiSlider
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        MultiSlider::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = MultiSlider;
+
+    fn idle(&self, style: &Self::Style) -> Appearance {
+        match style {
+            MultiSlider::Default => Default::default(),
+            MultiSlider::Custom(custom) => custom.idle(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        match style {
+            MultiSlider::Default => Appearance {
+                back_color: default_colors::LIGHT_BACK_HOVER,
+                ..Default::default()
+            },
+            MultiSlider::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        match style {
+            MultiSlider::Default => self.hovered(style),
+            MultiSlider::Custom(custom) => custom.gesturing(self),
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        match style {
+            MultiSlider::Default => {
+                let mut appearance = self.idle(style);
+                for color in [
+                    &mut appearance.filled_color,
+                    &mut appearance.lower_filled_color,
+                    &mut appearance.upper_filled_color,
+                    &mut appearance.handle_color,
+                    &mut appearance.handle_lower_color,
+                    &mut appearance.handle_upper_color,
+                    &mut appearance.handle_center_color,
+                ] {
+                    *color = default_colors::disabled(*color);
+                }
+                appearance
+            }
+            MultiSlider::Custom(custom) => custom.disabled(self),
+        }
+    }
+}
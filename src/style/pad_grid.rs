@@ -0,0 +1,174 @@
+//! Various styles for the [`PadGrid`] widget
+//!
+//! [`PadGrid`]: ../native/pad_grid/struct.PadGrid.html
+
+use crate::style::default_colors;
+use iced_core::Color;
+
+/// The appearance of a [`PadGrid`].
+///
+/// [`PadGrid`]: ../../native/pad_grid/struct.PadGrid.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// The color of the background rectangle behind the pads
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The radius of the background rectangle
+    pub back_border_radius: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The appearance of a pad that is not being pressed
+    pub pad: PadAppearance,
+    /// The appearance of the pad under the cursor
+    pub hovered_pad: PadAppearance,
+    /// The appearance of a pad that is currently being pressed
+    pub pressed_pad: PadAppearance,
+    /// The color of the highlight drawn over a pad that was triggered
+    /// externally. The alpha of this color is scaled as the highlight
+    /// decays.
+    pub flash_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::PAD_GRID_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 3.0,
+            back_border_color: default_colors::BORDER,
+            pad: PadAppearance::default(),
+            hovered_pad: PadAppearance {
+                color: default_colors::LIGHT_BACK_HOVER,
+                ..PadAppearance::default()
+            },
+            pressed_pad: PadAppearance {
+                color: default_colors::PAD_PRESSED,
+                ..PadAppearance::default()
+            },
+            flash_color: default_colors::PAD_FLASH,
+        }
+    }
+}
+
+/// The appearance of a single pad in a [`PadGrid`].
+///
+/// [`PadGrid`]: ../../native/pad_grid/struct.PadGrid.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PadAppearance {
+    /// The color of the pad
+    pub color: Color,
+    /// The width of the border of the pad
+    pub border_width: f32,
+    /// The radius of the corners of the pad
+    pub border_radius: f32,
+    /// The color of the border of the pad
+    pub border_color: Color,
+}
+
+impl Default for PadAppearance {
+    fn default() -> Self {
+        PadAppearance {
+            color: default_colors::LIGHT_BACK,
+            border_width: 1.0,
+            border_radius: 3.0,
+            border_color: default_colors::BORDER,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a [`PadGrid`].
+///
+/// [`PadGrid`]: ../../native/pad_grid/struct.PadGrid.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of an enabled, idle [`PadGrid`].
+    ///
+    /// [`PadGrid`]: ../../native/pad_grid/struct.PadGrid.html
+    fn idle(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a hovered [`PadGrid`].
+    ///
+    /// [`PadGrid`]: ../../native/pad_grid/struct.PadGrid.html
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// Produces the style of a [`PadGrid`] that has at least one pad
+    /// being pressed.
+    ///
+    /// [`PadGrid`]: ../../native/pad_grid/struct.PadGrid.html
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the style of a [`PadGrid`] that is currently disabled.
+    ///
+    /// [`PadGrid`]: ../../native/pad_grid/struct.PadGrid.html
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+}
+
+/// The style of a PadGrid.
+#[derive(Default)]
+pub enum PadGrid {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for PadGrid
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        PadGrid::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = PadGrid;
+
+    fn idle(&self, style: &Self::Style) -> Appearance {
+        match style {
+            PadGrid::Default => Default::default(),
+            PadGrid::Custom(custom) => custom.idle(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        match style {
+            PadGrid::Default => Default::default(),
+            PadGrid::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        match style {
+            PadGrid::Default => Default::default(),
+            PadGrid::Custom(custom) => custom.gesturing(self),
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        match style {
+            PadGrid::Default => {
+                let mut appearance = self.idle(style);
+                appearance.pad.color = default_colors::disabled(appearance.pad.color);
+                appearance.pad.border_color = default_colors::disabled(appearance.pad.border_color);
+                appearance.hovered_pad = appearance.pad;
+                appearance.pressed_pad = appearance.pad;
+                appearance.flash_color = default_colors::disabled(appearance.flash_color);
+                appearance
+            }
+            PadGrid::Custom(custom) => custom.disabled(self),
+        }
+    }
+}
@@ -5,10 +5,13 @@
 use crate::style::default_colors;
 use iced_core::Color;
 
+pub use crate::style::Status;
+
 /// The appearance of a [`Ramp`],
 ///
 /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Appearance {
     /// The color of the background rectangle
     pub back_color: Color,
@@ -74,51 +77,120 @@ pub trait StyleSheet {
     }
 }
 
-/// The style of a Ramp.
+/// The theme catalog of a [`Ramp`].
+///
+/// Every [`StyleSheet`] is a [`Catalog`] whose classes are its styles, so
+/// existing themes keep working.
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+/// [`Catalog`]: trait.Catalog.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Produces the [`Appearance`] of a [`Ramp`] with the given [`Status`].
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`Status`]: ../enum.Status.html
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+}
+
+impl<T> Catalog for T
+where
+    T: StyleSheet,
+    T::Style: Default,
+{
+    type Class<'a> = T::Style;
+
+    fn default<'a>() -> Self::Class<'a> {
+        T::Style::default()
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        status_appearance(self, class, status)
+    }
+}
+
+/// Picks the [`StyleSheet`] method for `status`.
+///
+/// [`StyleSheet`]: trait.StyleSheet.html
+fn status_appearance<S>(sheet: &S, style: &S::Style, status: Status) -> Appearance
+where
+    S: StyleSheet + ?Sized,
+{
+    match status {
+        Status::Idle => sheet.idle(style),
+        Status::Hovered => sheet.hovered(style),
+        Status::Gesturing => sheet.gesturing(style),
+        Status::Disabled => sheet.disabled(style),
+    }
+}
+
+/// A styling function for a [`Ramp`].
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The class of a [`Ramp`] in the built-in theme.
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
 #[derive(Default)]
-pub enum Ramp {
+pub enum Ramp<'a> {
     /// The default style.
     #[default]
     Default,
     /// A custom style.
-    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme> + 'a>),
+    /// A closure that produces the [`Appearance`] of each [`Status`].
+    /// Everything else comes from the default style.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`Status`]: ../enum.Status.html
+    Styled(StyleFn<'a, iced_core::Theme>),
 }
 
-impl<S> From<S> for Ramp
+impl<'a, S> From<S> for Ramp<'a>
 where
-    S: 'static + StyleSheet<Style = iced_core::Theme>,
+    S: 'a + StyleSheet<Style = iced_core::Theme>,
 {
     fn from(val: S) -> Self {
         Ramp::Custom(Box::new(val))
     }
 }
 
-impl StyleSheet for iced_core::Theme {
-    type Style = Ramp;
-
-    fn idle(&self, style: &Self::Style) -> Appearance {
-        match style {
-            Ramp::Default => Default::default(),
-            Ramp::Custom(custom) => custom.idle(self),
-        }
+impl<'a> From<StyleFn<'a, iced_core::Theme>> for Ramp<'a> {
+    fn from(style: StyleFn<'a, iced_core::Theme>) -> Self {
+        Ramp::Styled(style)
     }
+}
 
-    fn hovered(&self, style: &Self::Style) -> Appearance {
-        match style {
-            Ramp::Default => Appearance {
-                back_color: default_colors::RAMP_BACK_HOVER,
-                ..Default::default()
-            },
-            Ramp::Custom(custom) => custom.idle(self),
-        }
-    }
+impl Catalog for iced_core::Theme {
+    type Class<'a> = Ramp<'a>;
 
-    fn gesturing(&self, style: &Self::Style) -> Appearance {
-        self.hovered(style)
+    fn default<'a>() -> Self::Class<'a> {
+        Ramp::Default
     }
 
-    fn disabled(&self, style: &Self::Style) -> Appearance {
-        // TODO
-        self.idle(style)
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            Ramp::Default => match status {
+                Status::Idle | Status::Disabled => Default::default(),
+                Status::Hovered | Status::Gesturing => Appearance {
+                    back_color: default_colors::RAMP_BACK_HOVER,
+                    ..Default::default()
+                },
+            },
+            Ramp::Custom(custom) => status_appearance(custom.as_ref(), self, status),
+            Ramp::Styled(style) => style(self, status),
+        }
     }
 }
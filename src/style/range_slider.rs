@@ -0,0 +1,321 @@
+//! Various styles for the [`RangeSlider`] widget
+//!
+//! [`RangeSlider`]: ../native/range_slider/struct.RangeSlider.html
+
+use crate::{
+    Offset,
+    style::{default_colors, text_marks, tick_marks},
+};
+use iced_core::Color;
+
+/// The appearance of a [`RangeSlider`].
+///
+/// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: f32,
+    /// The radius of the background rectangle
+    pub back_border_radius: f32,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The band filled between the two handles
+    pub fill: FillAppearance,
+    /// The appearance of both handles
+    pub handle: HandleAppearance,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: default_colors::BORDER,
+            fill: FillAppearance::default(),
+            handle: HandleAppearance::default(),
+        }
+    }
+}
+
+/// The placement of the filled band of a [`RangeSlider`] across the
+/// thickness of the widget.
+///
+/// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FillPlacement {
+    /// In the center of the widget
+    Center {
+        /// The thickness of the band.
+        thickness: f32,
+        /// The offset from the center of the widget.
+        offset: f32,
+    },
+    /// In the center of the widget while filling the thickness
+    /// of the widget.
+    CenterFilled {
+        /// The padding from the edges of the widget.
+        edge_padding: f32,
+    },
+}
+
+/// The appearance of the band filled between the two handles of a
+/// [`RangeSlider`].
+///
+/// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FillAppearance {
+    /// The placement of the band
+    pub placement: FillPlacement,
+    /// The color of the band
+    pub color: Color,
+    /// The width of the border of the band
+    pub border_width: f32,
+    /// The radius of the band
+    pub border_radius: f32,
+    /// The color of the border of the band
+    pub border_color: Color,
+}
+
+impl Default for FillAppearance {
+    fn default() -> Self {
+        FillAppearance {
+            placement: FillPlacement::CenterFilled { edge_padding: 1.0 },
+            color: default_colors::RANGE_SLIDER_FILL,
+            border_width: 0.0,
+            border_radius: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+}
+
+/// The appearance of a handle of a [`RangeSlider`].
+///
+/// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandleAppearance {
+    /// The color of the handle
+    pub color: Color,
+    /// The width of the border of the handle
+    pub border_width: f32,
+    /// The radius of the handle
+    pub border_radius: f32,
+    /// The color of the border of the handle
+    pub border_color: Color,
+    /// The width (thickness) of the middle notch
+    pub notch_width: f32,
+    /// The color of the middle notch
+    pub notch_color: Color,
+}
+
+impl Default for HandleAppearance {
+    fn default() -> Self {
+        HandleAppearance {
+            color: default_colors::LIGHT_BACK,
+            border_width: 1.0,
+            border_radius: 2.0,
+            border_color: default_colors::BORDER,
+            notch_width: 2.0,
+            notch_color: default_colors::BORDER,
+        }
+    }
+}
+
+/// Style of tick marks for a [`RangeSlider`].
+///
+/// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickMarksAppearance {
+    /// The style of the tick marks
+    pub style: tick_marks::Appearance,
+    /// The placement of the tick marks
+    pub placement: tick_marks::Placement,
+}
+
+/// Style of text marks for a [`RangeSlider`].
+///
+/// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextMarksAppearance {
+    /// The style of the text marks
+    pub style: text_marks::Appearance,
+    /// The placement of the text marks
+    pub placement: text_marks::Placement,
+}
+
+/// A set of rules that dictate the style of a [`RangeSlider`].
+///
+/// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of an enabled, idle [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+    fn idle(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a hovered [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// Produces the style of a [`RangeSlider`] that is being gestured
+    /// (dragged).
+    ///
+    /// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the style of a [`RangeSlider`] that is currently disabled.
+    ///
+    /// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// The style of tick marks for a [`RangeSlider`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+    fn tick_marks_appearance(&self, _style: &Self::Style) -> Option<TickMarksAppearance> {
+        None
+    }
+
+    /// The style of text marks for a [`RangeSlider`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`RangeSlider`]: ../../native/range_slider/struct.RangeSlider.html
+    fn text_marks_appearance(&self, _style: &Self::Style) -> Option<TextMarksAppearance> {
+        None
+    }
+}
+
+/// The style of a RangeSlider.
+#[derive(Default)]
+pub enum RangeSlider {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for RangeSlider
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        RangeSlider::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = RangeSlider;
+
+    fn idle(&self, style: &Self::Style) -> Appearance {
+        match style {
+            RangeSlider::Default => Default::default(),
+            RangeSlider::Custom(custom) => custom.idle(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        match style {
+            RangeSlider::Default => Appearance {
+                handle: HandleAppearance {
+                    color: default_colors::LIGHT_BACK_HOVER,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            RangeSlider::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        match style {
+            RangeSlider::Default => Appearance {
+                handle: HandleAppearance {
+                    color: default_colors::LIGHT_BACK_DRAG,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            RangeSlider::Custom(custom) => custom.gesturing(self),
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        match style {
+            RangeSlider::Default => {
+                let mut appearance = self.idle(style);
+                appearance.fill.color = default_colors::disabled(appearance.fill.color);
+                appearance.fill.border_color =
+                    default_colors::disabled(appearance.fill.border_color);
+                appearance.handle.color = default_colors::disabled(appearance.handle.color);
+                appearance.handle.border_color =
+                    default_colors::disabled(appearance.handle.border_color);
+                appearance.handle.notch_color =
+                    default_colors::disabled(appearance.handle.notch_color);
+                appearance
+            }
+            RangeSlider::Custom(custom) => custom.disabled(self),
+        }
+    }
+
+    fn tick_marks_appearance(&self, style: &Self::Style) -> Option<TickMarksAppearance> {
+        match style {
+            RangeSlider::Default => Some(TickMarksAppearance {
+                style: tick_marks::Appearance {
+                    tier_1: tick_marks::Shape::Line {
+                        length: 4.0,
+                        width: 2.0,
+                        color: default_colors::TICK_TIER_1,
+                    },
+                    tier_2: tick_marks::Shape::Line {
+                        length: 3.0,
+                        width: 1.0,
+                        color: default_colors::TICK_TIER_2,
+                    },
+                    tier_3: tick_marks::Shape::Line {
+                        length: 2.0,
+                        width: 1.0,
+                        color: default_colors::TICK_TIER_3,
+                    },
+                },
+                placement: tick_marks::Placement::BothSides {
+                    offset: Offset::ZERO,
+                    inside: true,
+                },
+            }),
+            RangeSlider::Custom(custom) => custom.tick_marks_appearance(self),
+        }
+    }
+
+    fn text_marks_appearance(&self, style: &Self::Style) -> Option<TextMarksAppearance> {
+        match style {
+            RangeSlider::Default => Some(TextMarksAppearance {
+                style: Default::default(),
+                placement: text_marks::Placement::RightOrBottom {
+                    inside: false,
+                    offset: Offset::ZERO,
+                },
+            }),
+            RangeSlider::Custom(custom) => custom.text_marks_appearance(self),
+        }
+    }
+}
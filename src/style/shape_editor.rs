@@ -0,0 +1,151 @@
+//! Various styles for the [`ShapeEditor`] widget
+//!
+//! [`ShapeEditor`]: ../native/shape_editor/struct.ShapeEditor.html
+
+use crate::style::default_colors;
+use iced_core::Color;
+
+/// The appearance of a [`ShapeEditor`].
+///
+/// [`ShapeEditor`]: ../../native/shape_editor/struct.ShapeEditor.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// color of the background rectangle
+    pub back_color: Color,
+    /// width of the background rectangle border
+    pub back_border_width: f32,
+    /// radius of the background rectangle
+    pub back_border_radius: f32,
+    /// color of the background rectangle border
+    pub back_border_color: Color,
+    /// color of the snapping grid lines
+    pub grid_color: Color,
+    /// color of the horizontal line through the center. Set this to `None`
+    /// for no center line.
+    pub center_line_color: Option<Color>,
+    /// color of the line drawn through the shape
+    pub line_color: Color,
+    /// width of the line drawn through the shape
+    pub line_width: f32,
+    /// color of the area between the shape and the center. Set this to
+    /// `None` for no fill.
+    pub fill_color: Option<Color>,
+    /// color of the phase indicator dot
+    pub phase_dot_color: Color,
+    /// diameter of the phase indicator dot
+    pub phase_dot_diameter: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: default_colors::BORDER,
+            grid_color: default_colors::SHAPE_EDITOR_GRID,
+            center_line_color: Some(default_colors::XY_PAD_CENTER_LINE),
+            line_color: default_colors::BORDER,
+            line_width: 2.0,
+            fill_color: Some(default_colors::SHAPE_EDITOR_FILL),
+            phase_dot_color: default_colors::SHAPE_EDITOR_PHASE,
+            phase_dot_diameter: 8.0,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a [`ShapeEditor`].
+///
+/// [`ShapeEditor`]: ../../native/shape_editor/struct.ShapeEditor.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of an enabled, idle [`ShapeEditor`].
+    ///
+    /// [`ShapeEditor`]: ../../native/shape_editor/struct.ShapeEditor.html
+    fn idle(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a hovered [`ShapeEditor`].
+    ///
+    /// [`ShapeEditor`]: ../../native/shape_editor/struct.ShapeEditor.html
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// Produces the style of a [`ShapeEditor`] that is being gestured
+    /// (drawn on).
+    ///
+    /// [`ShapeEditor`]: ../../native/shape_editor/struct.ShapeEditor.html
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the style of a [`ShapeEditor`] that is currently disabled.
+    ///
+    /// [`ShapeEditor`]: ../../native/shape_editor/struct.ShapeEditor.html
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+}
+
+/// The style of a ShapeEditor.
+#[derive(Default)]
+pub enum ShapeEditor {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for ShapeEditor
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        ShapeEditor::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = ShapeEditor;
+
+    fn idle(&self, style: &Self::Style) -> Appearance {
+        match style {
+            ShapeEditor::Default => Default::default(),
+            ShapeEditor::Custom(custom) => custom.idle(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        match style {
+            ShapeEditor::Default => Appearance {
+                back_color: default_colors::LIGHT_BACK_HOVER,
+                ..Default::default()
+            },
+            ShapeEditor::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        match style {
+            ShapeEditor::Default => self.hovered(style),
+            ShapeEditor::Custom(custom) => custom.gesturing(self),
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        match style {
+            ShapeEditor::Default => {
+                let mut appearance = self.idle(style);
+                appearance.line_color = default_colors::disabled(appearance.line_color);
+                appearance.fill_color = appearance.fill_color.map(default_colors::disabled);
+                appearance.phase_dot_color = default_colors::disabled(appearance.phase_dot_color);
+                appearance
+            }
+            ShapeEditor::Custom(custom) => custom.disabled(self),
+        }
+    }
+}
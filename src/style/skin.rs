@@ -0,0 +1,755 @@
+//! Widget styles loaded from TOML or JSON skin files
+//!
+//! A [`Skin`] describes the [`Appearance`] of each supported widget for
+//! every widget state, along with its tick marks, text marks and other
+//! extras. It can be loaded at runtime, so colors and sizes can be tweaked
+//! without recompiling the application.
+//!
+//! Every section of a skin is optional. Widgets without a section keep
+//! their default style, and a missing `hovered`, `gesturing` or `disabled`
+//! state falls back just like it does in the widget's `StyleSheet`.
+//!
+//! ```toml
+//! [knob.idle.Arc]
+//! width = { Scaled = 0.14 }
+//! empty_color = { r = 0.2, g = 0.2, b = 0.2, a = 1.0 }
+//! filled_color = { r = 0.3, g = 0.6, b = 1.0, a = 1.0 }
+//! notch = "None"
+//! cap = "Round"
+//!
+//! [knob.hovered.Arc]
+//! width = { Scaled = 0.14 }
+//! empty_color = { r = 0.25, g = 0.25, b = 0.25, a = 1.0 }
+//! filled_color = { r = 0.4, g = 0.7, b = 1.0, a = 1.0 }
+//! notch = "None"
+//! cap = "Round"
+//! ```
+//!
+//! Apply a skin by passing one of its styles to a widget, for example
+//! `Knob::new(..).style(skin.knob())`. Use a [`SkinWatcher`] to reload the
+//! skin whenever its file changes during development.
+//!
+//! [`Skin`]: struct.Skin.html
+//! [`Appearance`]: ../knob/enum.Appearance.html
+//! [`SkinWatcher`]: struct.SkinWatcher.html
+
+use crate::KnobAngleRange;
+use crate::style::{h_slider, knob, mod_range_input, ramp, v_slider, value_box, wheel, xy_pad};
+
+use serde::Deserialize;
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// A set of widget styles loaded from a TOML or JSON file.
+///
+/// See the [module documentation] for the file layout.
+///
+/// [module documentation]: index.html
+#[derive(Debug, Clone, Default, serde::Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Skin {
+    /// The style of [`Knob`] widgets
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub knob: Option<KnobSkin>,
+    /// The style of [`HSlider`] widgets
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    pub h_slider: Option<HSliderSkin>,
+    /// The style of [`VSlider`] widgets
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub v_slider: Option<VSliderSkin>,
+    /// The style of [`Ramp`] widgets
+    ///
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    pub ramp: Option<States<ramp::Appearance>>,
+    /// The style of [`XYPad`] widgets
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub xy_pad: Option<States<xy_pad::Appearance>>,
+    /// The style of [`Wheel`] widgets
+    ///
+    /// [`Wheel`]: ../../native/wheel/struct.Wheel.html
+    pub wheel: Option<States<wheel::Appearance>>,
+    /// The style of [`ModRangeInput`] widgets
+    ///
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    pub mod_range_input: Option<States<mod_range_input::Appearance>>,
+    /// The style of [`ValueBox`] widgets
+    ///
+    /// [`ValueBox`]: ../../native/value_box/struct.ValueBox.html
+    pub value_box: Option<ValueBoxSkin>,
+}
+
+impl Skin {
+    /// Parses a skin from the contents of a TOML file.
+    pub fn from_toml_str(source: &str) -> Result<Self, SkinError> {
+        toml::from_str(source).map_err(|e| {
+            let (line, column) = e
+                .span()
+                .map(|span| line_column(source, span.start))
+                .unwrap_or((0, 0));
+
+            SkinError::Parse {
+                path: None,
+                line,
+                column,
+                message: e.message().to_string(),
+            }
+        })
+    }
+
+    /// Parses a skin from the contents of a JSON file.
+    pub fn from_json_str(source: &str) -> Result<Self, SkinError> {
+        serde_json::from_str(source).map_err(|e| {
+            // `serde_json` appends the position to its message.
+            let message = e.to_string();
+            let message = match message.rsplit_once(" at line ") {
+                Some((message, _)) if e.line() > 0 => message.to_string(),
+                _ => message,
+            };
+
+            SkinError::Parse {
+                path: None,
+                line: e.line(),
+                column: e.column(),
+                message,
+            }
+        })
+    }
+
+    /// Parses a skin in the given [`SkinFormat`].
+    ///
+    /// [`SkinFormat`]: enum.SkinFormat.html
+    pub fn from_str(source: &str, format: SkinFormat) -> Result<Self, SkinError> {
+        match format {
+            SkinFormat::Toml => Self::from_toml_str(source),
+            SkinFormat::Json => Self::from_json_str(source),
+        }
+    }
+
+    /// Loads a skin from a file. The format is picked from the extension of
+    /// the file, which must be either `.toml` or `.json`.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, SkinError> {
+        let path = path.as_ref();
+
+        let format = SkinFormat::from_path(path)
+            .ok_or_else(|| SkinError::UnknownFormat(path.to_path_buf()))?;
+
+        let source = fs::read_to_string(path).map_err(|error| SkinError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+
+        Self::from_str(&source, format).map_err(|e| e.with_path(path))
+    }
+
+    /// Returns the [`Knob`] style of this skin.
+    ///
+    /// [`Knob`]: ../knob/enum.Knob.html
+    pub fn knob(&self) -> knob::Knob<'static> {
+        self.knob.clone().map(knob::Knob::from).unwrap_or_default()
+    }
+
+    /// Returns the [`HSlider`] style of this skin.
+    ///
+    /// [`HSlider`]: ../h_slider/enum.HSlider.html
+    pub fn h_slider(&self) -> h_slider::HSlider<'static> {
+        self.h_slider
+            .clone()
+            .map(h_slider::HSlider::from)
+            .unwrap_or_default()
+    }
+
+    /// Returns the [`VSlider`] style of this skin.
+    ///
+    /// [`VSlider`]: ../v_slider/enum.VSlider.html
+    pub fn v_slider(&self) -> v_slider::VSlider<'static> {
+        self.v_slider
+            .clone()
+            .map(v_slider::VSlider::from)
+            .unwrap_or_default()
+    }
+
+    /// Returns the [`Ramp`] style of this skin.
+    ///
+    /// [`Ramp`]: ../ramp/enum.Ramp.html
+    pub fn ramp(&self) -> ramp::Ramp<'static> {
+        self.ramp.clone().map(ramp::Ramp::from).unwrap_or_default()
+    }
+
+    /// Returns the [`XYPad`] style of this skin.
+    ///
+    /// [`XYPad`]: ../xy_pad/enum.XYPad.html
+    pub fn xy_pad(&self) -> xy_pad::XYPad<'static> {
+        self.xy_pad
+            .clone()
+            .map(xy_pad::XYPad::from)
+            .unwrap_or_default()
+    }
+
+    /// Returns the [`Wheel`] style of this skin.
+    ///
+    /// [`Wheel`]: ../wheel/enum.Wheel.html
+    pub fn wheel(&self) -> wheel::Wheel {
+        self.wheel
+            .clone()
+            .map(wheel::Wheel::from)
+            .unwrap_or_default()
+    }
+
+    /// Returns the [`ModRangeInput`] style of this skin.
+    ///
+    /// [`ModRangeInput`]: ../mod_range_input/enum.ModRangeInput.html
+    pub fn mod_range_input(&self) -> mod_range_input::ModRangeInput<'static> {
+        self.mod_range_input
+            .clone()
+            .map(mod_range_input::ModRangeInput::from)
+            .unwrap_or_default()
+    }
+
+    /// Returns the [`ValueBox`] style of this skin.
+    ///
+    /// [`ValueBox`]: ../value_box/enum.ValueBox.html
+    pub fn value_box(&self) -> value_box::ValueBox {
+        self.value_box
+            .clone()
+            .map(value_box::ValueBox::from)
+            .unwrap_or_default()
+    }
+}
+
+/// The appearance of a widget in each of its states.
+///
+/// Missing states fall back the same way as the widget's `StyleSheet`:
+/// `hovered` and `disabled` use `idle`, and `gesturing` uses `hovered`.
+#[derive(Debug, Clone, serde::Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct States<A> {
+    /// The appearance of an enabled, idle widget
+    pub idle: A,
+    /// The appearance of a hovered widget
+    pub hovered: Option<A>,
+    /// The appearance of a widget that is being gestured (dragged)
+    pub gesturing: Option<A>,
+    /// The appearance of a disabled widget
+    pub disabled: Option<A>,
+}
+
+impl<A: Clone> States<A> {
+    /// Returns the idle appearance.
+    pub fn idle(&self) -> A {
+        self.idle.clone()
+    }
+
+    /// Returns the hovered appearance.
+    pub fn hovered(&self) -> A {
+        self.hovered.as_ref().unwrap_or(&self.idle).clone()
+    }
+
+    /// Returns the gesturing appearance.
+    pub fn gesturing(&self) -> A {
+        self.gesturing
+            .as_ref()
+            .or(self.hovered.as_ref())
+            .unwrap_or(&self.idle)
+            .clone()
+    }
+
+    /// Returns the disabled appearance.
+    pub fn disabled(&self) -> A {
+        self.disabled.as_ref().unwrap_or(&self.idle).clone()
+    }
+}
+
+macro_rules! impl_states_style_sheet {
+    ($($module:ident),*) => {
+        $(
+            impl $module::StyleSheet for States<$module::Appearance> {
+                type Style = iced_core::Theme;
+
+                fn idle(&self, _style: &Self::Style) -> $module::Appearance {
+                    States::idle(self)
+                }
+
+                fn hovered(&self, _style: &Self::Style) -> $module::Appearance {
+                    States::hovered(self)
+                }
+
+                fn gesturing(&self, _style: &Self::Style) -> $module::Appearance {
+                    States::gesturing(self)
+                }
+
+                fn disabled(&self, _style: &Self::Style) -> $module::Appearance {
+                    States::disabled(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_states_style_sheet!(ramp, xy_pad, wheel, mod_range_input);
+
+/// The section of a [`Skin`] for [`Knob`] widgets.
+///
+/// [`Skin`]: struct.Skin.html
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Clone, serde::Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KnobSkin {
+    /// The appearance of an enabled, idle knob
+    pub idle: knob::Appearance,
+    /// The appearance of a hovered knob
+    pub hovered: Option<knob::Appearance>,
+    /// The appearance of a knob that is being gestured (dragged)
+    pub gesturing: Option<knob::Appearance>,
+    /// The appearance of a disabled knob
+    pub disabled: Option<knob::Appearance>,
+    /// The minimum and maximum angle that the knob rotates
+    pub angle_range: Option<KnobAngleRange>,
+    /// The style of the tick marks
+    pub tick_marks: Option<knob::TickMarksAppearance>,
+    /// The style of the text marks
+    pub text_marks: Option<knob::TextMarksAppearance>,
+    /// The style of the value arc
+    pub value_arc: Option<knob::ValueArcAppearance>,
+    /// The style of the modulation range arc
+    pub mod_range_arc: Option<knob::ModRangeArcAppearance>,
+    /// The style of the second modulation range arc
+    pub mod_range_arc_2: Option<knob::ModRangeArcAppearance>,
+}
+
+impl knob::StyleSheet for KnobSkin {
+    type Style = iced_core::Theme;
+
+    fn idle(&self, _style: &Self::Style) -> knob::Appearance {
+        self.idle.clone()
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> knob::Appearance {
+        self.hovered.as_ref().unwrap_or(&self.idle).clone()
+    }
+
+    fn gesturing(&self, style: &Self::Style) -> knob::Appearance {
+        match &self.gesturing {
+            Some(appearance) => appearance.clone(),
+            None => self.hovered(style),
+        }
+    }
+
+    fn disabled(&self, _style: &Self::Style) -> knob::Appearance {
+        self.disabled.as_ref().unwrap_or(&self.idle).clone()
+    }
+
+    fn angle_range(&self, _style: &Self::Style) -> KnobAngleRange {
+        self.angle_range.clone().unwrap_or_default()
+    }
+
+    fn tick_marks_appearance(&self, _style: &Self::Style) -> Option<knob::TickMarksAppearance> {
+        self.tick_marks.clone()
+    }
+
+    fn value_arc_appearance(&self, _style: &Self::Style) -> Option<knob::ValueArcAppearance> {
+        self.value_arc
+    }
+
+    fn mod_range_arc_appearance(
+        &self,
+        _style: &Self::Style,
+    ) -> Option<knob::ModRangeArcAppearance> {
+        self.mod_range_arc
+    }
+
+    fn mod_range_arc_appearance_2(
+        &self,
+        _style: &Self::Style,
+    ) -> Option<knob::ModRangeArcAppearance> {
+        self.mod_range_arc_2
+    }
+
+    fn text_marks_appearance(&self, _style: &Self::Style) -> Option<knob::TextMarksAppearance> {
+        self.text_marks.clone()
+    }
+}
+
+macro_rules! slider_skin {
+    ($(#[$attr:meta])* $name:ident, $module:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, serde::Serialize, Deserialize)]
+        #[serde(deny_unknown_fields)]
+        pub struct $name {
+            /// The appearance of an enabled, idle slider
+            pub idle: $module::Appearance,
+            /// The appearance of a hovered slider
+            pub hovered: Option<$module::Appearance>,
+            /// The appearance of a slider that is being gestured (dragged)
+            pub gesturing: Option<$module::Appearance>,
+            /// The appearance of a disabled slider
+            pub disabled: Option<$module::Appearance>,
+            /// The style of the tick marks
+            pub tick_marks: Option<$module::TickMarksAppearance>,
+            /// The style of the text marks
+            pub text_marks: Option<$module::TextMarksAppearance>,
+            /// The style of the modulation range
+            pub mod_range: Option<$module::ModRangeAppearance>,
+            /// The style of the second modulation range
+            pub mod_range_2: Option<$module::ModRangeAppearance>,
+        }
+
+        impl $module::StyleSheet for $name {
+            type Style = iced_core::Theme;
+
+            fn idle(&self, _style: &Self::Style) -> $module::Appearance {
+                self.idle.clone()
+            }
+
+            fn hovered(&self, _style: &Self::Style) -> $module::Appearance {
+                self.hovered.as_ref().unwrap_or(&self.idle).clone()
+            }
+
+            fn gesturing(&self, style: &Self::Style) -> $module::Appearance {
+                match &self.gesturing {
+                    Some(appearance) => appearance.clone(),
+                    None => self.hovered(style),
+                }
+            }
+
+            fn disabled(&self, _style: &Self::Style) -> $module::Appearance {
+                self.disabled.as_ref().unwrap_or(&self.idle).clone()
+            }
+
+            fn tick_marks_appearance(
+                &self,
+                _style: &Self::Style,
+            ) -> Option<$module::TickMarksAppearance> {
+                self.tick_marks.clone()
+            }
+
+            fn mod_range_appearance(
+                &self,
+                _style: &Self::Style,
+            ) -> Option<$module::ModRangeAppearance> {
+                self.mod_range.clone()
+            }
+
+            fn mod_range_appearance_2(
+                &self,
+                _style: &Self::Style,
+            ) -> Option<$module::ModRangeAppearance> {
+                self.mod_range_2.clone()
+            }
+
+            fn text_marks_appearance(
+                &self,
+                _style: &Self::Style,
+            ) -> Option<$module::TextMarksAppearance> {
+                self.text_marks.clone()
+            }
+        }
+    };
+}
+
+slider_skin!(
+    /// The section of a [`Skin`] for [`HSlider`] widgets.
+    ///
+    /// [`Skin`]: struct.Skin.html
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    HSliderSkin,
+    h_slider
+);
+
+slider_skin!(
+    /// The section of a [`Skin`] for [`VSlider`] widgets.
+    ///
+    /// [`Skin`]: struct.Skin.html
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    VSliderSkin,
+    v_slider
+);
+
+/// The section of a [`Skin`] for [`ValueBox`] widgets.
+///
+/// [`Skin`]: struct.Skin.html
+/// [`ValueBox`]: ../../native/value_box/struct.ValueBox.html
+#[derive(Debug, Clone, serde::Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ValueBoxSkin {
+    /// The appearance of an enabled, idle value box
+    pub idle: value_box::Appearance,
+    /// The appearance of a hovered value box
+    pub hovered: Option<value_box::Appearance>,
+    /// The appearance of a value box that is being gestured (dragged)
+    pub gesturing: Option<value_box::Appearance>,
+    /// The appearance of a value box that the user is typing a value into
+    pub editing: Option<value_box::Appearance>,
+    /// The appearance of a disabled value box
+    pub disabled: Option<value_box::Appearance>,
+}
+
+impl value_box::StyleSheet for ValueBoxSkin {
+    type Style = iced_core::Theme;
+
+    fn idle(&self, _style: &Self::Style) -> value_box::Appearance {
+        self.idle
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> value_box::Appearance {
+        self.hovered.unwrap_or(self.idle)
+    }
+
+    fn gesturing(&self, style: &Self::Style) -> value_box::Appearance {
+        self.gesturing.unwrap_or_else(|| self.hovered(style))
+    }
+
+    fn editing(&self, style: &Self::Style) -> value_box::Appearance {
+        self.editing.unwrap_or_else(|| self.hovered(style))
+    }
+
+    fn disabled(&self, _style: &Self::Style) -> value_box::Appearance {
+        self.disabled.unwrap_or(self.idle)
+    }
+}
+
+/// The file format of a [`Skin`].
+///
+/// [`Skin`]: struct.Skin.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SkinFormat {
+    /// TOML
+    Toml,
+    /// JSON
+    Json,
+}
+
+impl SkinFormat {
+    /// Returns the format matching the extension of `path`, if any.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?;
+
+        if extension.eq_ignore_ascii_case("toml") {
+            Some(SkinFormat::Toml)
+        } else if extension.eq_ignore_ascii_case("json") {
+            Some(SkinFormat::Json)
+        } else {
+            None
+        }
+    }
+}
+
+/// An error returned when a [`Skin`] could not be loaded.
+///
+/// [`Skin`]: struct.Skin.html
+#[derive(Debug)]
+pub enum SkinError {
+    /// The skin file could not be read.
+    Io {
+        /// The path of the skin file
+        path: PathBuf,
+        /// The underlying error
+        error: io::Error,
+    },
+    /// The extension of the skin file is neither `.toml` nor `.json`.
+    UnknownFormat(PathBuf),
+    /// The skin contains invalid syntax or an invalid entry.
+    Parse {
+        /// The path of the skin file, if it was loaded from a file
+        path: Option<PathBuf>,
+        /// The line of the invalid entry, starting at `1`. This is `0` if
+        /// the position is unknown.
+        line: usize,
+        /// The column of the invalid entry, starting at `1`. This is `0` if
+        /// the position is unknown.
+        column: usize,
+        /// A description of the problem
+        message: String,
+    },
+}
+
+impl SkinError {
+    fn with_path(self, path: &Path) -> Self {
+        match self {
+            SkinError::Parse {
+                line,
+                column,
+                message,
+                ..
+            } => SkinError::Parse {
+                path: Some(path.to_path_buf()),
+                line,
+                column,
+                message,
+            },
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for SkinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkinError::Io { path, error } => {
+                write!(f, "{}: {}", path.display(), error)
+            }
+            SkinError::UnknownFormat(path) => write!(
+                f,
+                "{}: unknown skin format, expected a `.toml` or `.json` file",
+                path.display()
+            ),
+            SkinError::Parse {
+                path,
+                line,
+                column,
+                message,
+            } => {
+                if let Some(path) = path {
+                    write!(f, "{}:", path.display())?;
+                }
+                if *line > 0 {
+                    write!(f, "{}:{}: ", line, column)?;
+                } else if path.is_some() {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SkinError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SkinError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the 1-based line and column of the byte `offset` in `source`.
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// Reloads a [`Skin`] whenever its file changes.
+///
+/// This is meant for development. Call [`poll`] periodically, for example
+/// from a timer or `window::frames()` subscription, and swap in the new
+/// skin when one is returned.
+///
+/// [`Skin`]: struct.Skin.html
+/// [`poll`]: #method.poll
+#[derive(Debug)]
+pub struct SkinWatcher {
+    path: PathBuf,
+    last: Option<Result<SystemTime, io::ErrorKind>>,
+}
+
+impl SkinWatcher {
+    /// Creates a new watcher for the skin file at `path`.
+    ///
+    /// The first call to [`poll`] always loads the skin.
+    ///
+    /// [`poll`]: #method.poll
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            last: None,
+        }
+    }
+
+    /// Returns the path of the watched skin file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Checks whether the skin file was modified since the last call.
+    ///
+    /// Returns `None` if nothing changed, or the result of loading the file
+    /// again otherwise. A failure is only reported once until the file
+    /// changes again.
+    pub fn poll(&mut self) -> Option<Result<Skin, SkinError>> {
+        let modified = fs::metadata(&self.path).and_then(|m| m.modified());
+        let stamp = modified.as_ref().map(|t| *t).map_err(|e| e.kind());
+
+        if self.last.as_ref() == Some(&stamp) {
+            return None;
+        }
+        self.last = Some(stamp);
+
+        Some(match modified {
+            Ok(_) => Skin::from_file(&self.path),
+            Err(error) => Err(SkinError::Io {
+                path: self.path.clone(),
+                error,
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toml_states_fall_back() {
+        let skin = Skin::from_toml_str(
+            r#"
+            [value_box.idle]
+            back_color = { r = 0.1, g = 0.1, b = 0.1, a = 1.0 }
+            back_border_width = 1.0
+            back_border_radius = 2.0
+            back_border_color = { r = 0.5, g = 0.5, b = 0.5, a = 1.0 }
+            text_color = { r = 1.0, g = 1.0, b = 1.0, a = 1.0 }
+            caret_color = { r = 1.0, g = 1.0, b = 1.0, a = 1.0 }
+            "#,
+        )
+        .unwrap();
+
+        assert!(skin.knob.is_none());
+
+        let value_box = skin.value_box.unwrap();
+        let theme = iced_core::Theme::Dark;
+        let editing = value_box::StyleSheet::editing(&value_box, &theme);
+        assert_eq!(editing.back_border_width, 1.0);
+        assert!(editing.digit_highlight_color.is_none());
+    }
+
+    #[test]
+    fn example_skin_loads() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/skins/dark.toml");
+        let skin = Skin::from_file(path).unwrap();
+
+        assert!(skin.knob.is_some());
+        assert!(skin.h_slider.is_some());
+        assert!(skin.v_slider.is_some());
+        assert!(skin.value_box.is_some());
+    }
+
+    #[test]
+    fn errors_have_positions() {
+        let toml = "[value_box.idle]\nback_border_width = \"wide\"\n";
+        match Skin::from_toml_str(toml) {
+            Err(SkinError::Parse { line, column, .. }) => {
+                assert_eq!((line, column), (2, 21));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let json = "{\n  \"wheel\": {\n    \"idle\": 1,\n  }\n}";
+        match Skin::from_json_str(json) {
+            Err(SkinError::Parse { line, .. }) => assert_eq!(line, 3),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let error = Skin::from_toml_str("[knobs]\n").unwrap_err();
+        assert!(error.to_string().starts_with("1:"));
+    }
+}
@@ -0,0 +1,81 @@
+//! Various styles for the [`Spectrogram`] widget
+//!
+//! [`Spectrogram`]: ../native/spectrogram/struct.Spectrogram.html
+
+use crate::style::default_colors;
+use iced_core::Color;
+
+/// The appearance of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// color of the background rectangle
+    pub back_color: Color,
+    /// width of the background rectangle border
+    pub back_border_width: f32,
+    /// radius of the background rectangle
+    pub back_border_radius: f32,
+    /// color of the background rectangle border
+    pub back_border_color: Color,
+    /// The colors that magnitudes are mapped to, evenly spaced from the
+    /// quietest magnitude to the loudest magnitude. Colors in between are
+    /// interpolated.
+    pub color_map: Vec<Color>,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::SPECTROGRAM_COLOR_MAP[0],
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: default_colors::BORDER,
+            color_map: default_colors::SPECTROGRAM_COLOR_MAP.to_vec(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of a [`Spectrogram`].
+    ///
+    /// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The style of a Spectrogram.
+#[derive(Default)]
+pub enum Spectrogram {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for Spectrogram
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        Spectrogram::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = Spectrogram;
+
+    fn appearance(&self, style: &Self::Style) -> Appearance {
+        match style {
+            Spectrogram::Default => Default::default(),
+            Spectrogram::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
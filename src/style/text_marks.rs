@@ -8,6 +8,7 @@ use crate::style::default_colors;
 
 /// The alignment of text in text marks.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Align {
     /// Align to the start of the text.
     Start,
@@ -19,6 +20,7 @@ pub enum Align {
 
 /// The placement of text marks relative to the widget
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Placement {
     /// Text marks on both sides of the widget.
     BothSides {
@@ -66,12 +68,14 @@ impl std::default::Default for Placement {
 ///
 /// [`TextMarkGroup`]: ../../core/text_marks/struct.TextMarkGroup.html
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Appearance {
     /// The color of the text.
     pub color: Color,
     /// The size of the text.
     pub text_size: u16,
     /// The font of the text.
+    #[cfg_attr(feature = "serde", serde(with = "crate::core::serde_support::FontDef"))]
     pub font: Font,
     /// The width of the text bounds.
     pub bounds_width: u16,
@@ -8,6 +8,7 @@ use crate::style::default_colors;
 
 /// The placement of tick marks relative to the widget
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Placement {
     /// Tick marks on both sides of the widget.
     BothSides {
@@ -67,6 +68,7 @@ impl std::default::Default for Placement {
 
 /// The appearance of a tick mark
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Appearance {
     /// The style of a tier 1 tick mark.
     pub tier_1: Shape,
@@ -78,6 +80,7 @@ pub struct Appearance {
 
 /// The shape of a tick mark
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Shape {
     /// No shape
     None,
@@ -0,0 +1,282 @@
+//! Various styles for the [`TransferCurve`] widget
+//!
+//! [`TransferCurve`]: ../native/transfer_curve/struct.TransferCurve.html
+
+use crate::{
+    Offset,
+    style::{default_colors, text_marks, tick_marks},
+};
+use iced_core::{Color, Font};
+
+/// The appearance of a [`TransferCurve`].
+///
+/// [`TransferCurve`]: ../../native/transfer_curve/struct.TransferCurve.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// color of the background rectangle
+    pub back_color: Color,
+    /// width of the background rectangle border
+    pub back_border_width: f32,
+    /// radius of the background rectangle
+    pub back_border_radius: f32,
+    /// color of the background rectangle border
+    pub back_border_color: Color,
+    /// color of the grid lines drawn at each tick mark. Set this to `None`
+    /// for no grid.
+    pub grid_color: Option<Color>,
+    /// color of the diagonal line where output equals input. Set this to
+    /// `None` for no line.
+    pub unity_line_color: Option<Color>,
+    /// color of the transfer curve
+    pub line_color: Color,
+    /// width of the transfer curve
+    pub line_width: f32,
+    /// appearance of a handle
+    pub handle: HandleAppearance,
+    /// appearance of a handle that is hovered or being dragged
+    pub active_handle: HandleAppearance,
+    /// color of the dot showing the current input level
+    pub input_color: Color,
+    /// diameter of the dot showing the current input level
+    pub input_diameter: f32,
+    /// color of the gain reduction readout
+    pub readout_color: Color,
+    /// size of the text of the gain reduction readout
+    pub readout_text_size: u16,
+    /// font of the gain reduction readout
+    #[cfg_attr(feature = "serde", serde(with = "crate::core::serde_support::FontDef"))]
+    pub readout_font: Font,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: default_colors::BORDER,
+            grid_color: Some(default_colors::SHAPE_EDITOR_GRID),
+            unity_line_color: Some(default_colors::XY_PAD_CENTER_LINE),
+            line_color: default_colors::BORDER,
+            line_width: 2.0,
+            handle: HandleAppearance::default(),
+            active_handle: HandleAppearance {
+                color: default_colors::TRANSFER_CURVE_HANDLE_ACTIVE,
+                ..HandleAppearance::default()
+            },
+            input_color: default_colors::TRANSFER_CURVE_INPUT,
+            input_diameter: 8.0,
+            readout_color: default_colors::TEXT_MARK,
+            readout_text_size: 12,
+            readout_font: Font::default(),
+        }
+    }
+}
+
+/// The appearance of a handle of a [`TransferCurve`].
+///
+/// [`TransferCurve`]: ../../native/transfer_curve/struct.TransferCurve.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandleAppearance {
+    /// the diameter of the handle
+    pub diameter: f32,
+    /// the color of the handle
+    pub color: Color,
+    /// the width of the border of the handle
+    pub border_width: f32,
+    /// the color of the border of the handle
+    pub border_color: Color,
+}
+
+impl Default for HandleAppearance {
+    fn default() -> Self {
+        HandleAppearance {
+            diameter: 9.0,
+            color: default_colors::LIGHT_BACK,
+            border_width: 1.0,
+            border_color: default_colors::BORDER,
+        }
+    }
+}
+
+/// Style of the tick marks on both axes of a [`TransferCurve`].
+///
+/// [`TransferCurve`]: ../../native/transfer_curve/struct.TransferCurve.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickMarksAppearance {
+    /// The style of the tick marks
+    pub style: tick_marks::Appearance,
+    /// The placement of the tick marks on the input (horizontal) axis
+    pub input_placement: tick_marks::Placement,
+    /// The placement of the tick marks on the output (vertical) axis
+    pub output_placement: tick_marks::Placement,
+}
+
+/// Style of the text marks on both axes of a [`TransferCurve`].
+///
+/// [`TransferCurve`]: ../../native/transfer_curve/struct.TransferCurve.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextMarksAppearance {
+    /// The style of the text marks
+    pub style: text_marks::Appearance,
+    /// The placement of the text marks on the input (horizontal) axis
+    pub input_placement: text_marks::Placement,
+    /// The placement of the text marks on the output (vertical) axis
+    pub output_placement: text_marks::Placement,
+}
+
+/// A set of rules that dictate the style of a [`TransferCurve`].
+///
+/// [`TransferCurve`]: ../../native/transfer_curve/struct.TransferCurve.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of an enabled, idle [`TransferCurve`].
+    ///
+    /// [`TransferCurve`]: ../../native/transfer_curve/struct.TransferCurve.html
+    fn idle(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a hovered [`TransferCurve`].
+    ///
+    /// [`TransferCurve`]: ../../native/transfer_curve/struct.TransferCurve.html
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// Produces the style of a [`TransferCurve`] that is being gestured
+    /// (a handle is being dragged).
+    ///
+    /// [`TransferCurve`]: ../../native/transfer_curve/struct.TransferCurve.html
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the style of a [`TransferCurve`] that is currently
+    /// disabled.
+    ///
+    /// [`TransferCurve`]: ../../native/transfer_curve/struct.TransferCurve.html
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// The style of the tick marks on both axes of a [`TransferCurve`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`TransferCurve`]: ../../native/transfer_curve/struct.TransferCurve.html
+    fn tick_marks_appearance(&self, _style: &Self::Style) -> Option<TickMarksAppearance> {
+        None
+    }
+
+    /// The style of the text marks on both axes of a [`TransferCurve`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`TransferCurve`]: ../../native/transfer_curve/struct.TransferCurve.html
+    fn text_marks_appearance(&self, _style: &Self::Style) -> Option<TextMarksAppearance> {
+        None
+    }
+}
+
+/// The style of a TransferCurve.
+#[derive(Default)]
+pub enum TransferCurve {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for TransferCurve
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        TransferCurve::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = TransferCurve;
+
+    fn idle(&self, style: &Self::Style) -> Appearance {
+        match style {
+            TransferCurve::Default => Default::default(),
+            TransferCurve::Custom(custom) => custom.idle(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        match style {
+            TransferCurve::Default => Appearance {
+                back_color: default_colors::LIGHT_BACK_HOVER,
+                ..Default::default()
+            },
+            TransferCurve::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        match style {
+            TransferCurve::Default => self.hovered(style),
+            TransferCurve::Custom(custom) => custom.gesturing(self),
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        match style {
+            TransferCurve::Default => {
+                let mut appearance = self.idle(style);
+                appearance.line_color = default_colors::disabled(appearance.line_color);
+                appearance.handle.color = default_colors::disabled(appearance.handle.color);
+                appearance.handle.border_color =
+                    default_colors::disabled(appearance.handle.border_color);
+                appearance.active_handle = appearance.handle;
+                appearance.input_color = default_colors::disabled(appearance.input_color);
+                appearance.readout_color = default_colors::disabled(appearance.readout_color);
+                appearance
+            }
+            TransferCurve::Custom(custom) => custom.disabled(self),
+        }
+    }
+
+    fn tick_marks_appearance(&self, style: &Self::Style) -> Option<TickMarksAppearance> {
+        match style {
+            TransferCurve::Default => Some(TickMarksAppearance {
+                style: Default::default(),
+                input_placement: tick_marks::Placement::RightOrBottom {
+                    offset: Offset::new(0.0, 2.0),
+                    inside: false,
+                },
+                output_placement: tick_marks::Placement::LeftOrTop {
+                    offset: Offset::new(-2.0, 0.0),
+                    inside: false,
+                },
+            }),
+            TransferCurve::Custom(custom) => custom.tick_marks_appearance(self),
+        }
+    }
+
+    fn text_marks_appearance(&self, style: &Self::Style) -> Option<TextMarksAppearance> {
+        match style {
+            TransferCurve::Default => Some(TextMarksAppearance {
+                style: Default::default(),
+                input_placement: text_marks::Placement::RightOrBottom {
+                    inside: false,
+                    offset: Offset::new(0.0, 10.0),
+                },
+                output_placement: text_marks::Placement::LeftOrTop {
+                    inside: false,
+                    offset: Offset::new(-9.0, 0.0),
+                },
+            }),
+            TransferCurve::Custom(custom) => custom.text_marks_appearance(self),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Various styles for the [`Tuner`] widget
+//!
+//! [`Tuner`]: ../native/tuner/struct.Tuner.html
+
+use crate::{
+    Offset,
+    style::{default_colors, text_marks, tick_marks},
+};
+use iced_core::{Color, Font};
+
+/// The appearance of a [`Tuner`].
+///
+/// [`Tuner`]: ../../native/tuner/struct.Tuner.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// color of the background rectangle
+    pub back_color: Color,
+    /// width of the background rectangle border
+    pub back_border_width: f32,
+    /// radius of the background rectangle
+    pub back_border_radius: f32,
+    /// color of the background rectangle border
+    pub back_border_color: Color,
+    /// color of the note name
+    pub note_color: Color,
+    /// color of the note name while the pitch is in tune
+    pub in_tune_note_color: Color,
+    /// size of the text of the note name
+    pub note_text_size: u16,
+    /// color of the cents readout
+    pub cents_color: Color,
+    /// size of the text of the cents readout
+    pub cents_text_size: u16,
+    /// font of the note name and the cents readout
+    #[cfg_attr(feature = "serde", serde(with = "crate::core::serde_support::FontDef"))]
+    pub font: Font,
+    /// color of the note name while no pitch is detected
+    pub inactive_color: Color,
+    /// color of the bar of the cents scale
+    pub scale_color: Color,
+    /// height of the bar of the cents scale
+    pub scale_height: f32,
+    /// color of the in-tune zone drawn over the bar of the cents scale
+    pub in_tune_zone_color: Color,
+    /// color of the needle
+    pub needle_color: Color,
+    /// color of the needle while the pitch is in tune
+    pub in_tune_needle_color: Color,
+    /// width of the needle
+    pub needle_width: f32,
+    /// how far the needle reaches past the top and bottom of the bar
+    pub needle_overhang: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 4.0,
+            back_border_color: default_colors::BORDER,
+            note_color: default_colors::TUNER_NOTE,
+            in_tune_note_color: default_colors::TUNER_IN_TUNE,
+            note_text_size: 40,
+            cents_color: default_colors::TUNER_NOTE,
+            cents_text_size: 12,
+            font: Font::default(),
+            inactive_color: default_colors::TUNER_INACTIVE,
+            scale_color: default_colors::TUNER_INACTIVE,
+            scale_height: 6.0,
+            in_tune_zone_color: default_colors::TUNER_IN_TUNE_ZONE,
+            needle_color: default_colors::TUNER_NEEDLE,
+            in_tune_needle_color: default_colors::TUNER_IN_TUNE,
+            needle_width: 3.0,
+            needle_overhang: 6.0,
+        }
+    }
+}
+
+/// Style of tick marks for a [`Tuner`].
+///
+/// [`Tuner`]: ../../native/tuner/struct.Tuner.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickMarksAppearance {
+    /// The style of the tick marks
+    pub style: tick_marks::Appearance,
+    /// The placement of the tick marks
+    pub placement: tick_marks::Placement,
+}
+
+/// Style of text marks for a [`Tuner`].
+///
+/// [`Tuner`]: ../../native/tuner/struct.Tuner.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextMarksAppearance {
+    /// The style of the text marks
+    pub style: text_marks::Appearance,
+    /// The placement of the text marks
+    pub placement: text_marks::Placement,
+}
+
+/// A set of rules that dictate the style of a [`Tuner`].
+///
+/// [`Tuner`]: ../../native/tuner/struct.Tuner.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of a [`Tuner`].
+    ///
+    /// [`Tuner`]: ../../native/tuner/struct.Tuner.html
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// The style of tick marks for a [`Tuner`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`Tuner`]: ../../native/tuner/struct.Tuner.html
+    fn tick_marks_appearance(&self, _style: &Self::Style) -> Option<TickMarksAppearance> {
+        None
+    }
+
+    /// The style of text marks for a [`Tuner`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`Tuner`]: ../../native/tuner/struct.Tuner.html
+    fn text_marks_appearance(&self, _style: &Self::Style) -> Option<TextMarksAppearance> {
+        None
+    }
+}
+
+/// The style of a Tuner.
+#[derive(Default)]
+pub enum Tuner {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for Tuner
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        Tuner::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = Tuner;
+
+    fn appearance(&self, style: &Self::Style) -> Appearance {
+        match style {
+            Tuner::Default => Default::default(),
+            Tuner::Custom(custom) => custom.appearance(self),
+        }
+    }
+
+    fn tick_marks_appearance(&self, style: &Self::Style) -> Option<TickMarksAppearance> {
+        match style {
+            Tuner::Default => Some(TickMarksAppearance {
+                style: tick_marks::Appearance {
+                    tier_1: tick_marks::Shape::Line {
+                        length: 8.0,
+                        width: 2.0,
+                        color: default_colors::TICK_TIER_1,
+                    },
+                    tier_2: tick_marks::Shape::Line {
+                        length: 5.0,
+                        width: 1.0,
+                        color: default_colors::TICK_TIER_2,
+                    },
+                    tier_3: tick_marks::Shape::Line {
+                        length: 3.0,
+                        width: 1.0,
+                        color: default_colors::TICK_TIER_3,
+                    },
+                },
+                placement: tick_marks::Placement::LeftOrTop {
+                    offset: Offset::new(0.0, -2.0),
+                    inside: false,
+                },
+            }),
+            Tuner::Custom(custom) => custom.tick_marks_appearance(self),
+        }
+    }
+
+    fn text_marks_appearance(&self, style: &Self::Style) -> Option<TextMarksAppearance> {
+        match style {
+            Tuner::Default => Some(TextMarksAppearance {
+                style: Default::default(),
+                placement: text_marks::Placement::RightOrBottom {
+                    inside: false,
+                    offset: Offset::new(0.0, 7.0),
+                },
+            }),
+            Tuner::Custom(custom) => custom.text_marks_appearance(self),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Various styles for the [`ValueBox`] widget
+//!
+//! [`ValueBox`]: ../native/value_box/struct.ValueBox.html
+
+use crate::style::default_colors;
+use iced_core::Color;
+
+/// The appearance of a [`ValueBox`].
+///
+/// [`ValueBox`]: ../../native/value_box/struct.ValueBox.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// color of the background rectangle
+    pub back_color: Color,
+    /// width of the background rectangle border
+    pub back_border_width: f32,
+    /// radius of the background rectangle
+    pub back_border_radius: f32,
+    /// color of the background rectangle border
+    pub back_border_color: Color,
+    /// color of the text
+    pub text_color: Color,
+    /// color of the line under the digit that will be dragged. Set this to
+    /// `None` to not highlight the digit.
+    pub digit_highlight_color: Option<Color>,
+    /// color of the text cursor while the user is typing in a value
+    pub caret_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: default_colors::BORDER,
+            text_color: default_colors::VALUE_BOX_TEXT,
+            digit_highlight_color: Some(default_colors::VALUE_BOX_DIGIT),
+            caret_color: default_colors::VALUE_BOX_TEXT,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a [`ValueBox`].
+///
+/// [`ValueBox`]: ../../native/value_box/struct.ValueBox.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of an enabled, idle [`ValueBox`].
+    ///
+    /// [`ValueBox`]: ../../native/value_box/struct.ValueBox.html
+    fn idle(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a hovered [`ValueBox`].
+    ///
+    /// [`ValueBox`]: ../../native/value_box/struct.ValueBox.html
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// Produces the style of a [`ValueBox`] that is being gestured (dragged).
+    ///
+    /// [`ValueBox`]: ../../native/value_box/struct.ValueBox.html
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the style of a [`ValueBox`] that the user is typing a value
+    /// into.
+    ///
+    /// [`ValueBox`]: ../../native/value_box/struct.ValueBox.html
+    fn editing(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the style of a [`ValueBox`] that is currently disabled.
+    ///
+    /// [`ValueBox`]: ../../native/value_box/struct.ValueBox.html
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+}
+
+/// The style of a ValueBox.
+#[derive(Default)]
+pub enum ValueBox {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for ValueBox
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        ValueBox::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = ValueBox;
+
+    fn idle(&self, style: &Self::Style) -> Appearance {
+        match style {
+            ValueBox::Default => Default::default(),
+            ValueBox::Custom(custom) => custom.idle(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        match style {
+            ValueBox::Default => Appearance {
+                back_color: default_colors::LIGHT_BACK_HOVER,
+                ..Default::default()
+            },
+            ValueBox::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        match style {
+            ValueBox::Default => Appearance {
+                back_color: default_colors::LIGHT_BACK_DRAG,
+                ..Default::default()
+            },
+            ValueBox::Custom(custom) => custom.gesturing(self),
+        }
+    }
+
+    fn editing(&self, style: &Self::Style) -> Appearance {
+        match style {
+            ValueBox::Default => Appearance {
+                back_border_color: default_colors::VALUE_BOX_EDITING,
+                ..Default::default()
+            },
+            ValueBox::Custom(custom) => custom.editing(self),
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        match style {
+            ValueBox::Default => {
+                let mut appearance = self.idle(style);
+                appearance.text_color = default_colors::disabled(appearance.text_color);
+                appearance.digit_highlight_color = None;
+                appearance
+            }
+            ValueBox::Custom(custom) => custom.disabled(self),
+        }
+    }
+}
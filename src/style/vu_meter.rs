@@ -0,0 +1,190 @@
+//! Various styles for the [`VuMeter`] widget
+//!
+//! [`VuMeter`]: ../native/vu_meter/struct.VuMeter.html
+
+use crate::style::{default_colors, text_marks, tick_marks};
+use iced_core::Color;
+
+/// The appearance of a [`VuMeter`].
+///
+/// [`VuMeter`]: ../../native/vu_meter/struct.VuMeter.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// color of the meter face
+    pub back_color: Color,
+    /// width of the meter face border
+    pub back_border_width: f32,
+    /// radius of the meter face
+    pub back_border_radius: f32,
+    /// color of the meter face border
+    pub back_border_color: Color,
+    /// color of the arc of the scale
+    pub scale_color: Color,
+    /// width of the arc of the scale
+    pub scale_width: f32,
+    /// color of the arc drawn over the scale above 0 VU. Set this to `None`
+    /// to not show the red zone.
+    pub red_zone_color: Option<Color>,
+    /// width of the red zone arc
+    pub red_zone_width: f32,
+    /// color of the needle
+    pub needle_color: Color,
+    /// width of the needle
+    pub needle_width: f32,
+    /// color of the cap over the pivot of the needle
+    pub pivot_color: Color,
+    /// radius of the cap over the pivot of the needle
+    pub pivot_radius: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::VU_FACE,
+            back_border_width: 1.0,
+            back_border_radius: 4.0,
+            back_border_color: default_colors::BORDER,
+            scale_color: default_colors::VU_SCALE,
+            scale_width: 1.5,
+            red_zone_color: Some(default_colors::VU_RED_ZONE),
+            red_zone_width: 4.0,
+            needle_color: default_colors::VU_NEEDLE,
+            needle_width: 1.5,
+            pivot_color: default_colors::VU_NEEDLE,
+            pivot_radius: 5.0,
+        }
+    }
+}
+
+/// Style of tick marks for a [`VuMeter`].
+///
+/// [`VuMeter`]: ../../native/vu_meter/struct.VuMeter.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickMarksAppearance {
+    /// The style of the tick marks
+    pub style: tick_marks::Appearance,
+    /// The offset from the arc of the scale in pixels
+    pub offset: f32,
+}
+
+/// Style of text marks for a [`VuMeter`].
+///
+/// [`VuMeter`]: ../../native/vu_meter/struct.VuMeter.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextMarksAppearance {
+    /// The style of the text marks
+    pub style: text_marks::Appearance,
+    /// The offset from the arc of the scale in pixels
+    pub offset: f32,
+    /// Extra horizontal offset in pixels for each additional character
+    /// in the text label. This is used to keep longer labels on the sides
+    /// of the scale from being too close to the tick marks.
+    pub h_char_offset: f32,
+    /// The vertical offset in pixels.
+    pub v_offset: f32,
+}
+
+/// A set of rules that dictate the style of a [`VuMeter`].
+///
+/// [`VuMeter`]: ../../native/vu_meter/struct.VuMeter.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of a [`VuMeter`].
+    ///
+    /// [`VuMeter`]: ../../native/vu_meter/struct.VuMeter.html
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// The style of tick marks for a [`VuMeter`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
+    ///
+    /// [`VuMeter`]: ../../native/vu_meter/struct.VuMeter.html
+    fn tick_marks_appearance(&self, _style: &Self::Style) -> Option<TickMarksAppearance> {
+        None
+    }
+
+    /// The style of text marks for a [`VuMeter`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`VuMeter`]: ../../native/vu_meter/struct.VuMeter.html
+    fn text_marks_appearance(&self, _style: &Self::Style) -> Option<TextMarksAppearance> {
+        None
+    }
+}
+
+/// The style of a VuMeter.
+#[derive(Default)]
+pub enum VuMeter {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for VuMeter
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        VuMeter::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = VuMeter;
+
+    fn appearance(&self, style: &Self::Style) -> Appearance {
+        match style {
+            VuMeter::Default => Default::default(),
+            VuMeter::Custom(custom) => custom.appearance(self),
+        }
+    }
+
+    fn tick_marks_appearance(&self, style: &Self::Style) -> Option<TickMarksAppearance> {
+        match style {
+            VuMeter::Default => Some(TickMarksAppearance {
+                style: tick_marks::Appearance {
+                    tier_1: tick_marks::Shape::Line {
+                        length: 8.0,
+                        width: 2.0,
+                        color: default_colors::VU_SCALE,
+                    },
+                    tier_2: tick_marks::Shape::Line {
+                        length: 6.0,
+                        width: 1.5,
+                        color: default_colors::VU_SCALE,
+                    },
+                    tier_3: tick_marks::Shape::Line {
+                        length: 4.0,
+                        width: 1.0,
+                        color: default_colors::VU_SCALE,
+                    },
+                },
+                offset: 0.0,
+            }),
+            VuMeter::Custom(custom) => custom.tick_marks_appearance(self),
+        }
+    }
+
+    fn text_marks_appearance(&self, style: &Self::Style) -> Option<TextMarksAppearance> {
+        match style {
+            VuMeter::Default => Some(TextMarksAppearance {
+                style: text_marks::Appearance {
+                    color: default_colors::VU_SCALE,
+                    ..Default::default()
+                },
+                offset: 16.0,
+                h_char_offset: 2.0,
+                v_offset: -0.75,
+            }),
+            VuMeter::Custom(custom) => custom.text_marks_appearance(self),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Various styles for the [`WaveformEditor`] widget
+//!
+//! [`WaveformEditor`]: ../native/waveform_editor/struct.WaveformEditor.html
+
+use crate::style::default_colors;
+use iced_core::Color;
+
+/// The appearance of a [`WaveformEditor`].
+///
+/// [`WaveformEditor`]: ../../native/waveform_editor/struct.WaveformEditor.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// color of the background rectangle
+    pub back_color: Color,
+    /// width of the background rectangle border
+    pub back_border_width: f32,
+    /// radius of the background rectangle
+    pub back_border_radius: f32,
+    /// color of the background rectangle border
+    pub back_border_color: Color,
+    /// color of the waveform
+    pub waveform_color: Color,
+    /// color of the horizontal line through the center. Set this to `None`
+    /// for no center line.
+    pub center_line_color: Option<Color>,
+    /// color drawn over the parts of the waveform before the start marker
+    /// and after the end marker
+    pub outside_color: Color,
+    /// color of the region between the loop markers
+    pub loop_color: Color,
+    /// color of the region between the crossfade marker and the loop end
+    /// marker
+    pub crossfade_color: Color,
+    /// appearance of the sample start marker
+    pub start_marker: MarkerAppearance,
+    /// appearance of the sample end marker
+    pub end_marker: MarkerAppearance,
+    /// appearance of the loop start and loop end markers
+    pub loop_marker: MarkerAppearance,
+    /// appearance of the crossfade marker
+    pub crossfade_marker: MarkerAppearance,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::LIGHT_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: default_colors::BORDER,
+            waveform_color: default_colors::WAVEFORM,
+            center_line_color: Some(default_colors::XY_PAD_CENTER_LINE),
+            outside_color: default_colors::WAVEFORM_OUTSIDE,
+            loop_color: default_colors::WAVEFORM_LOOP,
+            crossfade_color: default_colors::WAVEFORM_CROSSFADE,
+            start_marker: MarkerAppearance {
+                color: default_colors::WAVEFORM_START_MARKER,
+                ..MarkerAppearance::default()
+            },
+            end_marker: MarkerAppearance {
+                color: default_colors::WAVEFORM_END_MARKER,
+                ..MarkerAppearance::default()
+            },
+            loop_marker: MarkerAppearance {
+                color: default_colors::WAVEFORM_LOOP_MARKER,
+                ..MarkerAppearance::default()
+            },
+            crossfade_marker: MarkerAppearance {
+                color: default_colors::WAVEFORM_CROSSFADE_MARKER,
+                ..MarkerAppearance::default()
+            },
+        }
+    }
+}
+
+/// The appearance of a marker of a [`WaveformEditor`].
+///
+/// [`WaveformEditor`]: ../../native/waveform_editor/struct.WaveformEditor.html
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarkerAppearance {
+    /// the color of the marker line and flag
+    pub color: Color,
+    /// the width of the marker line
+    pub width: f32,
+    /// the size of the square flag drawn at one end of the marker line
+    pub flag_size: f32,
+}
+
+impl Default for MarkerAppearance {
+    fn default() -> Self {
+        MarkerAppearance {
+            color: default_colors::BORDER,
+            width: 2.0,
+            flag_size: 8.0,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a [`WaveformEditor`].
+///
+/// [`WaveformEditor`]: ../../native/waveform_editor/struct.WaveformEditor.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of an enabled, idle [`WaveformEditor`].
+    ///
+    /// [`WaveformEditor`]: ../../native/waveform_editor/struct.WaveformEditor.html
+    fn idle(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a hovered [`WaveformEditor`].
+    ///
+    /// [`WaveformEditor`]: ../../native/waveform_editor/struct.WaveformEditor.html
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// Produces the style of a [`WaveformEditor`] that is being gestured
+    /// (a marker is being dragged).
+    ///
+    /// [`WaveformEditor`]: ../../native/waveform_editor/struct.WaveformEditor.html
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the style of a [`WaveformEditor`] that is currently
+    /// disabled.
+    ///
+    /// [`WaveformEditor`]: ../../native/waveform_editor/struct.WaveformEditor.html
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+}
+
+/// The style of a WaveformEditor.
+#[derive(Default)]
+pub enum WaveformEditor {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for WaveformEditor
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        WaveformEditor::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = WaveformEditor;
+
+    fn idle(&self, style: &Self::Style) -> Appearance {
+        match style {
+            WaveformEditor::Default => Default::default(),
+            WaveformEditor::Custom(custom) => custom.idle(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        match style {
+            WaveformEditor::Default => Appearance {
+                back_color: default_colors::LIGHT_BACK_HOVER,
+                ..Default::default()
+            },
+            WaveformEditor::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        match style {
+            WaveformEditor::Default => self.hovered(style),
+            WaveformEditor::Custom(custom) => custom.gesturing(self),
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        match style {
+            WaveformEditor::Default => {
+                let mut appearance = self.idle(style);
+                appearance.waveform_color = default_colors::disabled(appearance.waveform_color);
+                for marker in [
+                    &mut appearance.start_marker,
+                    &mut appearance.end_marker,
+                    &mut appearance.loop_marker,
+                    &mut appearance.crossfade_marker,
+                ] {
+                    marker.color = default_colors::disabled(marker.color);
+                }
+                appearance
+            }
+            WaveformEditor::Custom(custom) => custom.disabled(self),
+        }
+    }
+}
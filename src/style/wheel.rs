@@ -0,0 +1,211 @@
+//! Various styles for the [`Wheel`] widget
+//!
+//! [`Wheel`]: ../native/wheel/struct.Wheel.html
+
+use crate::style::default_colors;
+use iced_core::Color;
+
+/// The appearance of a [`Wheel`].
+///
+/// [`Wheel`]: ../../native/wheel/struct.Wheel.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Appearance {
+    /// The color of the housing (slot) the wheel sits in
+    pub back_color: Color,
+    /// The width of the border of the housing
+    pub back_border_width: f32,
+    /// The radius of the corners of the housing
+    pub back_border_radius: f32,
+    /// The color of the border of the housing
+    pub back_border_color: Color,
+    /// The gap between the housing and the wheel
+    pub wheel_padding: f32,
+    /// The color of the wheel where it faces the viewer
+    pub wheel_color: Color,
+    /// The color of the wheel at its top and bottom edges, where it
+    /// curves away from the viewer
+    pub wheel_edge_color: Color,
+    /// The radius of the corners of the wheel
+    pub wheel_border_radius: f32,
+    /// The color of the ridges on the wheel
+    pub ridge_color: Color,
+    /// The thickness of a ridge when it faces the viewer
+    pub ridge_width: f32,
+    /// The number of ridges around the visible half of the wheel
+    pub ridge_count: u16,
+    /// The line on the wheel that marks its current position
+    pub notch: Option<WheelNotch>,
+    /// The marks on the housing that show the rest position of a
+    /// bipolar [`Wheel`]
+    ///
+    /// [`Wheel`]: ../../native/wheel/struct.Wheel.html
+    pub center_marker: Option<CenterMarker>,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            back_color: default_colors::WHEEL_BACK,
+            back_border_width: 1.0,
+            back_border_radius: 3.0,
+            back_border_color: default_colors::BORDER,
+            wheel_padding: 2.0,
+            wheel_color: default_colors::LIGHT_BACK,
+            wheel_edge_color: default_colors::WHEEL_EDGE,
+            wheel_border_radius: 2.0,
+            ridge_color: default_colors::WHEEL_RIDGE,
+            ridge_width: 2.0,
+            ridge_count: 10,
+            notch: Some(WheelNotch::default()),
+            center_marker: Some(CenterMarker::default()),
+        }
+    }
+}
+
+/// The line drawn on a [`Wheel`] that marks its current position.
+///
+/// [`Wheel`]: ../../native/wheel/struct.Wheel.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WheelNotch {
+    /// The color of the notch
+    pub color: Color,
+    /// The thickness of the notch when it faces the viewer
+    pub width: f32,
+}
+
+impl Default for WheelNotch {
+    fn default() -> Self {
+        WheelNotch {
+            color: default_colors::BORDER,
+            width: 3.0,
+        }
+    }
+}
+
+/// The marks drawn on both sides of the housing of a bipolar [`Wheel`]
+/// that show its rest position.
+///
+/// [`Wheel`]: ../../native/wheel/struct.Wheel.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CenterMarker {
+    /// The color of the marks
+    pub color: Color,
+    /// The thickness of the marks
+    pub width: f32,
+    /// How far the marks reach into the housing
+    pub length: f32,
+}
+
+impl Default for CenterMarker {
+    fn default() -> Self {
+        CenterMarker {
+            color: default_colors::BORDER,
+            width: 2.0,
+            length: 2.0,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a [`Wheel`].
+///
+/// [`Wheel`]: ../../native/wheel/struct.Wheel.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style;
+
+    /// Produces the style of an enabled, idle [`Wheel`].
+    ///
+    /// [`Wheel`]: ../../native/wheel/struct.Wheel.html
+    fn idle(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a hovered [`Wheel`].
+    ///
+    /// [`Wheel`]: ../../native/wheel/struct.Wheel.html
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+
+    /// Produces the style of a [`Wheel`] that is being gestured (dragged)
+    /// or is springing back to its rest position.
+    ///
+    /// [`Wheel`]: ../../native/wheel/struct.Wheel.html
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the style of a [`Wheel`] that is currently disabled.
+    ///
+    /// [`Wheel`]: ../../native/wheel/struct.Wheel.html
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.idle(style)
+    }
+}
+
+/// The style of a Wheel.
+#[derive(Default)]
+pub enum Wheel {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+}
+
+impl<S> From<S> for Wheel
+where
+    S: 'static + StyleSheet<Style = iced_core::Theme>,
+{
+    fn from(val: S) -> Self {
+        Wheel::Custom(Box::new(val))
+    }
+}
+
+impl StyleSheet for iced_core::Theme {
+    type Style = Wheel;
+
+    fn idle(&self, style: &Self::Style) -> Appearance {
+        match style {
+            Wheel::Default => Default::default(),
+            Wheel::Custom(custom) => custom.idle(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        match style {
+            Wheel::Default => Appearance {
+                wheel_color: default_colors::WHEEL_HOVER,
+                ..Default::default()
+            },
+            Wheel::Custom(custom) => custom.hovered(self),
+        }
+    }
+
+    fn gesturing(&self, style: &Self::Style) -> Appearance {
+        match style {
+            Wheel::Default => self.hovered(style),
+            Wheel::Custom(custom) => custom.gesturing(self),
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        match style {
+            Wheel::Default => {
+                let mut appearance = self.idle(style);
+                appearance.wheel_color = default_colors::disabled(appearance.wheel_color);
+                appearance.wheel_edge_color = default_colors::disabled(appearance.wheel_edge_color);
+                appearance.ridge_color = default_colors::disabled(appearance.ridge_color);
+                if let Some(notch) = appearance.notch.as_mut() {
+                    notch.color = default_colors::disabled(notch.color);
+                }
+                if let Some(marker) = appearance.center_marker.as_mut() {
+                    marker.color = default_colors::disabled(marker.color);
+                }
+                appearance
+            }
+            Wheel::Custom(custom) => custom.disabled(self),
+        }
+    }
+}
@@ -5,11 +5,14 @@
 use crate::style::default_colors;
 use iced_core::Color;
 
+pub use crate::style::Status;
+
 /// The appearance of an [`XYPad`].
 ///
 /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
 /// [`HandleShape`]: enum.HandleShape.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Appearance {
     /// the width of the horizontal and vertical rail lines
     pub rail_width: f32,
@@ -54,6 +57,7 @@ impl Default for Appearance {
 /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
 /// [`Style`]: struct.Style.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HandleShape {
     /// a circular handle
     Circle(HandleCircle),
@@ -66,6 +70,7 @@ pub enum HandleShape {
 /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
 /// [`Style`]: struct.Style.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HandleCircle {
     /// the color of the circle
     pub color: Color,
@@ -93,6 +98,7 @@ impl Default for HandleCircle {
 /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
 /// [`Style`]: struct.Style.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HandleSquare {
     /// the color of the square
     pub color: Color,
@@ -140,64 +146,131 @@ pub trait StyleSheet {
     }
 }
 
-/// The style of a XYPad.
+/// The theme catalog of a [`XYPad`].
+///
+/// Every [`StyleSheet`] is a [`Catalog`] whose classes are its styles, so
+/// existing themes keep working.
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+/// [`StyleSheet`]: trait.StyleSheet.html
+/// [`Catalog`]: trait.Catalog.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// Produces the [`Appearance`] of a [`XYPad`] with the given [`Status`].
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`Status`]: ../enum.Status.html
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+}
+
+impl<T> Catalog for T
+where
+    T: StyleSheet,
+    T::Style: Default,
+{
+    type Class<'a> = T::Style;
+
+    fn default<'a>() -> Self::Class<'a> {
+        T::Style::default()
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        status_appearance(self, class, status)
+    }
+}
+
+/// Picks the [`StyleSheet`] method for `status`.
+///
+/// [`StyleSheet`]: trait.StyleSheet.html
+fn status_appearance<S>(sheet: &S, style: &S::Style, status: Status) -> Appearance
+where
+    S: StyleSheet + ?Sized,
+{
+    match status {
+        Status::Idle => sheet.idle(style),
+        Status::Hovered => sheet.hovered(style),
+        Status::Gesturing => sheet.gesturing(style),
+        Status::Disabled => sheet.disabled(style),
+    }
+}
+
+/// A styling function for a [`XYPad`].
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The class of a [`XYPad`] in the built-in theme.
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
 #[derive(Default)]
-pub enum XYPad {
+pub enum XYPad<'a> {
     /// The default style.
     #[default]
     Default,
     /// A custom style.
-    Custom(Box<dyn StyleSheet<Style = iced_core::Theme>>),
+    Custom(Box<dyn StyleSheet<Style = iced_core::Theme> + 'a>),
+    /// A closure that produces the [`Appearance`] of each [`Status`].
+    /// Everything else comes from the default style.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`Status`]: ../enum.Status.html
+    Styled(StyleFn<'a, iced_core::Theme>),
 }
 
-impl<S> From<S> for XYPad
+impl<'a, S> From<S> for XYPad<'a>
 where
-    S: 'static + StyleSheet<Style = iced_core::Theme>,
+    S: 'a + StyleSheet<Style = iced_core::Theme>,
 {
     fn from(val: S) -> Self {
         XYPad::Custom(Box::new(val))
     }
 }
 
-impl StyleSheet for iced_core::Theme {
-    type Style = XYPad;
-
-    fn idle(&self, style: &Self::Style) -> Appearance {
-        match style {
-            XYPad::Default => Default::default(),
-            XYPad::Custom(custom) => custom.idle(self),
-        }
+impl<'a> From<StyleFn<'a, iced_core::Theme>> for XYPad<'a> {
+    fn from(style: StyleFn<'a, iced_core::Theme>) -> Self {
+        XYPad::Styled(style)
     }
+}
 
-    fn hovered(&self, style: &Self::Style) -> Appearance {
-        match style {
-            XYPad::Default => Appearance {
-                handle: HandleShape::Circle(HandleCircle {
-                    color: default_colors::LIGHT_BACK_HOVER,
-                    ..Default::default()
-                }),
-                ..Default::default()
-            },
-            XYPad::Custom(custom) => custom.hovered(self),
-        }
+impl Catalog for iced_core::Theme {
+    type Class<'a> = XYPad<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        XYPad::Default
     }
 
-    fn gesturing(&self, style: &Self::Style) -> Appearance {
-        match style {
-            XYPad::Default => Appearance {
-                handle: HandleShape::Circle(HandleCircle {
-                    color: default_colors::LIGHT_BACK_DRAG,
-                    diameter: 9.0,
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            XYPad::Default => match status {
+                Status::Idle | Status::Disabled => Default::default(),
+                Status::Hovered => Appearance {
+                    handle: HandleShape::Circle(HandleCircle {
+                        color: default_colors::LIGHT_BACK_HOVER,
+                        ..Default::default()
+                    }),
                     ..Default::default()
-                }),
-                ..Default::default()
+                },
+                Status::Gesturing => Appearance {
+                    handle: HandleShape::Circle(HandleCircle {
+                        color: default_colors::LIGHT_BACK_DRAG,
+                        diameter: 9.0,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
             },
-            XYPad::Custom(custom) => custom.gesturing(self),
+            XYPad::Custom(custom) => status_appearance(custom.as_ref(), self, status),
+            XYPad::Styled(style) => style(self, status),
         }
     }
-
-    fn disabled(&self, style: &Self::Style) -> Appearance {
-        // TODO
-        self.idle(style)
-    }
 }
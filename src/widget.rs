@@ -1,12 +1,44 @@
+#[cfg(feature = "automation_lane")]
+pub mod automation_lane;
+#[cfg(feature = "dual_knob")]
+pub mod dual_knob;
+#[cfg(feature = "gain_reduction_meter")]
+pub mod gain_reduction_meter;
 #[cfg(feature = "h_slider")]
 pub mod h_slider;
 #[cfg(feature = "knob")]
 pub mod knob;
+#[cfg(feature = "loudness_meter")]
+pub mod loudness_meter;
 #[cfg(feature = "mod_range_input")]
 pub mod mod_range_input;
+#[cfg(feature = "multi_slider")]
+pub mod multi_slider;
+#[cfg(feature = "pad_grid")]
+pub mod pad_grid;
 #[cfg(feature = "ramp")]
 pub mod ramp;
+#[cfg(feature = "range_slider")]
+pub mod range_slider;
+#[cfg(feature = "shape_editor")]
+pub mod shape_editor;
+#[cfg(feature = "spectrogram")]
+pub mod spectrogram;
+#[cfg(any(feature = "multi_slider", feature = "shape_editor"))]
+mod stroke;
+#[cfg(feature = "transfer_curve")]
+pub mod transfer_curve;
+#[cfg(feature = "tuner")]
+pub mod tuner;
 #[cfg(feature = "v_slider")]
 pub mod v_slider;
+#[cfg(feature = "value_box")]
+pub mod value_box;
+#[cfg(feature = "vu_meter")]
+pub mod vu_meter;
+#[cfg(feature = "waveform_editor")]
+pub mod waveform_editor;
+#[cfg(feature = "wheel")]
+pub mod wheel;
 #[cfg(feature = "xy_pad")]
 pub mod xy_pad;
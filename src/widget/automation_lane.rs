@@ -0,0 +1,980 @@
+//! Display an automation lane for drawing and editing the curve of a
+//! parameter over time with breakpoints.
+
+use crate::{core::Normal, text_marks};
+use iced_core::{
+    Border, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shadow, Shell, Size,
+    Vector, Widget,
+    border::Radius,
+    keyboard, layout, mouse,
+    renderer::{Quad, Style},
+    touch,
+    widget::{Tree, tree},
+    window,
+};
+use iced_graphics::geometry::{self, Frame, Path, Stroke};
+
+pub use crate::style::automation_lane::{
+    Appearance, PointAppearance, StyleSheet, TextMarksAppearance,
+};
+
+const DEFAULT_HEIGHT: f32 = 120.0;
+const DEFAULT_POINT_HIT_RADIUS: f32 = 6.0;
+
+/// A breakpoint of an [`AutomationLane`].
+///
+/// [`AutomationLane`]: struct.AutomationLane.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Breakpoint {
+    /// The time of the breakpoint, in the same units as the visible range
+    /// of the [`AutomationLane`] (such as seconds or beats).
+    ///
+    /// [`AutomationLane`]: struct.AutomationLane.html
+    pub time: f32,
+    /// The value of the breakpoint.
+    pub value: Normal,
+    /// The tension of the curve from this breakpoint to the next one.
+    /// `Normal::CENTER` is a straight line, lower values bend the curve
+    /// towards the start value, and higher values bend the curve towards the
+    /// end value.
+    pub tension: Normal,
+}
+
+impl Breakpoint {
+    /// Creates a new [`Breakpoint`] with a straight curve to the next
+    /// breakpoint.
+    ///
+    /// [`Breakpoint`]: struct.Breakpoint.html
+    pub const fn new(time: f32, value: Normal) -> Self {
+        Self {
+            time,
+            value,
+            tension: Normal::CENTER,
+        }
+    }
+
+    /// Sets the tension of the curve from this breakpoint to the next one.
+    pub const fn tension(mut self, tension: Normal) -> Self {
+        self.tension = tension;
+        self
+    }
+}
+
+/// A message emitted by an [`AutomationLane`] when the user edits its
+/// breakpoints.
+///
+/// [`AutomationLane`]: struct.AutomationLane.html
+#[derive(Debug, Clone, PartialEq)]
+pub enum LaneGesture {
+    /// The user has just started editing the breakpoints.
+    ///
+    /// This will always be sent before [`LaneGesture::Gesturing`].
+    GestureStart,
+    /// The breakpoints have changed. This holds every breakpoint of the
+    /// lane, sorted by time.
+    Gesturing(Vec<Breakpoint>),
+    /// The user has finished editing the breakpoints.
+    GestureEnd,
+}
+
+/// Returns the control point of the quadratic curve from `from` to `to`.
+///
+/// This uses the same approach as the [`Ramp`] widget, where the control
+/// point slides along the edges of the rectangle spanned by the two points.
+///
+/// [`Ramp`]: ../ramp/struct.Ramp.html
+fn control_point(from: Point, to: Point, tension: Normal) -> Point {
+    let tension = tension.as_f32() - 0.5;
+
+    if tension < 0.0 {
+        let corner = Point::new(to.x, from.y);
+        let t = -tension * 2.0;
+        Point::new(
+            from.x + (corner.x - from.x) * t,
+            from.y + (corner.y - from.y) * t,
+        )
+    } else {
+        let corner = Point::new(from.x, to.y);
+        let t = tension * 2.0;
+        Point::new(to.x + (corner.x - to.x) * t, to.y + (corner.y - to.y) * t)
+    }
+}
+
+/// Returns the value of the automation curve described by `breakpoints`
+/// (sorted by time) at `time`, or `None` if there are no breakpoints.
+///
+/// Before the first breakpoint the value of the first breakpoint is
+/// returned, and after the last breakpoint the value of the last breakpoint
+/// is returned.
+pub fn value_at(breakpoints: &[Breakpoint], time: f32) -> Option<Normal> {
+    let first = breakpoints.first()?;
+    let last = breakpoints.last()?;
+
+    if time <= first.time {
+        return Some(first.value);
+    }
+    if time >= last.time {
+        return Some(last.value);
+    }
+
+    let index = breakpoints.partition_point(|point| point.time <= time) - 1;
+    let (start, end) = (&breakpoints[index], &breakpoints[index + 1]);
+
+    if end.time - start.time <= f32::EPSILON {
+        return Some(end.value);
+    }
+
+    let from = Point::new(start.time, start.value.as_f32());
+    let to = Point::new(end.time, end.value.as_f32());
+    let control = control_point(from, to, start.tension);
+
+    // Solve x(u) = time for the curve parameter `u`. The curve is monotonic
+    // in x because the control point never leaves the rectangle spanned by
+    // the two points.
+    let a = from.x - 2.0 * control.x + to.x;
+    let b = 2.0 * (control.x - from.x);
+    let c = from.x - time;
+
+    let u = if a.abs() <= f32::EPSILON {
+        -c / b
+    } else {
+        (-b + (b * b - 4.0 * a * c).max(0.0).sqrt()) / (2.0 * a)
+    }
+    .clamp(0.0, 1.0);
+
+    let inv = 1.0 - u;
+    Some(Normal::new(
+        inv * inv * from.y + 2.0 * inv * u * control.y + u * u * to.y,
+    ))
+}
+
+/// An automation lane GUI widget for drawing and editing the curve of a
+/// parameter over time.
+///
+/// * Double-click an empty spot to add a breakpoint.
+/// * Click a breakpoint to select it, or drag an empty spot to select every
+///   breakpoint in a rectangle. Hold `Shift` to add to the selection.
+/// * Drag a selected breakpoint to move every selected breakpoint.
+/// * Press `Delete` or `Backspace` to remove the selected breakpoints. The
+///   lane only takes these keys after it was last clicked.
+/// * Drag a segment vertically while holding down the tension modifier key
+///   (`Alt` by default) to bend its curve.
+#[allow(missing_debug_implementations)]
+pub struct AutomationLane<'a, Message, Theme: StyleSheet> {
+    breakpoints: Vec<Breakpoint>,
+    on_gesture: Option<Box<dyn 'a + FnMut(LaneGesture) -> Message>>,
+    visible_start: f32,
+    visible_end: f32,
+    point_hit_radius: f32,
+    tension_modifiers: keyboard::Modifiers,
+    enabled: bool,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+    text_marks: Option<&'a text_marks::Group>,
+}
+
+impl<'a, Message, Theme> AutomationLane<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`AutomationLane`] with the given breakpoints. The
+    /// breakpoints must be sorted by time.
+    ///
+    /// [`AutomationLane`]: struct.AutomationLane.html
+    pub fn new(breakpoints: &[Breakpoint]) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        AutomationLane {
+            breakpoints: breakpoints.to_vec(),
+            on_gesture: None,
+            visible_start: 0.0,
+            visible_end: 1.0,
+            point_hit_radius: DEFAULT_POINT_HIT_RADIUS,
+            tension_modifiers: keyboard::Modifiers::ALT,
+            enabled: true,
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: Default::default(),
+            text_marks: None,
+        }
+    }
+
+    /// Sets the message to emit when the user edits the breakpoints.
+    pub fn on_gesture(mut self, on_gesture: impl 'a + FnMut(LaneGesture) -> Message) -> Self {
+        self.on_gesture = Some(Box::new(on_gesture));
+        self
+    }
+
+    /// Sets the range of time shown from the left edge to the right edge of
+    /// the lane. Breakpoints can only be moved within this range.
+    ///
+    /// The default range is `0.0` to `1.0`.
+    pub fn visible_range(mut self, start: f32, end: f32) -> Self {
+        self.visible_start = start;
+        self.visible_end = end.max(start + f32::EPSILON);
+        self
+    }
+
+    /// Sets the distance in pixels from the center of a breakpoint within
+    /// which the breakpoint can be grabbed.
+    ///
+    /// The default is `6.0`.
+    pub fn point_hit_radius(mut self, radius: f32) -> Self {
+        self.point_hit_radius = radius;
+        self
+    }
+
+    /// Sets the modifier key/keys to hold down to bend the curve of a
+    /// segment.
+    ///
+    /// The default key is `Alt`.
+    pub const fn tension_modifiers(mut self, modifiers: keyboard::Modifiers) -> Self {
+        self.tension_modifiers = modifiers;
+        self
+    }
+
+    /// Enable/disable this widget.
+    ///
+    /// The default is `true`.
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the width of the [`AutomationLane`].
+    ///
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`AutomationLane`]: struct.AutomationLane.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`AutomationLane`].
+    ///
+    /// The default height is `Length::Fixed(120.0)`.
+    ///
+    /// [`AutomationLane`]: struct.AutomationLane.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`AutomationLane`].
+    ///
+    /// [`AutomationLane`]: struct.AutomationLane.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the text marks to display on the value axis. Note your
+    /// [`StyleSheet`] must also implement
+    /// `text_marks_appearance(&self) -> Option<TextMarksAppearance>` for them
+    /// to display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/automation_lane/trait.StyleSheet.html
+    pub fn text_marks(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks = Some(text_marks);
+        self
+    }
+
+    fn time_span(&self) -> f32 {
+        self.visible_end - self.visible_start
+    }
+
+    fn time_to_x(&self, bounds: &Rectangle, time: f32) -> f32 {
+        bounds.x + (time - self.visible_start) / self.time_span() * bounds.width
+    }
+
+    fn x_to_time(&self, bounds: &Rectangle, x: f32) -> f32 {
+        if bounds.width > 0.0 {
+            self.visible_start + (x - bounds.x) / bounds.width * self.time_span()
+        } else {
+            self.visible_start
+        }
+    }
+
+    fn point_position(&self, bounds: &Rectangle, breakpoint: &Breakpoint) -> Point {
+        Point::new(
+            self.time_to_x(bounds, breakpoint.time),
+            bounds.y + breakpoint.value.scale_inv(bounds.height),
+        )
+    }
+
+    /// Returns the index of the breakpoint closest to `position` within the
+    /// hit radius.
+    fn point_at(&self, bounds: &Rectangle, position: Point) -> Option<usize> {
+        self.breakpoints
+            .iter()
+            .enumerate()
+            .map(|(i, point)| (i, self.point_position(bounds, point).distance(position)))
+            .filter(|(_, distance)| *distance <= self.point_hit_radius)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    }
+
+    /// Returns the index of the breakpoint that starts the segment at `x`.
+    fn segment_at(&self, bounds: &Rectangle, x: f32) -> Option<usize> {
+        let time = self.x_to_time(bounds, x);
+        let index = self.breakpoints.partition_point(|point| point.time <= time);
+
+        (index > 0 && index < self.breakpoints.len()).then(|| index - 1)
+    }
+
+    fn publish(&mut self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        if !state.is_gesturing {
+            // Send a GestureStart message first.
+            if let Some(on_gesture) = &mut self.on_gesture {
+                shell.publish((on_gesture)(LaneGesture::GestureStart));
+            }
+            state.is_gesturing = true;
+        }
+
+        if let Some(on_gesture) = &mut self.on_gesture {
+            shell.publish((on_gesture)(LaneGesture::Gesturing(
+                self.breakpoints.clone(),
+            )));
+        }
+    }
+
+    fn end_gesture(&mut self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        state.drag = None;
+
+        if state.is_gesturing {
+            if let Some(on_gesture) = &mut self.on_gesture {
+                shell.publish((on_gesture)(LaneGesture::GestureEnd));
+            }
+            state.is_gesturing = false;
+        }
+    }
+
+    fn add_point(
+        &mut self,
+        bounds: &Rectangle,
+        position: Point,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let time = self
+            .x_to_time(bounds, position.x)
+            .clamp(self.visible_start, self.visible_end);
+        let value = if bounds.height > 0.0 {
+            Normal::new(1.0 - (position.y - bounds.y) / bounds.height)
+        } else {
+            Normal::MIN
+        };
+
+        let index = self.breakpoints.partition_point(|point| point.time <= time);
+        self.breakpoints.insert(index, Breakpoint::new(time, value));
+        state.selected = vec![index];
+
+        self.publish(state, shell);
+        self.end_gesture(state, shell);
+    }
+
+    fn remove_selected(&mut self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let mut index = 0;
+        self.breakpoints.retain(|_| {
+            let keep = !state.selected.contains(&index);
+            index += 1;
+            keep
+        });
+        state.selected.clear();
+
+        self.publish(state, shell);
+        self.end_gesture(state, shell);
+    }
+
+    /// Moves the selected breakpoints by the given offset from where they
+    /// were grabbed, without letting them cross unselected breakpoints or
+    /// leave the visible range.
+    fn move_selected(&mut self, original: &[Breakpoint], selected: &[usize], delta: (f32, f32)) {
+        let (mut min_dt, mut max_dt) = (f32::MIN, f32::MAX);
+        let (mut min_dv, mut max_dv) = (f32::MIN, f32::MAX);
+
+        for &i in selected {
+            let point = &original[i];
+
+            min_dt = min_dt.max(self.visible_start - point.time);
+            max_dt = max_dt.min(self.visible_end - point.time);
+            if i > 0 && !selected.contains(&(i - 1)) {
+                min_dt = min_dt.max(original[i - 1].time - point.time);
+            }
+            if i + 1 < original.len() && !selected.contains(&(i + 1)) {
+                max_dt = max_dt.min(original[i + 1].time - point.time);
+            }
+
+            min_dv = min_dv.max(-point.value.as_f32());
+            max_dv = max_dv.min(1.0 - point.value.as_f32());
+        }
+
+        let dt = delta.0.clamp(min_dt.min(0.0), max_dt.max(0.0));
+        let dv = delta.1.clamp(min_dv.min(0.0), max_dv.max(0.0));
+
+        for &i in selected {
+            self.breakpoints[i].time = original[i].time + dt;
+            self.breakpoints[i].value = Normal::new(original[i].value.as_f32() + dv);
+        }
+    }
+}
+
+/// How the breakpoints are being edited.
+enum Drag {
+    /// Moving the selected breakpoints. The breakpoints from before the
+    /// drag was started are kept so the offset is applied to them.
+    Points {
+        grab_position: Point,
+        original: Vec<Breakpoint>,
+    },
+    /// Bending the curve of the segment starting at `segment`.
+    Tension {
+        segment: usize,
+        grab_y: f32,
+        original: Normal,
+    },
+    /// Selecting every breakpoint in the rectangle between `anchor` and
+    /// `current`, in addition to `base`.
+    Select {
+        anchor: Point,
+        current: Point,
+        base: Vec<usize>,
+    },
+}
+
+struct State {
+    selected: Vec<usize>,
+    drag: Option<Drag>,
+    is_gesturing: bool,
+    hovered: bool,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+    /// Whether the last press landed inside the lane, so that `Delete` and
+    /// `Backspace` only act on the lane the user is working in.
+    focused: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for AutomationLane<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer
+        + iced_core::text::Renderer<Font = iced_core::Font>
+        + iced_graphics::geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            selected: Vec::new(),
+            drag: None,
+            is_gesturing: false,
+            hovered: false,
+            pressed_modifiers: keyboard::Modifiers::NONE,
+            last_click: None,
+            focused: false,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+
+        // The breakpoints may have been changed outside of the widget.
+        let len = self.breakpoints.len();
+        state.selected.retain(|i| *i < len);
+
+        let mut capture_event = false;
+        let mut should_redraw = false;
+
+        if cursor_is_over != state.hovered {
+            state.hovered = cursor_is_over;
+            should_redraw = true;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => match state.drag.take() {
+                Some(Drag::Points {
+                    grab_position,
+                    original,
+                }) => {
+                    if original.len() == self.breakpoints.len() && bounds.height > 0.0 {
+                        let dt = (position.x - grab_position.x) / bounds.width * self.time_span();
+                        let dv = (grab_position.y - position.y) / bounds.height;
+
+                        let selected = state.selected.clone();
+                        self.move_selected(&original, &selected, (dt, dv));
+                        self.publish(state, shell);
+                    }
+
+                    state.drag = Some(Drag::Points {
+                        grab_position,
+                        original,
+                    });
+                    should_redraw = true;
+                    capture_event = true;
+                }
+                Some(Drag::Tension {
+                    segment,
+                    grab_y,
+                    original,
+                }) => {
+                    if segment + 1 < self.breakpoints.len() && bounds.height > 0.0 {
+                        // Dragging towards the end value bends the curve
+                        // towards the end value.
+                        let rising =
+                            self.breakpoints[segment + 1].value >= self.breakpoints[segment].value;
+                        let delta = (grab_y - position.y) / bounds.height;
+                        let delta = if rising { delta } else { -delta };
+
+                        self.breakpoints[segment].tension = Normal::new(original.as_f32() + delta);
+                        self.publish(state, shell);
+                    }
+
+                    state.drag = Some(Drag::Tension {
+                        segment,
+                        grab_y,
+                        original,
+                    });
+                    should_redraw = true;
+                    capture_event = true;
+                }
+                Some(Drag::Select { anchor, base, .. }) => {
+                    let rect = Rectangle::new(
+                        Point::new(anchor.x.min(position.x), anchor.y.min(position.y)),
+                        Size::new((anchor.x - position.x).abs(), (anchor.y - position.y).abs()),
+                    );
+
+                    let mut selected = base.clone();
+                    for (i, point) in self.breakpoints.iter().enumerate() {
+                        if rect.contains(self.point_position(&bounds, point))
+                            && !selected.contains(&i)
+                        {
+                            selected.push(i);
+                        }
+                    }
+                    selected.sort_unstable();
+                    state.selected = selected;
+
+                    state.drag = Some(Drag::Select {
+                        anchor,
+                        current: *position,
+                        base,
+                    });
+                    should_redraw = true;
+                    capture_event = true;
+                }
+                None => {}
+            },
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.focused = cursor_is_over;
+
+                if cursor_is_over && let Some(position) = cursor.position() {
+                    let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                    state.last_click = Some(click);
+
+                    let shift = state.pressed_modifiers.shift();
+                    let hit = self.point_at(&bounds, position);
+
+                    if let Some(index) = hit {
+                        if shift && state.selected.contains(&index) {
+                            state.selected.retain(|i| *i != index);
+                        } else {
+                            if !state.selected.contains(&index) {
+                                if !shift {
+                                    state.selected.clear();
+                                }
+                                state.selected.push(index);
+                                state.selected.sort_unstable();
+                            }
+
+                            state.drag = Some(Drag::Points {
+                                grab_position: position,
+                                original: self.breakpoints.clone(),
+                            });
+                        }
+                    } else if let mouse::click::Kind::Double = click.kind() {
+                        self.add_point(&bounds, position, state, shell);
+                    } else if !self.tension_modifiers.is_empty()
+                        && state.pressed_modifiers.contains(self.tension_modifiers)
+                        && let Some(segment) = self.segment_at(&bounds, position.x)
+                    {
+                        state.drag = Some(Drag::Tension {
+                            segment,
+                            grab_y: position.y,
+                            original: self.breakpoints[segment].tension,
+                        });
+                    } else {
+                        if !shift {
+                            state.selected.clear();
+                        }
+                        state.drag = Some(Drag::Select {
+                            anchor: position,
+                            current: position,
+                            base: state.selected.clone(),
+                        });
+                    }
+
+                    should_redraw = true;
+                    capture_event = true;
+                } else if !state.selected.is_empty() {
+                    state.selected.clear();
+                    should_redraw = true;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.is_some() {
+                    should_redraw = true;
+                    capture_event = true;
+                }
+                self.end_gesture(state, shell);
+            }
+            Event::Window(window::Event::Unfocused) => {
+                self.end_gesture(state, shell);
+                state.focused = false;
+            }
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { key, modifiers, .. } => {
+                    state.pressed_modifiers = *modifiers;
+
+                    if let keyboard::Key::Named(
+                        keyboard::key::Named::Delete | keyboard::key::Named::Backspace,
+                    ) = key
+                        && state.focused
+                        && !state.selected.is_empty()
+                        && state.drag.is_none()
+                    {
+                        self.remove_selected(state, shell);
+                        should_redraw = true;
+                        capture_event = true;
+                    }
+                }
+                keyboard::Event::KeyReleased { modifiers, .. }
+                | keyboard::Event::ModifiersChanged(modifiers) => {
+                    state.pressed_modifiers = *modifiers;
+                }
+            },
+            _ => {}
+        }
+
+        if capture_event {
+            shell.capture_event();
+        }
+
+        if should_redraw {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+
+        let appearance = if !self.enabled {
+            theme.disabled(&self.style)
+        } else if state.drag.is_some() {
+            theme.gesturing(&self.style)
+        } else if cursor_is_over {
+            theme.hovered(&self.style)
+        } else {
+            theme.idle(&self.style)
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        if let Some(text_marks) = self.text_marks
+            && let Some(value_line_color) = appearance.value_line_color
+        {
+            for (normal, _) in &text_marks.group {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle {
+                            x: bounds.x,
+                            y: (bounds.y + normal.scale_inv(bounds.height)).round(),
+                            width: bounds.width,
+                            height: 1.0,
+                        },
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                        snap: false,
+                    },
+                    value_line_color,
+                );
+            }
+        }
+
+        if let Some(text_marks) = self.text_marks
+            && let Some(style) = theme.text_marks_appearance(&self.style)
+        {
+            text_marks::draw_vertical_text_marks(
+                renderer,
+                &bounds,
+                text_marks,
+                &style.style,
+                &style.placement,
+                false,
+            );
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            let positions: Vec<Point> = self
+                .breakpoints
+                .iter()
+                .map(|point| self.point_position(&bounds, point))
+                .map(|position| Point::new(position.x - bounds.x, position.y - bounds.y))
+                .collect();
+
+            if let (Some(first), Some(last)) = (positions.first(), positions.last()) {
+                let curve = |path: &mut geometry::path::Builder| {
+                    path.line_to(Point::new(first.x.min(0.0), first.y));
+                    path.line_to(*first);
+                    for (i, pair) in positions.windows(2).enumerate() {
+                        let control = control_point(pair[0], pair[1], self.breakpoints[i].tension);
+                        path.quadratic_curve_to(control, pair[1]);
+                    }
+                    path.line_to(Point::new(last.x.max(bounds.width), last.y));
+                };
+
+                let mut frame = Frame::new(renderer, bounds.size());
+
+                if let Some(fill_color) = appearance.fill_color {
+                    let fill_path = Path::new(|path| {
+                        path.move_to(Point::new(first.x.min(0.0), bounds.height));
+                        curve(path);
+                        path.line_to(Point::new(last.x.max(bounds.width), bounds.height));
+                        path.close();
+                    });
+
+                    frame.fill(&fill_path, fill_color);
+                }
+
+                let line_path = Path::new(|path| {
+                    path.move_to(Point::new(first.x.min(0.0), first.y));
+                    curve(path);
+                });
+
+                frame.stroke(
+                    &line_path,
+                    Stroke {
+                        width: appearance.line_width,
+                        style: geometry::Style::Solid(appearance.line_color),
+                        ..Stroke::default()
+                    },
+                );
+
+                renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                    // clippy gets confused when default iced features are disabled
+                    #[allow(clippy::unit_arg)]
+                    renderer.draw_geometry(frame.into_geometry());
+                });
+            }
+
+            for (i, position) in positions.iter().enumerate() {
+                let point = if state.selected.contains(&i) {
+                    &appearance.selected_point
+                } else {
+                    &appearance.point
+                };
+                let radius = point.diameter / 2.0;
+
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + position.x - radius,
+                            y: bounds.y + position.y - radius,
+                            width: point.diameter,
+                            height: point.diameter,
+                        },
+                        border: Border {
+                            color: point.border_color,
+                            width: point.border_width,
+                            radius: Radius::new(radius),
+                        },
+                        shadow: Shadow::default(),
+                        snap: false,
+                    },
+                    point.color,
+                );
+            }
+
+            if let Some(Drag::Select {
+                anchor, current, ..
+            }) = &state.drag
+            {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle {
+                            x: anchor.x.min(current.x),
+                            y: anchor.y.min(current.y),
+                            width: (anchor.x - current.x).abs(),
+                            height: (anchor.y - current.y).abs(),
+                        },
+                        border: Border {
+                            color: appearance.selection_border_color,
+                            width: 1.0,
+                            radius: Radius::new(0.0),
+                        },
+                        shadow: Shadow::default(),
+                        snap: false,
+                    },
+                    appearance.selection_color,
+                );
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AutomationLane<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer
+        + iced_core::text::Renderer<Font = iced_core::Font>
+        + iced_graphics::geometry::Renderer,
+{
+    fn from(automation_lane: AutomationLane<'a, Message, Theme>) -> Self {
+        Self::new(automation_lane)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Breakpoint, value_at};
+    use crate::core::Normal;
+
+    fn segment(tension: Normal) -> [Breakpoint; 2] {
+        [
+            Breakpoint::new(0.0, Normal::MIN).tension(tension),
+            Breakpoint::new(1.0, Normal::MAX),
+        ]
+    }
+
+    fn assert_near(value: Option<Normal>, expected: f32) {
+        let value = value.unwrap().as_f32();
+        assert!((value - expected).abs() < 1.0e-3, "{value} != {expected}");
+    }
+
+    #[test]
+    fn empty_lane_has_no_value() {
+        assert_eq!(value_at(&[], 0.5), None);
+    }
+
+    #[test]
+    fn endpoints_hold_outside_the_lane() {
+        let points = [
+            Breakpoint::new(1.0, Normal::new(0.25)),
+            Breakpoint::new(2.0, Normal::new(0.75)),
+        ];
+        assert_near(value_at(&points, 0.0), 0.25);
+        assert_near(value_at(&points, 1.0), 0.25);
+        assert_near(value_at(&points, 2.0), 0.75);
+        assert_near(value_at(&points, 3.0), 0.75);
+
+        let single = [Breakpoint::new(1.0, Normal::new(0.4))];
+        assert_near(value_at(&single, 0.0), 0.4);
+        assert_near(value_at(&single, 2.0), 0.4);
+    }
+
+    #[test]
+    fn center_tension_is_linear() {
+        let points = segment(Normal::CENTER);
+        for time in [0.0, 0.1, 0.25, 0.5, 0.9, 1.0] {
+            assert_near(value_at(&points, time), time);
+        }
+    }
+
+    #[test]
+    fn tension_extremes() {
+        // The control point sits in the corner of the segment, so the
+        // curve is a parabola through both ends.
+        let low = segment(Normal::MIN);
+        assert_near(value_at(&low, 0.5), (1.0 - 0.5_f32.sqrt()).powi(2));
+        assert_near(value_at(&low, 0.0), 0.0);
+        assert_near(value_at(&low, 1.0), 1.0);
+
+        let high = segment(Normal::MAX);
+        assert_near(value_at(&high, 0.5), 2.0 * 0.5_f32.sqrt() - 0.5);
+        assert_near(value_at(&high, 0.0), 0.0);
+        assert_near(value_at(&high, 1.0), 1.0);
+
+        // Both stay monotonic between the ends.
+        for points in [low, high] {
+            let mut prev = 0.0;
+            for step in 0..=100 {
+                let value = value_at(&points, step as f32 / 100.0).unwrap().as_f32();
+                assert!(value >= prev - 1.0e-5);
+                prev = value;
+            }
+        }
+    }
+}
@@ -0,0 +1,356 @@
+//! Display a concentric knob with an outer and an inner ring that each
+//! control their own [`NormalParam`]
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use crate::{
+    core::NormalParam,
+    virtual_slider::{self, Gesture, VirtualSlider},
+    widget::knob::{draw, knob_info::KnobInfo},
+};
+use iced_core::{
+    Border, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shadow, Shell, Size,
+    Widget,
+    border::Radius,
+    keyboard, layout, mouse,
+    renderer::{Quad, Style},
+    widget::{Tree, tree},
+};
+
+pub use crate::style::dual_knob::{Appearance, CapAppearance, StyleSheet};
+
+const DEFAULT_SIZE: f32 = 40.0;
+const DEFAULT_INNER_SIZE: f32 = 0.6;
+
+/// A concentric knob GUI widget with two rings (for example an outer ring
+/// for coarse tuning and an inner ring for fine tuning). Each ring controls
+/// its own [`NormalParam`].
+///
+/// The ring that is dragged or scrolled is picked by how far the cursor is
+/// from the center of the knob. Holding the [`inner_modifiers`] always
+/// picks the inner ring.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`inner_modifiers`]: struct.DualKnob.html#method.inner_modifiers
+#[allow(missing_debug_implementations)]
+pub struct DualKnob<'a, Message, Theme: StyleSheet> {
+    outer: VirtualSlider<'a, Message>,
+    inner: VirtualSlider<'a, Message>,
+    inner_size: f32,
+    inner_modifiers: keyboard::Modifiers,
+    enabled: bool,
+    size: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme: StyleSheet> DualKnob<'a, Message, Theme> {
+    /// Creates a new [`DualKnob`].
+    ///
+    /// * `outer_param` - The normalized value of the parameter of the outer ring.
+    /// * `inner_param` - The normalized value of the parameter of the inner ring.
+    ///
+    /// [`DualKnob`]: struct.DualKnob.html
+    pub fn new(outer_param: impl Into<NormalParam>, inner_param: impl Into<NormalParam>) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        DualKnob {
+            outer: VirtualSlider::new(outer_param.into()),
+            inner: VirtualSlider::new(inner_param.into()),
+            inner_size: DEFAULT_INNER_SIZE,
+            inner_modifiers: keyboard::Modifiers::ALT,
+            enabled: true,
+            size: Length::Fixed(DEFAULT_SIZE),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the message to emit when the user gestures the outer ring.
+    pub fn on_gesture_outer(mut self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.outer.set_on_gesture(on_gesture);
+        self
+    }
+
+    /// Sets the message to emit when the user gestures the inner ring.
+    pub fn on_gesture_inner(mut self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.inner.set_on_gesture(on_gesture);
+        self
+    }
+
+    /// Set a custom configuration to use for the virtual slider of the
+    /// outer ring.
+    pub fn config_outer(mut self, config: &virtual_slider::Config) -> Self {
+        self.outer.config = *config;
+        self
+    }
+
+    /// Set a custom configuration to use for the virtual slider of the
+    /// inner ring.
+    pub fn config_inner(mut self, config: &virtual_slider::Config) -> Self {
+        self.inner.config = *config;
+        self
+    }
+
+    /// Sets the diameter of the inner ring, scaled from the diameter of the
+    /// whole knob. Presses inside of this diameter control the inner ring.
+    ///
+    /// The value is clamped to the range `[0.1, 0.9]`. The default is `0.6`.
+    pub fn inner_size(mut self, inner_size: f32) -> Self {
+        self.inner_size = inner_size.clamp(0.1, 0.9);
+        self
+    }
+
+    /// Sets the modifier keys that, while held, make the inner ring active
+    /// wherever the knob is pressed or scrolled. Set this to
+    /// `keyboard::Modifiers::NONE` to only pick the ring by its radius.
+    ///
+    /// The default is `keyboard::Modifiers::ALT`.
+    pub fn inner_modifiers(mut self, modifiers: keyboard::Modifiers) -> Self {
+        self.inner_modifiers = modifiers;
+        self
+    }
+
+    /// Enable/disable this widget.
+    ///
+    /// The default is `true`.
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the diameter of the [`DualKnob`]. The default size is
+    /// `Length::Fixed(40.0)`.
+    ///
+    /// [`DualKnob`]: struct.DualKnob.html
+    pub fn size(mut self, size: Length) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the style of the [`DualKnob`].
+    ///
+    /// [`DualKnob`]: struct.DualKnob.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn active_ring(&self, state: &State, bounds: Rectangle, cursor: mouse::Cursor) -> Option<Ring> {
+        if state.outer.is_dragging() {
+            return Some(Ring::Outer);
+        }
+        if state.inner.is_dragging() {
+            return Some(Ring::Inner);
+        }
+
+        let position = cursor.position_over(bounds)?;
+
+        if !self.inner_modifiers.is_empty() && state.modifiers.contains(self.inner_modifiers) {
+            return Some(Ring::Inner);
+        }
+
+        if position.distance(bounds.center()) <= bounds.width * self.inner_size / 2.0 {
+            Some(Ring::Inner)
+        } else {
+            Some(Ring::Outer)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ring {
+    Outer,
+    Inner,
+}
+
+struct State {
+    outer: virtual_slider::State,
+    inner: virtual_slider::State,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for DualKnob<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer + iced_graphics::geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            outer: virtual_slider::State::new(self.outer.param().normal),
+            inner: virtual_slider::State::new(self.inner.param().normal),
+            modifiers: keyboard::Modifiers::NONE,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.size,
+            height: self.size,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.size, self.size, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Keyboard(
+            keyboard::Event::KeyPressed { modifiers, .. }
+            | keyboard::Event::KeyReleased { modifiers, .. }
+            | keyboard::Event::ModifiersChanged(modifiers),
+        ) = event
+        {
+            state.modifiers = *modifiers;
+        }
+
+        let bounds = square_bounds(layout.bounds());
+        let active_ring = self.active_ring(state, bounds, cursor);
+
+        let outer_status = self.outer.update(
+            &mut state.outer,
+            active_ring == Some(Ring::Outer),
+            false,
+            false,
+            event,
+            cursor,
+            shell,
+        );
+        let inner_status = self.inner.update(
+            &mut state.inner,
+            active_ring == Some(Ring::Inner),
+            false,
+            false,
+            event,
+            cursor,
+            shell,
+        );
+
+        if outer_status.should_redraw() || inner_status.should_redraw() {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let bounds = square_bounds(layout.bounds());
+        let active_ring = self.active_ring(state, bounds, cursor);
+
+        let appearance = |ring: Ring, slider_state: &virtual_slider::State| {
+            if !self.enabled {
+                theme.disabled(&self.style)
+            } else if slider_state.is_gesturing() {
+                theme.gesturing(&self.style)
+            } else if active_ring == Some(ring) {
+                theme.hovered(&self.style)
+            } else {
+                theme.idle(&self.style)
+            }
+        };
+        let outer_appearance = appearance(Ring::Outer, &state.outer);
+        let inner_appearance = appearance(Ring::Inner, &state.inner);
+
+        let outer_angle_range = theme.outer_angle_range(&self.style);
+        let inner_angle_range = theme.inner_angle_range(&self.style);
+        let outer_normal = self.outer.param().normal;
+        let inner_normal = self.inner.param().normal;
+
+        let outer_info = KnobInfo::new(bounds, &outer_angle_range, outer_normal, None);
+        draw::value_arc(renderer, &outer_info, &Some(outer_appearance.outer_arc));
+
+        let inner_bounds = centered_square(bounds, bounds.width * self.inner_size);
+        let inner_info = KnobInfo::new(inner_bounds, &inner_angle_range, inner_normal, None);
+        draw::value_arc(renderer, &inner_info, &Some(inner_appearance.inner_arc));
+
+        let cap = &inner_appearance.cap;
+        let cap_bounds = centered_square(bounds, cap.diameter.from_knob_diameter(bounds.width));
+        let cap_info = KnobInfo::new(cap_bounds, &inner_angle_range, inner_normal, None);
+
+        renderer.fill_quad(
+            Quad {
+                bounds: cap_bounds,
+                border: Border {
+                    color: cap.border_color,
+                    width: cap.border_width,
+                    radius: Radius::new(cap_info.radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            cap.color,
+        );
+
+        draw::notch(renderer, &cap_info, &cap.notch);
+    }
+}
+
+/// Returns the largest square with rounded coordinates centered in `bounds`.
+fn square_bounds(bounds: Rectangle) -> Rectangle {
+    let size = bounds.width.min(bounds.height).round();
+
+    Rectangle {
+        x: (bounds.x + (bounds.width - size) / 2.0).round(),
+        y: (bounds.y + (bounds.height - size) / 2.0).round(),
+        width: size,
+        height: size,
+    }
+}
+
+/// Returns a square of the given size centered in the square `bounds`.
+fn centered_square(bounds: Rectangle, size: f32) -> Rectangle {
+    let size = size.round().clamp(0.0, bounds.width);
+    let center = Point::new(bounds.center_x(), bounds.center_y());
+
+    Rectangle {
+        x: (center.x - size / 2.0).round(),
+        y: (center.y - size / 2.0).round(),
+        width: size,
+        height: size,
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DualKnob<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer + iced_graphics::geometry::Renderer,
+{
+    fn from(dual_knob: DualKnob<'a, Message, Theme>) -> Self {
+        Self::new(dual_knob)
+    }
+}
@@ -0,0 +1,495 @@
+//! Display the gain reduction of a compressor or limiter as a bar that hangs
+//! down from 0 dB.
+
+use std::time::{Duration, Instant};
+
+use crate::core::{DBRange, Normal};
+use crate::{text_marks, tick_marks};
+use iced_core::{
+    Border, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shadow, Shell, Size,
+    Widget,
+    border::Radius,
+    layout, mouse,
+    renderer::{Quad, Style},
+    widget::{Tree, tree},
+    window,
+};
+
+pub use crate::style::gain_reduction_meter::{
+    Appearance, StyleSheet, TextMarksAppearance, TickMarksAppearance,
+};
+
+const DEFAULT_THICKNESS: f32 = 14.0;
+
+/// The longest time step the ballistics are advanced by in a single frame,
+/// so a stalled frame doesn't make the bar jump straight to its target.
+const MAX_BALLISTICS_STEP_SECS: f32 = 1.0 / 30.0;
+
+/// The distance in dB at which the displayed value snaps to the target.
+const SETTLED_DB: f32 = 0.01;
+
+/// The direction a [`GainReductionMeter`] is drawn in.
+///
+/// [`GainReductionMeter`]: struct.GainReductionMeter.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// 0 dB is at the top and the bar grows downward.
+    #[default]
+    Vertical,
+    /// 0 dB is at the right and the bar grows to the left.
+    Horizontal,
+}
+
+/// How fast the bar of a [`GainReductionMeter`] follows the gain reduction.
+///
+/// Both times are the time constant of an exponential smoother, the time it
+/// takes the bar to cover about 63% of the distance to a new value.
+///
+/// [`GainReductionMeter`]: struct.GainReductionMeter.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ballistics {
+    /// The time constant used while the gain reduction increases.
+    pub attack: Duration,
+    /// The time constant used while the gain reduction decreases.
+    pub release: Duration,
+}
+
+impl Ballistics {
+    /// The bar follows the gain reduction without any smoothing.
+    pub const INSTANT: Self = Self::new(Duration::ZERO, Duration::ZERO);
+
+    /// Creates new `Ballistics` from an attack and a release time constant.
+    pub const fn new(attack: Duration, release: Duration) -> Self {
+        Self { attack, release }
+    }
+
+    /// Moves `displayed` towards `target` over `dt` seconds.
+    fn step(&self, displayed: f32, target: f32, dt: f32) -> f32 {
+        let time = if target > displayed {
+            self.attack
+        } else {
+            self.release
+        };
+
+        let time = time.as_secs_f32();
+        if time <= 0.0 {
+            return target;
+        }
+
+        let next = displayed + (target - displayed) * (1.0 - (-dt / time).exp());
+
+        if (target - next).abs() < SETTLED_DB {
+            target
+        } else {
+            next
+        }
+    }
+}
+
+impl Default for Ballistics {
+    /// A fast 5 ms attack and a slow 300 ms release.
+    fn default() -> Self {
+        Self::new(Duration::from_millis(5), Duration::from_millis(300))
+    }
+}
+
+/// A gain reduction meter GUI widget.
+///
+/// The bar hangs from 0 dB at the top (or the right when horizontal) and
+/// grows towards the bottom of a [`DBRange`] as the gain reduction
+/// increases. The bar follows the gain reduction with configurable
+/// [`Ballistics`], so it can be fed the raw value from the audio thread.
+///
+/// [`DBRange`]: ../../core/range/struct.DBRange.html
+/// [`Ballistics`]: struct.Ballistics.html
+#[allow(missing_debug_implementations)]
+pub struct GainReductionMeter<'a, Theme: StyleSheet> {
+    gain_reduction: f32,
+    range: DBRange,
+    ballistics: Ballistics,
+    orientation: Orientation,
+    tick_marks: Option<&'a tick_marks::Group>,
+    text_marks: Option<&'a text_marks::Group>,
+    width: Option<Length>,
+    height: Option<Length>,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Theme> GainReductionMeter<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`GainReductionMeter`].
+    ///
+    /// * `gain_reduction` - the current gain reduction in dB. Both `6.0` and
+    ///   `-6.0` are shown as 6 dB of gain reduction.
+    ///
+    /// [`GainReductionMeter`]: struct.GainReductionMeter.html
+    pub fn new(gain_reduction: f32) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        GainReductionMeter {
+            gain_reduction: if gain_reduction.is_finite() {
+                gain_reduction.abs()
+            } else {
+                0.0
+            },
+            range: DBRange::new(-24.0, 0.0, Normal::MAX, 1.0),
+            ballistics: Ballistics::default(),
+            orientation: Orientation::default(),
+            tick_marks: None,
+            text_marks: None,
+            width: None,
+            height: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`DBRange`] of the meter. The gain reduction is shown as
+    /// negative decibels on this range, so its maximum should be `0.0`
+    /// with a `zero_position` of `Normal::MAX`.
+    ///
+    /// The default range is -24 dB to 0 dB.
+    ///
+    /// [`DBRange`]: ../../core/range/struct.DBRange.html
+    pub fn range(mut self, range: DBRange) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets the [`Ballistics`] of the bar.
+    ///
+    /// The default is a 5 ms attack and a 300 ms release.
+    ///
+    /// [`Ballistics`]: struct.Ballistics.html
+    pub fn ballistics(mut self, ballistics: Ballistics) -> Self {
+        self.ballistics = ballistics;
+        self
+    }
+
+    /// Sets the [`Orientation`] of the meter.
+    ///
+    /// The default is [`Orientation::Vertical`].
+    ///
+    /// [`Orientation`]: enum.Orientation.html
+    /// [`Orientation::Vertical`]: enum.Orientation.html#variant.Vertical
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the tick marks to display. Create them from the positions of
+    /// negative decibels on the meter's [`DBRange`] so they line up with the
+    /// bar. Note your [`StyleSheet`] must also implement
+    /// `tick_marks_appearance(&self) -> Option<TickMarksAppearance>` for them
+    /// to display (which the default style does).
+    ///
+    /// [`DBRange`]: ../../core/range/struct.DBRange.html
+    /// [`StyleSheet`]: ../../style/gain_reduction_meter/trait.StyleSheet.html
+    pub fn tick_marks(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display. Note your [`StyleSheet`] must also
+    /// implement `text_marks_appearance(&self) -> Option<TextMarksAppearance>`
+    /// for them to display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/gain_reduction_meter/trait.StyleSheet.html
+    pub fn text_marks(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks = Some(text_marks);
+        self
+    }
+
+    /// Sets the width of the [`GainReductionMeter`].
+    ///
+    /// The default width is `Length::Fixed(14.0)` when vertical and
+    /// `Length::Fill` when horizontal.
+    ///
+    /// [`GainReductionMeter`]: struct.GainReductionMeter.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the height of the [`GainReductionMeter`].
+    ///
+    /// The default height is `Length::Fill` when vertical and
+    /// `Length::Fixed(14.0)` when horizontal.
+    ///
+    /// [`GainReductionMeter`]: struct.GainReductionMeter.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets the style of the [`GainReductionMeter`].
+    ///
+    /// [`GainReductionMeter`]: struct.GainReductionMeter.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn resolved_size(&self) -> (Length, Length) {
+        let (width, height) = match self.orientation {
+            Orientation::Vertical => (Length::Fixed(DEFAULT_THICKNESS), Length::Fill),
+            Orientation::Horizontal => (Length::Fill, Length::Fixed(DEFAULT_THICKNESS)),
+        };
+
+        (self.width.unwrap_or(width), self.height.unwrap_or(height))
+    }
+}
+
+/// The local state of a [`GainReductionMeter`].
+///
+/// [`GainReductionMeter`]: struct.GainReductionMeter.html
+#[derive(Debug, Clone)]
+struct State {
+    displayed: f32,
+    last_instant: Option<Instant>,
+}
+
+fn fill<Renderer: iced_core::Renderer>(renderer: &mut Renderer, bounds: Rectangle, color: Color) {
+    if bounds.width > 0.0 && bounds.height > 0.0 {
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            color,
+        );
+    }
+}
+
+/// Returns the part of `bounds` between two positions on the meter.
+fn span(bounds: &Rectangle, orientation: Orientation, from: Normal, to: Normal) -> Rectangle {
+    match orientation {
+        Orientation::Vertical => {
+            let top = bounds.y + to.scale_inv(bounds.height);
+            let bottom = bounds.y + from.scale_inv(bounds.height);
+
+            Rectangle {
+                x: bounds.x,
+                y: top.round(),
+                width: bounds.width,
+                height: (bottom - top).round(),
+            }
+        }
+        Orientation::Horizontal => {
+            let left = bounds.x + from.scale(bounds.width);
+            let right = bounds.x + to.scale(bounds.width);
+
+            Rectangle {
+                x: left.round(),
+                y: bounds.y,
+                width: (right - left).round(),
+                height: bounds.height,
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for GainReductionMeter<'a, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            displayed: self.gain_reduction,
+            last_instant: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        let (width, height) = self.resolved_size();
+
+        Size { width, height }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (width, height) = self.resolved_size();
+
+        layout::Node::new(limits.resolve(width, height, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.displayed == self.gain_reduction {
+            state.last_instant = None;
+            return;
+        }
+
+        if let Some(last_instant) = state.last_instant {
+            let dt = now
+                .saturating_duration_since(last_instant)
+                .as_secs_f32()
+                .min(MAX_BALLISTICS_STEP_SECS);
+
+            state.displayed = self
+                .ballistics
+                .step(state.displayed, self.gain_reduction, dt);
+        }
+        state.last_instant = Some(*now);
+
+        if state.displayed != self.gain_reduction {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = layout.bounds();
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        if let Some(tick_marks) = self.tick_marks
+            && let Some(style) = theme.tick_marks_appearance(&self.style)
+        {
+            match self.orientation {
+                Orientation::Vertical => tick_marks::draw_vertical_tick_marks(
+                    renderer,
+                    &bounds,
+                    tick_marks,
+                    &style.style,
+                    &style.vertical_placement,
+                    false,
+                ),
+                Orientation::Horizontal => tick_marks::draw_horizontal_tick_marks(
+                    renderer,
+                    &bounds,
+                    tick_marks,
+                    &style.style,
+                    &style.horizontal_placement,
+                    false,
+                ),
+            }
+        }
+
+        if let Some(text_marks) = self.text_marks
+            && let Some(style) = theme.text_marks_appearance(&self.style)
+        {
+            match self.orientation {
+                Orientation::Vertical => text_marks::draw_vertical_text_marks(
+                    renderer,
+                    &bounds,
+                    text_marks,
+                    &style.style,
+                    &style.vertical_placement,
+                    false,
+                ),
+                Orientation::Horizontal => text_marks::draw_horizontal_text_marks(
+                    renderer,
+                    &bounds,
+                    text_marks,
+                    &style.style,
+                    &style.horizontal_placement,
+                    false,
+                ),
+            }
+        }
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        let border_width = appearance.back_border_width;
+        let inner = Rectangle {
+            x: bounds.x + border_width,
+            y: bounds.y + border_width,
+            width: bounds.width - (border_width * 2.0),
+            height: bounds.height - (border_width * 2.0),
+        };
+
+        let zero = self.range.map_db(0.0);
+        let value = self.range.map_db(-state.displayed);
+
+        match appearance.heavy_color {
+            Some(heavy_color) if state.displayed > appearance.heavy_threshold => {
+                let heavy = self.range.map_db(-appearance.heavy_threshold);
+
+                fill(
+                    renderer,
+                    span(&inner, self.orientation, heavy, zero),
+                    appearance.color,
+                );
+                fill(
+                    renderer,
+                    span(&inner, self.orientation, value, heavy),
+                    heavy_color,
+                );
+            }
+            _ => fill(
+                renderer,
+                span(&inner, self.orientation, value, zero),
+                appearance.color,
+            ),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<GainReductionMeter<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    fn from(gain_reduction_meter: GainReductionMeter<'a, Theme>) -> Self {
+        Self::new(gain_reduction_meter)
+    }
+}
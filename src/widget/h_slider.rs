@@ -6,7 +6,7 @@ mod draw;
 mod value_markers;
 
 use crate::{
-    core::{ModulationRange, NormalParam},
+    core::{ModulationRange, NormalParam, ParamRange},
     text_marks, tick_marks,
     virtual_slider::{self, Gesture, VirtualSlider},
 };
@@ -20,9 +20,9 @@ use iced_core::{
 use value_markers::ValueMarkers;
 
 pub use crate::style::h_slider::{
-    Appearance, ClassicAppearance, ClassicHandle, ClassicRail, ModRangeAppearance,
-    ModRangePlacement, RectAppearance, RectBipolarAppearance, StyleSheet, TextMarksAppearance,
-    TextureAppearance, TickMarksAppearance,
+    Appearance, Catalog, ClassicAppearance, ClassicHandle, ClassicRail, ModRangeAppearance,
+    ModRangePlacement, RectAppearance, RectBipolarAppearance, Status, StyleFn, StyleSheet,
+    TextMarksAppearance, TextureAppearance, TickMarksAppearance,
 };
 
 const DEFAULT_HEIGHT: f32 = 14.0;
@@ -34,13 +34,13 @@ const DEFAULT_HEIGHT: f32 = 14.0;
 /// [`NormalParam`]: ../../core/normal_param/struct.Param.html
 /// [`HSlider`]: struct.HSlider.html
 #[allow(missing_debug_implementations)]
-pub struct HSlider<'a, Message, Theme: StyleSheet> {
+pub struct HSlider<'a, Message, Theme: Catalog> {
     virtual_slider: VirtualSlider<'a, Message>,
     drag_horizontally: bool,
     enabled: bool,
     width: Length,
     height: Length,
-    style: <Theme as StyleSheet>::Style,
+    class: Theme::Class<'a>,
     tick_marks: Option<&'a tick_marks::Group>,
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
@@ -49,22 +49,19 @@ pub struct HSlider<'a, Message, Theme: StyleSheet> {
 
 impl<'a, Message, Theme> HSlider<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     /// Creates a new [`HSlider`].
     ///
     /// * `normal_param` - The normalized value of the parameter.
-    pub fn new(normal_param: impl Into<NormalParam>) -> Self
-    where
-        <Theme as StyleSheet>::Style: Default,
-    {
+    pub fn new(normal_param: impl Into<NormalParam>) -> Self {
         HSlider {
             virtual_slider: VirtualSlider::new(normal_param.into()),
             drag_horizontally: true,
             enabled: true,
             width: Length::Fill,
             height: Length::Fixed(DEFAULT_HEIGHT),
-            style: Default::default(),
+            class: Theme::default(),
             tick_marks: None,
             text_marks: None,
             mod_range_1: None,
@@ -72,6 +69,19 @@ where
         }
     }
 
+    /// Creates a new [`HSlider`] for a parameter in any [`ParamRange`].
+    ///
+    /// * `value` - The initial value of the parameter.
+    /// * `range` - The range the value is mapped from. Its
+    ///   [`default_value`] is used as the default of the parameter.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`ParamRange`]: ../../core/range/trait.ParamRange.html
+    /// [`default_value`]: ../../core/range/trait.ParamRange.html#tymethod.default_value
+    pub fn from_range<R: ParamRange>(value: R::Value, range: &R) -> Self {
+        Self::new(range.param(value, range.default_value()))
+    }
+
     /// Sets the message to emit when the user gestures this widget.
     pub fn on_gesture(mut self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
         self.virtual_slider.set_on_gesture(on_gesture);
@@ -123,11 +133,30 @@ where
     /// Sets the style of the [`HSlider`].
     ///
     /// [`HSlider`]: struct.HSlider.html
-    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
-        self.style = style.into();
+    pub fn style(mut self, style: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = style.into();
         self
     }
 
+    /// Sets the style of the [`HSlider`] from a closure that produces its
+    /// [`Appearance`] for each [`Status`].
+    ///
+    /// Only the base [`Appearance`] comes from the closure. The tick marks,
+    /// text marks and modulation range keep the default style; use
+    /// [`style`] with a [`StyleSheet`] to change those.
+    ///
+    /// [`style`]: #method.style
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Appearance`]: enum.Appearance.html
+    /// [`Status`]: enum.Status.html
+    pub fn style_fn(self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.style(Box::new(style) as StyleFn<'a, Theme>)
+    }
+
     /// Sets the tick marks to display. Note your [`StyleSheet`] must
     /// also implement `tick_marks_style(&self) -> Option<tick_marks::Style>` for
     /// them to display (which the default style does).
@@ -171,10 +200,10 @@ where
     }
 }
 
-impl<'a, Message, Theme: StyleSheet, Renderer> Widget<Message, Theme, Renderer>
+impl<'a, Message, Theme: Catalog, Renderer> Widget<Message, Theme, Renderer>
     for HSlider<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
     Renderer: iced_core::Renderer
         + iced_core::text::Renderer<Font = iced_core::Font>
         + iced_core::image::Renderer<Handle = iced_core::image::Handle>,
@@ -250,13 +279,8 @@ where
         let cursor_is_over = cursor.is_over(bounds);
         let normal_val = self.virtual_slider.param().normal;
 
-        let appearance = if state.is_gesturing() {
-            theme.gesturing(&self.style)
-        } else if cursor_is_over {
-            theme.hovered(&self.style)
-        } else {
-            theme.idle(&self.style)
-        };
+        let status = Status::new(self.enabled, state.is_gesturing(), cursor_is_over);
+        let appearance = Catalog::style(theme, &self.class, status);
 
         let bounds = Rectangle {
             x: bounds.x.round(),
@@ -270,10 +294,10 @@ where
             text_marks: self.text_marks,
             mod_range_1: self.mod_range_1,
             mod_range_2: self.mod_range_2,
-            tick_marks_style: theme.tick_marks_appearance(&self.style),
-            text_marks_style: theme.text_marks_appearance(&self.style),
-            mod_range_style_1: theme.mod_range_appearance(&self.style),
-            mod_range_style_2: theme.mod_range_appearance_2(&self.style),
+            tick_marks_style: theme.tick_marks_appearance(&self.class),
+            text_marks_style: theme.text_marks_appearance(&self.class),
+            mod_range_style_1: theme.mod_range_appearance(&self.class),
+            mod_range_style_2: theme.mod_range_appearance_2(&self.class),
         };
 
         match appearance {
@@ -297,7 +321,7 @@ impl<'a, Message, Theme, Renderer> From<HSlider<'a, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a,
-    Theme: 'a + StyleSheet,
+    Theme: 'a + Catalog,
     Renderer: iced_core::Renderer
         + iced_core::text::Renderer<Font = iced_core::Font>
         + iced_core::image::Renderer<Handle = iced_core::image::Handle>,
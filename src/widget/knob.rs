@@ -3,12 +3,12 @@
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
 mod bipolar_state;
-mod draw;
-mod knob_info;
+pub(crate) mod draw;
+pub(crate) mod knob_info;
 mod value_markers;
 
 use crate::{
-    core::{ModulationRange, Normal, NormalParam},
+    core::{ModulationRange, Normal, NormalParam, ParamRange},
     text_marks, tick_marks,
     virtual_slider::{self, Gesture, VirtualSlider},
 };
@@ -21,9 +21,9 @@ use knob_info::KnobInfo;
 use value_markers::ValueMarkers;
 
 pub use crate::style::knob::{
-    Appearance, ArcAppearance, ArcBipolarAppearance, CircleAppearance, CircleNotch, LineNotch,
-    ModRangeArcAppearance, NotchShape, StyleLength, StyleSheet, TextMarksAppearance,
-    TickMarksAppearance, ValueArcAppearance,
+    Appearance, ArcAppearance, ArcBipolarAppearance, Catalog, CircleAppearance, CircleNotch,
+    LineNotch, ModRangeArcAppearance, NotchShape, Status, StyleFn, StyleLength, StyleSheet,
+    TextMarksAppearance, TickMarksAppearance, ValueArcAppearance,
 };
 
 const DEFAULT_SIZE: f32 = 30.0;
@@ -32,32 +32,29 @@ const DEFAULT_SIZE: f32 = 30.0;
 ///
 /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
 #[allow(missing_debug_implementations)]
-pub struct Knob<'a, Message, Theme: StyleSheet> {
+pub struct Knob<'a, Message, Theme: Catalog> {
     virtual_slider: VirtualSlider<'a, Message>,
     enabled: bool,
     size: Length,
     bipolar_center: Option<Normal>,
-    style: <Theme as StyleSheet>::Style,
+    class: Theme::Class<'a>,
     tick_marks: Option<&'a tick_marks::Group>,
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
 }
 
-impl<'a, Message, Theme: StyleSheet> Knob<'a, Message, Theme> {
+impl<'a, Message, Theme: Catalog> Knob<'a, Message, Theme> {
     /// Creates a new [`Knob`].
     ///
     /// * `normal_param` - The normalized value of the parameter.
-    pub fn new(normal_param: impl Into<NormalParam>) -> Self
-    where
-        <Theme as StyleSheet>::Style: Default,
-    {
+    pub fn new(normal_param: impl Into<NormalParam>) -> Self {
         Knob {
             virtual_slider: VirtualSlider::new(normal_param.into()),
             enabled: true,
             size: Length::Fixed(DEFAULT_SIZE),
             bipolar_center: None,
-            style: Default::default(),
+            class: Theme::default(),
             tick_marks: None,
             text_marks: None,
             mod_range_1: None,
@@ -65,6 +62,19 @@ impl<'a, Message, Theme: StyleSheet> Knob<'a, Message, Theme> {
         }
     }
 
+    /// Creates a new [`Knob`] for a parameter in any [`ParamRange`].
+    ///
+    /// * `value` - The initial value of the parameter.
+    /// * `range` - The range the value is mapped from. Its
+    ///   [`default_value`] is used as the default of the parameter.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`ParamRange`]: ../../core/range/trait.ParamRange.html
+    /// [`default_value`]: ../../core/range/trait.ParamRange.html#tymethod.default_value
+    pub fn from_range<R: ParamRange>(value: R::Value, range: &R) -> Self {
+        Self::new(range.param(value, range.default_value()))
+    }
+
     /// Sets the message to emit when the user gestures this widget.
     pub fn on_gesture(mut self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
         self.virtual_slider.set_on_gesture(on_gesture);
@@ -97,11 +107,30 @@ impl<'a, Message, Theme: StyleSheet> Knob<'a, Message, Theme> {
     /// Sets the style of the [`Knob`].
     ///
     /// [`Knob`]: struct.Knob.html
-    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
-        self.style = style.into();
+    pub fn style(mut self, style: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = style.into();
         self
     }
 
+    /// Sets the style of the [`Knob`] from a closure that produces its
+    /// [`Appearance`] for each [`Status`].
+    ///
+    /// Only the base [`Appearance`] comes from the closure. The angle range,
+    /// tick marks, text marks, value arc and modulation range arcs keep the
+    /// default style; use [`style`] with a [`StyleSheet`] to change those.
+    ///
+    /// [`style`]: #method.style
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    /// [`Knob`]: struct.Knob.html
+    /// [`Appearance`]: enum.Appearance.html
+    /// [`Status`]: enum.Status.html
+    pub fn style_fn(self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.style(Box::new(style) as StyleFn<'a, Theme>)
+    }
+
     /// Sets the tick marks to display. Note your [`StyleSheet`] must
     /// also implement `tick_marks_style(&self) -> Option<tick_marks::Style>` for
     /// them to display (which the default style does).
@@ -157,7 +186,7 @@ impl<'a, Message, Theme: StyleSheet> Knob<'a, Message, Theme> {
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Knob<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
     Renderer: iced_core::Renderer
         + iced_core::text::Renderer<Font = iced_core::Font>
         + iced_graphics::geometry::Renderer,
@@ -229,27 +258,22 @@ where
 
         let bounds = layout.bounds();
         let cursor_is_over = cursor.is_over(bounds);
-        let angle_range = theme.angle_range(&self.style);
+        let angle_range = theme.angle_range(&self.class);
         let normal_val = self.virtual_slider.param().normal;
 
-        let appearance = if state.is_gesturing() {
-            theme.gesturing(&self.style)
-        } else if cursor_is_over {
-            theme.hovered(&self.style)
-        } else {
-            theme.idle(&self.style)
-        };
+        let status = Status::new(self.enabled, state.is_gesturing(), cursor_is_over);
+        let appearance = Catalog::style(theme, &self.class, status);
 
         let value_markers = ValueMarkers {
             tick_marks: self.tick_marks,
             text_marks: self.text_marks,
             mod_range_1: self.mod_range_1,
             mod_range_2: self.mod_range_2,
-            tick_marks_style: theme.tick_marks_appearance(&self.style),
-            text_marks_style: theme.text_marks_appearance(&self.style),
-            value_arc_style: theme.value_arc_appearance(&self.style),
-            mod_range_style_1: theme.mod_range_arc_appearance(&self.style),
-            mod_range_style_2: theme.mod_range_arc_appearance_2(&self.style),
+            tick_marks_style: theme.tick_marks_appearance(&self.class),
+            text_marks_style: theme.text_marks_appearance(&self.class),
+            value_arc_style: theme.value_arc_appearance(&self.class),
+            mod_range_style_1: theme.mod_range_arc_appearance(&self.class),
+            mod_range_style_2: theme.mod_range_arc_appearance_2(&self.class),
         };
 
         let bounds = {
@@ -279,25 +303,7 @@ where
             }
         };
 
-        let radius = bounds.width / 2.0;
-
-        let start_angle = if angle_range.min() >= crate::core::math::THREE_HALVES_PI {
-            angle_range.min() - crate::core::math::THREE_HALVES_PI
-        } else {
-            angle_range.min() + std::f32::consts::FRAC_PI_2
-        };
-        let angle_span = angle_range.max() - angle_range.min();
-        let value_angle = start_angle + (normal_val.scale(angle_span));
-
-        let knob_info = KnobInfo {
-            bounds,
-            start_angle,
-            angle_span,
-            radius,
-            value: normal_val,
-            bipolar_center: self.bipolar_center,
-            value_angle,
-        };
+        let knob_info = KnobInfo::new(bounds, &angle_range, normal_val, self.bipolar_center);
 
         match appearance {
             Appearance::Circle(style) => {
@@ -316,7 +322,7 @@ impl<'a, Message, Theme, Renderer> From<Knob<'a, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a,
-    Theme: 'a + StyleSheet,
+    Theme: 'a + Catalog,
     Renderer: iced_core::Renderer
         + iced_core::text::Renderer<Font = iced_core::Font>
         + iced_graphics::geometry::Renderer,
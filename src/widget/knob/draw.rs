@@ -109,7 +109,7 @@ fn text_marks<R: iced_core::Renderer + iced_core::text::Renderer<Font = iced_cor
     }
 }
 
-fn value_arc<R: iced_core::Renderer + iced_graphics::geometry::Renderer>(
+pub fn value_arc<R: iced_core::Renderer + iced_graphics::geometry::Renderer>(
     renderer: &mut R,
     knob_info: &KnobInfo,
     style: &Option<ValueArcAppearance>,
@@ -394,7 +394,7 @@ fn line_notch<R: iced_core::Renderer + iced_graphics::geometry::Renderer>(
     );
 }
 
-fn notch<R: iced_core::Renderer + iced_graphics::geometry::Renderer>(
+pub fn notch<R: iced_core::Renderer + iced_graphics::geometry::Renderer>(
     renderer: &mut R,
     knob_info: &KnobInfo,
     notch: &NotchShape,
@@ -1,4 +1,4 @@
-use crate::Normal;
+use crate::{KnobAngleRange, Normal};
 use iced_core::Rectangle;
 
 pub struct KnobInfo {
@@ -10,3 +10,30 @@ pub struct KnobInfo {
     pub bipolar_center: Option<Normal>,
     pub value_angle: f32,
 }
+
+impl KnobInfo {
+    /// Lays out a knob in the square `bounds`, turning through `angle_range`.
+    pub fn new(
+        bounds: Rectangle,
+        angle_range: &KnobAngleRange,
+        value: Normal,
+        bipolar_center: Option<Normal>,
+    ) -> Self {
+        let start_angle = if angle_range.min() >= crate::core::math::THREE_HALVES_PI {
+            angle_range.min() - crate::core::math::THREE_HALVES_PI
+        } else {
+            angle_range.min() + std::f32::consts::FRAC_PI_2
+        };
+        let angle_span = angle_range.max() - angle_range.min();
+
+        Self {
+            bounds,
+            start_angle,
+            angle_span,
+            radius: bounds.width / 2.0,
+            value,
+            bipolar_center,
+            value_angle: start_angle + value.scale(angle_span),
+        }
+    }
+}
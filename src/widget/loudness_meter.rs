@@ -0,0 +1,465 @@
+//! Display the momentary, short-term and integrated loudness, the loudness
+//! range and the true peak measured by a [`loudness::Analyzer`].
+//!
+//! [`loudness::Analyzer`]: ../../core/loudness/struct.Analyzer.html
+
+use crate::core::{Normal, loudness::Measurement};
+use crate::{text_marks, tick_marks};
+use iced_core::{
+    Border, Color, Element, Layout, Length, Rectangle, Shadow, Size, Widget,
+    border::Radius,
+    layout, mouse,
+    renderer::{Quad, Style},
+    widget::Tree,
+};
+
+pub use crate::style::loudness_meter::{
+    Appearance, StyleSheet, TextMarksAppearance, TickMarksAppearance,
+};
+
+const DEFAULT_WIDTH: f32 = 48.0;
+const DEFAULT_HEIGHT: f32 = 240.0;
+
+/// The range of a [`LoudnessMeter`] in LU, relative to the target loudness.
+///
+/// [`LoudnessMeter`]: struct.LoudnessMeter.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Scale {
+    min: f32,
+    max: f32,
+}
+
+impl Scale {
+    /// The EBU +9 scale, from -18 LU to +9 LU.
+    pub const EBU_PLUS_9: Self = Self::new(-18.0, 9.0);
+    /// The EBU +18 scale, from -36 LU to +18 LU.
+    pub const EBU_PLUS_18: Self = Self::new(-36.0, 18.0);
+
+    /// Creates a new `Scale` from `min` LU at the bottom to `max` LU at the
+    /// top.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max <= min`.
+    pub const fn new(min: f32, max: f32) -> Self {
+        assert!(max > min, "max must be > min");
+
+        Self { min, max }
+    }
+
+    /// Returns the position of `lu` on this scale.
+    pub fn normal(&self, lu: f32) -> Normal {
+        Normal::new((lu - self.min) / (self.max - self.min))
+    }
+
+    /// Returns tick marks every `step` LU, with a larger tick mark at the
+    /// target (0 LU).
+    pub fn tick_marks(&self, step: f32) -> tick_marks::Group {
+        let marks: Vec<(Normal, tick_marks::Tier)> = self
+            .steps(step)
+            .map(|lu| {
+                let tier = if lu == 0.0 {
+                    tick_marks::Tier::One
+                } else {
+                    tick_marks::Tier::Two
+                };
+                (self.normal(lu), tier)
+            })
+            .collect();
+
+        tick_marks::Group::from_normalized(&marks)
+    }
+
+    /// Returns text marks every `step` LU, labeled relative to the target
+    /// (such as "+9", "0" and "-9").
+    pub fn text_marks(&self, step: f32) -> text_marks::Group {
+        self.steps(step)
+            .map(|lu| {
+                let text = if lu == 0.0 {
+                    String::from("0")
+                } else {
+                    format!("{lu:+}")
+                };
+                (self.normal(lu), text)
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn steps(&self, step: f32) -> impl Iterator<Item = f32> {
+        let step = step.abs().max(f32::EPSILON);
+        let first = (self.min / step).ceil() as i32;
+        let last = (self.max / step).floor() as i32;
+
+        (first..=last).map(move |i| i as f32 * step)
+    }
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Self::EBU_PLUS_9
+    }
+}
+
+/// A loudness meter GUI widget.
+///
+/// It shows a [`Measurement`] as three bars on an LU scale: the momentary,
+/// short-term and integrated loudness, with the loudness range drawn over
+/// the integrated bar. A line marks the target loudness, and an indicator
+/// above the bars lights up once the true peak has gone over the limit.
+///
+/// [`Measurement`]: ../../core/loudness/struct.Measurement.html
+#[allow(missing_debug_implementations)]
+pub struct LoudnessMeter<'a, Theme: StyleSheet> {
+    measurement: Measurement,
+    target: f32,
+    scale: Scale,
+    true_peak_limit: f32,
+    tick_marks: Option<&'a tick_marks::Group>,
+    text_marks: Option<&'a text_marks::Group>,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Theme> LoudnessMeter<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`LoudnessMeter`] showing the given [`Measurement`].
+    ///
+    /// [`LoudnessMeter`]: struct.LoudnessMeter.html
+    /// [`Measurement`]: ../../core/loudness/struct.Measurement.html
+    pub fn new(measurement: Measurement) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        LoudnessMeter {
+            measurement,
+            target: -23.0,
+            scale: Scale::default(),
+            true_peak_limit: -1.0,
+            tick_marks: None,
+            text_marks: None,
+            width: Length::Fixed(DEFAULT_WIDTH),
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the target loudness in LUFS. This is 0 LU on the scale.
+    ///
+    /// The default is `-23.0` (EBU R128).
+    pub fn target(mut self, target: f32) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Sets the [`Scale`] of the meter.
+    ///
+    /// The default is [`Scale::EBU_PLUS_9`].
+    ///
+    /// [`Scale`]: struct.Scale.html
+    /// [`Scale::EBU_PLUS_9`]: struct.Scale.html#associatedconstant.EBU_PLUS_9
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the true peak in dBTP above which the true peak indicator
+    /// lights up.
+    ///
+    /// The default is `-1.0`.
+    pub fn true_peak_limit(mut self, limit: f32) -> Self {
+        self.true_peak_limit = limit;
+        self
+    }
+
+    /// Sets the tick marks to display. [`Scale::tick_marks`] creates tick
+    /// marks that line up with the scale. Note your [`StyleSheet`] must
+    /// also implement `tick_marks_appearance(&self) -> Option<TickMarksAppearance>`
+    /// for them to display (which the default style does).
+    ///
+    /// [`Scale::tick_marks`]: struct.Scale.html#method.tick_marks
+    /// [`StyleSheet`]: ../../style/loudness_meter/trait.StyleSheet.html
+    pub fn tick_marks(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display. [`Scale::text_marks`] creates text
+    /// marks that line up with the scale. Note your [`StyleSheet`] must
+    /// also implement `text_marks_appearance(&self) -> Option<TextMarksAppearance>`
+    /// for them to display (which the default style does).
+    ///
+    /// [`Scale::text_marks`]: struct.Scale.html#method.text_marks
+    /// [`StyleSheet`]: ../../style/loudness_meter/trait.StyleSheet.html
+    pub fn text_marks(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks = Some(text_marks);
+        self
+    }
+
+    /// Sets the width of the [`LoudnessMeter`].
+    ///
+    /// The default width is `Length::Fixed(48.0)`.
+    ///
+    /// [`LoudnessMeter`]: struct.LoudnessMeter.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`LoudnessMeter`].
+    ///
+    /// The default height is `Length::Fixed(240.0)`.
+    ///
+    /// [`LoudnessMeter`]: struct.LoudnessMeter.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`LoudnessMeter`].
+    ///
+    /// [`LoudnessMeter`]: struct.LoudnessMeter.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the position of a loudness in LUFS on the scale.
+    fn normal(&self, lufs: f32) -> Normal {
+        if lufs.is_finite() {
+            self.scale.normal(lufs - self.target)
+        } else {
+            Normal::MIN
+        }
+    }
+}
+
+fn fill<Renderer: iced_core::Renderer>(renderer: &mut Renderer, bounds: Rectangle, color: Color) {
+    if bounds.width > 0.0 && bounds.height > 0.0 {
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            color,
+        );
+    }
+}
+
+fn back<Renderer: iced_core::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+    appearance: &Appearance,
+) {
+    renderer.fill_quad(
+        Quad {
+            bounds,
+            border: Border {
+                color: appearance.back_border_color,
+                width: appearance.back_border_width,
+                radius: Radius::new(appearance.back_border_radius),
+            },
+            shadow: Shadow::default(),
+            snap: false,
+        },
+        color,
+    );
+}
+
+/// Returns the part of `bounds` between two positions, measured from the
+/// bottom.
+fn span(bounds: &Rectangle, from: Normal, to: Normal) -> Rectangle {
+    let top = bounds.y + to.scale_inv(bounds.height);
+    let bottom = bounds.y + from.scale_inv(bounds.height);
+
+    Rectangle {
+        x: bounds.x,
+        y: top.round(),
+        width: bounds.width,
+        height: (bottom - top).round(),
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for LoudnessMeter<'a, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let gap = appearance.bar_gap;
+
+        let peak_bounds = Rectangle {
+            height: appearance.peak_height,
+            ..bounds
+        };
+        let peak_color = if self.measurement.true_peak > self.true_peak_limit {
+            appearance.peak_over_color
+        } else {
+            appearance.peak_color
+        };
+        back(renderer, peak_bounds, peak_color, &appearance);
+
+        let bars_top = (bounds.y + appearance.peak_height + gap).round();
+        let bars_bounds = Rectangle {
+            y: bars_top,
+            height: bounds.y + bounds.height - bars_top,
+            ..bounds
+        };
+
+        let bar_width = ((bars_bounds.width - gap * 2.0) / 3.0).floor();
+        let target = self.scale.normal(0.0);
+        let border_width = appearance.back_border_width;
+
+        let bars = [
+            (self.measurement.momentary, appearance.momentary_color),
+            (self.measurement.short_term, appearance.short_term_color),
+            (self.measurement.integrated, appearance.integrated_color),
+        ];
+
+        for (i, (lufs, color)) in bars.into_iter().enumerate() {
+            let x = if i == 2 {
+                // Keep the last bar flush with the right edge.
+                bars_bounds.x + bars_bounds.width - bar_width
+            } else {
+                bars_bounds.x + (bar_width + gap) * i as f32
+            };
+
+            let bar_bounds = Rectangle {
+                x,
+                width: bar_width,
+                ..bars_bounds
+            };
+            back(renderer, bar_bounds, appearance.back_color, &appearance);
+
+            let inner = Rectangle {
+                x: bar_bounds.x + border_width,
+                y: bar_bounds.y + border_width,
+                width: bar_bounds.width - (border_width * 2.0),
+                height: bar_bounds.height - (border_width * 2.0),
+            };
+
+            let value = self.normal(lufs);
+
+            match appearance.above_target_color {
+                Some(above_target_color) if value > target => {
+                    fill(renderer, span(&inner, Normal::MIN, target), color);
+                    fill(renderer, span(&inner, target, value), above_target_color);
+                }
+                _ => fill(renderer, span(&inner, Normal::MIN, value), color),
+            }
+
+            if i == 2
+                && let Some(range_color) = appearance.range_color
+                && self.measurement.range_low.is_finite()
+                && self.measurement.range_high.is_finite()
+            {
+                let range = span(
+                    &inner,
+                    self.normal(self.measurement.range_low),
+                    self.normal(self.measurement.range_high),
+                );
+                fill(renderer, range, range_color);
+            }
+        }
+
+        // The bars fill inside their borders, so the marks and the target
+        // line are placed on the same inset span.
+        let marks_bounds = Rectangle {
+            y: bars_bounds.y + border_width,
+            height: bars_bounds.height - (border_width * 2.0),
+            ..bars_bounds
+        };
+
+        // Marks go on top of the bars so they stay visible.
+        if let Some(tick_marks) = self.tick_marks
+            && let Some(style) = theme.tick_marks_appearance(&self.style)
+        {
+            tick_marks::draw_vertical_tick_marks(
+                renderer,
+                &marks_bounds,
+                tick_marks,
+                &style.style,
+                &style.placement,
+                false,
+            );
+        }
+
+        if let Some(text_marks) = self.text_marks
+            && let Some(style) = theme.text_marks_appearance(&self.style)
+        {
+            text_marks::draw_vertical_text_marks(
+                renderer,
+                &marks_bounds,
+                text_marks,
+                &style.style,
+                &style.placement,
+                false,
+            );
+        }
+
+        let target_y = marks_bounds.y + target.scale_inv(marks_bounds.height);
+        fill(
+            renderer,
+            Rectangle {
+                x: bars_bounds.x,
+                y: (target_y - appearance.target_line_width / 2.0).round(),
+                width: bars_bounds.width,
+                height: appearance.target_line_width,
+            },
+            appearance.target_line_color,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<LoudnessMeter<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    fn from(loudness_meter: LoudnessMeter<'a, Theme>) -> Self {
+        Self::new(loudness_meter)
+    }
+}
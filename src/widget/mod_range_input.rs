@@ -15,7 +15,8 @@ use iced_core::{
 };
 
 pub use crate::style::mod_range_input::{
-    Appearance, CircleAppearance, InvisibleStyle, SquareAppearance, StyleSheet,
+    Appearance, Catalog, CircleAppearance, InvisibleStyle, SquareAppearance, Status, StyleFn,
+    StyleSheet,
 };
 
 const DEFAULT_SIZE: f32 = 10.0;
@@ -24,31 +25,28 @@ const DEFAULT_SIZE: f32 = 10.0;
 ///
 /// [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 #[allow(missing_debug_implementations)]
-pub struct ModRangeInput<'a, Message, Theme: StyleSheet> {
+pub struct ModRangeInput<'a, Message, Theme: Catalog> {
     virtual_slider: VirtualSlider<'a, Message>,
     size: Length,
     enabled: bool,
     bipolar: bool,
-    style: <Theme as StyleSheet>::Style,
+    class: Theme::Class<'a>,
 }
 
 impl<'a, Message, Theme> ModRangeInput<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     /// Creates a new [`ModRangeInput`].
     ///
     /// * `normal_param` - The normalized value of the parameter.
-    pub fn new(normal_param: impl Into<NormalParam>) -> Self
-    where
-        <Theme as StyleSheet>::Style: Default,
-    {
+    pub fn new(normal_param: impl Into<NormalParam>) -> Self {
         ModRangeInput {
             virtual_slider: VirtualSlider::new(normal_param.into()),
             size: Length::Fixed(DEFAULT_SIZE),
             enabled: true,
             bipolar: true,
-            style: Default::default(),
+            class: Theme::default(),
         }
     }
 
@@ -85,11 +83,24 @@ where
     /// Sets the style of the [`ModRangeInput`].
     ///
     /// [`ModRangeInput`]: struct.ModRangeInput.html
-    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
-        self.style = style.into();
+    pub fn style(mut self, style: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = style.into();
         self
     }
 
+    /// Sets the style of the [`ModRangeInput`] from a closure that produces its
+    /// [`Appearance`] for each [`Status`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Appearance`]: enum.Appearance.html
+    /// [`Status`]: enum.Status.html
+    pub fn style_fn(self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.style(Box::new(style) as StyleFn<'a, Theme>)
+    }
+
     /// Enable/disable this widget.
     ///
     /// The default is `true`.
@@ -102,7 +113,7 @@ where
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for ModRangeInput<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
     Renderer: iced_core::Renderer,
 {
     fn tag(&self) -> tree::Tag {
@@ -180,15 +191,8 @@ where
         let bounds = layout.bounds();
         let is_over = cursor.is_over(layout.bounds());
 
-        let appearance = if !self.enabled {
-            theme.disabled(&self.style)
-        } else if state.is_gesturing() {
-            theme.gesturing(&self.style)
-        } else if is_over {
-            theme.hovered(&self.style)
-        } else {
-            theme.idle(&self.style)
-        };
+        let status = Status::new(self.enabled, state.is_gesturing(), is_over);
+        let appearance = Catalog::style(theme, &self.class, status);
 
         match appearance {
             Appearance::Circle(style) => {
@@ -250,7 +254,7 @@ impl<'a, Message, Theme, Renderer> From<ModRangeInput<'a, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a,
-    Theme: 'a + StyleSheet,
+    Theme: 'a + Catalog,
     Renderer: iced_core::Renderer,
 {
     fn from(mod_range_input: ModRangeInput<'a, Message, Theme>) -> Self {
@@ -0,0 +1,515 @@
+//! Display a row of bars that each control a [`Normal`] value. It is usually
+//! used to edit additive synth harmonics, wavetables, and per-step values.
+//!
+//! [`Normal`]: ../core/normal/struct.Normal.html
+
+use super::stroke::{Cells, Drag};
+use crate::core::Normal;
+use iced_core::{
+    Border, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shadow, Shell,
+    Size, Widget,
+    border::Radius,
+    keyboard, layout, mouse,
+    renderer::{Quad, Style},
+    touch,
+    widget::{Tree, tree},
+    window,
+};
+use std::cell::Cell;
+
+pub use crate::style::multi_slider::{Appearance, StyleSheet};
+
+const DEFAULT_HEIGHT: f32 = 100.0;
+
+/// A message emitted by a [`MultiSlider`] when the user edits its bars.
+///
+/// [`MultiSlider`]: struct.MultiSlider.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BarGesture {
+    /// The user has just started editing the bars.
+    ///
+    /// This will always be sent before [`BarGesture::Gesturing`].
+    GestureStart,
+    /// The bar at `index` has a new value.
+    Gesturing {
+        /// The index of the bar, starting from the left.
+        index: usize,
+        /// The new normalized value of the bar.
+        normal: Normal,
+    },
+    /// The user has finished editing the bars.
+    GestureEnd,
+}
+
+/// A multi-slider GUI widget that displays a row of bars, each controlling a
+/// [`Normal`] value.
+///
+/// Dragging across the widget "paints" values into every bar the cursor
+/// crosses. Dragging while holding down the line modifier key (`Alt` by
+/// default) draws a straight line from where the drag started instead.
+///
+/// [`Normal`]: ../../core/normal/struct.Normal.html
+/// [`MultiSlider`]: struct.MultiSlider.html
+#[allow(missing_debug_implementations)]
+pub struct MultiSlider<'a, Message, Theme: StyleSheet> {
+    values: Vec<Normal>,
+    on_gesture: Option<Box<dyn 'a + FnMut(BarGesture) -> Message>>,
+    bipolar_center: Option<Normal>,
+    line_modifiers: keyboard::Modifiers,
+    enabled: bool,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> MultiSlider<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`MultiSlider`] with one bar for each value.
+    ///
+    /// [`MultiSlider`]: struct.MultiSlider.html
+    pub fn new(values: &[Normal]) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        MultiSlider {
+            values: values.to_vec(),
+            on_gesture: None,
+            bipolar_center: None,
+            line_modifiers: keyboard::Modifiers::ALT,
+            enabled: true,
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the message to emit when the user edits the bars.
+    pub fn on_gesture(mut self, on_gesture: impl 'a + FnMut(BarGesture) -> Message) -> Self {
+        self.on_gesture = Some(Box::new(on_gesture));
+        self
+    }
+
+    /// Makes the bars bipolar, so that they are filled from the given center
+    /// value instead of from the bottom.
+    pub const fn bipolar_center(mut self, center: Normal) -> Self {
+        self.bipolar_center = Some(center);
+        self
+    }
+
+    /// Sets the modifier key/keys to hold down to draw a straight line.
+    ///
+    /// The default key is `Alt`.
+    pub const fn line_modifiers(mut self, modifiers: keyboard::Modifiers) -> Self {
+        self.line_modifiers = modifiers;
+        self
+    }
+
+    /// Enable/disable this widget.
+    ///
+    /// The default is `true`.
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the width of the [`MultiSlider`].
+    ///
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`MultiSlider`]: struct.MultiSlider.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`MultiSlider`].
+    ///
+    /// The default height is `Length::Fixed(100.0)`.
+    ///
+    /// [`MultiSlider`]: struct.MultiSlider.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`MultiSlider`].
+    ///
+    /// [`MultiSlider`]: struct.MultiSlider.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn cells(&self, bounds: &Rectangle) -> Cells {
+        Cells::new(bounds, self.values.len(), true)
+    }
+
+    fn normal_at(bounds: &Rectangle, y: f32) -> Normal {
+        if bounds.height > 0.0 {
+            Normal::new(1.0 - ((y - bounds.y) / bounds.height))
+        } else {
+            Normal::MIN
+        }
+    }
+
+    /// Sets every bar between `from` and `to` to the value on the straight
+    /// line between the two points. Returns the range of bars that were set.
+    fn paint(
+        &mut self,
+        bounds: &Rectangle,
+        from: Point,
+        to: Point,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+    ) -> (usize, usize) {
+        let (covered, line) = self.cells(bounds).line(from, to);
+        for (index, y) in line {
+            self.set_value(index, Self::normal_at(bounds, y), state, shell);
+        }
+
+        covered
+    }
+
+    fn set_value(
+        &mut self,
+        index: usize,
+        normal: Normal,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if (self.values[index].as_f32() - normal.as_f32()).abs() <= f32::EPSILON {
+            return;
+        }
+        self.values[index] = normal;
+
+        if !state.is_gesturing {
+            // Send a GestureStart message first.
+            if let Some(on_gesture) = &mut self.on_gesture {
+                shell.publish((on_gesture)(BarGesture::GestureStart));
+            }
+            state.is_gesturing = true;
+        }
+
+        if let Some(on_gesture) = &mut self.on_gesture {
+            shell.publish((on_gesture)(BarGesture::Gesturing { index, normal }));
+        }
+    }
+
+    fn end_gesture(&mut self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        state.drag = None;
+
+        if state.is_gesturing {
+            if let Some(on_gesture) = &mut self.on_gesture {
+                shell.publish((on_gesture)(BarGesture::GestureEnd));
+            }
+            state.is_gesturing = false;
+        }
+    }
+}
+
+struct State {
+    drag: Option<Drag>,
+    is_gesturing: bool,
+    hovered: bool,
+    pressed_modifiers: keyboard::Modifiers,
+    /// The width of the back border as last drawn, so the cursor can be
+    /// mapped to the bars inside of it.
+    border_width: Cell<f32>,
+}
+
+/// Returns the area inside the back border, where the bars are drawn.
+fn inner_bounds(bounds: &Rectangle, border_width: f32) -> Rectangle {
+    Rectangle {
+        x: bounds.x.round() + border_width,
+        y: bounds.y.round() + border_width,
+        width: bounds.width.round() - (border_width * 2.0),
+        height: bounds.height.round() - (border_width * 2.0),
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MultiSlider<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            drag: None,
+            is_gesturing: false,
+            hovered: false,
+            pressed_modifiers: keyboard::Modifiers::NONE,
+            border_width: Cell::new(0.0),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.enabled || self.values.is_empty() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+        let inner = inner_bounds(&bounds, state.border_width.get());
+
+        let mut capture_event = false;
+        let mut should_redraw = false;
+
+        if cursor_is_over != state.hovered {
+            state.hovered = cursor_is_over;
+            should_redraw = true;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(mut drag) = state.drag.take() {
+                    let (from, to) = drag.segment_to(*position);
+                    let covered = self.paint(&inner, from, to, state, shell);
+
+                    for index in drag.cover(covered).into_iter().flatten() {
+                        self.set_value(index, drag.original()[index], state, shell);
+                    }
+
+                    state.drag = Some(drag);
+                    should_redraw = true;
+                    capture_event = true;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor_is_over && let Some(position) = cursor.position() {
+                    let original = self.values.clone();
+                    let covered = self.paint(&inner, position, position, state, shell);
+
+                    state.drag = if state.pressed_modifiers.contains(self.line_modifiers) {
+                        Some(Drag::Line {
+                            anchor: position,
+                            original,
+                            covered,
+                        })
+                    } else {
+                        Some(Drag::Freehand {
+                            prev_position: position,
+                        })
+                    };
+
+                    should_redraw = true;
+                    capture_event = true;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.is_some() {
+                    should_redraw = true;
+                    capture_event = true;
+                }
+                self.end_gesture(state, shell);
+            }
+            Event::Window(window::Event::Unfocused) => {
+                self.end_gesture(state, shell);
+            }
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. }
+                | keyboard::Event::KeyReleased { modifiers, .. }
+                | keyboard::Event::ModifiersChanged(modifiers) => {
+                    state.pressed_modifiers = *modifiers;
+                }
+            },
+            _ => {}
+        }
+
+        if capture_event {
+            shell.capture_event();
+        }
+
+        if should_redraw {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+
+        let appearance = if !self.enabled {
+            theme.disabled(&self.style)
+        } else if state.drag.is_some() {
+            theme.gesturing(&self.style)
+        } else if cursor_is_over {
+            theme.hovered(&self.style)
+        } else {
+            theme.idle(&self.style)
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        if self.values.is_empty() {
+            return;
+        }
+
+        state.border_width.set(appearance.back_border_width);
+        let inner = inner_bounds(&bounds, appearance.back_border_width);
+
+        let handle_height = f32::from(appearance.handle_height);
+        let bar_pitch = inner.width / self.values.len() as f32;
+        let bar_width = (bar_pitch - appearance.bar_spacing).max(1.0);
+
+        let center_y = self
+            .bipolar_center
+            .map(|center| (inner.y + center.scale_inv(inner.height)).round());
+
+        if let Some(center_y) = center_y {
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle {
+                        x: inner.x,
+                        y: center_y,
+                        width: inner.width,
+                        height: 1.0,
+                    },
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                appearance.center_line_color,
+            );
+        }
+
+        let fill = |renderer: &mut Renderer, x: f32, y: f32, height: f32, color: Color| {
+            if height > 0.0 {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle {
+                            x,
+                            y,
+                            width: bar_width,
+                            height,
+                        },
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                        snap: false,
+                    },
+                    color,
+                );
+            }
+        };
+
+        for (index, normal) in self.values.iter().enumerate() {
+            let x = (inner.x + (index as f32 * bar_pitch) + (appearance.bar_spacing / 2.0)).round();
+            let value_y = (inner.y + normal.scale_inv(inner.height)).round();
+
+            let handle_color = match (self.bipolar_center, center_y) {
+                (Some(center), Some(center_y)) => {
+                    let (filled_color, handle_color) =
+                        if (normal.as_f32() - center.as_f32()).abs() < 0.001 {
+                            (Color::TRANSPARENT, appearance.handle_center_color)
+                        } else if normal.as_f32() < center.as_f32() {
+                            (appearance.lower_filled_color, appearance.handle_lower_color)
+                        } else {
+                            (appearance.upper_filled_color, appearance.handle_upper_color)
+                        };
+
+                    let (top, bottom) = if value_y < center_y {
+                        (value_y, center_y)
+                    } else {
+                        (center_y, value_y)
+                    };
+                    fill(renderer, x, top, bottom - top, filled_color);
+
+                    handle_color
+                }
+                _ => {
+                    fill(
+                        renderer,
+                        x,
+                        value_y,
+                        inner.y + inner.height - value_y,
+                        appearance.filled_color,
+                    );
+
+                    appearance.handle_color
+                }
+            };
+
+            let handle_y = (value_y - (handle_height / 2.0))
+                .clamp(inner.y, inner.y + inner.height - handle_height);
+            fill(renderer, x, handle_y, handle_height, handle_color);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MultiSlider<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer,
+{
+    fn from(multi_slider: MultiSlider<'a, Message, Theme>) -> Self {
+        Self::new(multi_slider)
+    }
+}
@@ -0,0 +1,567 @@
+//! Display an MPC-style grid of trigger pads that emit note-on/note-off
+//! style messages.
+
+use std::time::{Duration, Instant};
+
+use crate::core::Normal;
+use iced_core::{
+    Border, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shadow, Shell,
+    Size, Widget,
+    border::Radius,
+    layout, mouse,
+    renderer::{Quad, Style},
+    touch,
+    widget::{Tree, tree},
+    window,
+};
+
+pub use crate::style::pad_grid::{Appearance, PadAppearance, StyleSheet};
+
+const DEFAULT_PAD_SIZE: f32 = 50.0;
+const DEFAULT_SPACING: f32 = 4.0;
+const DEFAULT_FLASH_DECAY_SECS: f32 = 0.3;
+
+/// A message emitted by a [`PadGrid`] when a pad is pressed or released.
+///
+/// Pads are indexed in row-major order starting from the top-left pad.
+///
+/// [`PadGrid`]: struct.PadGrid.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PadEvent {
+    /// A pad was pressed with the given velocity.
+    NoteOn {
+        /// The index of the pad.
+        pad: usize,
+        /// The velocity of the hit.
+        velocity: Normal,
+    },
+    /// A pad was released.
+    ///
+    /// This is only sent once every pointer holding the pad has been lifted.
+    NoteOff {
+        /// The index of the pad.
+        pad: usize,
+    },
+}
+
+/// How the velocity of a pad hit is determined.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum VelocityMode {
+    /// The velocity is derived from the vertical position of the hit inside
+    /// the pad. The top edge of the pad is full velocity, and the bottom edge
+    /// is `min`.
+    Vertical {
+        /// The velocity at the bottom edge of the pad.
+        min: Normal,
+    },
+    /// Every hit has the same velocity.
+    Fixed(Normal),
+    /// Every hit has full velocity.
+    #[default]
+    Full,
+}
+
+impl VelocityMode {
+    fn velocity(&self, pad_bounds: &Rectangle, position: Point) -> Normal {
+        match self {
+            VelocityMode::Vertical { min } => {
+                let y = if pad_bounds.height > 0.0 {
+                    1.0 - ((position.y - pad_bounds.y) / pad_bounds.height)
+                } else {
+                    1.0
+                };
+                let min = min.as_f32();
+                Normal::new(min + (y.clamp(0.0, 1.0) * (1.0 - min)))
+            }
+            VelocityMode::Fixed(velocity) => *velocity,
+            VelocityMode::Full => Normal::MAX,
+        }
+    }
+}
+
+/// An MPC-style grid of trigger pads.
+///
+/// Each pad emits a [`PadEvent::NoteOn`] when pressed and a
+/// [`PadEvent::NoteOff`] when released. Multiple pads can be held at once
+/// with multi-touch input.
+///
+/// Pads can also be flashed from outside the widget (i.e. when an incoming
+/// MIDI note triggers them) with [`PadGrid::flashes`].
+///
+/// [`PadEvent::NoteOn`]: enum.PadEvent.html
+/// [`PadEvent::NoteOff`]: enum.PadEvent.html
+/// [`PadGrid::flashes`]: struct.PadGrid.html#method.flashes
+#[allow(missing_debug_implementations)]
+pub struct PadGrid<'a, Message, Theme: StyleSheet> {
+    rows: usize,
+    columns: usize,
+    on_pad: Option<Box<dyn 'a + FnMut(PadEvent) -> Message>>,
+    velocity_mode: VelocityMode,
+    flashes: Option<&'a [Option<Instant>]>,
+    flash_decay: Duration,
+    enabled: bool,
+    spacing: f32,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme: StyleSheet> PadGrid<'a, Message, Theme> {
+    /// Creates a new [`PadGrid`].
+    ///
+    /// * `rows` - The number of rows of pads.
+    /// * `columns` - The number of columns of pads.
+    ///
+    /// [`PadGrid`]: struct.PadGrid.html
+    pub fn new(rows: usize, columns: usize) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        PadGrid {
+            rows,
+            columns,
+            on_pad: None,
+            velocity_mode: VelocityMode::default(),
+            flashes: None,
+            flash_decay: Duration::from_secs_f32(DEFAULT_FLASH_DECAY_SECS),
+            enabled: true,
+            spacing: DEFAULT_SPACING,
+            width: Length::Fixed(columns as f32 * DEFAULT_PAD_SIZE),
+            height: Length::Fixed(rows as f32 * DEFAULT_PAD_SIZE),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the message to emit when the user presses or releases a pad.
+    pub fn on_pad(mut self, on_pad: impl 'a + FnMut(PadEvent) -> Message) -> Self {
+        self.on_pad = Some(Box::new(on_pad));
+        self
+    }
+
+    /// Sets how the velocity of a hit is determined.
+    ///
+    /// The default is [`VelocityMode::Full`].
+    ///
+    /// [`VelocityMode::Full`]: enum.VelocityMode.html
+    pub fn velocity_mode(mut self, velocity_mode: VelocityMode) -> Self {
+        self.velocity_mode = velocity_mode;
+        self
+    }
+
+    /// Sets the instants at which each pad was last triggered from outside
+    /// of the widget (i.e. by an incoming MIDI note).
+    ///
+    /// The slice is indexed the same way as the pads. A triggered pad is
+    /// highlighted with the `flash_color` of its [`Appearance`], which then
+    /// fades out over the [`flash_decay`] duration.
+    ///
+    /// [`Appearance`]: ../../style/pad_grid/struct.Appearance.html
+    /// [`flash_decay`]: struct.PadGrid.html#method.flash_decay
+    pub fn flashes(mut self, flashes: &'a [Option<Instant>]) -> Self {
+        self.flashes = Some(flashes);
+        self
+    }
+
+    /// Sets how long it takes for the highlight of a triggered pad to fade
+    /// out.
+    ///
+    /// The default is `300` milliseconds.
+    pub fn flash_decay(mut self, flash_decay: Duration) -> Self {
+        self.flash_decay = flash_decay;
+        self
+    }
+
+    /// Enable/disable this widget.
+    ///
+    /// A disabled grid ignores new presses and sends a
+    /// [`PadEvent::NoteOff`] for every pad that was still held.
+    ///
+    /// The default is `true`.
+    ///
+    /// [`PadEvent::NoteOff`]: enum.PadEvent.html#variant.NoteOff
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the spacing between pads (and between the pads and the edges of
+    /// the widget) in pixels.
+    ///
+    /// The default is `4.0`.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the width of the [`PadGrid`].
+    ///
+    /// The default width is `50` pixels per column.
+    ///
+    /// [`PadGrid`]: struct.PadGrid.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`PadGrid`].
+    ///
+    /// The default height is `50` pixels per row.
+    ///
+    /// [`PadGrid`]: struct.PadGrid.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`PadGrid`].
+    ///
+    /// [`PadGrid`]: struct.PadGrid.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn pad_count(&self) -> usize {
+        self.rows * self.columns
+    }
+
+    /// Returns the bounds of the pad at `index`.
+    fn pad_bounds(&self, bounds: &Rectangle, index: usize) -> Rectangle {
+        let spacing = self.spacing;
+        let row = index / self.columns;
+        let column = index % self.columns;
+
+        let pad_width =
+            (bounds.width - (spacing * (self.columns + 1) as f32)) / self.columns as f32;
+        let pad_height = (bounds.height - (spacing * (self.rows + 1) as f32)) / self.rows as f32;
+
+        Rectangle {
+            x: (bounds.x + spacing + (column as f32 * (pad_width + spacing))).round(),
+            y: (bounds.y + spacing + (row as f32 * (pad_height + spacing))).round(),
+            width: pad_width.max(0.0).round(),
+            height: pad_height.max(0.0).round(),
+        }
+    }
+
+    /// Returns the index of the pad at `position`, if any.
+    fn pad_at(&self, bounds: &Rectangle, position: Point) -> Option<usize> {
+        (0..self.pad_count()).find(|i| self.pad_bounds(bounds, *i).contains(position))
+    }
+
+    /// Returns the intensity of the flash highlight of the pad at `index`.
+    fn flash_intensity(&self, index: usize, now: Instant) -> f32 {
+        let Some(Some(triggered)) = self.flashes.and_then(|f| f.get(index)) else {
+            return 0.0;
+        };
+
+        let decay = self.flash_decay.as_secs_f32();
+        if decay <= 0.0 || now < *triggered {
+            return 0.0;
+        }
+
+        let t = (now - *triggered).as_secs_f32() / decay;
+        if t >= 1.0 { 0.0 } else { (1.0 - t) * (1.0 - t) }
+    }
+
+    fn press(
+        &mut self,
+        state: &mut State,
+        pointer: Pointer,
+        bounds: &Rectangle,
+        position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let Some(pad) = self.pad_at(bounds, position) else {
+            return false;
+        };
+
+        // A pointer can only hold one pad at a time.
+        self.release(state, pointer, shell);
+
+        let velocity = self
+            .velocity_mode
+            .velocity(&self.pad_bounds(bounds, pad), position);
+
+        state.held.push((pointer, pad));
+
+        if let Some(on_pad) = &mut self.on_pad {
+            shell.publish((on_pad)(PadEvent::NoteOn { pad, velocity }));
+        }
+
+        true
+    }
+
+    fn release(
+        &mut self,
+        state: &mut State,
+        pointer: Pointer,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let Some(i) = state.held.iter().position(|(p, _)| *p == pointer) else {
+            return false;
+        };
+
+        let (_, pad) = state.held.remove(i);
+
+        // Only release the note once no other pointer is holding this pad.
+        if !state.held.iter().any(|(_, p)| *p == pad)
+            && let Some(on_pad) = &mut self.on_pad
+        {
+            shell.publish((on_pad)(PadEvent::NoteOff { pad }));
+        }
+
+        true
+    }
+
+    fn release_all(&mut self, state: &mut State, shell: &mut Shell<'_, Message>) -> bool {
+        let mut released = false;
+        while let Some((pointer, _)) = state.held.first().copied() {
+            released |= self.release(state, pointer, shell);
+        }
+        released
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Pointer {
+    Mouse,
+    Finger(touch::Finger),
+}
+
+#[derive(Debug, Default, Clone)]
+struct State {
+    /// The pads that are currently held, and the pointers holding them.
+    held: Vec<(Pointer, usize)>,
+}
+
+impl State {
+    fn is_held(&self, pad: usize) -> bool {
+        self.held.iter().any(|(_, p)| *p == pad)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for PadGrid<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if self.pad_count() == 0 {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let mut capture_event = false;
+        let mut needs_redraw = false;
+
+        // Release the pads that were held when the grid was disabled, so
+        // their notes don't get stuck. Flashes keep decaying either way.
+        if !self.enabled {
+            needs_redraw = self.release_all(state, shell);
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if self.enabled
+                    && let Some(position) = cursor.position_over(bounds)
+                {
+                    needs_redraw = self.press(state, Pointer::Mouse, &bounds, position, shell);
+                    capture_event = true;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                needs_redraw = self.release(state, Pointer::Mouse, shell);
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if self.enabled => {
+                // Update the hovered pad.
+                needs_redraw = cursor.is_over(bounds) || !state.held.is_empty();
+            }
+            Event::Mouse(mouse::Event::CursorLeft) if self.enabled => {
+                needs_redraw = true;
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position })
+                if self.enabled && bounds.contains(*position) =>
+            {
+                needs_redraw = self.press(state, Pointer::Finger(*id), &bounds, *position, shell);
+                capture_event = true;
+            }
+            Event::Touch(touch::Event::FingerLifted { id, .. })
+            | Event::Touch(touch::Event::FingerLost { id, .. }) => {
+                needs_redraw = self.release(state, Pointer::Finger(*id), shell);
+                capture_event = needs_redraw;
+            }
+            Event::Window(window::Event::Unfocused) => {
+                needs_redraw = self.release_all(state, shell);
+            }
+            // Keep animating while any flash highlight is still decaying.
+            Event::Window(window::Event::RedrawRequested(now))
+                if (0..self.pad_count()).any(|i| self.flash_intensity(i, *now) > 0.0) =>
+            {
+                needs_redraw = true;
+            }
+            _ => {}
+        }
+
+        if capture_event {
+            shell.capture_event();
+        }
+
+        if needs_redraw {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+
+        let appearance = if !self.enabled {
+            theme.disabled(&self.style)
+        } else if !state.held.is_empty() {
+            theme.gesturing(&self.style)
+        } else if cursor_is_over {
+            theme.hovered(&self.style)
+        } else {
+            theme.idle(&self.style)
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        if self.pad_count() == 0 {
+            return;
+        }
+
+        let hovered_pad = if self.enabled {
+            cursor
+                .position_over(bounds)
+                .and_then(|position| self.pad_at(&bounds, position))
+        } else {
+            None
+        };
+
+        let now = Instant::now();
+
+        for i in 0..self.pad_count() {
+            let pad_bounds = self.pad_bounds(&bounds, i);
+
+            let pad_appearance = if state.is_held(i) {
+                &appearance.pressed_pad
+            } else if hovered_pad == Some(i) {
+                &appearance.hovered_pad
+            } else {
+                &appearance.pad
+            };
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: pad_bounds,
+                    border: Border {
+                        color: pad_appearance.border_color,
+                        width: pad_appearance.border_width,
+                        radius: Radius::new(pad_appearance.border_radius),
+                    },
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                pad_appearance.color,
+            );
+
+            let intensity = self.flash_intensity(i, now);
+            if intensity > 0.0 {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: pad_bounds,
+                        border: Border {
+                            color: Color::TRANSPARENT,
+                            width: 0.0,
+                            radius: Radius::new(pad_appearance.border_radius),
+                        },
+                        shadow: Shadow::default(),
+                        snap: false,
+                    },
+                    Color {
+                        a: appearance.flash_color.a * intensity,
+                        ..appearance.flash_color
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<PadGrid<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer,
+{
+    fn from(pad_grid: PadGrid<'a, Message, Theme>) -> Self {
+        Self::new(pad_grid)
+    }
+}
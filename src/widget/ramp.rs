@@ -17,7 +17,7 @@ use iced_core::{
 };
 use iced_graphics::geometry::{self, Frame, LineCap, Path, Stroke};
 
-pub use crate::style::ramp::{Appearance, StyleSheet};
+pub use crate::style::ramp::{Appearance, Catalog, Status, StyleFn, StyleSheet};
 
 const DEFAULT_WIDTH: f32 = 40.0;
 const DEFAULT_HEIGHT: f32 = 20.0;
@@ -38,16 +38,16 @@ pub enum RampDirection {
 /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
 /// [`Ramp`]: struct.Ramp.html
 #[allow(missing_debug_implementations)]
-pub struct Ramp<'a, Message, Theme: StyleSheet> {
+pub struct Ramp<'a, Message, Theme: Catalog> {
     virtual_slider: VirtualSlider<'a, Message>,
     enabled: bool,
     width: Length,
     height: Length,
-    style: <Theme as StyleSheet>::Style,
+    class: Theme::Class<'a>,
     direction: RampDirection,
 }
 
-impl<'a, Message, Theme: StyleSheet> Ramp<'a, Message, Theme> {
+impl<'a, Message, Theme: Catalog> Ramp<'a, Message, Theme> {
     /// Creates a new [`Ramp`].
     ///
     /// It expects:
@@ -59,16 +59,13 @@ impl<'a, Message, Theme: StyleSheet> Ramp<'a, Message, Theme> {
     /// [`RampDirection`]: enum.RampDirection.html
     /// [`NormalParam`]: struct.NormalParam.html
     /// [`Ramp`]: struct.Ramp.html
-    pub fn new(normal_param: impl Into<NormalParam>, direction: RampDirection) -> Self
-    where
-        <Theme as StyleSheet>::Style: Default,
-    {
+    pub fn new(normal_param: impl Into<NormalParam>, direction: RampDirection) -> Self {
         Ramp {
             virtual_slider: VirtualSlider::new(normal_param.into()),
             enabled: true,
             width: Length::Fixed(DEFAULT_WIDTH),
             height: Length::Fixed(DEFAULT_HEIGHT),
-            style: Default::default(),
+            class: Theme::default(),
             direction,
         }
     }
@@ -114,15 +111,28 @@ impl<'a, Message, Theme: StyleSheet> Ramp<'a, Message, Theme> {
     /// Sets the style of the [`Ramp`].
     ///
     /// [`Ramp`]: struct.Ramp.html
-    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
-        self.style = style.into();
+    pub fn style(mut self, style: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = style.into();
         self
     }
+
+    /// Sets the style of the [`Ramp`] from a closure that produces its
+    /// [`Appearance`] for each [`Status`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`Status`]: enum.Status.html
+    pub fn style_fn(self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.style(Box::new(style) as StyleFn<'a, Theme>)
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Ramp<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
     Renderer: iced_core::Renderer + iced_graphics::geometry::Renderer,
 {
     fn tag(&self) -> tree::Tag {
@@ -194,13 +204,8 @@ where
         let cursor_is_over = cursor.is_over(layout.bounds());
         let normal_val = self.virtual_slider.param().normal;
 
-        let appearance = if state.is_gesturing() {
-            theme.gesturing(&self.style)
-        } else if cursor_is_over {
-            theme.hovered(&self.style)
-        } else {
-            theme.idle(&self.style)
-        };
+        let status = Status::new(self.enabled, state.is_gesturing(), cursor_is_over);
+        let appearance = Catalog::style(theme, &self.class, status);
 
         let bounds_x = bounds.x.floor();
         let bounds_y = bounds.y.floor();
@@ -427,7 +432,7 @@ impl<'a, Message, Theme, Renderer> From<Ramp<'a, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a,
-    Theme: 'a + StyleSheet,
+    Theme: 'a + Catalog,
     Renderer: iced_core::Renderer + iced_graphics::geometry::Renderer,
 {
     fn from(ramp: Ramp<'a, Message, Theme>) -> Self {
@@ -0,0 +1,799 @@
+//! Display an interactive slider with two handles that controls the lower and
+//! upper bounds of a range with two [`NormalParam`]s. It is usually used for
+//! key zones, velocity ranges, loop windows, and band limits.
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use crate::{
+    core::{Normal, NormalParam},
+    text_marks, tick_marks,
+    virtual_slider::{Gesture, OnGesture, set_param_value},
+};
+use iced_core::{
+    Border, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shadow, Shell,
+    Size, Widget,
+    border::Radius,
+    layout, mouse,
+    renderer::{Quad, Style},
+    touch,
+    widget::{Tree, tree},
+    window,
+};
+
+pub use crate::style::range_slider::{
+    Appearance, FillAppearance, FillPlacement, HandleAppearance, StyleSheet, TextMarksAppearance,
+    TickMarksAppearance,
+};
+
+const DEFAULT_THICKNESS: f32 = 14.0;
+const DEFAULT_HANDLE_WIDTH: u16 = 10;
+
+/// The direction a [`RangeSlider`] travels in.
+///
+/// [`RangeSlider`]: struct.RangeSlider.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// The lower handle is on the left and the upper handle is on the right.
+    #[default]
+    Horizontal,
+    /// The lower handle is on the bottom and the upper handle is on the top.
+    Vertical,
+}
+
+impl Orientation {
+    /// Builds a rectangle from a position and length along the direction of
+    /// travel, and a position and length across it.
+    fn rect(self, main: f32, main_length: f32, cross: f32, cross_length: f32) -> Rectangle {
+        match self {
+            Orientation::Horizontal => Rectangle {
+                x: main,
+                y: cross,
+                width: main_length,
+                height: cross_length,
+            },
+            Orientation::Vertical => Rectangle {
+                x: cross,
+                y: main,
+                width: cross_length,
+                height: main_length,
+            },
+        }
+    }
+
+    /// Returns the start and length of `bounds` across the direction of travel.
+    fn cross(self, bounds: &Rectangle) -> (f32, f32) {
+        match self {
+            Orientation::Horizontal => (bounds.y, bounds.height),
+            Orientation::Vertical => (bounds.x, bounds.width),
+        }
+    }
+
+    /// Returns the pixel position of `normal` along the direction of travel.
+    fn position_of(self, value_bounds: &Rectangle, normal: f32) -> f32 {
+        match self {
+            Orientation::Horizontal => value_bounds.x + normal * value_bounds.width,
+            Orientation::Vertical => value_bounds.y + (1.0 - normal) * value_bounds.height,
+        }
+    }
+
+    /// Returns the (unclamped) normalized value at the given point.
+    fn normal_at(self, value_bounds: &Rectangle, point: Point) -> f32 {
+        match self {
+            Orientation::Horizontal if value_bounds.width > 0.0 => {
+                (point.x - value_bounds.x) / value_bounds.width
+            }
+            Orientation::Vertical if value_bounds.height > 0.0 => {
+                1.0 - ((point.y - value_bounds.y) / value_bounds.height)
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// What happens when the user drags one handle of a [`RangeSlider`] past
+/// the other.
+///
+/// [`RangeSlider`]: struct.RangeSlider.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Crossing {
+    /// The dragged handle stops at the other handle.
+    #[default]
+    Block,
+    /// The handles swap roles, so the dragged handle keeps following the
+    /// cursor and the other handle takes its place.
+    Swap,
+}
+
+/// A slider GUI widget with two handles that controls the lower and upper
+/// bounds of a range with two [`NormalParam`]s.
+///
+/// Each handle can be dragged on its own, and dragging the band between the
+/// handles moves both at once.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`RangeSlider`]: struct.RangeSlider.html
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, Message, Theme: StyleSheet> {
+    lower: NormalParam,
+    upper: NormalParam,
+    on_gesture_lower: OnGesture<'a, Message>,
+    on_gesture_upper: OnGesture<'a, Message>,
+    orientation: Orientation,
+    crossing: Crossing,
+    handle_width: u16,
+    enabled: bool,
+    width: Option<Length>,
+    height: Option<Length>,
+    style: <Theme as StyleSheet>::Style,
+    tick_marks: Option<&'a tick_marks::Group>,
+    text_marks: Option<&'a text_marks::Group>,
+}
+
+impl<'a, Message, Theme> RangeSlider<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new horizontal [`RangeSlider`].
+    ///
+    /// * `lower` - The normalized value of the lower bound of the range.
+    /// * `upper` - The normalized value of the upper bound of the range.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn new(lower: impl Into<NormalParam>, upper: impl Into<NormalParam>) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        RangeSlider {
+            lower: lower.into(),
+            upper: upper.into(),
+            on_gesture_lower: None,
+            on_gesture_upper: None,
+            orientation: Orientation::default(),
+            crossing: Crossing::default(),
+            handle_width: DEFAULT_HANDLE_WIDTH,
+            enabled: true,
+            width: None,
+            height: None,
+            style: Default::default(),
+            tick_marks: None,
+            text_marks: None,
+        }
+    }
+
+    /// Sets the message to emit when the user gestures the lower handle.
+    pub fn on_gesture_lower(mut self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.on_gesture_lower = Some(Box::new(on_gesture));
+        self
+    }
+
+    /// Sets the message to emit when the user gestures the upper handle.
+    pub fn on_gesture_upper(mut self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.on_gesture_upper = Some(Box::new(on_gesture));
+        self
+    }
+
+    /// Sets the [`Orientation`] of the [`RangeSlider`].
+    ///
+    /// The default is [`Orientation::Horizontal`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub const fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets what happens when the user drags one handle past the other.
+    ///
+    /// The default is [`Crossing::Block`].
+    pub const fn crossing(mut self, crossing: Crossing) -> Self {
+        self.crossing = crossing;
+        self
+    }
+
+    /// Sets the length of each handle along the direction of travel.
+    ///
+    /// The default is `10`.
+    pub const fn handle_width(mut self, handle_width: u16) -> Self {
+        self.handle_width = handle_width;
+        self
+    }
+
+    /// Enable/disable this widget.
+    ///
+    /// The default is `true`.
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    ///
+    /// The default width is `Length::Fill` when horizontal and
+    /// `Length::Fixed(14.0)` when vertical.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    ///
+    /// The default height is `Length::Fixed(14.0)` when horizontal and
+    /// `Length::Fill` when vertical.
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    ///
+    /// [`RangeSlider`]: struct.RangeSlider.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the tick marks to display. Note your [`StyleSheet`] must
+    /// also implement `tick_marks_appearance(&self) -> Option<TickMarksAppearance>` for
+    /// them to display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/range_slider/trait.StyleSheet.html
+    pub fn tick_marks(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display. Note your [`StyleSheet`] must
+    /// also implement `text_marks_appearance(&self) -> Option<TextMarksAppearance>` for
+    /// them to display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/range_slider/trait.StyleSheet.html
+    pub fn text_marks(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks = Some(text_marks);
+        self
+    }
+
+    fn lengths(&self) -> (Length, Length) {
+        let (default_width, default_height) = match self.orientation {
+            Orientation::Horizontal => (Length::Fill, Length::Fixed(DEFAULT_THICKNESS)),
+            Orientation::Vertical => (Length::Fixed(DEFAULT_THICKNESS), Length::Fill),
+        };
+
+        (
+            self.width.unwrap_or(default_width),
+            self.height.unwrap_or(default_height),
+        )
+    }
+
+    /// The bounds that the handle centers travel along.
+    fn value_bounds(&self, bounds: &Rectangle) -> Rectangle {
+        let handle_width = f32::from(self.handle_width);
+
+        match self.orientation {
+            Orientation::Horizontal => Rectangle {
+                x: (bounds.x + (handle_width / 2.0)).round(),
+                y: bounds.y,
+                width: bounds.width - handle_width,
+                height: bounds.height,
+            },
+            Orientation::Vertical => Rectangle {
+                x: bounds.x,
+                y: (bounds.y + (handle_width / 2.0)).round(),
+                width: bounds.width,
+                height: bounds.height - handle_width,
+            },
+        }
+    }
+
+    /// Picks which part of the slider the user grabbed at `normal`.
+    fn target_at(&self, value_bounds: &Rectangle, normal: f32) -> Target {
+        let lower = self.lower.normal.as_f32();
+        let upper = self.upper.normal.as_f32();
+
+        let span = match self.orientation {
+            Orientation::Horizontal => value_bounds.width,
+            Orientation::Vertical => value_bounds.height,
+        };
+        let half_handle = if span > 0.0 {
+            f32::from(self.handle_width) / 2.0 / span
+        } else {
+            0.0
+        };
+
+        let over_lower = (normal - lower).abs() <= half_handle;
+        let over_upper = (normal - upper).abs() <= half_handle;
+
+        match (over_lower, over_upper) {
+            // The handles overlap, so pick the one on the side of the cursor.
+            (true, true) => {
+                if normal < (lower + upper) / 2.0 {
+                    Target::Lower
+                } else {
+                    Target::Upper
+                }
+            }
+            (true, false) => Target::Lower,
+            (false, true) => Target::Upper,
+            (false, false) => {
+                if normal > lower && normal < upper {
+                    Target::Band
+                } else if normal <= lower {
+                    Target::Lower
+                } else {
+                    Target::Upper
+                }
+            }
+        }
+    }
+
+    /// Returns the new values of the lower and upper handles when the cursor
+    /// is dragged to `normal`.
+    fn drag_to(&self, drag: &mut Drag, normal: f32) -> (f32, f32) {
+        let delta = normal - drag.grab_normal;
+
+        match drag.target {
+            Target::Band => {
+                let delta = delta.clamp(-drag.lower_start, 1.0 - drag.upper_start);
+                (drag.lower_start + delta, drag.upper_start + delta)
+            }
+            Target::Lower => {
+                let value = (drag.lower_start + delta).clamp(0.0, 1.0);
+                let upper = self.upper.normal.as_f32();
+
+                if value <= upper {
+                    (value, upper)
+                } else if self.crossing == Crossing::Swap {
+                    drag.target = Target::Upper;
+                    drag.upper_start = drag.lower_start;
+                    (upper, value)
+                } else {
+                    (upper, upper)
+                }
+            }
+            Target::Upper => {
+                let value = (drag.upper_start + delta).clamp(0.0, 1.0);
+                let lower = self.lower.normal.as_f32();
+
+                if value >= lower {
+                    (lower, value)
+                } else if self.crossing == Crossing::Swap {
+                    drag.target = Target::Lower;
+                    drag.lower_start = drag.upper_start;
+                    (value, lower)
+                } else {
+                    (lower, lower)
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if any param value has changed.
+    fn set_param_values(
+        &mut self,
+        lower: f32,
+        upper: f32,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let lower_changed = set_param_value(
+            &mut self.lower,
+            Normal::new(lower),
+            &mut state.last_sent_gesture_lower,
+            &mut self.on_gesture_lower,
+            shell,
+        );
+        let upper_changed = set_param_value(
+            &mut self.upper,
+            Normal::new(upper),
+            &mut state.last_sent_gesture_upper,
+            &mut self.on_gesture_upper,
+            shell,
+        );
+
+        lower_changed || upper_changed
+    }
+
+    fn end_gesture(&mut self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        state.drag = None;
+
+        for (last_sent_gesture, on_gesture) in [
+            (
+                &mut state.last_sent_gesture_lower,
+                &mut self.on_gesture_lower,
+            ),
+            (
+                &mut state.last_sent_gesture_upper,
+                &mut self.on_gesture_upper,
+            ),
+        ] {
+            if *last_sent_gesture != Gesture::GestureEnd {
+                if let Some(on_gesture) = on_gesture {
+                    shell.publish((on_gesture)(Gesture::GestureEnd));
+                }
+                *last_sent_gesture = Gesture::GestureEnd;
+            }
+        }
+    }
+}
+
+/// The part of a [`RangeSlider`] being dragged.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Target {
+    Lower,
+    Upper,
+    Band,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Drag {
+    target: Target,
+    grab_normal: f32,
+    lower_start: f32,
+    upper_start: f32,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct State {
+    drag: Option<Drag>,
+    hovered: bool,
+    last_sent_gesture_lower: Gesture,
+    last_sent_gesture_upper: Gesture,
+    last_click: Option<mouse::Click>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RangeSlider<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            drag: None,
+            hovered: false,
+            last_sent_gesture_lower: Gesture::GestureEnd,
+            last_sent_gesture_upper: Gesture::GestureEnd,
+            last_click: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        let (width, height) = self.lengths();
+
+        Size { width, height }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (width, height) = self.lengths();
+
+        layout::Node::new(limits.resolve(width, height, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let value_bounds = self.value_bounds(&bounds);
+        let cursor_is_over = cursor.is_over(bounds);
+
+        let mut capture_event = false;
+        let mut param_changed = false;
+        let mut hover_state_changed = false;
+
+        if cursor_is_over != state.hovered {
+            state.hovered = cursor_is_over;
+            hover_state_changed = true;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(mut drag) = state.drag {
+                    let normal = self.orientation.normal_at(&value_bounds, *position);
+                    let (lower, upper) = self.drag_to(&mut drag, normal);
+                    state.drag = Some(drag);
+
+                    param_changed = self.set_param_values(lower, upper, state, shell);
+
+                    capture_event = true;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor_is_over && let Some(cursor_position) = cursor.position() {
+                    let click =
+                        mouse::Click::new(cursor_position, mouse::Button::Left, state.last_click);
+
+                    let normal = self.orientation.normal_at(&value_bounds, cursor_position);
+                    let target = self.target_at(&value_bounds, normal);
+
+                    let lower = self.lower.normal.as_f32();
+                    let upper = self.upper.normal.as_f32();
+
+                    match click.kind() {
+                        mouse::click::Kind::Single => {
+                            let mut drag = Drag {
+                                target,
+                                grab_normal: normal,
+                                lower_start: lower,
+                                upper_start: upper,
+                            };
+
+                            // Jump the nearest handle to the cursor when
+                            // clicking outside of the band.
+                            if target == Target::Lower && normal < lower - f32::EPSILON {
+                                drag.lower_start = normal;
+                            } else if target == Target::Upper && normal > upper + f32::EPSILON {
+                                drag.upper_start = normal;
+                            }
+
+                            let (lower, upper) = self.drag_to(&mut drag, normal);
+                            state.drag = Some(drag);
+
+                            param_changed = self.set_param_values(lower, upper, state, shell);
+                        }
+                        _ => {
+                            // Reset to default
+
+                            let (lower, upper) = match target {
+                                Target::Lower => (self.lower.default.as_f32().min(upper), upper),
+                                Target::Upper => (lower, self.upper.default.as_f32().max(lower)),
+                                Target::Band => {
+                                    (self.lower.default.as_f32(), self.upper.default.as_f32())
+                                }
+                            };
+
+                            param_changed = self.set_param_values(lower, upper, state, shell);
+                            self.end_gesture(state, shell);
+                        }
+                    }
+
+                    state.last_click = Some(click);
+
+                    capture_event = true;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.is_some() {
+                    capture_event = true;
+                }
+                self.end_gesture(state, shell);
+            }
+            Event::Window(window::Event::Unfocused) => {
+                self.end_gesture(state, shell);
+            }
+            _ => {}
+        }
+
+        if capture_event {
+            shell.capture_event();
+        }
+
+        if param_changed || hover_state_changed {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+
+        let appearance = if !self.enabled {
+            theme.disabled(&self.style)
+        } else if state.drag.is_some() {
+            theme.gesturing(&self.style)
+        } else if cursor_is_over {
+            theme.hovered(&self.style)
+        } else {
+            theme.idle(&self.style)
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+        let value_bounds = self.value_bounds(&bounds);
+        let orientation = self.orientation;
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        let lower_position = orientation
+            .position_of(&value_bounds, self.lower.normal.as_f32())
+            .round();
+        let upper_position = orientation
+            .position_of(&value_bounds, self.upper.normal.as_f32())
+            .round();
+        let (band_start, band_end) = if lower_position <= upper_position {
+            (lower_position, upper_position)
+        } else {
+            (upper_position, lower_position)
+        };
+
+        let (cross, cross_length) = orientation.cross(&bounds);
+        let fill = &appearance.fill;
+        let (fill_cross, fill_cross_length) = match fill.placement {
+            FillPlacement::Center { thickness, offset } => (
+                cross + offset + ((cross_length - thickness) / 2.0),
+                thickness,
+            ),
+            FillPlacement::CenterFilled { edge_padding } => {
+                (cross + edge_padding, cross_length - (edge_padding * 2.0))
+            }
+        };
+
+        if band_end > band_start {
+            renderer.fill_quad(
+                Quad {
+                    bounds: orientation.rect(
+                        band_start,
+                        band_end - band_start,
+                        fill_cross,
+                        fill_cross_length,
+                    ),
+                    border: Border {
+                        color: fill.border_color,
+                        width: fill.border_width,
+                        radius: Radius::new(fill.border_radius),
+                    },
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                fill.color,
+            );
+        }
+
+        if let Some(tick_marks) = self.tick_marks
+            && let Some(style) = theme.tick_marks_appearance(&self.style)
+        {
+            match orientation {
+                Orientation::Horizontal => tick_marks::draw_horizontal_tick_marks(
+                    renderer,
+                    &value_bounds,
+                    tick_marks,
+                    &style.style,
+                    &style.placement,
+                    false,
+                ),
+                Orientation::Vertical => tick_marks::draw_vertical_tick_marks(
+                    renderer,
+                    &value_bounds,
+                    tick_marks,
+                    &style.style,
+                    &style.placement,
+                    false,
+                ),
+            }
+        }
+
+        if let Some(text_marks) = self.text_marks
+            && let Some(style) = theme.text_marks_appearance(&self.style)
+        {
+            match orientation {
+                Orientation::Horizontal => text_marks::draw_horizontal_text_marks(
+                    renderer,
+                    &value_bounds,
+                    text_marks,
+                    &style.style,
+                    &style.placement,
+                    false,
+                ),
+                Orientation::Vertical => text_marks::draw_vertical_text_marks(
+                    renderer,
+                    &value_bounds,
+                    text_marks,
+                    &style.style,
+                    &style.placement,
+                    false,
+                ),
+            }
+        }
+
+        let handle = &appearance.handle;
+        let handle_width = f32::from(self.handle_width);
+
+        for position in [lower_position, upper_position] {
+            renderer.fill_quad(
+                Quad {
+                    bounds: orientation.rect(
+                        position - (handle_width / 2.0),
+                        handle_width,
+                        cross,
+                        cross_length,
+                    ),
+                    border: Border {
+                        color: handle.border_color,
+                        width: handle.border_width,
+                        radius: Radius::new(handle.border_radius),
+                    },
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                handle.color,
+            );
+
+            if handle.notch_width != 0.0 {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: orientation.rect(
+                            (position - (handle.notch_width / 2.0)).round(),
+                            handle.notch_width,
+                            cross,
+                            cross_length,
+                        ),
+                        border: Border {
+                            color: Color::TRANSPARENT,
+                            width: 0.0,
+                            radius: Radius::new(0.0),
+                        },
+                        shadow: Shadow::default(),
+                        snap: false,
+                    },
+                    handle.notch_color,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<RangeSlider<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    fn from(range_slider: RangeSlider<'a, Message, Theme>) -> Self {
+        Self::new(range_slider)
+    }
+}
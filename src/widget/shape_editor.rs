@@ -0,0 +1,717 @@
+//! Display a canvas for drawing one period of a waveform, such as an LFO
+//! shape or a single-cycle wavetable. The shape is stored as a table of
+//! [`Normal`] samples.
+//!
+//! [`Normal`]: ../core/normal/struct.Normal.html
+
+use std::cell::Cell;
+use std::ops::Range;
+
+use super::stroke::{Cells, Drag};
+use crate::core::Normal;
+use iced_core::{
+    Border, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shadow, Shell, Size,
+    Vector, Widget,
+    border::Radius,
+    keyboard, layout, mouse,
+    renderer::{Quad, Style},
+    touch,
+    widget::{Tree, tree},
+    window,
+};
+use iced_graphics::geometry::{self, Frame, Path, Stroke};
+
+pub use crate::style::shape_editor::{Appearance, StyleSheet};
+
+const DEFAULT_HEIGHT: f32 = 100.0;
+
+/// A message emitted by a [`ShapeEditor`] when the user draws on it.
+///
+/// Every stroke is wrapped in a [`ShapeGesture::GestureStart`] and a
+/// [`ShapeGesture::GestureEnd`], so all of the edits in between can be
+/// undone as a unit.
+///
+/// [`ShapeEditor`]: struct.ShapeEditor.html
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShapeGesture {
+    /// The user has just started a stroke.
+    ///
+    /// This will always be sent before [`ShapeGesture::Gesturing`].
+    GestureStart,
+    /// The samples starting at index `start` have new values.
+    Gesturing {
+        /// The index of the first sample that was edited.
+        start: usize,
+        /// The new values of the samples, starting at `start`.
+        values: Vec<Normal>,
+    },
+    /// The user has finished the stroke.
+    GestureEnd,
+}
+
+impl ShapeGesture {
+    /// Copies the values of a [`ShapeGesture::Gesturing`] message into
+    /// `table`. Other messages are ignored.
+    pub fn apply(&self, table: &mut [Normal]) {
+        if let ShapeGesture::Gesturing { start, values } = self
+            && let Some(samples) = table.get_mut(*start..*start + values.len())
+        {
+            samples.copy_from_slice(values);
+        }
+    }
+}
+
+/// The tool used to draw on a [`ShapeEditor`].
+///
+/// [`ShapeEditor`]: struct.ShapeEditor.html
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum ShapeTool {
+    /// Draw freehand, setting every sample the cursor crosses.
+    #[default]
+    Pencil,
+    /// Draw a straight line from where the drag started.
+    Line,
+}
+
+/// A grid that the shape drawn on a [`ShapeEditor`] snaps to.
+///
+/// [`ShapeEditor`]: struct.ShapeEditor.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Grid {
+    /// The number of horizontal steps. Every sample in a step is set to the
+    /// same value. Set this to `0` to not snap horizontally.
+    pub steps: usize,
+    /// The number of vertical divisions the values snap to. Set this to `0`
+    /// to not snap vertically.
+    pub levels: usize,
+}
+
+impl Grid {
+    /// Creates a new [`Grid`] with the given number of horizontal `steps`
+    /// and vertical `levels`.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub const fn new(steps: usize, levels: usize) -> Self {
+        Self { steps, levels }
+    }
+}
+
+/// Resamples one period of a waveform `table` to a table with `len`
+/// samples using linear interpolation.
+pub fn resample(table: &[Normal], len: usize) -> Vec<Normal> {
+    (0..len)
+        .map(|i| value_at_phase(table, i as f32 / len as f32))
+        .collect()
+}
+
+/// Returns the linearly interpolated value of one period of a waveform
+/// `table` at `phase` (in the range `[0.0, 1.0)`).
+fn value_at_phase(table: &[Normal], phase: f32) -> Normal {
+    if table.is_empty() {
+        return Normal::MIN;
+    }
+
+    let pos = phase.rem_euclid(1.0) * table.len() as f32;
+    let index = (pos.floor() as usize).min(table.len() - 1);
+    let next = (index + 1) % table.len();
+    let t = pos - index as f32;
+
+    Normal::new(table[index].as_f32() + (table[next].as_f32() - table[index].as_f32()) * t)
+}
+
+/// A GUI widget for drawing one period of a waveform with a pencil or
+/// line tool, with optional snapping to a [`Grid`].
+///
+/// Dragging while holding down the line modifier key (`Alt` by default)
+/// uses the [`ShapeTool::Line`] tool regardless of the selected tool.
+///
+/// [`Grid`]: struct.Grid.html
+#[allow(missing_debug_implementations)]
+pub struct ShapeEditor<'a, Message, Theme: StyleSheet> {
+    table: Vec<Normal>,
+    on_gesture: Option<Box<dyn 'a + FnMut(ShapeGesture) -> Message>>,
+    tool: ShapeTool,
+    grid: Option<Grid>,
+    phase: Option<Normal>,
+    line_modifiers: keyboard::Modifiers,
+    enabled: bool,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> ShapeEditor<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`ShapeEditor`] that edits the given table of samples.
+    ///
+    /// [`ShapeEditor`]: struct.ShapeEditor.html
+    pub fn new(table: &[Normal]) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        ShapeEditor {
+            table: table.to_vec(),
+            on_gesture: None,
+            tool: ShapeTool::default(),
+            grid: None,
+            phase: None,
+            line_modifiers: keyboard::Modifiers::ALT,
+            enabled: true,
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the message to emit when the user draws on the shape.
+    pub fn on_gesture(mut self, on_gesture: impl 'a + FnMut(ShapeGesture) -> Message) -> Self {
+        self.on_gesture = Some(Box::new(on_gesture));
+        self
+    }
+
+    /// Sets the tool used to draw.
+    ///
+    /// The default tool is [`ShapeTool::Pencil`].
+    pub const fn tool(mut self, tool: ShapeTool) -> Self {
+        self.tool = tool;
+        self
+    }
+
+    /// Sets the [`Grid`] to snap to and display. Set this to `None` to draw
+    /// freely.
+    ///
+    /// The default is `None`.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub const fn grid(mut self, grid: Option<Grid>) -> Self {
+        self.grid = grid;
+        self
+    }
+
+    /// Sets the phase (in the range `[0.0, 1.0]`) at which to display the
+    /// phase indicator dot. The phase is driven by the application, so call
+    /// this with a new value every frame to animate it.
+    pub const fn phase(mut self, phase: Option<Normal>) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Sets the modifier key/keys to hold down to draw a straight line.
+    ///
+    /// The default key is `Alt`.
+    pub const fn line_modifiers(mut self, modifiers: keyboard::Modifiers) -> Self {
+        self.line_modifiers = modifiers;
+        self
+    }
+
+    /// Enable/disable this widget.
+    ///
+    /// The default is `true`.
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the width of the [`ShapeEditor`].
+    ///
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`ShapeEditor`]: struct.ShapeEditor.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ShapeEditor`].
+    ///
+    /// The default height is `Length::Fixed(100.0)`.
+    ///
+    /// [`ShapeEditor`]: struct.ShapeEditor.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`ShapeEditor`].
+    ///
+    /// [`ShapeEditor`]: struct.ShapeEditor.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the number of grid steps, or `None` when not snapping
+    /// horizontally.
+    fn steps(&self) -> Option<usize> {
+        self.grid
+            .map(|grid| grid.steps.min(self.table.len()))
+            .filter(|steps| *steps > 0)
+    }
+
+    /// Returns the number of cells that are edited as a unit. This is
+    /// either the number of grid steps or the number of samples.
+    fn cell_count(&self) -> usize {
+        self.steps().unwrap_or(self.table.len())
+    }
+
+    fn cells(&self, bounds: &Rectangle) -> Cells {
+        Cells::new(bounds, self.cell_count(), self.steps().is_some())
+    }
+
+    /// Returns the range of samples in the cell at `index`.
+    fn cell_samples(&self, index: usize) -> Range<usize> {
+        match self.steps() {
+            Some(steps) => {
+                let len = self.table.len();
+                (index * len / steps)..((index + 1) * len / steps)
+            }
+            None => index..index + 1,
+        }
+    }
+
+    fn normal_at(&self, bounds: &Rectangle, y: f32) -> Normal {
+        let normal = if bounds.height > 0.0 {
+            1.0 - ((y - bounds.y) / bounds.height)
+        } else {
+            0.0
+        };
+
+        match self.grid.map(|grid| grid.levels).filter(|l| *l > 0) {
+            Some(levels) => Normal::new((normal * levels as f32).round() / levels as f32),
+            None => Normal::new(normal),
+        }
+    }
+
+    /// Sets every cell between `from` and `to` to the value on the straight
+    /// line between the two points. Returns the range of cells that were set.
+    fn paint(
+        &mut self,
+        bounds: &Rectangle,
+        from: Point,
+        to: Point,
+        state: &mut State,
+    ) -> (usize, usize) {
+        let (covered, line) = self.cells(bounds).line(from, to);
+        for (index, y) in line {
+            let normal = self.normal_at(bounds, y);
+            for sample in self.cell_samples(index) {
+                self.set_sample(sample, normal, state);
+            }
+        }
+
+        covered
+    }
+
+    fn set_sample(&mut self, index: usize, normal: Normal, state: &mut State) {
+        if (self.table[index].as_f32() - normal.as_f32()).abs() <= f32::EPSILON {
+            return;
+        }
+        self.table[index] = normal;
+
+        state.edited = Some(match state.edited {
+            Some((first, last)) => (first.min(index), last.max(index)),
+            None => (index, index),
+        });
+    }
+
+    /// Publishes the samples that were edited since the last call.
+    fn publish_edits(&mut self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let Some((first, last)) = state.edited.take() else {
+            return;
+        };
+
+        if !state.is_gesturing {
+            // Send a GestureStart message first.
+            if let Some(on_gesture) = &mut self.on_gesture {
+                shell.publish((on_gesture)(ShapeGesture::GestureStart));
+            }
+            state.is_gesturing = true;
+        }
+
+        if let Some(on_gesture) = &mut self.on_gesture {
+            shell.publish((on_gesture)(ShapeGesture::Gesturing {
+                start: first,
+                values: self.table[first..=last].to_vec(),
+            }));
+        }
+    }
+
+    fn end_gesture(&mut self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        state.drag = None;
+
+        if state.is_gesturing {
+            if let Some(on_gesture) = &mut self.on_gesture {
+                shell.publish((on_gesture)(ShapeGesture::GestureEnd));
+            }
+            state.is_gesturing = false;
+        }
+    }
+}
+
+struct State {
+    drag: Option<Drag>,
+    edited: Option<(usize, usize)>,
+    is_gesturing: bool,
+    hovered: bool,
+    pressed_modifiers: keyboard::Modifiers,
+    /// The width of the back border as last drawn, so the cursor can be
+    /// mapped to the table inside of it.
+    border_width: Cell<f32>,
+}
+
+/// Returns the area inside the back border, where the table is drawn.
+fn inner_bounds(bounds: &Rectangle, border_width: f32) -> Rectangle {
+    Rectangle {
+        x: bounds.x.round() + border_width,
+        y: bounds.y.round() + border_width,
+        width: bounds.width.round() - (border_width * 2.0),
+        height: bounds.height.round() - (border_width * 2.0),
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ShapeEditor<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer + iced_graphics::geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            drag: None,
+            edited: None,
+            is_gesturing: false,
+            hovered: false,
+            pressed_modifiers: keyboard::Modifiers::NONE,
+            border_width: Cell::new(0.0),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.enabled || self.table.is_empty() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+        let inner = inner_bounds(&bounds, state.border_width.get());
+
+        let mut capture_event = false;
+        let mut should_redraw = false;
+
+        if cursor_is_over != state.hovered {
+            state.hovered = cursor_is_over;
+            should_redraw = true;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(mut drag) = state.drag.take() {
+                    let (from, to) = drag.segment_to(*position);
+                    let covered = self.paint(&inner, from, to, state);
+
+                    for index in drag.cover(covered).into_iter().flatten() {
+                        for sample in self.cell_samples(index) {
+                            self.set_sample(sample, drag.original()[sample], state);
+                        }
+                    }
+
+                    state.drag = Some(drag);
+                    should_redraw = true;
+                    capture_event = true;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor_is_over && let Some(position) = cursor.position() {
+                    let original = self.table.clone();
+                    let covered = self.paint(&inner, position, position, state);
+
+                    let tool = if state.pressed_modifiers.contains(self.line_modifiers) {
+                        ShapeTool::Line
+                    } else {
+                        self.tool
+                    };
+
+                    state.drag = match tool {
+                        ShapeTool::Pencil => Some(Drag::Freehand {
+                            prev_position: position,
+                        }),
+                        ShapeTool::Line => Some(Drag::Line {
+                            anchor: position,
+                            original,
+                            covered,
+                        }),
+                    };
+
+                    should_redraw = true;
+                    capture_event = true;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.is_some() {
+                    should_redraw = true;
+                    capture_event = true;
+                }
+                self.end_gesture(state, shell);
+            }
+            Event::Window(window::Event::Unfocused) => {
+                self.end_gesture(state, shell);
+            }
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. }
+                | keyboard::Event::KeyReleased { modifiers, .. }
+                | keyboard::Event::ModifiersChanged(modifiers) => {
+                    state.pressed_modifiers = *modifiers;
+                }
+            },
+            _ => {}
+        }
+
+        self.publish_edits(state, shell);
+
+        if capture_event {
+            shell.capture_event();
+        }
+
+        if should_redraw {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+
+        let appearance = if !self.enabled {
+            theme.disabled(&self.style)
+        } else if state.drag.is_some() {
+            theme.gesturing(&self.style)
+        } else if cursor_is_over {
+            theme.hovered(&self.style)
+        } else {
+            theme.idle(&self.style)
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        state.border_width.set(appearance.back_border_width);
+        let inner = inner_bounds(&bounds, appearance.back_border_width);
+
+        if inner.width <= 0.0 || inner.height <= 0.0 {
+            return;
+        }
+
+        let line = |renderer: &mut Renderer, bounds: Rectangle, color| {
+            renderer.fill_quad(
+                Quad {
+                    bounds,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                color,
+            );
+        };
+
+        if let Some(grid) = self.grid {
+            for step in 1..grid.steps {
+                let x = (inner.x + inner.width * step as f32 / grid.steps as f32).round();
+                line(
+                    renderer,
+                    Rectangle {
+                        x,
+                        y: inner.y,
+                        width: 1.0,
+                        height: inner.height,
+                    },
+                    appearance.grid_color,
+                );
+            }
+            for level in 1..grid.levels {
+                let y = (inner.y + inner.height * level as f32 / grid.levels as f32).round();
+                line(
+                    renderer,
+                    Rectangle {
+                        x: inner.x,
+                        y,
+                        width: inner.width,
+                        height: 1.0,
+                    },
+                    appearance.grid_color,
+                );
+            }
+        }
+
+        let center_y = inner.height / 2.0;
+
+        if let Some(center_line_color) = appearance.center_line_color {
+            line(
+                renderer,
+                Rectangle {
+                    x: inner.x,
+                    y: (inner.y + center_y).round(),
+                    width: inner.width,
+                    height: 1.0,
+                },
+                center_line_color,
+            );
+        }
+
+        if self.table.is_empty() {
+            return;
+        }
+
+        // The shape is drawn relative to the inner bounds, with one extra
+        // point at the end that wraps around to the first sample.
+        let len = self.table.len();
+        let points: Vec<Point> = self
+            .table
+            .iter()
+            .chain(std::iter::once(&self.table[0]))
+            .enumerate()
+            .map(|(i, normal)| {
+                Point::new(
+                    inner.width * i as f32 / len as f32,
+                    normal.scale_inv(inner.height),
+                )
+            })
+            .collect();
+
+        let mut frame = Frame::new(renderer, inner.size());
+
+        if let Some(fill_color) = appearance.fill_color {
+            let fill_path = Path::new(|path| {
+                path.move_to(Point::new(0.0, center_y));
+                for point in &points {
+                    path.line_to(*point);
+                }
+                path.line_to(Point::new(inner.width, center_y));
+                path.close();
+            });
+
+            frame.fill(&fill_path, fill_color);
+        }
+
+        let line_path = Path::new(|path| {
+            path.move_to(points[0]);
+            for point in &points[1..] {
+                path.line_to(*point);
+            }
+        });
+
+        frame.stroke(
+            &line_path,
+            Stroke {
+                width: appearance.line_width,
+                style: geometry::Style::Solid(appearance.line_color),
+                ..Stroke::default()
+            },
+        );
+
+        renderer.with_translation(Vector::new(inner.x, inner.y), |renderer| {
+            // clippy gets confused when default iced features are disabled
+            #[allow(clippy::unit_arg)]
+            renderer.draw_geometry(frame.into_geometry());
+        });
+
+        if let Some(phase) = self.phase {
+            let diameter = appearance.phase_dot_diameter;
+            let value = value_at_phase(&self.table, phase.as_f32());
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle {
+                        x: inner.x + phase.scale(inner.width) - diameter / 2.0,
+                        y: inner.y + value.scale_inv(inner.height) - diameter / 2.0,
+                        width: diameter,
+                        height: diameter,
+                    },
+                    border: Border {
+                        radius: Radius::new(diameter / 2.0),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                appearance.phase_dot_color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ShapeEditor<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer + iced_graphics::geometry::Renderer,
+{
+    fn from(shape_editor: ShapeEditor<'a, Message, Theme>) -> Self {
+        Self::new(shape_editor)
+    }
+}
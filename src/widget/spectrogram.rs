@@ -0,0 +1,443 @@
+//! Display a scrolling spectrogram (waterfall) of FFT columns.
+
+use crate::core::{DBRange, FreqRange, Normal};
+use iced_core::{
+    Border, Color, Element, Layout, Length, Rectangle, Shadow, Size, Widget,
+    border::Radius,
+    image::{self, Handle, Image},
+    layout, mouse,
+    renderer::{Quad, Style},
+    widget::{Tree, tree},
+};
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub use crate::style::spectrogram::{Appearance, StyleSheet};
+
+const DEFAULT_HEIGHT: f32 = 200.0;
+/// The number of entries in the lookup table a color map is sampled into.
+const COLOR_MAP_LEN: usize = 256;
+
+static NEXT_HISTORY_ID: AtomicU64 = AtomicU64::new(0);
+
+/// A ring buffer of spectrum columns shown by a [`Spectrogram`].
+///
+/// Push one FFT column at a time with [`push_column`]. Once the buffer is
+/// full, the oldest column is replaced. Each column is resampled into a
+/// fixed number of rows, with frequency mapped through a [`FreqRange`] and
+/// magnitude mapped through a [`DBRange`].
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+/// [`push_column`]: #method.push_column
+/// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+/// [`DBRange`]: ../../core/range/struct.DBRange.html
+#[derive(Debug, Clone)]
+pub struct History {
+    id: u64,
+    generation: u64,
+    columns: usize,
+    rows: usize,
+    sample_rate: f32,
+    freq_range: FreqRange,
+    db_range: DBRange,
+    /// The normalized magnitudes of each column, bottom row first.
+    data: Vec<f32>,
+    /// The index of the column the next push writes to, which is also the
+    /// oldest column.
+    next_column: usize,
+}
+
+impl History {
+    /// Creates a new empty [`History`].
+    ///
+    /// * `columns` - The number of columns to keep. This is the number of
+    ///   columns visible at once.
+    /// * `rows` - The vertical resolution each column is resampled to.
+    /// * `sample_rate` - The sample rate of the analyzed audio in Hz.
+    ///
+    /// By default frequencies are mapped with [`FreqRange::HZ_20_TO_20K`]
+    /// and magnitudes from `-96 dB` to `0 dB` are mapped linearly.
+    ///
+    /// [`History`]: struct.History.html
+    /// [`FreqRange::HZ_20_TO_20K`]: ../../core/range/struct.FreqRange.html#associatedconstant.HZ_20_TO_20K
+    pub fn new(columns: usize, rows: usize, sample_rate: f32) -> Self {
+        let columns = columns.max(1);
+        let rows = rows.max(1);
+
+        Self {
+            id: NEXT_HISTORY_ID.fetch_add(1, Ordering::Relaxed),
+            generation: 0,
+            columns,
+            rows,
+            sample_rate,
+            freq_range: FreqRange::HZ_20_TO_20K,
+            db_range: DBRange::new(-96.0, 0.0, Normal::MAX, 1.0),
+            data: vec![0.0; columns * rows],
+            next_column: 0,
+        }
+    }
+
+    /// Sets the [`FreqRange`] shown from the bottom to the top.
+    ///
+    /// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+    pub fn freq_range(mut self, freq_range: FreqRange) -> Self {
+        self.freq_range = freq_range;
+        self
+    }
+
+    /// Sets the [`DBRange`] that magnitudes are mapped through before they
+    /// are colored. The minimum of the range gets the first color of the
+    /// color map, and the maximum gets the last color.
+    ///
+    /// [`DBRange`]: ../../core/range/struct.DBRange.html
+    pub fn db_range(mut self, db_range: DBRange) -> Self {
+        self.db_range = db_range;
+        self
+    }
+
+    /// Sets the sample rate of the analyzed audio in Hz. This only affects
+    /// columns pushed after this call.
+    pub fn set_sample_rate(&mut self, sample_rate: f32) {
+        self.sample_rate = sample_rate;
+    }
+
+    /// Returns the number of columns kept.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the number of rows each column is resampled to.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Pushes a new column on the right, scrolling the older columns to the
+    /// left.
+    ///
+    /// `magnitudes` are the magnitudes in dB of each FFT bin, evenly spaced
+    /// from 0 Hz to the Nyquist frequency (inclusive). For an FFT of size
+    /// `n`, that is `n / 2 + 1` bins.
+    pub fn push_column(&mut self, magnitudes: &[f32]) {
+        let start = self.next_column * self.rows;
+        let column = &mut self.data[start..start + self.rows];
+
+        if magnitudes.len() < 2 || self.sample_rate <= 0.0 {
+            column.fill(0.0);
+        } else {
+            let last_bin = magnitudes.len() - 1;
+            let bins_per_hz = last_bin as f32 / (self.sample_rate / 2.0);
+            let rows = self.rows as f32;
+
+            let bin_at =
+                |normal: f32| self.freq_range.unmap_to_freq(Normal::new(normal)) * bins_per_hz;
+
+            for (row, value) in column.iter_mut().enumerate() {
+                let low = bin_at(row as f32 / rows);
+                let high = bin_at((row + 1) as f32 / rows);
+
+                let db = if high - low < 1.0 {
+                    // Less than one bin per row, so interpolate between the
+                    // two nearest bins.
+                    let bin = ((low + high) / 2.0).clamp(0.0, last_bin as f32);
+                    let index = (bin.floor() as usize).min(last_bin - 1);
+                    let fract = bin - index as f32;
+                    magnitudes[index] + (magnitudes[index + 1] - magnitudes[index]) * fract
+                } else {
+                    // Several bins per row, so keep the loudest one so peaks
+                    // don't get lost.
+                    let first = (low.ceil() as usize).min(last_bin);
+                    let last = (high.floor() as usize).min(last_bin);
+                    magnitudes[first..=last]
+                        .iter()
+                        .fold(f32::MIN, |max, magnitude| max.max(*magnitude))
+                };
+
+                *value = self.db_range.map_db(db).as_f32();
+            }
+        }
+
+        self.next_column = (self.next_column + 1) % self.columns;
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Clears all columns.
+    pub fn clear(&mut self) {
+        self.data.fill(0.0);
+        self.next_column = 0;
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Renders the columns into RGBA pixels, oldest column on the left and
+    /// lowest row on the bottom.
+    fn to_rgba(&self, color_map: &[[u8; 4]]) -> Vec<u8> {
+        let mut pixels = vec![0; self.columns * self.rows * 4];
+        let max_index = (color_map.len() - 1) as f32;
+
+        for x in 0..self.columns {
+            let column = (self.next_column + x) % self.columns;
+            let column = &self.data[column * self.rows..(column + 1) * self.rows];
+
+            for (row, value) in column.iter().enumerate() {
+                let y = self.rows - 1 - row;
+                let color = color_map[(value * max_index).round() as usize];
+
+                let i = (y * self.columns + x) * 4;
+                pixels[i..i + 4].copy_from_slice(&color);
+            }
+        }
+
+        pixels
+    }
+}
+
+/// Samples the color map into a lookup table.
+fn color_lookup_table(color_map: &[Color]) -> Vec<[u8; 4]> {
+    let max_index = (COLOR_MAP_LEN - 1) as f32;
+
+    (0..COLOR_MAP_LEN)
+        .map(|i| {
+            let color = match color_map {
+                [] => Color::TRANSPARENT,
+                [color] => *color,
+                _ => {
+                    let position = i as f32 / max_index * (color_map.len() - 1) as f32;
+                    let index = (position.floor() as usize).min(color_map.len() - 2);
+                    let fract = position - index as f32;
+                    let (a, b) = (color_map[index], color_map[index + 1]);
+
+                    Color {
+                        r: a.r + (b.r - a.r) * fract,
+                        g: a.g + (b.g - a.g) * fract,
+                        b: a.b + (b.b - a.b) * fract,
+                        a: a.a + (b.a - a.a) * fract,
+                    }
+                }
+            };
+
+            color.into_rgba8()
+        })
+        .collect()
+}
+
+/// A scrolling spectrogram (waterfall) GUI widget.
+///
+/// It shows the columns of a [`History`] as a single image, with time from
+/// left to right and frequency from bottom to top. The image is only
+/// rebuilt when the [`History`] changes.
+///
+/// [`History`]: struct.History.html
+#[allow(missing_debug_implementations)]
+pub struct Spectrogram<'a, Theme: StyleSheet> {
+    history: &'a History,
+    filter_method: image::FilterMethod,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Theme> Spectrogram<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Spectrogram`] showing the given [`History`].
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    /// [`History`]: struct.History.html
+    pub fn new(history: &'a History) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        Spectrogram {
+            history,
+            filter_method: image::FilterMethod::Linear,
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets how the image is scaled to the size of the widget.
+    ///
+    /// The default is `FilterMethod::Linear`.
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the width of the [`Spectrogram`].
+    ///
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Spectrogram`].
+    ///
+    /// The default height is `Length::Fixed(200.0)`.
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`Spectrogram`].
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The last image rendered from a [`History`].
+///
+/// [`History`]: struct.History.html
+struct Cache {
+    history_id: u64,
+    generation: u64,
+    color_map: Vec<Color>,
+    color_lookup_table: Vec<[u8; 4]>,
+    handle: Handle,
+}
+
+#[derive(Default)]
+struct State {
+    cache: RefCell<Option<Cache>>,
+}
+
+impl State {
+    fn handle(&self, history: &History, color_map: &[Color]) -> Handle {
+        let mut cache = self.cache.borrow_mut();
+
+        if let Some(cache) = cache.as_ref()
+            && cache.history_id == history.id
+            && cache.generation == history.generation
+            && cache.color_map == color_map
+        {
+            return cache.handle.clone();
+        }
+
+        let color_lookup_table = match cache.take() {
+            Some(cache) if cache.color_map == color_map => cache.color_lookup_table,
+            _ => color_lookup_table(color_map),
+        };
+
+        let handle = Handle::from_rgba(
+            history.columns as u32,
+            history.rows as u32,
+            history.to_rgba(&color_lookup_table),
+        );
+
+        *cache = Some(Cache {
+            history_id: history.id,
+            generation: history.generation,
+            color_map: color_map.to_vec(),
+            color_lookup_table,
+            handle: handle.clone(),
+        });
+
+        handle
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Spectrogram<'a, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer + image::Renderer<Handle = Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        let border_width = appearance.back_border_width;
+        let inner = Rectangle {
+            x: bounds.x + border_width,
+            y: bounds.y + border_width,
+            width: bounds.width - (border_width * 2.0),
+            height: bounds.height - (border_width * 2.0),
+        };
+
+        if inner.width <= 0.0 || inner.height <= 0.0 {
+            return;
+        }
+
+        let handle = state.handle(self.history, &appearance.color_map);
+
+        renderer.draw_image(
+            Image::new(handle).filter_method(self.filter_method),
+            inner,
+            inner,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Spectrogram<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer + image::Renderer<Handle = Handle>,
+{
+    fn from(spectrogram: Spectrogram<'a, Theme>) -> Self {
+        Self::new(spectrogram)
+    }
+}
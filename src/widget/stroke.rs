@@ -0,0 +1,137 @@
+//! Drawing values into a row of cells, either freehand or as a straight
+//! line. Shared by the [`MultiSlider`] and the [`ShapeEditor`].
+//!
+//! [`MultiSlider`]: ../multi_slider/struct.MultiSlider.html
+//! [`ShapeEditor`]: ../shape_editor/struct.ShapeEditor.html
+
+use std::ops::Range;
+
+use crate::core::Normal;
+use iced_core::{Point, Rectangle};
+
+/// A row of equally wide cells spanning the width of some bounds.
+#[derive(Debug, Copy, Clone)]
+pub(super) struct Cells {
+    x: f32,
+    width: f32,
+    count: usize,
+    /// Whether the cursor picks the cell it is inside of (`true`), or the
+    /// cell whose left edge is nearest (`false`).
+    centered: bool,
+}
+
+impl Cells {
+    pub(super) fn new(bounds: &Rectangle, count: usize, centered: bool) -> Self {
+        Self {
+            x: bounds.x,
+            width: bounds.width,
+            count,
+            centered,
+        }
+    }
+
+    /// Returns the cell at `x`, clamped to the first and last cell.
+    pub(super) fn at(&self, x: f32) -> usize {
+        if self.width <= 0.0 {
+            return 0;
+        }
+
+        let pos = (x - self.x) / self.width * self.count as f32;
+        let index = if self.centered {
+            pos.floor()
+        } else {
+            pos.round()
+        };
+        (index.max(0.0) as usize).min(self.count - 1)
+    }
+
+    /// Returns the horizontal position of the cell at `index`.
+    pub(super) fn x(&self, index: usize) -> f32 {
+        let offset = if self.centered { 0.5 } else { 0.0 };
+        self.x + (index as f32 + offset) * self.width / self.count as f32
+    }
+
+    /// Returns the first and last cell between `from` and `to`, and the
+    /// height of the straight line between the two points at each of them.
+    pub(super) fn line(
+        self,
+        from: Point,
+        to: Point,
+    ) -> ((usize, usize), impl Iterator<Item = (usize, f32)>) {
+        let from_index = self.at(from.x);
+        let to_index = self.at(to.x);
+
+        let (first, last) = if from_index <= to_index {
+            (from_index, to_index)
+        } else {
+            (to_index, from_index)
+        };
+
+        let heights = (first..=last).map(move |index| {
+            let y = if index == to_index {
+                to.y
+            } else if index == from_index {
+                from.y
+            } else {
+                let t = (self.x(index) - from.x) / (to.x - from.x);
+                from.y + (to.y - from.y) * t.clamp(0.0, 1.0)
+            };
+
+            (index, y)
+        });
+
+        ((first, last), heights)
+    }
+}
+
+/// How the user is drawing across the cells.
+pub(super) enum Drag {
+    /// Drawing freehand, remembering the last position of the cursor.
+    Freehand { prev_position: Point },
+    /// Drawing a straight line from `anchor`. The values from before the
+    /// line was started are kept so cells the line no longer covers can be
+    /// restored.
+    Line {
+        anchor: Point,
+        original: Vec<Normal>,
+        covered: (usize, usize),
+    },
+}
+
+impl Drag {
+    /// Returns the segment to draw now that the cursor has moved to
+    /// `position`.
+    pub(super) fn segment_to(&mut self, position: Point) -> (Point, Point) {
+        match self {
+            Self::Freehand { prev_position } => {
+                let from = std::mem::replace(prev_position, position);
+                (from, position)
+            }
+            Self::Line { anchor, .. } => (*anchor, position),
+        }
+    }
+
+    /// Records the first and last cell drawn by the latest segment. Returns
+    /// the cells a line covered before but no longer does, which should be
+    /// restored to their [`original`](Self::original) values.
+    pub(super) fn cover(&mut self, (first, last): (usize, usize)) -> [Range<usize>; 2] {
+        match self {
+            Self::Freehand { .. } => [0..0, 0..0],
+            Self::Line { covered, .. } => {
+                let (prev_first, prev_last) = std::mem::replace(covered, (first, last));
+                [
+                    prev_first..first.min(prev_last + 1),
+                    (last + 1).max(prev_first)..prev_last + 1,
+                ]
+            }
+        }
+    }
+
+    /// Returns the values from before a line was started.
+    pub(super) fn original(&self) -> &[Normal] {
+        match self {
+            Self::Freehand { .. } => &[],
+            Self::Line { original, .. } => original,
+        }
+    }
+}
@@ -0,0 +1,925 @@
+//! Display an interactive compressor transfer curve where the threshold,
+//! ratio, knee width and makeup gain are dragged directly on the graph.
+
+use crate::core::{
+    DBRange, FloatRange, Normal, NormalParam,
+    virtual_slider::{Gesture, OnGesture, set_param_value},
+};
+use crate::{text_marks, tick_marks};
+use iced_core::{
+    Border, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shadow,
+    Shell, Size, Text, Vector, Widget,
+    alignment::Vertical,
+    border::Radius,
+    keyboard, layout, mouse,
+    renderer::{Quad, Style},
+    touch,
+    widget::{
+        Tree,
+        text::{Alignment, LineHeight, Shaping, Wrapping},
+        tree,
+    },
+    window,
+};
+use iced_graphics::geometry::{self, Frame, Path, Stroke};
+
+pub use crate::style::transfer_curve::{
+    Appearance, HandleAppearance, StyleSheet, TextMarksAppearance, TickMarksAppearance,
+};
+
+const DEFAULT_SIZE: f32 = 200.0;
+const DEFAULT_HANDLE_HIT_RADIUS: f32 = 8.0;
+/// The number of line segments the curve is drawn with.
+const CURVE_SEGMENTS: usize = 128;
+
+/// Returns the output level in dB of a compressor for the given input level
+/// in dB, before makeup gain.
+///
+/// * `threshold` - The threshold in dB.
+/// * `ratio` - The compression ratio (such as `4.0` for 4:1).
+/// * `knee` - The width of the soft knee in dB, centered on the threshold.
+///   Use `0.0` for a hard knee.
+pub fn output_db(input: f32, threshold: f32, ratio: f32, knee: f32) -> f32 {
+    let ratio = ratio.max(1.0);
+    let over = input - threshold;
+
+    if knee > 0.0 && over.abs() <= knee / 2.0 {
+        let x = over + knee / 2.0;
+        input + (1.0 / ratio - 1.0) * x * x / (2.0 * knee)
+    } else if over <= 0.0 {
+        input
+    } else {
+        threshold + over / ratio
+    }
+}
+
+/// The handles of a [`TransferCurve`].
+///
+/// [`TransferCurve`]: struct.TransferCurve.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Handle {
+    Threshold,
+    Ratio,
+    Knee,
+    Makeup,
+}
+
+/// A compressor transfer curve GUI widget.
+///
+/// It shows the output level against the input level, from the minimum of
+/// the display range in the bottom left corner to the maximum in the top
+/// right corner. The threshold, ratio, knee width and makeup gain each
+/// control their own [`NormalParam`] and send their own stream of gestures.
+///
+/// * Drag the handle on the threshold left or right to move the threshold.
+/// * Drag the handle at the right edge up or down to change the ratio.
+/// * Drag the handle at the end of the knee left or right to change the
+///   knee width. Hold `Alt` to grab it when it overlaps the threshold
+///   handle.
+/// * Drag the handle at the left edge up or down to change the makeup gain.
+/// * Double-click a handle to reset it to its default value.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+#[allow(missing_debug_implementations)]
+pub struct TransferCurve<'a, Message, Theme: StyleSheet> {
+    params: [NormalParam; 4],
+    on_gestures: [OnGesture<'a, Message>; 4],
+    threshold_range: DBRange,
+    ratio_range: FloatRange,
+    knee_range: FloatRange,
+    makeup_range: DBRange,
+    display_min: f32,
+    display_max: f32,
+    input_level: Option<f32>,
+    gain_reduction: Option<f32>,
+    handle_hit_radius: f32,
+    knee_modifiers: keyboard::Modifiers,
+    tick_marks: Option<&'a tick_marks::Group>,
+    text_marks: Option<&'a text_marks::Group>,
+    enabled: bool,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> TransferCurve<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`TransferCurve`].
+    ///
+    /// * `threshold` - The threshold, mapped through the threshold range.
+    /// * `ratio` - The ratio, mapped through the ratio range.
+    /// * `knee` - The knee width, mapped through the knee range.
+    /// * `makeup` - The makeup gain, mapped through the makeup range.
+    ///
+    /// [`TransferCurve`]: struct.TransferCurve.html
+    pub fn new(
+        threshold: impl Into<NormalParam>,
+        ratio: impl Into<NormalParam>,
+        knee: impl Into<NormalParam>,
+        makeup: impl Into<NormalParam>,
+    ) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        TransferCurve {
+            params: [threshold.into(), ratio.into(), knee.into(), makeup.into()],
+            on_gestures: [None, None, None, None],
+            threshold_range: DBRange::new(-60.0, 0.0, Normal::MAX, 1.0),
+            ratio_range: FloatRange::new(1.0, 20.0),
+            knee_range: FloatRange::new(0.0, 24.0),
+            makeup_range: DBRange::new(0.0, 24.0, Normal::MIN, 1.0),
+            display_min: -60.0,
+            display_max: 0.0,
+            input_level: None,
+            gain_reduction: None,
+            handle_hit_radius: DEFAULT_HANDLE_HIT_RADIUS,
+            knee_modifiers: keyboard::Modifiers::ALT,
+            tick_marks: None,
+            text_marks: None,
+            enabled: true,
+            width: Length::Fixed(DEFAULT_SIZE),
+            height: Length::Fixed(DEFAULT_SIZE),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the message to emit when the user gestures the threshold.
+    pub fn on_gesture_threshold(self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.set_on_gesture(Handle::Threshold, on_gesture)
+    }
+
+    /// Sets the message to emit when the user gestures the ratio.
+    pub fn on_gesture_ratio(self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.set_on_gesture(Handle::Ratio, on_gesture)
+    }
+
+    /// Sets the message to emit when the user gestures the knee width.
+    pub fn on_gesture_knee(self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.set_on_gesture(Handle::Knee, on_gesture)
+    }
+
+    /// Sets the message to emit when the user gestures the makeup gain.
+    pub fn on_gesture_makeup(self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.set_on_gesture(Handle::Makeup, on_gesture)
+    }
+
+    /// Sets the range the threshold param is mapped through.
+    ///
+    /// The default is `-60 dB` to `0 dB`, mapped linearly.
+    pub fn threshold_range(mut self, range: DBRange) -> Self {
+        self.threshold_range = range;
+        self
+    }
+
+    /// Sets the range the ratio param is mapped through.
+    ///
+    /// The default is `1.0` to `20.0`.
+    pub fn ratio_range(mut self, range: FloatRange) -> Self {
+        self.ratio_range = range;
+        self
+    }
+
+    /// Sets the range in dB the knee width param is mapped through.
+    ///
+    /// The default is `0.0` to `24.0`.
+    pub fn knee_range(mut self, range: FloatRange) -> Self {
+        self.knee_range = range;
+        self
+    }
+
+    /// Sets the range the makeup gain param is mapped through.
+    ///
+    /// The default is `0 dB` to `24 dB`, mapped linearly.
+    pub fn makeup_range(mut self, range: DBRange) -> Self {
+        self.makeup_range = range;
+        self
+    }
+
+    /// Sets the range of levels in dB shown on both axes. Tick marks and
+    /// text marks are positioned relative to this range.
+    ///
+    /// The default is `-60.0` to `0.0`.
+    pub fn display_range(mut self, min: f32, max: f32) -> Self {
+        self.display_min = min;
+        self.display_max = max.max(min + f32::EPSILON);
+        self
+    }
+
+    /// Sets the current input level in dB, shown as a dot on the curve. Set
+    /// this to `None` to hide the dot.
+    pub fn input_level(mut self, input_level: Option<f32>) -> Self {
+        self.input_level = input_level;
+        self
+    }
+
+    /// Sets the current gain reduction in dB, shown as a readout in the top
+    /// left corner. Set this to `None` to hide the readout.
+    pub fn gain_reduction(mut self, gain_reduction: Option<f32>) -> Self {
+        self.gain_reduction = gain_reduction;
+        self
+    }
+
+    /// Sets the distance in pixels from a handle within which the handle
+    /// can be grabbed.
+    ///
+    /// The default is `8.0`.
+    pub fn handle_hit_radius(mut self, radius: f32) -> Self {
+        self.handle_hit_radius = radius;
+        self
+    }
+
+    /// Sets the modifier keys that give the knee handle priority over the
+    /// threshold handle when they overlap. While they are held, the knee
+    /// handle is grabbed whenever the cursor is within its hit radius.
+    /// `keyboard::Modifiers::NONE` turns this off.
+    ///
+    /// The default is `keyboard::Modifiers::ALT`.
+    pub fn knee_modifiers(mut self, modifiers: keyboard::Modifiers) -> Self {
+        self.knee_modifiers = modifiers;
+        self
+    }
+
+    /// Sets the tick marks to display on both axes, and to draw grid lines
+    /// at. Note your [`StyleSheet`] must also implement
+    /// `tick_marks_appearance(&self) -> Option<TickMarksAppearance>` for the
+    /// tick marks to display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/transfer_curve/trait.StyleSheet.html
+    pub fn tick_marks(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display on both axes. Note your
+    /// [`StyleSheet`] must also implement
+    /// `text_marks_appearance(&self) -> Option<TextMarksAppearance>` for them
+    /// to display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/transfer_curve/trait.StyleSheet.html
+    pub fn text_marks(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks = Some(text_marks);
+        self
+    }
+
+    /// Enable/disable this widget.
+    ///
+    /// The default is `true`.
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the width of the [`TransferCurve`].
+    ///
+    /// The default width is `Length::Fixed(200.0)`.
+    ///
+    /// [`TransferCurve`]: struct.TransferCurve.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TransferCurve`].
+    ///
+    /// The default height is `Length::Fixed(200.0)`.
+    ///
+    /// [`TransferCurve`]: struct.TransferCurve.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`TransferCurve`].
+    ///
+    /// [`TransferCurve`]: struct.TransferCurve.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn set_on_gesture(
+        mut self,
+        handle: Handle,
+        on_gesture: impl 'a + FnMut(Gesture) -> Message,
+    ) -> Self {
+        self.on_gestures[handle as usize] = Some(Box::new(on_gesture));
+        self
+    }
+
+    fn threshold(&self) -> f32 {
+        self.threshold_range
+            .unmap_to_db(self.params[Handle::Threshold as usize].normal)
+    }
+
+    fn ratio(&self) -> f32 {
+        self.ratio_range
+            .unmap_to_value(self.params[Handle::Ratio as usize].normal)
+    }
+
+    fn knee(&self) -> f32 {
+        self.knee_range
+            .unmap_to_value(self.params[Handle::Knee as usize].normal)
+            .max(0.0)
+    }
+
+    fn makeup(&self) -> f32 {
+        self.makeup_range
+            .unmap_to_db(self.params[Handle::Makeup as usize].normal)
+    }
+
+    /// Returns the output level in dB for the input level in dB, including
+    /// makeup gain.
+    fn curve(&self, input: f32) -> f32 {
+        output_db(input, self.threshold(), self.ratio(), self.knee()) + self.makeup()
+    }
+
+    fn db_to_point(&self, bounds: &Rectangle, input: f32, output: f32) -> Point {
+        let span = self.display_max - self.display_min;
+
+        Point::new(
+            bounds.x + (input - self.display_min) / span * bounds.width,
+            bounds.y + (1.0 - (output - self.display_min) / span) * bounds.height,
+        )
+    }
+
+    fn point_to_db(&self, bounds: &Rectangle, point: Point) -> (f32, f32) {
+        let span = self.display_max - self.display_min;
+
+        (
+            self.display_min + (point.x - bounds.x) / bounds.width.max(1.0) * span,
+            self.display_min + (1.0 - (point.y - bounds.y) / bounds.height.max(1.0)) * span,
+        )
+    }
+
+    fn handle_position(&self, bounds: &Rectangle, handle: Handle) -> Point {
+        let input = match handle {
+            Handle::Threshold => self.threshold(),
+            Handle::Knee => self.threshold() + self.knee() / 2.0,
+            Handle::Ratio => self.display_max,
+            Handle::Makeup => self.display_min,
+        };
+
+        self.db_to_point(bounds, input, self.curve(input))
+    }
+
+    /// Returns the handle closest to `point` within the hit radius. When
+    /// `knee_first` is set, the knee handle wins whenever it is in reach,
+    /// even if another handle is closer.
+    fn handle_at(&self, bounds: &Rectangle, point: Point, knee_first: bool) -> Option<Handle> {
+        if knee_first
+            && self.handle_position(bounds, Handle::Knee).distance(point) <= self.handle_hit_radius
+        {
+            return Some(Handle::Knee);
+        }
+
+        [
+            Handle::Threshold,
+            Handle::Knee,
+            Handle::Ratio,
+            Handle::Makeup,
+        ]
+        .into_iter()
+        .map(|handle| (handle, self.handle_position(bounds, handle).distance(point)))
+        .filter(|(_, distance)| *distance <= self.handle_hit_radius)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(handle, _)| handle)
+    }
+
+    /// Returns `true` if the knee modifiers are held.
+    fn knee_first(&self, state: &State) -> bool {
+        !self.knee_modifiers.is_empty() && state.pressed_modifiers.contains(self.knee_modifiers)
+    }
+
+    /// Returns the new normal value of a handle dragged to `point`.
+    fn drag_value(&self, bounds: &Rectangle, handle: Handle, point: Point) -> Normal {
+        let (input, output) = self.point_to_db(bounds, point);
+
+        match handle {
+            Handle::Threshold => self.threshold_range.map_db(input),
+            Handle::Knee => self
+                .knee_range
+                .map(((input - self.threshold()) * 2.0).max(0.0)),
+            Handle::Ratio => {
+                let threshold = self.threshold();
+                let over = output - self.makeup() - threshold;
+                let ratio = if over > 0.0 {
+                    (self.display_max - threshold) / over
+                } else {
+                    f32::MAX
+                };
+                self.ratio_range.map(ratio.max(1.0))
+            }
+            Handle::Makeup => self.makeup_range.map_db(output - self.display_min),
+        }
+    }
+
+    fn end_gesture(&mut self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        state.drag = None;
+
+        for (last_sent_gesture, on_gesture) in state
+            .last_sent_gestures
+            .iter_mut()
+            .zip(self.on_gestures.iter_mut())
+        {
+            if *last_sent_gesture != Gesture::GestureEnd {
+                if let Some(on_gesture) = on_gesture {
+                    shell.publish((on_gesture)(Gesture::GestureEnd));
+                }
+                *last_sent_gesture = Gesture::GestureEnd;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Drag {
+    handle: Handle,
+    /// The offset from where the handle was grabbed to the handle.
+    offset: Vector,
+}
+
+struct State {
+    last_sent_gestures: [Gesture; 4],
+    drag: Option<Drag>,
+    hovered_handle: Option<Handle>,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TransferCurve<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer
+        + iced_core::text::Renderer<Font = iced_core::Font>
+        + iced_graphics::geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            last_sent_gestures: [Gesture::GestureEnd; 4],
+            drag: None,
+            hovered_handle: None,
+            pressed_modifiers: keyboard::Modifiers::NONE,
+            last_click: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let mut capture_event = false;
+        let mut should_redraw = false;
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = state.drag {
+                    let i = drag.handle as usize;
+                    let new_value = self.drag_value(&bounds, drag.handle, *position + drag.offset);
+
+                    if set_param_value(
+                        &mut self.params[i],
+                        new_value,
+                        &mut state.last_sent_gestures[i],
+                        &mut self.on_gestures[i],
+                        shell,
+                    ) {
+                        should_redraw = true;
+                    }
+                    capture_event = true;
+                } else {
+                    let knee_first = self.knee_first(state);
+                    let hovered = self.handle_at(&bounds, *position, knee_first);
+
+                    if hovered != state.hovered_handle {
+                        state.hovered_handle = hovered;
+                        should_redraw = true;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                    state.last_click = Some(click);
+
+                    let knee_first = self.knee_first(state);
+
+                    if let Some(handle) = self.handle_at(&bounds, position, knee_first) {
+                        let i = handle as usize;
+
+                        if let mouse::click::Kind::Double = click.kind() {
+                            let default = self.params[i].default;
+                            set_param_value(
+                                &mut self.params[i],
+                                default,
+                                &mut state.last_sent_gestures[i],
+                                &mut self.on_gestures[i],
+                                shell,
+                            );
+                            self.end_gesture(state, shell);
+                        } else {
+                            state.drag = Some(Drag {
+                                handle,
+                                offset: self.handle_position(&bounds, handle) - position,
+                            });
+                        }
+
+                        should_redraw = true;
+                    }
+
+                    capture_event = true;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.is_some() {
+                    should_redraw = true;
+                    capture_event = true;
+                }
+                self.end_gesture(state, shell);
+            }
+            Event::Window(window::Event::Unfocused) => {
+                self.end_gesture(state, shell);
+            }
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. }
+                | keyboard::Event::KeyReleased { modifiers, .. }
+                | keyboard::Event::ModifiersChanged(modifiers) => {
+                    state.pressed_modifiers = *modifiers;
+                }
+            },
+            _ => {}
+        }
+
+        if capture_event {
+            shell.capture_event();
+        }
+
+        if should_redraw {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+
+        let appearance = if !self.enabled {
+            theme.disabled(&self.style)
+        } else if state.drag.is_some() {
+            theme.gesturing(&self.style)
+        } else if cursor_is_over {
+            theme.hovered(&self.style)
+        } else {
+            theme.idle(&self.style)
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        if let Some(tick_marks) = self.tick_marks {
+            if let Some(grid_color) = appearance.grid_color {
+                let positions = [
+                    tick_marks.tier_1(),
+                    tick_marks.tier_2(),
+                    tick_marks.tier_3(),
+                ];
+
+                for normal in positions.into_iter().flatten().flatten() {
+                    let x = (bounds.x + normal.scale(bounds.width)).round();
+                    let y = (bounds.y + normal.scale_inv(bounds.height)).round();
+
+                    for line in [
+                        Rectangle::new(Point::new(x, bounds.y), Size::new(1.0, bounds.height)),
+                        Rectangle::new(Point::new(bounds.x, y), Size::new(bounds.width, 1.0)),
+                    ] {
+                        renderer.fill_quad(
+                            Quad {
+                                bounds: line,
+                                border: Border::default(),
+                                shadow: Shadow::default(),
+                                snap: false,
+                            },
+                            grid_color,
+                        );
+                    }
+                }
+            }
+
+            if let Some(style) = theme.tick_marks_appearance(&self.style) {
+                tick_marks::draw_horizontal_tick_marks(
+                    renderer,
+                    &bounds,
+                    tick_marks,
+                    &style.style,
+                    &style.input_placement,
+                    false,
+                );
+                tick_marks::draw_vertical_tick_marks(
+                    renderer,
+                    &bounds,
+                    tick_marks,
+                    &style.style,
+                    &style.output_placement,
+                    false,
+                );
+            }
+        }
+
+        if let Some(text_marks) = self.text_marks
+            && let Some(style) = theme.text_marks_appearance(&self.style)
+        {
+            text_marks::draw_horizontal_text_marks(
+                renderer,
+                &bounds,
+                text_marks,
+                &style.style,
+                &style.input_placement,
+                false,
+            );
+            text_marks::draw_vertical_text_marks(
+                renderer,
+                &bounds,
+                text_marks,
+                &style.style,
+                &style.output_placement,
+                false,
+            );
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            let mut frame = Frame::new(renderer, bounds.size());
+            let origin = Rectangle {
+                x: 0.0,
+                y: 0.0,
+                ..bounds
+            };
+
+            if let Some(unity_line_color) = appearance.unity_line_color {
+                let unity_line = Path::line(
+                    Point::new(0.0, bounds.height),
+                    Point::new(bounds.width, 0.0),
+                );
+
+                frame.stroke(
+                    &unity_line,
+                    Stroke {
+                        width: 1.0,
+                        style: geometry::Style::Solid(unity_line_color),
+                        ..Stroke::default()
+                    },
+                );
+            }
+
+            let span = self.display_max - self.display_min;
+            let curve = Path::new(|path| {
+                for i in 0..=CURVE_SEGMENTS {
+                    let input = self.display_min + span * i as f32 / CURVE_SEGMENTS as f32;
+                    let point = self.db_to_point(&origin, input, self.curve(input));
+
+                    if i == 0 {
+                        path.move_to(point);
+                    } else {
+                        path.line_to(point);
+                    }
+                }
+            });
+
+            frame.stroke(
+                &curve,
+                Stroke {
+                    width: appearance.line_width,
+                    style: geometry::Style::Solid(appearance.line_color),
+                    ..Stroke::default()
+                },
+            );
+
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                // clippy gets confused when default iced features are disabled
+                #[allow(clippy::unit_arg)]
+                renderer.draw_geometry(frame.into_geometry());
+            });
+
+            let active_handle = state.drag.map(|drag| drag.handle).or(state.hovered_handle);
+
+            for handle in [
+                Handle::Makeup,
+                Handle::Ratio,
+                Handle::Knee,
+                Handle::Threshold,
+            ] {
+                let position = self.handle_position(&bounds, handle);
+                let style = if active_handle == Some(handle) {
+                    &appearance.active_handle
+                } else {
+                    &appearance.handle
+                };
+
+                draw_dot(
+                    renderer,
+                    position,
+                    style.diameter,
+                    style.color,
+                    style.border_width,
+                    style.border_color,
+                );
+            }
+
+            if let Some(input) = self.input_level {
+                let input = input.clamp(self.display_min, self.display_max);
+                let position = self.db_to_point(&bounds, input, self.curve(input));
+
+                draw_dot(
+                    renderer,
+                    position,
+                    appearance.input_diameter,
+                    appearance.input_color,
+                    0.0,
+                    Color::TRANSPARENT,
+                );
+            }
+        });
+
+        if let Some(gain_reduction) = self.gain_reduction {
+            let text_size = f32::from(appearance.readout_text_size);
+            let position = Point::new(bounds.x + 6.0, bounds.y + 4.0 + text_size / 2.0);
+
+            renderer.fill_text(
+                Text {
+                    content: format!("GR {:.1} dB", -gain_reduction.abs()),
+                    size: Pixels(text_size),
+                    bounds: Size::new(bounds.width, text_size * 2.0),
+                    align_x: Alignment::Left,
+                    align_y: Vertical::Center,
+                    line_height: LineHeight::default(),
+                    wrapping: Wrapping::default(),
+                    shaping: Shaping::Basic,
+                    font: appearance.readout_font,
+                },
+                position,
+                appearance.readout_color,
+                bounds,
+            );
+        }
+    }
+}
+
+fn draw_dot<Renderer: iced_core::Renderer>(
+    renderer: &mut Renderer,
+    center: Point,
+    diameter: f32,
+    color: Color,
+    border_width: f32,
+    border_color: Color,
+) {
+    let radius = diameter / 2.0;
+
+    renderer.fill_quad(
+        Quad {
+            bounds: Rectangle {
+                x: center.x - radius,
+                y: center.y - radius,
+                width: diameter,
+                height: diameter,
+            },
+            border: Border {
+                color: border_color,
+                width: border_width,
+                radius: Radius::new(radius),
+            },
+            shadow: Shadow::default(),
+            snap: false,
+        },
+        color,
+    );
+}
+
+impl<'a, Message, Theme, Renderer> From<TransferCurve<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer
+        + iced_core::text::Renderer<Font = iced_core::Font>
+        + iced_graphics::geometry::Renderer,
+{
+    fn from(transfer_curve: TransferCurve<'a, Message, Theme>) -> Self {
+        Self::new(transfer_curve)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::output_db;
+
+    fn assert_near(value: f32, expected: f32) {
+        assert!((value - expected).abs() < 1.0e-4, "{value} != {expected}");
+    }
+
+    #[test]
+    fn hard_knee() {
+        assert_near(output_db(-30.0, -20.0, 4.0, 0.0), -30.0);
+        assert_near(output_db(-20.0, -20.0, 4.0, 0.0), -20.0);
+        assert_near(output_db(-10.0, -20.0, 4.0, 0.0), -17.5);
+        assert_near(output_db(0.0, -20.0, 1.0, 0.0), 0.0);
+
+        // Ratios below 1:1 are treated as 1:1.
+        assert_near(output_db(0.0, -20.0, 0.5, 0.0), 0.0);
+    }
+
+    #[test]
+    fn soft_knee_meets_hard_knee() {
+        let (threshold, ratio, knee) = (-20.0, 4.0, 10.0);
+        let hard = |input| output_db(input, threshold, ratio, 0.0);
+        let soft = |input| output_db(input, threshold, ratio, knee);
+
+        // The soft knee matches the hard knee at both of its ends.
+        let start = threshold - knee / 2.0;
+        let end = threshold + knee / 2.0;
+        assert_near(soft(start), hard(start));
+        assert_near(soft(end), hard(end));
+
+        // ... and is continuous across them.
+        for edge in [start, end] {
+            assert!((soft(edge - 1.0e-3) - soft(edge + 1.0e-3)).abs() < 1.0e-2);
+        }
+
+        // Inside the knee the curve bends below the hard knee's corner.
+        assert_near(
+            soft(threshold),
+            threshold + (1.0 / ratio - 1.0) * knee / 8.0,
+        );
+        assert!(soft(threshold) < hard(threshold));
+
+        // Outside the knee both curves are the same.
+        assert_near(soft(-40.0), hard(-40.0));
+        assert_near(soft(0.0), hard(0.0));
+    }
+}
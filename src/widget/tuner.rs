@@ -0,0 +1,539 @@
+//! Display the note, octave and cents deviation of a detected pitch.
+
+use crate::core::Normal;
+use crate::core::range::NOTE_NAMES;
+use crate::{text_marks, tick_marks};
+use iced_core::{
+    Border, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shadow, Size, Text, Widget,
+    alignment::Vertical,
+    border::Radius,
+    layout, mouse,
+    renderer::{Quad, Style},
+    widget::{
+        Tree,
+        text::{Alignment, LineHeight, Shaping, Wrapping},
+    },
+};
+
+pub use crate::style::tuner::{Appearance, StyleSheet, TextMarksAppearance, TickMarksAppearance};
+
+const DEFAULT_WIDTH: f32 = 240.0;
+const DEFAULT_HEIGHT: f32 = 120.0;
+
+/// The space between the sides of the tuner and the cents scale.
+const SCALE_PADDING: f32 = 16.0;
+/// The space below the cents scale for the text marks.
+const SCALE_BOTTOM_MARGIN: f32 = 24.0;
+/// The space above the cents scale for the tick marks.
+const SCALE_TOP_MARGIN: f32 = 12.0;
+
+/// The range of the cents scale on either side of the note.
+const MAX_CENTS: f32 = 50.0;
+
+const FLAT_NAMES: [&str; 12] = [
+    "C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B",
+];
+
+/// How the black keys are named.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Accidentals {
+    /// Name the black keys with sharps, such as "C#".
+    #[default]
+    Sharps,
+    /// Name the black keys with flats, such as "Db".
+    Flats,
+}
+
+/// The nearest note to a frequency, and how far the frequency is from it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Pitch {
+    /// The MIDI note number of the nearest note, where `69` is A4.
+    pub note: i32,
+    /// How far the frequency is from the note in cents, from `-50.0` to
+    /// `50.0`.
+    pub cents: f32,
+}
+
+impl Pitch {
+    /// Returns the nearest note to `frequency` in Hz, with A4 tuned to
+    /// `reference` in Hz.
+    ///
+    /// Returns `None` if either frequency is not a positive number.
+    pub fn from_frequency(frequency: f32, reference: f32) -> Option<Self> {
+        if !(frequency.is_finite() && frequency > 0.0 && reference.is_finite() && reference > 0.0) {
+            return None;
+        }
+
+        let semitones = 69.0 + 12.0 * (frequency / reference).log2();
+        let note = semitones.round();
+
+        Some(Self {
+            note: note as i32,
+            cents: (semitones - note) * 100.0,
+        })
+    }
+
+    /// Returns the name of the note without the octave, such as "A" or
+    /// "C#".
+    pub fn name(&self, accidentals: Accidentals) -> &'static str {
+        let index = self.note.rem_euclid(12) as usize;
+
+        match accidentals {
+            Accidentals::Sharps => NOTE_NAMES[index],
+            Accidentals::Flats => FLAT_NAMES[index],
+        }
+    }
+
+    /// Returns the octave of the note, where middle C (MIDI note `60`) is
+    /// in octave `4`.
+    pub fn octave(&self) -> i32 {
+        self.note.div_euclid(12) - 1
+    }
+}
+
+/// Returns the position of `cents` on the scale of a [`Tuner`].
+///
+/// [`Tuner`]: struct.Tuner.html
+pub fn normal(cents: f32) -> Normal {
+    Normal::new((cents + MAX_CENTS) / (MAX_CENTS * 2.0))
+}
+
+/// Returns tick marks every 5 cents, with larger tick marks every 10 cents
+/// and the largest at 0 cents.
+pub fn standard_tick_marks() -> tick_marks::Group {
+    let marks: Vec<(Normal, tick_marks::Tier)> = (-10..=10)
+        .map(|i| {
+            let tier = if i == 0 {
+                tick_marks::Tier::One
+            } else if i % 2 == 0 {
+                tick_marks::Tier::Two
+            } else {
+                tick_marks::Tier::Three
+            };
+            (normal(i as f32 * 5.0), tier)
+        })
+        .collect();
+
+    tick_marks::Group::from_normalized(&marks)
+}
+
+/// Returns text marks every 25 cents.
+pub fn standard_text_marks() -> text_marks::Group {
+    (-2..=2)
+        .map(|i| {
+            let cents = i * 25;
+            let text = if cents > 0 {
+                format!("+{cents}")
+            } else {
+                format!("{cents}")
+            };
+            (normal(cents as f32), text)
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// An instrument tuner GUI widget.
+///
+/// It takes the frequency from a pitch detector and shows the nearest note
+/// with its octave, and a needle on a ±50 cent scale with a highlighted
+/// in-tune zone.
+#[allow(missing_debug_implementations)]
+pub struct Tuner<'a, Theme: StyleSheet> {
+    frequency: Option<f32>,
+    reference: f32,
+    accidentals: Accidentals,
+    in_tune_cents: f32,
+    tick_marks: Option<&'a tick_marks::Group>,
+    text_marks: Option<&'a text_marks::Group>,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Theme> Tuner<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Tuner`].
+    ///
+    /// * `frequency` - the detected frequency in Hz, or `None` while no
+    ///   pitch is detected.
+    ///
+    /// [`Tuner`]: struct.Tuner.html
+    pub fn new(frequency: Option<f32>) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        Tuner {
+            frequency,
+            reference: 440.0,
+            accidentals: Accidentals::default(),
+            in_tune_cents: 5.0,
+            tick_marks: None,
+            text_marks: None,
+            width: Length::Fixed(DEFAULT_WIDTH),
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the frequency of A4 in Hz.
+    ///
+    /// The default is `440.0`.
+    pub fn reference(mut self, reference: f32) -> Self {
+        self.reference = reference;
+        self
+    }
+
+    /// Sets how the black keys are named.
+    ///
+    /// The default is [`Accidentals::Sharps`].
+    ///
+    /// [`Accidentals::Sharps`]: enum.Accidentals.html#variant.Sharps
+    pub fn accidentals(mut self, accidentals: Accidentals) -> Self {
+        self.accidentals = accidentals;
+        self
+    }
+
+    /// Sets how many cents the pitch can be off on either side of the note
+    /// and still be shown as in tune.
+    ///
+    /// The default is `5.0`.
+    pub fn in_tune_cents(mut self, cents: f32) -> Self {
+        self.in_tune_cents = cents.abs();
+        self
+    }
+
+    /// Sets the tick marks to display. [`standard_tick_marks`] creates tick
+    /// marks that line up with the cents scale. Note your [`StyleSheet`]
+    /// must also implement `tick_marks_appearance(&self) -> Option<TickMarksAppearance>`
+    /// for them to display (which the default style does).
+    ///
+    /// [`standard_tick_marks`]: fn.standard_tick_marks.html
+    /// [`StyleSheet`]: ../../style/tuner/trait.StyleSheet.html
+    pub fn tick_marks(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display. [`standard_text_marks`] creates text
+    /// marks that line up with the cents scale. Note your [`StyleSheet`]
+    /// must also implement `text_marks_appearance(&self) -> Option<TextMarksAppearance>`
+    /// for them to display (which the default style does).
+    ///
+    /// [`standard_text_marks`]: fn.standard_text_marks.html
+    /// [`StyleSheet`]: ../../style/tuner/trait.StyleSheet.html
+    pub fn text_marks(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks = Some(text_marks);
+        self
+    }
+
+    /// Sets the width of the [`Tuner`].
+    ///
+    /// The default width is `Length::Fixed(240.0)`.
+    ///
+    /// [`Tuner`]: struct.Tuner.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Tuner`].
+    ///
+    /// The default height is `Length::Fixed(120.0)`.
+    ///
+    /// [`Tuner`]: struct.Tuner.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`Tuner`].
+    ///
+    /// [`Tuner`]: struct.Tuner.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+fn fill<Renderer: iced_core::Renderer>(renderer: &mut Renderer, bounds: Rectangle, color: Color) {
+    if bounds.width > 0.0 && bounds.height > 0.0 {
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            color,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Tuner<'a, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = layout.bounds();
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        let pitch = self
+            .frequency
+            .and_then(|frequency| Pitch::from_frequency(frequency, self.reference));
+        let in_tune = pitch.is_some_and(|pitch| pitch.cents.abs() <= self.in_tune_cents);
+
+        let scale_bounds = Rectangle {
+            x: bounds.x + SCALE_PADDING,
+            y: (bounds.y + bounds.height - SCALE_BOTTOM_MARGIN - appearance.scale_height).round(),
+            width: bounds.width - SCALE_PADDING * 2.0,
+            height: appearance.scale_height,
+        };
+
+        if let Some(tick_marks) = self.tick_marks
+            && let Some(style) = theme.tick_marks_appearance(&self.style)
+        {
+            tick_marks::draw_horizontal_tick_marks(
+                renderer,
+                &scale_bounds,
+                tick_marks,
+                &style.style,
+                &style.placement,
+                false,
+            );
+        }
+
+        if let Some(text_marks) = self.text_marks
+            && let Some(style) = theme.text_marks_appearance(&self.style)
+        {
+            text_marks::draw_horizontal_text_marks(
+                renderer,
+                &scale_bounds,
+                text_marks,
+                &style.style,
+                &style.placement,
+                false,
+            );
+        }
+
+        fill(renderer, scale_bounds, appearance.scale_color);
+
+        let zone_start = normal(-self.in_tune_cents).scale(scale_bounds.width);
+        let zone_end = normal(self.in_tune_cents).scale(scale_bounds.width);
+        fill(
+            renderer,
+            Rectangle {
+                x: (scale_bounds.x + zone_start).round(),
+                width: (zone_end - zone_start).round().max(1.0),
+                ..scale_bounds
+            },
+            appearance.in_tune_zone_color,
+        );
+
+        let (note_text, note_color) = match pitch {
+            Some(pitch) => {
+                let needle_x = scale_bounds.x + normal(pitch.cents).scale(scale_bounds.width);
+                let needle_color = if in_tune {
+                    appearance.in_tune_needle_color
+                } else {
+                    appearance.needle_color
+                };
+
+                fill(
+                    renderer,
+                    Rectangle {
+                        x: (needle_x - appearance.needle_width / 2.0).round(),
+                        y: scale_bounds.y - appearance.needle_overhang,
+                        width: appearance.needle_width,
+                        height: scale_bounds.height + appearance.needle_overhang * 2.0,
+                    },
+                    needle_color,
+                );
+
+                let note_color = if in_tune {
+                    appearance.in_tune_note_color
+                } else {
+                    appearance.note_color
+                };
+
+                (
+                    format!("{}{}", pitch.name(self.accidentals), pitch.octave()),
+                    note_color,
+                )
+            }
+            None => (String::from("-"), appearance.inactive_color),
+        };
+
+        let note_area_bottom = scale_bounds.y - SCALE_TOP_MARGIN;
+        let note_text_size = f32::from(appearance.note_text_size);
+
+        renderer.fill_text(
+            Text {
+                content: note_text,
+                size: Pixels(note_text_size),
+                bounds: Size::new(bounds.width, note_text_size * 1.5),
+                align_x: Alignment::Center,
+                align_y: Vertical::Center,
+                line_height: LineHeight::default(),
+                wrapping: Wrapping::default(),
+                shaping: Shaping::Basic,
+                font: appearance.font,
+            },
+            Point::new(
+                bounds.center_x(),
+                ((bounds.y + note_area_bottom) / 2.0).round(),
+            ),
+            note_color,
+            bounds,
+        );
+
+        if let Some(pitch) = pitch {
+            let cents_text_size = f32::from(appearance.cents_text_size);
+
+            renderer.fill_text(
+                Text {
+                    content: format!("{:+.1} cents", pitch.cents),
+                    size: Pixels(cents_text_size),
+                    bounds: Size::new(bounds.width, cents_text_size * 2.0),
+                    align_x: Alignment::Right,
+                    align_y: Vertical::Center,
+                    line_height: LineHeight::default(),
+                    wrapping: Wrapping::default(),
+                    shaping: Shaping::Basic,
+                    font: appearance.font,
+                },
+                Point::new(
+                    bounds.x + bounds.width - 8.0,
+                    bounds.y + 6.0 + cents_text_size / 2.0,
+                ),
+                appearance.cents_color,
+                bounds,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tuner<'a, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    fn from(tuner: Tuner<'a, Theme>) -> Self {
+        Self::new(tuner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Accidentals, Pitch};
+
+    fn note_frequency(note: f32, reference: f32) -> f32 {
+        reference * 2.0_f32.powf((note - 69.0) / 12.0)
+    }
+
+    #[test]
+    fn a4_reference() {
+        let pitch = Pitch::from_frequency(440.0, 440.0).unwrap();
+        assert_eq!(pitch.note, 69);
+        assert!(pitch.cents.abs() < 1.0e-3);
+        assert_eq!(pitch.name(Accidentals::Sharps), "A");
+        assert_eq!(pitch.octave(), 4);
+
+        let pitch = Pitch::from_frequency(442.0, 442.0).unwrap();
+        assert_eq!(pitch.note, 69);
+        assert!(pitch.cents.abs() < 1.0e-3);
+
+        // 440 Hz is about 7.85 cents flat of A4 at 442 Hz.
+        let pitch = Pitch::from_frequency(440.0, 442.0).unwrap();
+        assert_eq!(pitch.note, 69);
+        assert!((pitch.cents + 7.851).abs() < 1.0e-2);
+    }
+
+    #[test]
+    fn octave_boundary() {
+        for reference in [440.0, 442.0] {
+            let b3 = Pitch::from_frequency(note_frequency(59.0, reference), reference).unwrap();
+            assert_eq!((b3.name(Accidentals::Sharps), b3.octave()), ("B", 3));
+
+            let c4 = Pitch::from_frequency(note_frequency(60.0, reference), reference).unwrap();
+            assert_eq!((c4.name(Accidentals::Sharps), c4.octave()), ("C", 4));
+
+            // Just below and above the quarter tone between the two notes.
+            let sharp_b3 =
+                Pitch::from_frequency(note_frequency(59.49, reference), reference).unwrap();
+            assert_eq!((sharp_b3.note, sharp_b3.octave()), (59, 3));
+            assert!((sharp_b3.cents - 49.0).abs() < 1.0e-2);
+
+            let flat_c4 =
+                Pitch::from_frequency(note_frequency(59.51, reference), reference).unwrap();
+            assert_eq!((flat_c4.note, flat_c4.octave()), (60, 4));
+            assert!((flat_c4.cents + 49.0).abs() < 1.0e-2);
+        }
+    }
+
+    #[test]
+    fn accidentals() {
+        let pitch = Pitch::from_frequency(note_frequency(61.0, 440.0), 440.0).unwrap();
+        assert_eq!(pitch.name(Accidentals::Sharps), "C#");
+        assert_eq!(pitch.name(Accidentals::Flats), "Db");
+    }
+
+    #[test]
+    fn invalid_frequencies() {
+        assert_eq!(Pitch::from_frequency(0.0, 440.0), None);
+        assert_eq!(Pitch::from_frequency(-440.0, 440.0), None);
+        assert_eq!(Pitch::from_frequency(f32::NAN, 440.0), None);
+        assert_eq!(Pitch::from_frequency(440.0, 0.0), None);
+        assert_eq!(Pitch::from_frequency(440.0, f32::INFINITY), None);
+    }
+}
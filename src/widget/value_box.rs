@@ -0,0 +1,751 @@
+//! Display a compact number box that shows the formatted value of a
+//! [`NormalParam`] and changes it by dragging, scrolling, or typing.
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use crate::core::{
+    Normal, NormalParam, ParamRange,
+    virtual_slider::{self, Gesture, VirtualSlider},
+};
+use iced_core::{
+    Border, Clipboard, Element, Event, Font, Layout, Length, Pixels, Point, Rectangle, Shadow,
+    Shell, Size, Widget,
+    alignment::Vertical,
+    border::Radius,
+    keyboard::{self, Key, key::Named},
+    layout, mouse,
+    renderer::{Quad, Style},
+    text::{Paragraph, paragraph},
+    touch,
+    widget::{
+        Tree,
+        text::{Alignment, LineHeight, Shaping, Wrapping},
+        tree,
+    },
+    window,
+};
+
+pub use crate::style::value_box::{Appearance, StyleSheet};
+
+const DEFAULT_WIDTH: f32 = 80.0;
+const DEFAULT_PIXELS_PER_STEP: f32 = 6.0;
+
+/// The space between the text and the top and bottom edges of the box.
+const VERTICAL_PADDING: f32 = 4.0;
+/// The height of the line under the highlighted digit.
+const DIGIT_HIGHLIGHT_HEIGHT: f32 = 1.0;
+/// How many pixels of a pixel scroll (such as from a touchpad) count as
+/// one line, the same as iced's scrollables.
+const PIXELS_PER_LINE: f32 = 60.0;
+
+/// The parts of a [`ParamRange`] a [`ValueBox`] needs, in a form that can
+/// be boxed.
+///
+/// [`ParamRange`]: ../../core/range/trait.ParamRange.html
+/// [`ValueBox`]: struct.ValueBox.html
+trait ValueRange {
+    fn map(&self, value: f32) -> Normal;
+
+    fn unmap(&self, normal: Normal) -> f32;
+
+    fn default_decimals(&self) -> usize;
+
+    fn format_normal(&self, normal: Normal) -> String;
+}
+
+impl<R: ParamRange> ValueRange for R {
+    fn map(&self, value: f32) -> Normal {
+        self.map_f32(value)
+    }
+
+    fn unmap(&self, normal: Normal) -> f32 {
+        self.unmap_to_f32(normal)
+    }
+
+    fn default_decimals(&self) -> usize {
+        if self.is_discrete() { 0 } else { 2 }
+    }
+
+    fn format_normal(&self, normal: Normal) -> String {
+        ParamRange::format_normal(self, normal)
+    }
+}
+
+/// A value box GUI widget that controls a [`NormalParam`]
+///
+/// It shows the value of the parameter as text formatted by its range,
+/// such as "1.50 kHz", or as a number with a set unit, such as
+/// "120.00 BPM". Dragging a digit up or down changes the value in steps of
+/// that digit,
+/// while dragging anywhere else works like a [`Knob`]. The scroll wheel
+/// over a digit also steps by that digit. Click the box and type a value
+/// (or press Enter to edit the current one) to enter it directly.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`Knob`]: ../knob/struct.Knob.html
+#[allow(missing_debug_implementations)]
+pub struct ValueBox<'a, Message, Theme: StyleSheet> {
+    virtual_slider: VirtualSlider<'a, Message>,
+    range: Box<dyn ValueRange + 'a>,
+    decimals: Option<usize>,
+    unit: String,
+    pixels_per_step: f32,
+    text_size: Option<Pixels>,
+    font: Option<Font>,
+    enabled: bool,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme: StyleSheet> ValueBox<'a, Message, Theme> {
+    /// Creates a new [`ValueBox`].
+    ///
+    /// It expects:
+    ///   * `normal_param` - The normalized value of the parameter.
+    ///   * `range` - The range the parameter was mapped from, used to show
+    ///     and enter the actual value.
+    ///
+    /// [`ValueBox`]: struct.ValueBox.html
+    pub fn new(normal_param: impl Into<NormalParam>, range: impl ParamRange + 'a) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        ValueBox {
+            virtual_slider: VirtualSlider::new(normal_param.into()),
+            range: Box::new(range),
+            decimals: None,
+            unit: String::new(),
+            pixels_per_step: DEFAULT_PIXELS_PER_STEP,
+            text_size: None,
+            font: None,
+            enabled: true,
+            width: Length::Fixed(DEFAULT_WIDTH),
+            height: Length::Shrink,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the message to emit when the user gestures this widget.
+    pub fn on_gesture(mut self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.virtual_slider.set_on_gesture(on_gesture);
+        self
+    }
+
+    /// Set a custom configuration to use for this virtual slider. This is
+    /// used when dragging or scrolling outside of the digits.
+    pub fn config(mut self, config: &virtual_slider::Config) -> Self {
+        self.virtual_slider.config = *config;
+        self
+    }
+
+    /// Sets the number of digits shown after the decimal point.
+    ///
+    /// Setting this (or a [`unit`]) shows the value as a plain number
+    /// instead of the range's own formatting. The number is also what is
+    /// edited when typing a value.
+    ///
+    /// The default is `0` for discrete ranges (such as an [`IntRange`]) and
+    /// `2` for all other ranges.
+    ///
+    /// [`unit`]: #method.unit
+    ///
+    /// [`IntRange`]: ../../core/range/struct.IntRange.html
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Sets the unit shown after the value, such as "BPM" or "Hz".
+    ///
+    /// Setting this (or the number of [`decimals`]) shows the value as a
+    /// plain number followed by the unit instead of the range's own
+    /// formatting.
+    ///
+    /// The default is no unit, which shows the value as formatted by the
+    /// range (such as "-6.0 dB" for a [`DBRange`]).
+    ///
+    /// [`decimals`]: #method.decimals
+    /// [`DBRange`]: ../../core/range/struct.DBRange.html
+    pub fn unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    /// Sets how many pixels the cursor has to move up or down to change the
+    /// value by one step of the dragged digit.
+    ///
+    /// The default is `6.0`.
+    pub fn pixels_per_step(mut self, pixels: f32) -> Self {
+        self.pixels_per_step = pixels.max(1.0);
+        self
+    }
+
+    /// Sets the size of the text.
+    ///
+    /// The default is the default text size of the renderer.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the text.
+    ///
+    /// The default is the default font of the renderer.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Enable/disable this widget.
+    ///
+    /// The default is `true`.
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the width of the [`ValueBox`].
+    /// The default width is `Length::Fixed(80.0)`.
+    ///
+    /// [`ValueBox`]: struct.ValueBox.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ValueBox`].
+    /// The default height is `Length::Shrink`, which fits the text.
+    ///
+    /// [`ValueBox`]: struct.ValueBox.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`ValueBox`].
+    ///
+    /// [`ValueBox`]: struct.ValueBox.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn value(&self) -> f32 {
+        self.range.unmap(self.virtual_slider.param().normal)
+    }
+
+    /// Formats `value` without the unit.
+    fn format_number(&self, value: f32) -> String {
+        let decimals = self
+            .decimals
+            .unwrap_or_else(|| self.range.default_decimals());
+
+        let number = format!("{value:.decimals$}");
+
+        // Don't show "-0.00" for values that round to zero.
+        if number.starts_with('-') && number[1..].chars().all(|c| c == '0' || c == '.') {
+            number[1..].to_string()
+        } else {
+            number
+        }
+    }
+
+    fn content<P: Paragraph>(&self, state: &State<P>) -> String {
+        if let Some(editing) = &state.editing {
+            editing.clone()
+        } else if self.decimals.is_none() && self.unit.is_empty() {
+            self.range.format_normal(self.virtual_slider.param().normal)
+        } else if self.unit.is_empty() {
+            self.format_number(self.value())
+        } else {
+            format!("{} {}", self.format_number(self.value()), self.unit)
+        }
+    }
+
+    fn update_paragraph<Renderer>(
+        &self,
+        state: &mut State<Renderer::Paragraph>,
+        renderer: &Renderer,
+    ) where
+        Renderer: iced_core::text::Renderer<Font = Font>,
+    {
+        let content = self.content(state);
+
+        let _ = state.paragraph.update(iced_core::Text {
+            content: &content,
+            bounds: Size::new(f32::INFINITY, f32::INFINITY),
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: Alignment::Left,
+            align_y: Vertical::Top,
+            shaping: Shaping::Basic,
+            wrapping: Wrapping::None,
+        });
+    }
+
+    /// Sets the parameter to `value` as a complete gesture.
+    fn set_value<P: Paragraph>(
+        &mut self,
+        state: &mut State<P>,
+        value: f32,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let normal = self.range.map(value);
+
+        let _ = self
+            .virtual_slider
+            .set_normal(&mut state.slider, normal, shell);
+        let _ = self.virtual_slider.finish_gesture(&mut state.slider, shell);
+    }
+
+    /// Sets the parameter to the typed value (if it is a number) and stops
+    /// editing.
+    fn commit_edit<P: Paragraph>(&mut self, state: &mut State<P>, shell: &mut Shell<'_, Message>) {
+        if let Some(editing) = state.editing.take() {
+            if let Ok(value) = editing.trim().parse::<f32>() {
+                self.set_value(state, value, shell);
+            }
+            shell.invalidate_layout();
+        }
+    }
+
+    /// Handles a key press while the box is focused. Returns `true` if the
+    /// key was used.
+    fn key_pressed<P: Paragraph>(
+        &mut self,
+        state: &mut State<P>,
+        key: &Key,
+        text: Option<&str>,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        match key.as_ref() {
+            Key::Named(Named::Enter) => {
+                if state.editing.is_some() {
+                    self.commit_edit(state, shell);
+                } else {
+                    state.editing = Some(self.format_number(self.value()));
+                }
+            }
+            Key::Named(Named::Escape) if state.editing.is_some() => {
+                state.editing = None;
+            }
+            Key::Named(Named::Backspace) if state.editing.is_some() => {
+                if let Some(editing) = &mut state.editing {
+                    let _ = editing.pop();
+                }
+            }
+            _ => match text {
+                Some(text)
+                    if !text.is_empty()
+                        && text
+                            .chars()
+                            .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+')) =>
+                {
+                    state.editing.get_or_insert_with(String::new).push_str(text);
+                }
+                _ => return false,
+            },
+        }
+
+        true
+    }
+
+    /// Returns the top left corner of the text, which is centered in the box.
+    fn text_origin<P: Paragraph>(state: &State<P>, bounds: &Rectangle) -> Point {
+        let text_size = state.paragraph.min_bounds();
+
+        Point::new(
+            (bounds.center_x() - text_size.width / 2.0).round(),
+            (bounds.center_y() - text_size.height / 2.0).round(),
+        )
+    }
+
+    /// Returns the index of the digit under `position` and the step size of
+    /// that digit in the units of the range.
+    fn digit_at<P: Paragraph>(
+        &self,
+        state: &State<P>,
+        bounds: &Rectangle,
+        position: Point,
+    ) -> Option<(usize, f32)> {
+        if state.editing.is_some() {
+            return None;
+        }
+
+        let x = position.x - Self::text_origin(state, bounds).x;
+        let paragraph = state.paragraph.raw();
+
+        // The number always comes first, followed by the unit. Text that
+        // doesn't start with a number (such as a note name) has no digits.
+        let number = state.paragraph.content().split(' ').next()?;
+        let shown = number.parse::<f32>().ok().filter(|n| n.is_finite())?;
+        let point = number.find('.').unwrap_or(number.len());
+
+        // The range may show the value in a different unit (such as "kHz"
+        // for a value in Hz), so scale the step of the digit to match.
+        let ratio = (self.value() / shown).abs();
+        let scale = if ratio.is_finite() && ratio > 0.0 {
+            10.0f32.powi(ratio.log10().round() as i32)
+        } else {
+            1.0
+        };
+
+        for (i, c) in number.char_indices() {
+            let start = paragraph.grapheme_position(0, i)?.x;
+            let end = paragraph.grapheme_position(0, i + 1)?.x;
+
+            if x >= start && x < end {
+                if !c.is_ascii_digit() {
+                    return None;
+                }
+
+                let exponent = if i < point {
+                    (point - i - 1) as i32
+                } else {
+                    -((i - point) as i32)
+                };
+
+                return Some((i, 10.0f32.powi(exponent) * scale));
+            }
+        }
+
+        None
+    }
+
+    /// Returns the horizontal span of the character at `index`.
+    fn char_span<P: Paragraph>(state: &State<P>, index: usize) -> Option<(f32, f32)> {
+        let paragraph = state.paragraph.raw();
+
+        Some((
+            paragraph.grapheme_position(0, index)?.x,
+            paragraph.grapheme_position(0, index + 1)?.x,
+        ))
+    }
+}
+
+/// A drag that changes the value in steps of the digit that was pressed.
+#[derive(Debug, Clone, Copy)]
+struct DigitDrag {
+    index: usize,
+    step: f32,
+    start_value: f32,
+    start_y: f32,
+}
+
+/// The local state of a [`ValueBox`].
+///
+/// [`ValueBox`]: struct.ValueBox.html
+struct State<P: Paragraph> {
+    slider: virtual_slider::State,
+    paragraph: paragraph::Plain<P>,
+    digit_drag: Option<DigitDrag>,
+    last_click: Option<mouse::Click>,
+    focused: bool,
+    editing: Option<String>,
+    /// The pixel scroll that has not added up to a whole line yet.
+    scroll_pixels: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ValueBox<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer + iced_core::text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            slider: virtual_slider::State::new(self.virtual_slider.param().normal),
+            paragraph: paragraph::Plain::default(),
+            digit_drag: None,
+            last_click: None,
+            focused: false,
+            editing: None,
+            scroll_pixels: 0.0,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        self.update_paragraph(state, renderer);
+
+        let text_size = state.paragraph.min_bounds();
+        let intrinsic = Size::new(text_size.width, text_size.height + VERTICAL_PADDING * 2.0);
+
+        layout::Node::new(limits.resolve(self.width, self.height, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key, text, .. })
+                if state.focused && self.key_pressed(state, key, text.as_deref(), shell) =>
+            {
+                self.update_paragraph(state, renderer);
+                shell.invalidate_layout();
+                shell.capture_event();
+                shell.request_redraw();
+                return;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor_is_over {
+                    if state.focused {
+                        self.commit_edit(state, shell);
+                        state.focused = false;
+                        shell.request_redraw();
+                    }
+                } else if let Some(position) = cursor.position() {
+                    self.commit_edit(state, shell);
+                    self.update_paragraph(state, renderer);
+                    state.focused = true;
+
+                    let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                    state.last_click = Some(click);
+
+                    if click.kind() != mouse::click::Kind::Single {
+                        let default = self.range.unmap(self.virtual_slider.param().default);
+                        self.set_value(state, default, shell);
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+
+                    if let Some((index, step)) = self.digit_at(state, &bounds, position) {
+                        state.digit_drag = Some(DigitDrag {
+                            index,
+                            step,
+                            start_value: self.value(),
+                            start_y: position.y,
+                        });
+
+                        shell.capture_event();
+                        shell.request_redraw();
+                        return;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = state.digit_drag {
+                    let steps = ((drag.start_y - position.y) / self.pixels_per_step).trunc();
+                    let normal = self.range.map(drag.start_value + steps * drag.step);
+
+                    if normal != self.virtual_slider.param().normal {
+                        let _ = self
+                            .virtual_slider
+                            .set_normal(&mut state.slider, normal, shell);
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+
+                if cursor_is_over {
+                    // Redraw to move the digit highlight.
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor_is_over => {
+                if let Some(position) = cursor.position()
+                    && let Some((_, step)) = self.digit_at(state, &bounds, position)
+                {
+                    let lines = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => {
+                            state.scroll_pixels = 0.0;
+                            y.signum()
+                        }
+                        mouse::ScrollDelta::Pixels { y, .. } => {
+                            state.scroll_pixels += *y;
+                            let lines = (state.scroll_pixels / PIXELS_PER_LINE).trunc();
+                            state.scroll_pixels -= lines * PIXELS_PER_LINE;
+                            lines
+                        }
+                    };
+
+                    if lines != 0.0 {
+                        let value = self.value() + lines * step;
+                        self.set_value(state, value, shell);
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.digit_drag.take().is_some() =>
+            {
+                let _ = self.virtual_slider.finish_gesture(&mut state.slider, shell);
+                shell.request_redraw();
+            }
+            Event::Window(window::Event::Unfocused) => {
+                state.digit_drag = None;
+                state.editing = None;
+                state.focused = false;
+            }
+            _ => {}
+        }
+
+        let status = self.virtual_slider.update(
+            &mut state.slider,
+            cursor_is_over,
+            false,
+            false,
+            event,
+            cursor,
+            shell,
+        );
+
+        if status.should_redraw() {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let bounds = layout.bounds();
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+        let cursor_is_over = cursor.is_over(bounds);
+
+        let appearance = if !self.enabled {
+            theme.disabled(&self.style)
+        } else if state.editing.is_some() {
+            theme.editing(&self.style)
+        } else if state.slider.is_gesturing() || state.digit_drag.is_some() {
+            theme.gesturing(&self.style)
+        } else if cursor_is_over {
+            theme.hovered(&self.style)
+        } else {
+            theme.idle(&self.style)
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        let origin = Self::text_origin(state, &bounds);
+        let text_size = state.paragraph.min_bounds();
+
+        renderer.fill_paragraph(state.paragraph.raw(), origin, appearance.text_color, bounds);
+
+        if state.editing.is_some() {
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle {
+                        x: origin.x + text_size.width + 1.0,
+                        y: origin.y,
+                        width: 1.0,
+                        height: text_size.height,
+                    },
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                appearance.caret_color,
+            );
+        } else if self.enabled
+            && let Some(digit_highlight_color) = appearance.digit_highlight_color
+        {
+            let digit = match state.digit_drag {
+                Some(drag) => Some(drag.index),
+                None => cursor
+                    .position_over(bounds)
+                    .filter(|_| !state.slider.is_dragging())
+                    .and_then(|position| self.digit_at(state, &bounds, position))
+                    .map(|(index, _)| index),
+            };
+
+            if let Some((start, end)) = digit.and_then(|index| Self::char_span(state, index)) {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle {
+                            x: origin.x + start,
+                            y: origin.y + text_size.height,
+                            width: end - start,
+                            height: DIGIT_HIGHLIGHT_HEIGHT,
+                        },
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                        snap: false,
+                    },
+                    digit_highlight_color,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ValueBox<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer + iced_core::text::Renderer<Font = Font>,
+{
+    fn from(value_box: ValueBox<'a, Message, Theme>) -> Self {
+        Self::new(value_box)
+    }
+}
@@ -0,0 +1,599 @@
+//! Display an analog VU meter with a needle that follows the level like a
+//! real moving-coil meter.
+
+use std::f32::consts::FRAC_PI_2;
+use std::time::{Duration, Instant};
+
+use crate::core::{KnobAngleRange, Normal, math::THREE_HALVES_PI};
+use crate::{text_marks, tick_marks};
+use iced_core::{
+    Border, Clipboard, Element, Event, Layout, Length, Point, Radians, Rectangle, Shadow, Shell,
+    Size, Vector, Widget,
+    border::Radius,
+    layout, mouse,
+    renderer::{Quad, Style},
+    widget::{Tree, tree},
+    window,
+};
+use iced_graphics::geometry::{self, Fill, Frame, LineCap, Path, Stroke, path::Arc};
+
+pub use crate::style::vu_meter::{
+    Appearance, StyleSheet, TextMarksAppearance, TickMarksAppearance,
+};
+
+const DEFAULT_WIDTH: f32 = 240.0;
+const DEFAULT_HEIGHT: f32 = 150.0;
+
+/// The lowest level on the scale in VU.
+pub const MIN_VU: f32 = -20.0;
+/// The highest level on the scale in VU.
+pub const MAX_VU: f32 = 3.0;
+
+/// The distance from the bottom of the meter to the pivot of the needle.
+const PIVOT_MARGIN: f32 = 16.0;
+/// The room left around the arc of the scale for the tick and text marks.
+const SCALE_MARGIN: f32 = 28.0;
+/// How far the needle reaches past the arc of the scale.
+const NEEDLE_OVERHANG: f32 = 6.0;
+
+/// How far past the top of the scale the needle can swing before it hits
+/// the stop.
+const PEG_POSITION: f32 = 1.04;
+
+/// The interval between redraws while the needle is moving.
+const FRAME_INTERVAL: Duration = Duration::from_micros(16_667);
+/// The longest time step the needle is advanced by in a single frame, so a
+/// stalled frame doesn't make the needle jump straight to its target.
+const MAX_STEP_SECS: f32 = 1.0 / 30.0;
+/// The spring is integrated in fixed sub-steps of at most this length.
+const SUBSTEP_SECS: f32 = 0.001;
+
+const SETTLED_DISTANCE: f32 = 0.0001;
+const SETTLED_VELOCITY: f32 = 0.001;
+
+/// Returns the position of `vu` on the scale of a [`VuMeter`].
+///
+/// The deflection of the needle is proportional to the voltage, so the
+/// scale is linear in amplitude with [`MAX_VU`] at the end. Levels below
+/// the scale fall towards the needle's resting position.
+///
+/// [`VuMeter`]: struct.VuMeter.html
+/// [`MAX_VU`]: constant.MAX_VU.html
+pub fn normal(vu: f32) -> Normal {
+    if vu.is_finite() {
+        Normal::new(10.0f32.powf((vu - MAX_VU) / 20.0))
+    } else {
+        Normal::MIN
+    }
+}
+
+/// Returns the tick marks of a standard VU scale.
+pub fn standard_tick_marks() -> tick_marks::Group {
+    let marks: Vec<(Normal, tick_marks::Tier)> = [
+        -20.0, -10.0, -7.0, -5.0, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0,
+    ]
+    .into_iter()
+    .map(|vu: f32| {
+        let tier = if vu == 0.0 {
+            tick_marks::Tier::One
+        } else {
+            tick_marks::Tier::Two
+        };
+        (normal(vu), tier)
+    })
+    .collect();
+
+    tick_marks::Group::from_normalized(&marks)
+}
+
+/// Returns the text marks of a standard VU scale.
+pub fn standard_text_marks() -> text_marks::Group {
+    [-20.0, -10.0, -7.0, -5.0, -3.0, 0.0, 3.0]
+        .into_iter()
+        .map(|vu: f32| {
+            let text = if vu > 0.0 {
+                format!("+{vu}")
+            } else {
+                format!("{vu}")
+            };
+            (normal(vu), text)
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// How the needle of a [`VuMeter`] responds to the level.
+///
+/// The needle is modeled as a damped spring. It is described by the time
+/// it takes the needle to first reach 99% of a step in level, and by how
+/// far it overshoots the step before settling.
+///
+/// [`VuMeter`]: struct.VuMeter.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ballistics {
+    natural_frequency: f32,
+    damping: f32,
+}
+
+impl Ballistics {
+    /// Creates new `Ballistics`.
+    ///
+    /// * `rise_time` - the time it takes the needle to first reach 99% of a
+    ///   step in level
+    /// * `overshoot` - how far the needle swings past a step in level, as a
+    ///   fraction of the step. This is clamped to `0.0..=0.5`, where `0.0`
+    ///   settles without any overshoot.
+    pub fn new(rise_time: Duration, overshoot: f32) -> Self {
+        let overshoot = overshoot.clamp(0.0, 0.5);
+
+        // The damping ratio of a second order system with this overshoot.
+        let damping = if overshoot > 0.0 {
+            let ln = overshoot.ln();
+            -ln / (std::f32::consts::PI.powi(2) + ln * ln).sqrt()
+        } else {
+            1.0
+        };
+
+        // Find the rise time of a spring with a natural frequency of 1 rad/s,
+        // and scale it to the requested rise time.
+        let mut position = 0.0f32;
+        let mut velocity = 0.0f32;
+        let mut time = 0.0f32;
+        let step = 0.001;
+        while position < 0.99 && time < 20.0 {
+            let acceleration = (1.0 - position) - 2.0 * damping * velocity;
+            velocity += acceleration * step;
+            position += velocity * step;
+            time += step;
+        }
+
+        let rise_time = rise_time.as_secs_f32().max(f32::EPSILON);
+
+        Self {
+            natural_frequency: time / rise_time,
+            damping,
+        }
+    }
+}
+
+impl Default for Ballistics {
+    /// The standard VU ballistics: a 300 ms rise time with 1.25% overshoot.
+    fn default() -> Self {
+        Self::new(Duration::from_millis(300), 0.0125)
+    }
+}
+
+/// An analog VU meter GUI widget.
+///
+/// The level is given in dBFS and shown relative to a reference level,
+/// which is 0 VU on the scale. The needle follows the level with
+/// [`Ballistics`] that match the rise time and overshoot of a real VU meter,
+/// so it can be fed the RMS level from the audio thread.
+///
+/// [`Ballistics`]: struct.Ballistics.html
+#[allow(missing_debug_implementations)]
+pub struct VuMeter<'a, Theme: StyleSheet> {
+    level: f32,
+    reference: f32,
+    ballistics: Ballistics,
+    angle_range: KnobAngleRange,
+    tick_marks: Option<&'a tick_marks::Group>,
+    text_marks: Option<&'a text_marks::Group>,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Theme> VuMeter<'a, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`VuMeter`] showing the given level in dBFS.
+    ///
+    /// [`VuMeter`]: struct.VuMeter.html
+    pub fn new(level: f32) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        VuMeter {
+            level,
+            reference: -18.0,
+            ballistics: Ballistics::default(),
+            angle_range: KnobAngleRange::from_deg(135.0, 225.0),
+            tick_marks: None,
+            text_marks: None,
+            width: Length::Fixed(DEFAULT_WIDTH),
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the level in dBFS that reads 0 VU.
+    ///
+    /// The default is `-18.0`.
+    pub fn reference(mut self, reference: f32) -> Self {
+        self.reference = reference;
+        self
+    }
+
+    /// Sets the [`Ballistics`] of the needle.
+    ///
+    /// The default is a 300 ms rise time with 1.25% overshoot.
+    ///
+    /// [`Ballistics`]: struct.Ballistics.html
+    pub fn ballistics(mut self, ballistics: Ballistics) -> Self {
+        self.ballistics = ballistics;
+        self
+    }
+
+    /// Sets the angles the needle swings between, using the same convention
+    /// as a [`Knob`]. The scale is centered horizontally, so the range should
+    /// be symmetrical around straight up (180 degrees).
+    ///
+    /// The default is 135 degrees to 225 degrees.
+    ///
+    /// [`Knob`]: ../knob/struct.Knob.html
+    pub fn angle_range(mut self, angle_range: KnobAngleRange) -> Self {
+        self.angle_range = angle_range;
+        self
+    }
+
+    /// Sets the tick marks to display. [`standard_tick_marks`] creates the
+    /// tick marks of a standard VU scale. Note your [`StyleSheet`] must
+    /// also implement `tick_marks_appearance(&self) -> Option<TickMarksAppearance>`
+    /// for them to display (which the default style does).
+    ///
+    /// [`standard_tick_marks`]: fn.standard_tick_marks.html
+    /// [`StyleSheet`]: ../../style/vu_meter/trait.StyleSheet.html
+    pub fn tick_marks(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display. [`standard_text_marks`] creates the
+    /// text marks of a standard VU scale. Note your [`StyleSheet`] must
+    /// also implement `text_marks_appearance(&self) -> Option<TextMarksAppearance>`
+    /// for them to display (which the default style does).
+    ///
+    /// [`standard_text_marks`]: fn.standard_text_marks.html
+    /// [`StyleSheet`]: ../../style/vu_meter/trait.StyleSheet.html
+    pub fn text_marks(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks = Some(text_marks);
+        self
+    }
+
+    /// Sets the width of the [`VuMeter`].
+    ///
+    /// The default width is `Length::Fixed(240.0)`.
+    ///
+    /// [`VuMeter`]: struct.VuMeter.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`VuMeter`].
+    ///
+    /// The default height is `Length::Fixed(150.0)`.
+    ///
+    /// [`VuMeter`]: struct.VuMeter.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`VuMeter`].
+    ///
+    /// [`VuMeter`]: struct.VuMeter.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the position the needle is being pulled towards.
+    fn target(&self) -> f32 {
+        normal(self.level - self.reference).as_f32()
+    }
+
+    /// Returns the angle where the scale starts and the span of the scale,
+    /// in radians clockwise from the positive x axis.
+    fn angles(&self) -> (f32, f32) {
+        let min = self.angle_range.min();
+        let start_angle = if min >= THREE_HALVES_PI {
+            min - THREE_HALVES_PI
+        } else {
+            min + FRAC_PI_2
+        };
+
+        (start_angle, self.angle_range.max() - min)
+    }
+}
+
+/// The local state of a [`VuMeter`].
+///
+/// [`VuMeter`]: struct.VuMeter.html
+#[derive(Debug, Clone)]
+struct State {
+    position: f32,
+    velocity: f32,
+    last_instant: Option<Instant>,
+}
+
+impl State {
+    fn is_settled(&self, target: f32) -> bool {
+        self.position == target && self.velocity == 0.0
+    }
+
+    /// Advances the needle by `dt` seconds towards `target`.
+    fn step(&mut self, target: f32, ballistics: &Ballistics, dt: f32) {
+        let omega = ballistics.natural_frequency;
+        let damping = ballistics.damping;
+
+        let substeps = (dt / SUBSTEP_SECS).ceil().max(1.0);
+        let h = dt / substeps;
+
+        for _ in 0..substeps as usize {
+            let acceleration =
+                omega * omega * (target - self.position) - 2.0 * damping * omega * self.velocity;
+            self.velocity += acceleration * h;
+            self.position += self.velocity * h;
+
+            // The needle stops dead against the pegs at either end.
+            if self.position < 0.0 {
+                self.position = 0.0;
+                self.velocity = self.velocity.max(0.0);
+            } else if self.position > PEG_POSITION {
+                self.position = PEG_POSITION;
+                self.velocity = self.velocity.min(0.0);
+            }
+        }
+
+        if (target - self.position).abs() < SETTLED_DISTANCE
+            && self.velocity.abs() < SETTLED_VELOCITY
+        {
+            self.position = target;
+            self.velocity = 0.0;
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for VuMeter<'a, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer
+        + iced_core::text::Renderer<Font = iced_core::Font>
+        + iced_graphics::geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            position: self.target(),
+            velocity: 0.0,
+            last_instant: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let target = self.target();
+
+        if let Some(last_instant) = state.last_instant {
+            let dt = now
+                .saturating_duration_since(last_instant)
+                .as_secs_f32()
+                .min(MAX_STEP_SECS);
+
+            state.step(target, &self.ballistics, dt);
+        }
+
+        if state.is_settled(target) {
+            state.last_instant = None;
+        } else {
+            state.last_instant = Some(*now);
+            shell.request_redraw_at(*now + FRAME_INTERVAL);
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = layout.bounds();
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        let (start_angle, angle_span) = self.angles();
+        let half_span = (angle_span / 2.0).min(FRAC_PI_2);
+
+        // The pivot of the needle, relative to the top left of the meter.
+        let pivot = Point::new(bounds.width / 2.0, bounds.height - PIVOT_MARGIN);
+        let radius = (bounds.height - PIVOT_MARGIN - SCALE_MARGIN)
+            .min((bounds.width / 2.0 - SCALE_MARGIN) / half_span.sin().max(f32::EPSILON))
+            .max(0.0);
+
+        let absolute_pivot = Point::new(bounds.x + pivot.x, bounds.y + pivot.y);
+
+        if let Some(tick_marks) = self.tick_marks
+            && let Some(style) = theme.tick_marks_appearance(&self.style)
+        {
+            tick_marks::draw_radial_tick_marks(
+                renderer,
+                absolute_pivot,
+                radius + style.offset,
+                start_angle + FRAC_PI_2,
+                angle_span,
+                false,
+                tick_marks,
+                &style.style,
+                false,
+            );
+        }
+
+        if let Some(text_marks) = self.text_marks
+            && let Some(style) = theme.text_marks_appearance(&self.style)
+        {
+            text_marks::draw_radial_text_marks(
+                renderer,
+                Point::new(absolute_pivot.x, absolute_pivot.y + style.v_offset),
+                radius + style.offset,
+                start_angle,
+                angle_span,
+                text_marks,
+                &style.style,
+                style.h_char_offset,
+                false,
+            );
+        }
+
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        if let Some(red_zone_color) = appearance.red_zone_color {
+            let red_zone_start = start_angle + normal(0.0).scale(angle_span);
+            let red_zone = Path::new(|path| {
+                path.arc(Arc {
+                    center: pivot,
+                    radius: radius - appearance.red_zone_width / 2.0,
+                    start_angle: Radians(red_zone_start),
+                    end_angle: Radians(start_angle + angle_span),
+                })
+            });
+
+            frame.stroke(
+                &red_zone,
+                Stroke {
+                    width: appearance.red_zone_width,
+                    style: geometry::Style::Solid(red_zone_color),
+                    ..Stroke::default()
+                },
+            );
+        }
+
+        let scale = Path::new(|path| {
+            path.arc(Arc {
+                center: pivot,
+                radius,
+                start_angle: Radians(start_angle),
+                end_angle: Radians(start_angle + angle_span),
+            })
+        });
+
+        frame.stroke(
+            &scale,
+            Stroke {
+                width: appearance.scale_width,
+                style: geometry::Style::Solid(appearance.scale_color),
+                ..Stroke::default()
+            },
+        );
+
+        let needle_angle = start_angle + state.position * angle_span;
+        let needle_length = radius + NEEDLE_OVERHANG;
+        let (sin, cos) = needle_angle.sin_cos();
+        let needle = Path::line(
+            pivot,
+            Point::new(pivot.x + cos * needle_length, pivot.y + sin * needle_length),
+        );
+
+        frame.stroke(
+            &needle,
+            Stroke {
+                width: appearance.needle_width,
+                style: geometry::Style::Solid(appearance.needle_color),
+                line_cap: LineCap::Round,
+                ..Stroke::default()
+            },
+        );
+
+        if appearance.pivot_radius > 0.0 {
+            frame.fill(
+                &Path::circle(pivot, appearance.pivot_radius),
+                Fill {
+                    style: geometry::Style::Solid(appearance.pivot_color),
+                    ..Fill::default()
+                },
+            );
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                // clippy gets confused when default iced features are disabled
+                #[allow(clippy::unit_arg)]
+                renderer.draw_geometry(frame.into_geometry());
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<VuMeter<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer
+        + iced_core::text::Renderer<Font = iced_core::Font>
+        + iced_graphics::geometry::Renderer,
+{
+    fn from(vu_meter: VuMeter<'a, Theme>) -> Self {
+        Self::new(vu_meter)
+    }
+}
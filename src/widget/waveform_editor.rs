@@ -0,0 +1,792 @@
+//! Display a waveform view of sample data with draggable sample start, end,
+//! loop and crossfade markers that each control a [`NormalParam`].
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use crate::core::{
+    Normal, NormalParam,
+    virtual_slider::{Gesture, OnGesture, set_param_value},
+};
+use iced_core::{
+    Border, Clipboard, Element, Event, Layout, Length, Rectangle, Shadow, Shell, Size, Widget,
+    border::Radius,
+    keyboard, layout, mouse,
+    renderer::{Quad, Style},
+    touch,
+    widget::{Tree, tree},
+    window,
+};
+use std::cell::Cell;
+
+pub use crate::style::waveform_editor::{Appearance, MarkerAppearance, StyleSheet};
+
+const DEFAULT_HEIGHT: f32 = 120.0;
+const DEFAULT_MARKER_HIT_WIDTH: f32 = 5.0;
+/// The distance in pixels around the cursor that is searched for a zero
+/// crossing to snap to.
+const SNAP_DISTANCE: f32 = 10.0;
+/// The smallest number of samples that can be shown when zoomed in.
+const MIN_VISIBLE_SAMPLES: f32 = 32.0;
+/// How much of the visible range one line of the scroll wheel scrolls by.
+const SCROLL_AMOUNT: f32 = 0.1;
+/// How much one line of the scroll wheel zooms in by.
+const ZOOM_FACTOR: f32 = 0.8;
+/// How many pixels of a pixel scroll (such as from a touchpad) count as
+/// one line, the same as iced's scrollables.
+const PIXELS_PER_LINE: f32 = 60.0;
+
+/// The markers of a [`WaveformEditor`].
+///
+/// [`WaveformEditor`]: struct.WaveformEditor.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Marker {
+    Start,
+    End,
+    LoopStart,
+    LoopEnd,
+    Crossfade,
+}
+
+const MARKERS: [Marker; 5] = [
+    Marker::Start,
+    Marker::End,
+    Marker::LoopStart,
+    Marker::LoopEnd,
+    Marker::Crossfade,
+];
+
+/// A waveform editor GUI widget for samplers.
+///
+/// It shows the waveform of the given sample data with a sample start and
+/// sample end marker, and optional loop start, loop end and crossfade
+/// markers. Each marker controls its own [`NormalParam`] and sends its own
+/// stream of gestures, so a host can automate each of them.
+///
+/// * Drag a marker to move it. When snapping to zero crossings is enabled,
+///   hold `Shift` to move it freely.
+/// * Double-click a marker to reset it to its default value.
+/// * Scroll to move through the waveform, and scroll while holding `Ctrl`
+///   to zoom in or out around the cursor. Dragging an empty spot also moves
+///   through the waveform.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+#[allow(missing_debug_implementations)]
+pub struct WaveformEditor<'a, Message, Theme: StyleSheet> {
+    samples: &'a [f32],
+    params: [Option<NormalParam>; 5],
+    on_gestures: [OnGesture<'a, Message>; 5],
+    snap_to_zero_crossings: bool,
+    marker_hit_width: f32,
+    enabled: bool,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme> WaveformEditor<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`WaveformEditor`].
+    ///
+    /// * `samples` - The sample data to display, in the range `[-1.0, 1.0]`.
+    /// * `start` - The normalized position of the sample start marker.
+    /// * `end` - The normalized position of the sample end marker.
+    ///
+    /// [`WaveformEditor`]: struct.WaveformEditor.html
+    pub fn new(
+        samples: &'a [f32],
+        start: impl Into<NormalParam>,
+        end: impl Into<NormalParam>,
+    ) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        WaveformEditor {
+            samples,
+            params: [Some(start.into()), Some(end.into()), None, None, None],
+            on_gestures: [None, None, None, None, None],
+            snap_to_zero_crossings: false,
+            marker_hit_width: DEFAULT_MARKER_HIT_WIDTH,
+            enabled: true,
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: Default::default(),
+        }
+    }
+
+    /// Shows the loop start and loop end markers with the given normalized
+    /// positions.
+    pub fn loop_points(
+        mut self,
+        loop_start: impl Into<NormalParam>,
+        loop_end: impl Into<NormalParam>,
+    ) -> Self {
+        self.params[Marker::LoopStart as usize] = Some(loop_start.into());
+        self.params[Marker::LoopEnd as usize] = Some(loop_end.into());
+        self
+    }
+
+    /// Shows the crossfade marker with the given normalized position. The
+    /// crossfade happens between this marker and the loop end marker.
+    pub fn crossfade(mut self, crossfade: impl Into<NormalParam>) -> Self {
+        self.params[Marker::Crossfade as usize] = Some(crossfade.into());
+        self
+    }
+
+    /// Sets the message to emit when the user gestures the sample start
+    /// marker.
+    pub fn on_gesture_start(self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.set_on_gesture(Marker::Start, on_gesture)
+    }
+
+    /// Sets the message to emit when the user gestures the sample end
+    /// marker.
+    pub fn on_gesture_end(self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.set_on_gesture(Marker::End, on_gesture)
+    }
+
+    /// Sets the message to emit when the user gestures the loop start
+    /// marker.
+    pub fn on_gesture_loop_start(self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.set_on_gesture(Marker::LoopStart, on_gesture)
+    }
+
+    /// Sets the message to emit when the user gestures the loop end marker.
+    pub fn on_gesture_loop_end(self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.set_on_gesture(Marker::LoopEnd, on_gesture)
+    }
+
+    /// Sets the message to emit when the user gestures the crossfade
+    /// marker.
+    pub fn on_gesture_crossfade(self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.set_on_gesture(Marker::Crossfade, on_gesture)
+    }
+
+    /// Makes the markers snap to the nearest zero crossing of the sample
+    /// data while they are dragged.
+    ///
+    /// The default is `false`.
+    pub const fn snap_to_zero_crossings(mut self, snap: bool) -> Self {
+        self.snap_to_zero_crossings = snap;
+        self
+    }
+
+    /// Sets the distance in pixels from a marker within which the marker can
+    /// be grabbed.
+    ///
+    /// The default is `5.0`.
+    pub fn marker_hit_width(mut self, width: f32) -> Self {
+        self.marker_hit_width = width;
+        self
+    }
+
+    /// Enable/disable this widget.
+    ///
+    /// The default is `true`.
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the width of the [`WaveformEditor`].
+    ///
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`WaveformEditor`]: struct.WaveformEditor.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`WaveformEditor`].
+    ///
+    /// The default height is `Length::Fixed(120.0)`.
+    ///
+    /// [`WaveformEditor`]: struct.WaveformEditor.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`WaveformEditor`].
+    ///
+    /// [`WaveformEditor`]: struct.WaveformEditor.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn set_on_gesture(
+        mut self,
+        marker: Marker,
+        on_gesture: impl 'a + FnMut(Gesture) -> Message,
+    ) -> Self {
+        self.on_gestures[marker as usize] = Some(Box::new(on_gesture));
+        self
+    }
+
+    fn value(&self, marker: Marker) -> Option<f32> {
+        self.params[marker as usize].map(|param| param.normal.as_f32())
+    }
+
+    /// Returns the range a marker can be moved in without crossing the
+    /// markers it belongs with.
+    fn marker_range(&self, marker: Marker) -> (f32, f32) {
+        match marker {
+            Marker::Start => (0.0, self.value(Marker::End).unwrap_or(1.0)),
+            Marker::End => (self.value(Marker::Start).unwrap_or(0.0), 1.0),
+            Marker::LoopStart => (
+                0.0,
+                self.value(Marker::Crossfade)
+                    .or(self.value(Marker::LoopEnd))
+                    .unwrap_or(1.0),
+            ),
+            Marker::LoopEnd => (
+                self.value(Marker::Crossfade)
+                    .or(self.value(Marker::LoopStart))
+                    .unwrap_or(0.0),
+                1.0,
+            ),
+            Marker::Crossfade => (
+                self.value(Marker::LoopStart).unwrap_or(0.0),
+                self.value(Marker::LoopEnd).unwrap_or(1.0),
+            ),
+        }
+    }
+
+    /// Returns the normalized position of the zero crossing closest to
+    /// `normal`, or `normal` if there is none within `window` samples.
+    fn snap_to_zero_crossing(&self, normal: f32, window: usize) -> f32 {
+        let len = self.samples.len();
+        if len < 2 {
+            return normal;
+        }
+
+        let last = (len - 1) as f32;
+        let index = (normal * last).round() as usize;
+        let first = index.saturating_sub(window).max(1);
+        let end = (index + window).min(len - 1);
+
+        let is_zero_crossing = |i: usize| {
+            let (prev, sample) = (self.samples[i - 1], self.samples[i]);
+            (prev <= 0.0 && sample > 0.0) || (prev >= 0.0 && sample < 0.0)
+        };
+
+        (first..=end)
+            .filter(|i| is_zero_crossing(*i))
+            .min_by_key(|i| i.abs_diff(index))
+            .map_or(normal, |i| i as f32 / last)
+    }
+
+    /// Returns the marker closest to `x` within the hit width.
+    fn marker_at(&self, bounds: &Rectangle, view: &View, x: f32) -> Option<Marker> {
+        MARKERS
+            .iter()
+            .filter_map(|marker| {
+                self.value(*marker)
+                    .map(|value| (*marker, (view.x(bounds, value) - x).abs()))
+            })
+            .filter(|(_, distance)| *distance <= self.marker_hit_width)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(marker, _)| marker)
+    }
+
+    fn set_marker(
+        &mut self,
+        marker: Marker,
+        value: f32,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let (min, max) = self.marker_range(marker);
+        let i = marker as usize;
+
+        match &mut self.params[i] {
+            Some(param) => set_param_value(
+                param,
+                Normal::new(value.max(min).min(max)),
+                &mut state.last_sent_gestures[i],
+                &mut self.on_gestures[i],
+                shell,
+            ),
+            None => false,
+        }
+    }
+
+    fn end_gesture(&mut self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        state.drag = None;
+
+        for (last_sent_gesture, on_gesture) in state
+            .last_sent_gestures
+            .iter_mut()
+            .zip(self.on_gestures.iter_mut())
+        {
+            if *last_sent_gesture != Gesture::GestureEnd {
+                if let Some(on_gesture) = on_gesture {
+                    shell.publish((on_gesture)(Gesture::GestureEnd));
+                }
+                *last_sent_gesture = Gesture::GestureEnd;
+            }
+        }
+    }
+}
+
+/// The visible part of the sample data, in normalized positions.
+#[derive(Debug, Copy, Clone)]
+struct View {
+    start: f32,
+    len: f32,
+}
+
+impl View {
+    fn x(&self, bounds: &Rectangle, normal: f32) -> f32 {
+        bounds.x + (normal - self.start) / self.len * bounds.width
+    }
+
+    fn normal_at(&self, bounds: &Rectangle, x: f32) -> f32 {
+        if bounds.width > 0.0 {
+            self.start + (x - bounds.x) / bounds.width * self.len
+        } else {
+            self.start
+        }
+    }
+
+    fn scroll_to(&mut self, start: f32) {
+        self.start = start.clamp(0.0, 1.0 - self.len);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Drag {
+    /// Dragging a marker, with the offset from the marker to where it was
+    /// grabbed.
+    Marker { marker: Marker, grab_offset: f32 },
+    /// Moving through the waveform.
+    Scroll { grab_x: f32, view_start: f32 },
+}
+
+struct State {
+    last_sent_gestures: [Gesture; 5],
+    drag: Option<Drag>,
+    view: View,
+    hovered: bool,
+    pressed_modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+    /// The width of the back border as last drawn, so the cursor can be
+    /// mapped to the waveform inside of it.
+    border_width: Cell<f32>,
+    /// The pixel scroll that has not added up to a whole line yet.
+    scroll_pixels: f32,
+}
+
+/// Returns the area inside the back border, where the waveform is drawn.
+fn inner_bounds(bounds: &Rectangle, border_width: f32) -> Rectangle {
+    Rectangle {
+        x: bounds.x.round() + border_width,
+        y: bounds.y.round() + border_width,
+        width: bounds.width.round() - (border_width * 2.0),
+        height: bounds.height.round() - (border_width * 2.0),
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for WaveformEditor<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            last_sent_gestures: [Gesture::GestureEnd; 5],
+            drag: None,
+            view: View {
+                start: 0.0,
+                len: 1.0,
+            },
+            hovered: false,
+            pressed_modifiers: keyboard::Modifiers::NONE,
+            last_click: None,
+            border_width: Cell::new(0.0),
+            scroll_pixels: 0.0,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+        let inner = inner_bounds(&bounds, state.border_width.get());
+
+        let mut capture_event = false;
+        let mut should_redraw = false;
+
+        if cursor_is_over != state.hovered {
+            state.hovered = cursor_is_over;
+            should_redraw = true;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => match state.drag {
+                Some(Drag::Marker {
+                    marker,
+                    grab_offset,
+                }) => {
+                    let mut value = state.view.normal_at(&inner, position.x) - grab_offset;
+
+                    if self.snap_to_zero_crossings && !state.pressed_modifiers.shift() {
+                        let samples_per_pixel = state.view.len
+                            * self.samples.len().saturating_sub(1) as f32
+                            / inner.width.max(1.0);
+                        let window = (samples_per_pixel * SNAP_DISTANCE).ceil().max(1.0) as usize;
+
+                        value = self.snap_to_zero_crossing(value.clamp(0.0, 1.0), window);
+                    }
+
+                    if self.set_marker(marker, value, state, shell) {
+                        should_redraw = true;
+                    }
+                    capture_event = true;
+                }
+                Some(Drag::Scroll { grab_x, view_start }) => {
+                    let delta = (position.x - grab_x) / inner.width.max(1.0) * state.view.len;
+                    state.view.scroll_to(view_start - delta);
+
+                    should_redraw = true;
+                    capture_event = true;
+                }
+                None => {}
+            },
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor_is_over && let Some(position) = cursor.position() {
+                    let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                    state.last_click = Some(click);
+
+                    match self.marker_at(&inner, &state.view, position.x) {
+                        Some(marker) => {
+                            if let mouse::click::Kind::Double = click.kind() {
+                                if let Some(param) = self.params[marker as usize] {
+                                    self.set_marker(marker, param.default.as_f32(), state, shell);
+                                }
+                                self.end_gesture(state, shell);
+                            } else if let Some(value) = self.value(marker) {
+                                state.drag = Some(Drag::Marker {
+                                    marker,
+                                    grab_offset: state.view.normal_at(&inner, position.x) - value,
+                                });
+                            }
+                        }
+                        None => {
+                            state.drag = Some(Drag::Scroll {
+                                grab_x: position.x,
+                                view_start: state.view.start,
+                            });
+                        }
+                    }
+
+                    should_redraw = true;
+                    capture_event = true;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.is_some() {
+                    should_redraw = true;
+                    capture_event = true;
+                }
+                self.end_gesture(state, shell);
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if cursor_is_over && let Some(position) = cursor.position() {
+                    let lines = match delta {
+                        mouse::ScrollDelta::Lines { x, y } => {
+                            state.scroll_pixels = 0.0;
+
+                            if *x != 0.0 { -*x } else { *y }
+                        }
+                        mouse::ScrollDelta::Pixels { x, y } => {
+                            state.scroll_pixels += if *x != 0.0 { -*x } else { *y };
+
+                            let lines = (state.scroll_pixels / PIXELS_PER_LINE).trunc();
+                            state.scroll_pixels -= lines * PIXELS_PER_LINE;
+                            lines
+                        }
+                    };
+
+                    if lines != 0.0 {
+                        let view = &mut state.view;
+
+                        if state.pressed_modifiers.command() {
+                            let min_len =
+                                (MIN_VISIBLE_SAMPLES / self.samples.len().max(1) as f32).min(1.0);
+                            let anchor = view.normal_at(&inner, position.x);
+                            let len = (view.len * ZOOM_FACTOR.powf(lines)).clamp(min_len, 1.0);
+
+                            let start = anchor - (anchor - view.start) * (len / view.len);
+                            view.len = len;
+                            view.scroll_to(start);
+                        } else {
+                            view.scroll_to(view.start - lines * SCROLL_AMOUNT * view.len);
+                        }
+
+                        should_redraw = true;
+                    }
+
+                    capture_event = true;
+                }
+            }
+            Event::Window(window::Event::Unfocused) => {
+                self.end_gesture(state, shell);
+            }
+            Event::Keyboard(keyboard_event) => match keyboard_event {
+                keyboard::Event::KeyPressed { modifiers, .. }
+                | keyboard::Event::KeyReleased { modifiers, .. }
+                | keyboard::Event::ModifiersChanged(modifiers) => {
+                    state.pressed_modifiers = *modifiers;
+                }
+            },
+            _ => {}
+        }
+
+        if capture_event {
+            shell.capture_event();
+        }
+
+        if should_redraw {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+
+        let appearance = if !self.enabled {
+            theme.disabled(&self.style)
+        } else if matches!(state.drag, Some(Drag::Marker { .. })) {
+            theme.gesturing(&self.style)
+        } else if cursor_is_over {
+            theme.hovered(&self.style)
+        } else {
+            theme.idle(&self.style)
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        state.border_width.set(appearance.back_border_width);
+        let inner = inner_bounds(&bounds, appearance.back_border_width);
+
+        if inner.width <= 0.0 || inner.height <= 0.0 {
+            return;
+        }
+
+        let view = state.view;
+        let center_y = inner.y + inner.height / 2.0;
+        let half_height = inner.height / 2.0;
+
+        let fill = |renderer: &mut Renderer, x: f32, y: f32, width: f32, height: f32, color| {
+            if width > 0.0 && height > 0.0 {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle {
+                            x,
+                            y,
+                            width,
+                            height,
+                        },
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                        snap: false,
+                    },
+                    color,
+                );
+            }
+        };
+
+        // Fills the region between two normalized positions.
+        let fill_region = |renderer: &mut Renderer, from: f32, to: f32, color| {
+            let from_x = view.x(&inner, from).max(inner.x);
+            let to_x = view.x(&inner, to).min(inner.x + inner.width);
+            fill(
+                renderer,
+                from_x,
+                inner.y,
+                to_x - from_x,
+                inner.height,
+                color,
+            );
+        };
+
+        renderer.with_layer(inner, |renderer| {
+            if let (Some(loop_start), Some(loop_end)) =
+                (self.value(Marker::LoopStart), self.value(Marker::LoopEnd))
+            {
+                fill_region(renderer, loop_start, loop_end, appearance.loop_color);
+
+                if let Some(crossfade) = self.value(Marker::Crossfade) {
+                    fill_region(renderer, crossfade, loop_end, appearance.crossfade_color);
+                }
+            }
+
+            if let Some(center_line_color) = appearance.center_line_color {
+                fill(
+                    renderer,
+                    inner.x,
+                    center_y.round(),
+                    inner.width,
+                    1.0,
+                    center_line_color,
+                );
+            }
+
+            if self.samples.len() >= 2 {
+                let last = self.samples.len() - 1;
+
+                for column in 0..inner.width as usize {
+                    let from = view.normal_at(&inner, inner.x + column as f32) * last as f32;
+                    let to = view.normal_at(&inner, inner.x + column as f32 + 1.0) * last as f32;
+
+                    let first = (from.floor().max(0.0) as usize).min(last);
+                    let end = (to.ceil().max(0.0) as usize).min(last);
+
+                    let (min, max) = self.samples[first..=end]
+                        .iter()
+                        .fold((f32::MAX, f32::MIN), |(min, max), sample| {
+                            (min.min(*sample), max.max(*sample))
+                        });
+                    let (min, max) = (min.clamp(-1.0, 1.0), max.clamp(-1.0, 1.0));
+
+                    let top = center_y - max * half_height;
+                    let height = ((max - min) * half_height).max(1.0);
+
+                    fill(
+                        renderer,
+                        inner.x + column as f32,
+                        top,
+                        1.0,
+                        height,
+                        appearance.waveform_color,
+                    );
+                }
+            }
+
+            if let (Some(start), Some(end)) = (self.value(Marker::Start), self.value(Marker::End)) {
+                fill_region(renderer, 0.0, start, appearance.outside_color);
+                fill_region(renderer, end, 1.0, appearance.outside_color);
+            }
+
+            for marker in MARKERS {
+                let Some(value) = self.value(marker) else {
+                    continue;
+                };
+
+                let style = match marker {
+                    Marker::Start => appearance.start_marker,
+                    Marker::End => appearance.end_marker,
+                    Marker::LoopStart | Marker::LoopEnd => appearance.loop_marker,
+                    Marker::Crossfade => appearance.crossfade_marker,
+                };
+
+                let x = view.x(&inner, value).round();
+                let flag = style.flag_size;
+
+                fill(
+                    renderer,
+                    x - (style.width / 2.0).round(),
+                    inner.y,
+                    style.width,
+                    inner.height,
+                    style.color,
+                );
+
+                // Flags point inwards, away from the region they bound.
+                let (flag_x, flag_y) = match marker {
+                    Marker::Start => (x, inner.y),
+                    Marker::End => (x - flag, inner.y),
+                    Marker::LoopStart => (x, inner.y + inner.height - flag),
+                    Marker::LoopEnd => (x - flag, inner.y + inner.height - flag),
+                    Marker::Crossfade => (x - flag, center_y - flag / 2.0),
+                };
+                fill(renderer, flag_x, flag_y, flag, flag, style.color);
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<WaveformEditor<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer,
+{
+    fn from(waveform_editor: WaveformEditor<'a, Message, Theme>) -> Self {
+        Self::new(waveform_editor)
+    }
+}
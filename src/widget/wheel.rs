@@ -0,0 +1,508 @@
+//! Display a pitch bend / modulation wheel that controls a [`NormalParam`].
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+use std::f32::consts::{FRAC_PI_2, PI};
+use std::time::{Duration, Instant};
+
+use crate::core::{
+    Normal, NormalParam,
+    virtual_slider::{self, Gesture, VirtualSlider},
+};
+use iced_core::{
+    Background, Border, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shadow, Shell,
+    Size, Widget,
+    border::Radius,
+    gradient::Linear,
+    layout, mouse,
+    renderer::{Quad, Style},
+    widget::{Tree, tree},
+    window,
+};
+
+pub use crate::style::wheel::{Appearance, CenterMarker, StyleSheet, WheelNotch};
+
+const DEFAULT_WIDTH: f32 = 24.0;
+const DEFAULT_HEIGHT: f32 = 100.0;
+const DEFAULT_RETURN_TIME_SECS: f32 = 0.15;
+
+/// How far (in radians) the wheel turns over the full range of the parameter.
+const ROTATION_SPAN: f32 = PI * 5.0 / 6.0;
+
+/// The longest time step the spring is advanced by in a single frame, so a
+/// stalled frame doesn't make the wheel jump straight to its rest position.
+const MAX_SPRING_STEP_SECS: f32 = 1.0 / 30.0;
+
+const SPRING_SETTLED_DISTANCE: f32 = 0.001;
+const SPRING_SETTLED_VELOCITY: f32 = 0.01;
+
+/// How a [`Wheel`] behaves when the user lets go of it.
+///
+/// [`Wheel`]: struct.Wheel.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum WheelMode {
+    /// The wheel stays where it was left, like a modulation wheel.
+    #[default]
+    Free,
+    /// The wheel springs back to the default value of its parameter, like a
+    /// pitch bend wheel.
+    ///
+    /// The return is animated, and each intermediate value is emitted as a
+    /// [`Gesture::Gesturing`] inside its own gesture.
+    Spring,
+}
+
+/// A pitch bend / modulation wheel GUI widget that controls a [`NormalParam`].
+///
+/// The wheel is dragged vertically, and is drawn as a ridged cylinder that
+/// turns with the value of the parameter.
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`Wheel`]: struct.Wheel.html
+#[allow(missing_debug_implementations)]
+pub struct Wheel<'a, Message, Theme: StyleSheet> {
+    virtual_slider: VirtualSlider<'a, Message>,
+    mode: WheelMode,
+    return_time: Duration,
+    bipolar_center: Option<Normal>,
+    enabled: bool,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme: StyleSheet> Wheel<'a, Message, Theme> {
+    /// Creates a new [`Wheel`].
+    ///
+    /// It expects:
+    ///   * `normal_param` - The normalized value of the parameter.
+    ///
+    /// [`Wheel`]: struct.Wheel.html
+    pub fn new(normal_param: impl Into<NormalParam>) -> Self
+    where
+        <Theme as StyleSheet>::Style: Default,
+    {
+        Wheel {
+            virtual_slider: VirtualSlider::new(normal_param.into()),
+            mode: WheelMode::default(),
+            return_time: Duration::from_secs_f32(DEFAULT_RETURN_TIME_SECS),
+            bipolar_center: None,
+            enabled: true,
+            width: Length::Fixed(DEFAULT_WIDTH),
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the message to emit when the user gestures this widget, or when
+    /// the wheel springs back to its rest position.
+    pub fn on_gesture(mut self, on_gesture: impl 'a + FnMut(Gesture) -> Message) -> Self {
+        self.virtual_slider.set_on_gesture(on_gesture);
+        self
+    }
+
+    /// Set a custom configuration to use for this virtual slider.
+    pub fn config(mut self, config: &virtual_slider::Config) -> Self {
+        self.virtual_slider.config = *config;
+        self
+    }
+
+    /// Sets how the [`Wheel`] behaves when the user lets go of it.
+    ///
+    /// The default is [`WheelMode::Free`].
+    ///
+    /// [`Wheel`]: struct.Wheel.html
+    pub const fn mode(mut self, mode: WheelMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets roughly how long it takes a [`Wheel`] in [`WheelMode::Spring`]
+    /// to return to its rest position.
+    ///
+    /// The default is 150 milliseconds.
+    ///
+    /// [`Wheel`]: struct.Wheel.html
+    pub const fn return_time(mut self, return_time: Duration) -> Self {
+        self.return_time = return_time;
+        self
+    }
+
+    /// Marks the [`Wheel`] as bipolar, drawing the center markers on the
+    /// housing at the position of the given value.
+    ///
+    /// This is usually the default value of the parameter (i.e. `0.5` for a
+    /// pitch bend wheel).
+    ///
+    /// [`Wheel`]: struct.Wheel.html
+    pub const fn bipolar_center(mut self, center: Normal) -> Self {
+        self.bipolar_center = Some(center);
+        self
+    }
+
+    /// Enable/disable this widget.
+    ///
+    /// The default is `true`.
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the width of the [`Wheel`].
+    /// The default width is `Length::Fixed(24.0)`.
+    ///
+    /// [`Wheel`]: struct.Wheel.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Wheel`].
+    /// The default height is `Length::Fixed(100.0)`.
+    ///
+    /// [`Wheel`]: struct.Wheel.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`Wheel`].
+    ///
+    /// [`Wheel`]: struct.Wheel.html
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// A critically damped spring pulling the wheel back to its rest position.
+struct Spring {
+    position: f32,
+    velocity: f32,
+    last_instant: Option<Instant>,
+}
+
+impl Spring {
+    /// Advances the spring by `dt` seconds towards `target` using the exact
+    /// solution of a critically damped oscillator. Returns `true` once the
+    /// spring has settled.
+    fn step(&mut self, target: f32, return_time: f32, dt: f32) -> bool {
+        // A critically damped spring covers ~99% of the distance in 5 / omega.
+        let omega = 5.0 / return_time.max(f32::EPSILON);
+
+        let displacement = self.position - target;
+        let decay = (-omega * dt).exp();
+        let tmp = (self.velocity + omega * displacement) * dt;
+
+        self.position = target + (displacement + tmp) * decay;
+        self.velocity = (self.velocity - omega * tmp) * decay;
+
+        (self.position - target).abs() < SPRING_SETTLED_DISTANCE
+            && self.velocity.abs() < SPRING_SETTLED_VELOCITY
+    }
+}
+
+struct State {
+    slider: virtual_slider::State,
+    spring: Option<Spring>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Wheel<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+    Renderer: iced_core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            slider: virtual_slider::State::new(self.virtual_slider.param().normal),
+            spring: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let cursor_is_over = cursor.is_over(layout.bounds());
+        let was_gesturing = state.slider.is_gesturing();
+
+        let mut should_redraw = self
+            .virtual_slider
+            .update(
+                &mut state.slider,
+                cursor_is_over,
+                false,
+                false,
+                event,
+                cursor,
+                shell,
+            )
+            .should_redraw();
+
+        // Grabbing the wheel while it is springing back takes over the
+        // current gesture.
+        if state.slider.is_dragging() {
+            state.spring = None;
+        }
+
+        let param = *self.virtual_slider.param();
+
+        if self.mode == WheelMode::Spring
+            && was_gesturing
+            && !state.slider.is_gesturing()
+            && param.normal != param.default
+        {
+            state.spring = Some(Spring {
+                position: param.normal.as_f32(),
+                velocity: 0.0,
+                last_instant: None,
+            });
+            shell.request_redraw();
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event
+            && let Some(spring) = &mut state.spring
+        {
+            let settled = match spring.last_instant {
+                Some(last_instant) => {
+                    let dt = now
+                        .saturating_duration_since(last_instant)
+                        .as_secs_f32()
+                        .min(MAX_SPRING_STEP_SECS);
+
+                    spring.step(param.default.as_f32(), self.return_time.as_secs_f32(), dt)
+                }
+                None => false,
+            };
+            spring.last_instant = Some(*now);
+
+            if settled {
+                state.spring = None;
+
+                let status =
+                    self.virtual_slider
+                        .set_normal(&mut state.slider, param.default, shell);
+                should_redraw |= status.should_redraw();
+
+                let status = self.virtual_slider.finish_gesture(&mut state.slider, shell);
+                should_redraw |= status.should_redraw();
+            } else {
+                let status = self.virtual_slider.set_normal(
+                    &mut state.slider,
+                    Normal::new(spring.position),
+                    shell,
+                );
+                should_redraw |= status.should_redraw();
+
+                shell.request_redraw();
+            }
+        }
+
+        if should_redraw {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+
+        let bounds = layout.bounds();
+        let cursor_is_over = cursor.is_over(bounds);
+        let normal = self.virtual_slider.param().normal;
+
+        let appearance = if !self.enabled {
+            theme.disabled(&self.style)
+        } else if state.slider.is_gesturing() {
+            theme.gesturing(&self.style)
+        } else if cursor_is_over {
+            theme.hovered(&self.style)
+        } else {
+            theme.idle(&self.style)
+        };
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.back_border_color,
+                    width: appearance.back_border_width,
+                    radius: Radius::new(appearance.back_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            appearance.back_color,
+        );
+
+        let inset = appearance.back_border_width + appearance.wheel_padding;
+        let wheel_bounds = Rectangle {
+            x: bounds.x + inset,
+            y: bounds.y + inset,
+            width: (bounds.width - inset * 2.0).max(0.0),
+            height: (bounds.height - inset * 2.0).max(0.0),
+        };
+
+        // Shade the wheel so it looks like a cylinder facing the viewer.
+        let shading = Linear::new(0.0)
+            .add_stop(0.0, appearance.wheel_edge_color)
+            .add_stop(0.5, appearance.wheel_color)
+            .add_stop(1.0, appearance.wheel_edge_color);
+
+        renderer.fill_quad(
+            Quad {
+                bounds: wheel_bounds,
+                border: Border {
+                    color: Color::TRANSPARENT,
+                    width: 0.0,
+                    radius: Radius::new(appearance.wheel_border_radius),
+                },
+                shadow: Shadow::default(),
+                snap: false,
+            },
+            Background::from(shading),
+        );
+
+        let radius = wheel_bounds.height / 2.0;
+        let center_y = wheel_bounds.center_y();
+        let rotation = (normal.as_f32() - 0.5) * ROTATION_SPAN;
+
+        // Fills a band across the wheel at the given angle on the surface of
+        // the cylinder, foreshortening it as it turns away from the viewer.
+        let mut fill_band = |angle: f32, width: f32, color: Color| {
+            if angle.abs() >= FRAC_PI_2 {
+                return;
+            }
+            let facing = angle.cos();
+            let band_height = width * facing;
+            let y = center_y - radius * angle.sin();
+
+            let band = Rectangle {
+                x: wheel_bounds.x,
+                y: y - band_height / 2.0,
+                width: wheel_bounds.width,
+                height: band_height,
+            };
+            let Some(band) = band.intersection(&wheel_bounds) else {
+                return;
+            };
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: band,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                    snap: false,
+                },
+                Color {
+                    a: color.a * facing,
+                    ..color
+                },
+            );
+        };
+
+        if appearance.ridge_count > 0 {
+            let spacing = PI / f32::from(appearance.ridge_count);
+            let first = (-FRAC_PI_2 - rotation) / spacing;
+
+            for i in
+                (first.floor() as i32)..=(first.floor() as i32 + appearance.ridge_count as i32 + 1)
+            {
+                fill_band(
+                    i as f32 * spacing + rotation,
+                    appearance.ridge_width,
+                    appearance.ridge_color,
+                );
+            }
+        }
+
+        if let Some(notch) = &appearance.notch {
+            fill_band(rotation, notch.width, notch.color);
+        }
+
+        if let (Some(center), Some(marker)) = (self.bipolar_center, &appearance.center_marker) {
+            let angle = (center.as_f32() - 0.5) * ROTATION_SPAN;
+            let y = (center_y - radius * angle.sin() - marker.width / 2.0).round();
+            let length = marker.length + appearance.wheel_padding;
+
+            for x in [
+                bounds.x + appearance.back_border_width,
+                bounds.x + bounds.width - appearance.back_border_width - length,
+            ] {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle {
+                            x,
+                            y,
+                            width: length,
+                            height: marker.width,
+                        },
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                        snap: false,
+                    },
+                    marker.color,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Wheel<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + StyleSheet,
+    Renderer: iced_core::Renderer,
+{
+    fn from(wheel: Wheel<'a, Message, Theme>) -> Self {
+        Self::new(wheel)
+    }
+}
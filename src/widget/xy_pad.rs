@@ -4,7 +4,7 @@
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
 use crate::{
-    core::{Normal, NormalParam},
+    core::{Normal, NormalParam, ParamRange},
     virtual_slider::Gesture,
 };
 use iced_core::{
@@ -18,7 +18,9 @@ use iced_core::{
     window,
 };
 
-pub use crate::style::xy_pad::{Appearance, HandleCircle, HandleShape, HandleSquare, StyleSheet};
+pub use crate::style::xy_pad::{
+    Appearance, Catalog, HandleCircle, HandleShape, HandleSquare, Status, StyleFn, StyleSheet,
+};
 
 /// A 2D XY pad GUI widget that controls two [`NormalParam`] parameters at
 /// once. One in the `x` coordinate and one in the `y` coordinate.
@@ -31,7 +33,7 @@ pub use crate::style::xy_pad::{Appearance, HandleCircle, HandleShape, HandleSqua
 #[allow(missing_debug_implementations)]
 pub struct XYPad<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     on_gesture_x: Option<Box<dyn 'a + FnMut(Gesture) -> Message>>,
     on_gesture_y: Option<Box<dyn 'a + FnMut(Gesture) -> Message>>,
@@ -39,12 +41,12 @@ where
     param_x: Option<NormalParam>,
     param_y: Option<NormalParam>,
     size: Length,
-    style: <Theme as StyleSheet>::Style,
+    class: Theme::Class<'a>,
 }
 
 impl<'a, Message, Theme> XYPad<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     /// Creates a new [`XYPad`].
     ///
@@ -56,10 +58,7 @@ where
     pub fn new(
         param_x: Option<impl Into<NormalParam>>,
         param_y: Option<impl Into<NormalParam>>,
-    ) -> Self
-    where
-        <Theme as StyleSheet>::Style: Default,
-    {
+    ) -> Self {
         XYPad {
             param_x: param_x.map(|p| p.into()),
             param_y: param_y.map(|p| p.into()),
@@ -67,10 +66,34 @@ where
             on_gesture_x: None,
             on_gesture_y: None,
             size: Length::Fill,
-            style: Default::default(),
+            class: Theme::default(),
         }
     }
 
+    /// Creates a new [`XYPad`] for two parameters in any [`ParamRange`]s.
+    ///
+    /// It expects:
+    ///   * the initial value and range of the x axis
+    ///   * the initial value and range of the y axis
+    ///
+    /// The [`default_value`] of each range is used as the default of its
+    /// parameter.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`ParamRange`]: ../../core/range/trait.ParamRange.html
+    /// [`default_value`]: ../../core/range/trait.ParamRange.html#tymethod.default_value
+    pub fn from_ranges<X: ParamRange, Y: ParamRange>(
+        value_x: X::Value,
+        range_x: &X,
+        value_y: Y::Value,
+        range_y: &Y,
+    ) -> Self {
+        Self::new(
+            Some(range_x.param(value_x, range_x.default_value())),
+            Some(range_y.param(value_y, range_y.default_value())),
+        )
+    }
+
     /// Sets the message to emit when the user gestures the X axis of this widget.
     pub fn on_gesture_x(
         mut self,
@@ -112,11 +135,24 @@ where
     /// Sets the style of the [`XYPad`].
     ///
     /// [`XYPad`]: struct.XYPad.html
-    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
-        self.style = style.into();
+    pub fn style(mut self, style: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = style.into();
         self
     }
 
+    /// Sets the style of the [`XYPad`] from a closure that produces its
+    /// [`Appearance`] for each [`Status`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Appearance`]: struct.Appearance.html
+    /// [`Status`]: enum.Status.html
+    pub fn style_fn(self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.style(Box::new(style) as StyleFn<'a, Theme>)
+    }
+
     /// Returns `true` if any param value has changed.
     fn set_param_values(
         &mut self,
@@ -225,7 +261,7 @@ impl State {
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for XYPad<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
     Renderer: iced_core::Renderer,
 {
     fn tag(&self) -> tree::Tag {
@@ -393,13 +429,8 @@ where
         let bounds = layout.bounds();
         let cursor_is_over = cursor.is_over(layout.bounds());
 
-        let appearance = if state.is_dragging {
-            theme.gesturing(&self.style)
-        } else if cursor_is_over {
-            theme.hovered(&self.style)
-        } else {
-            theme.idle(&self.style)
-        };
+        let status = Status::new(self.enabled, state.is_dragging, cursor_is_over);
+        let appearance = Catalog::style(theme, &self.class, status);
 
         let bounds_x = bounds.x.floor();
         let bounds_y = bounds.y.floor();
@@ -588,7 +619,7 @@ impl<'a, Message, Theme, Renderer> From<XYPad<'a, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a,
-    Theme: 'a + StyleSheet,
+    Theme: 'a + Catalog,
     Renderer: iced_core::Renderer,
 {
     fn from(xy_pad: XYPad<'a, Message, Theme>) -> Self {